@@ -0,0 +1,151 @@
+//! Benchmarks of the core operations.
+//!
+//! All fixtures are generated deterministically, so results of different revisions can be compared
+//! using criterions baselines:
+//!
+//! ```text
+//! cargo bench --bench ops -- --save-baseline main
+//! # ... apply changes ...
+//! cargo bench --bench ops -- --baseline main
+//! ```
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use geo_types::{coord, Geometry, LineString, Polygon};
+use h3arrow::algorithm::{CompactOp, GridOp};
+use h3arrow::array::from_geo::{geometry_to_cells, ToCellsOptions};
+use h3arrow::array::CellIndexArray;
+use h3o::{LatLng, Resolution};
+
+/// Coordinates on a regular lattice around (lat 45, lng 10).
+fn lattice_coordinates(n: usize) -> Vec<(f64, f64)> {
+    let side = (n as f64).sqrt().ceil() as usize;
+    (0..n)
+        .map(|i| {
+            let (row, col) = (i / side, i % side);
+            (
+                45.0 + row as f64 / side as f64,
+                10.0 + col as f64 / side as f64,
+            )
+        })
+        .collect()
+}
+
+/// Densely located coordinates within a square of 0.01 degrees around (lat 45, lng 10),
+/// distributed using a low-discrepancy sequence - similar to the GPS fixes within a city.
+fn dense_coordinates(n: usize) -> Vec<(f64, f64)> {
+    const GOLDEN: f64 = 0.618_033_988_749_895;
+    (0..n)
+        .map(|i| {
+            let i = i as f64;
+            (
+                45.0 + 0.01 * (i * GOLDEN).fract(),
+                10.0 + 0.01 * (i / n as f64),
+            )
+        })
+        .collect()
+}
+
+/// A regular 64-gon around (lat 45, lng 10) with a radius of about 0.5 degrees.
+fn circle_polygon() -> Geometry {
+    let exterior: LineString = (0..=64)
+        .map(|i| {
+            let angle = (i as f64 / 64.0) * std::f64::consts::TAU;
+            coord! { x: 10.0 + 0.5 * angle.cos(), y: 45.0 + 0.5 * angle.sin() }
+        })
+        .collect();
+    Polygon::new(exterior, vec![]).into()
+}
+
+fn latlng_to_cell(c: &mut Criterion) {
+    let coordinates = lattice_coordinates(100_000);
+    let mut group = c.benchmark_group("latlng_to_cell");
+    group.throughput(Throughput::Elements(coordinates.len() as u64));
+    group.bench_function("res9", |b| {
+        b.iter(|| {
+            coordinates
+                .iter()
+                .map(|(lat, lng)| {
+                    LatLng::new(*lat, *lng)
+                        .ok()
+                        .map(|ll| ll.to_cell(Resolution::Nine))
+                })
+                .collect::<CellIndexArray>()
+        })
+    });
+
+    let coordinates = dense_coordinates(100_000);
+    group.bench_function("dense_res9", |b| {
+        b.iter(|| {
+            coordinates
+                .iter()
+                .map(|(lat, lng)| {
+                    LatLng::new(*lat, *lng)
+                        .ok()
+                        .map(|ll| ll.to_cell(Resolution::Nine))
+                })
+                .collect::<CellIndexArray>()
+        })
+    });
+    #[cfg(feature = "lut")]
+    group.bench_function("dense_res9_lut", |b| {
+        b.iter(|| {
+            let mut lut = h3arrow::algorithm::latlng_lut::LatLngLut::new(Resolution::Nine);
+            coordinates
+                .iter()
+                .map(|(lat, lng)| LatLng::new(*lat, *lng).ok().map(|ll| lut.to_cell(ll)))
+                .collect::<CellIndexArray>()
+        })
+    });
+    group.finish();
+}
+
+fn polyfill(c: &mut Criterion) {
+    let polygon = circle_polygon();
+    let mut group = c.benchmark_group("polyfill");
+    for resolution in [Resolution::Six, Resolution::Eight] {
+        let options = ToCellsOptions::new(resolution);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(resolution),
+            &options,
+            |b, options| b.iter(|| geometry_to_cells(black_box(&polygon), options).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+fn grid_disk(c: &mut Criterion) {
+    let cells: CellIndexArray = lattice_coordinates(10_000)
+        .into_iter()
+        .map(|(lat, lng)| LatLng::new(lat, lng).unwrap().to_cell(Resolution::Nine))
+        .collect::<Vec<_>>()
+        .into();
+    let mut group = c.benchmark_group("grid_disk");
+    group.throughput(Throughput::Elements(cells.len() as u64));
+    for k in [1, 3] {
+        group.bench_with_input(BenchmarkId::from_parameter(k), &k, |b, k| {
+            b.iter(|| cells.grid_disk::<i64>(*k).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn compaction(c: &mut Criterion) {
+    let cells: CellIndexArray =
+        geometry_to_cells(&circle_polygon(), &ToCellsOptions::new(Resolution::Eight))
+            .unwrap()
+            .into();
+    let mut group = c.benchmark_group("compaction");
+    group.throughput(Throughput::Elements(cells.len() as u64));
+    group.bench_function("compact", |b| b.iter(|| cells.compact().unwrap()));
+    group.bench_function("compact_mixed_resolutions", |b| {
+        b.iter(|| cells.compact_mixed_resolutions().unwrap())
+    });
+
+    let compacted = cells.compact().unwrap();
+    group.bench_function("uncompact", |b| {
+        b.iter(|| compacted.uncompact(Resolution::Eight))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, latlng_to_cell, polyfill, grid_disk, compaction);
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+//! Adjacency graph of labeled regions of cells.
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::{UInt32Array, UInt64Array};
+use h3o::CellIndex;
+
+/// Edge list of a region adjacency graph.
+pub struct RegionAdjacency {
+    pub labels_a: UInt32Array,
+    pub labels_b: UInt32Array,
+
+    /// Number of pairs of neighboring cells between both regions.
+    pub counts: UInt64Array,
+}
+
+/// Build the adjacency graph of the regions formed by the cells sharing the same label.
+///
+/// Every pair of neighboring cells with different labels contributes to the edge between both
+/// labels. Each edge is reported once with `labels_a < labels_b`, the edges are ordered by their
+/// labels. Cells without a label are skipped. All cells must be of the same resolution; a cell
+/// given more than once keeps its last label.
+pub fn region_adjacency(
+    cells: &CellIndexArray,
+    labels: &UInt32Array,
+) -> Result<RegionAdjacency, Error> {
+    if cells.len() != labels.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    let mut regions: HashMap<CellIndex, u32> = HashMap::with_capacity(cells.len());
+    let mut resolution = None;
+    for (cell, label) in cells.iter().zip(labels.iter()) {
+        if let (Some(cell), Some(label)) = (cell, label) {
+            if *resolution.get_or_insert(cell.resolution()) != cell.resolution() {
+                return Err(Error::MixedResolutions);
+            }
+            regions.insert(cell, label);
+        }
+    }
+
+    let mut edges: HashMap<(u32, u32), u64> = HashMap::new();
+    for (cell, label) in regions.iter() {
+        for neighbor in cell.grid_disk::<Vec<_>>(1) {
+            // visit each pair of cells only once
+            if neighbor <= *cell {
+                continue;
+            }
+            match regions.get(&neighbor) {
+                Some(neighbor_label) if neighbor_label != label => {
+                    let key = if label < neighbor_label {
+                        (*label, *neighbor_label)
+                    } else {
+                        (*neighbor_label, *label)
+                    };
+                    *edges.entry(key).or_insert(0) += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort_unstable();
+    Ok(RegionAdjacency {
+        labels_a: edges
+            .iter()
+            .map(|((a, _), _)| *a)
+            .collect::<Vec<_>>()
+            .into(),
+        labels_b: edges
+            .iter()
+            .map(|((_, b), _)| *b)
+            .collect::<Vec<_>>()
+            .into(),
+        counts: edges
+            .iter()
+            .map(|(_, count)| *count)
+            .collect::<Vec<_>>()
+            .into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn center_and_ring() {
+        let center = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let disk: Vec<_> = center.grid_disk_distances::<Vec<_>>(2);
+        let cells: CellIndexArray = disk
+            .iter()
+            .map(|(cell, _)| *cell)
+            .collect::<Vec<_>>()
+            .into();
+        // label 0 for the center, 1 for the first ring and 2 for the second ring
+        let labels: UInt32Array = disk.iter().map(|(_, k)| *k).collect::<Vec<_>>().into();
+
+        let adjacency = region_adjacency(&cells, &labels).unwrap();
+        assert_eq!(adjacency.labels_a.values().to_vec(), vec![0, 1]);
+        assert_eq!(adjacency.labels_b.values().to_vec(), vec![1, 2]);
+        // the center touches all six cells of the first ring, each cell of the first ring
+        // touches three cells of the second ring
+        assert_eq!(adjacency.counts.value(0), 6);
+        assert_eq!(adjacency.counts.value(1), 18);
+    }
+}
@@ -1,7 +1,10 @@
 use crate::array::to_geo::{ToLines, ToPoints, ToPolygons};
 use crate::array::{CellIndexArray, DirectedEdgeIndexArray, VertexIndexArray};
+use arrow::array::BooleanArray;
 use geo::bounding_rect::BoundingRect;
-use geo_types::Rect;
+use geo::Intersects;
+use geo_types::{Coord, LineString, Polygon, Rect};
+use h3o::{CellIndex, LatLng, Resolution};
 
 impl BoundingRect<f64> for CellIndexArray {
     type Output = Option<Rect>;
@@ -57,4 +60,443 @@ where
     })
 }
 
+/// Bounds in degrees which are aware of the antimeridian.
+///
+/// Following the convention of RFC 7946 (GeoJSON), `west` is larger than `east` when the bounds
+/// cross the antimeridian. Bounds containing a pole span all longitudes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoBounds {
+    pub west: f64,
+    pub south: f64,
+    pub east: f64,
+    pub north: f64,
+}
+
+impl GeoBounds {
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// The bounds as rectangles - split into two at the antimeridian when crossing it.
+    fn to_rects(self) -> Vec<Rect> {
+        if self.crosses_antimeridian() {
+            vec![
+                Rect::new((self.west, self.south), (180.0, self.north)),
+                Rect::new((-180.0, self.south), (self.east, self.north)),
+            ]
+        } else {
+            vec![Rect::new((self.west, self.south), (self.east, self.north))]
+        }
+    }
+}
+
+/// The bounds of a single cell.
+pub fn cell_geo_bounds(cell: CellIndex) -> GeoBounds {
+    let boundary = cell.boundary();
+    let mut vertices = boundary.iter();
+    let first = vertices.next().expect("cell boundary");
+
+    // unwrap the longitudes relative to the first vertex to get a continuous ring.
+    let (mut south, mut north) = (first.lat(), first.lat());
+    let (mut west, mut east) = (first.lng(), first.lng());
+    let mut lng = first.lng();
+    let mut prev = first.lng();
+    for ll in vertices.chain(std::iter::once(first)) {
+        lng += normalize_lng(ll.lng() - prev);
+        prev = ll.lng();
+        west = west.min(lng);
+        east = east.max(lng);
+        south = south.min(ll.lat());
+        north = north.max(ll.lat());
+    }
+
+    // the ring winds around a pole when the unwrapped longitude does not return to the start.
+    let winding = lng - first.lng();
+    if winding.abs() > 180.0 {
+        return if north > 0.0 {
+            GeoBounds {
+                west: -180.0,
+                south,
+                east: 180.0,
+                north: 90.0,
+            }
+        } else {
+            GeoBounds {
+                west: -180.0,
+                south: -90.0,
+                east: 180.0,
+                north,
+            }
+        };
+    }
+
+    GeoBounds {
+        west: normalize_lng(west),
+        south,
+        east: normalize_lng(east),
+        north,
+    }
+}
+
+/// The smallest bounds containing all cells of the array. `None` for arrays without any valid
+/// cells.
+///
+/// In contrast to the `BoundingRect` implementation, which covers all longitudes between the
+/// westernmost and easternmost cell, the longitudinal extent is the smallest one containing
+/// all cells - which may cross the antimeridian.
+pub fn coverage_geo_bounds(cells: &CellIndexArray) -> Option<GeoBounds> {
+    let mut south = f64::INFINITY;
+    let mut north = f64::NEG_INFINITY;
+    // longitude intervals with start <= end, split at the antimeridian.
+    let mut intervals: Vec<(f64, f64)> = vec![];
+    for cell in cells.iter().flatten() {
+        let bounds = cell_geo_bounds(cell);
+        south = south.min(bounds.south);
+        north = north.max(bounds.north);
+        if bounds.crosses_antimeridian() {
+            intervals.push((bounds.west, 180.0));
+            intervals.push((-180.0, bounds.east));
+        } else {
+            intervals.push((bounds.west, bounds.east));
+        }
+    }
+    if intervals.is_empty() {
+        return None;
+    }
+
+    intervals.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+    let mut merged: Vec<(f64, f64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    // the bounds are the complement of the largest gap between the intervals - including the gap
+    // across the antimeridian.
+    let first = merged[0];
+    let last = merged[merged.len() - 1];
+    let mut largest_gap = (last.1, first.0 + 360.0);
+    for window in merged.windows(2) {
+        let gap = (window[0].1, window[1].0);
+        if gap.1 - gap.0 > largest_gap.1 - largest_gap.0 {
+            largest_gap = gap;
+        }
+    }
+
+    let (west, east) = if largest_gap.1 - largest_gap.0 <= 0.0 {
+        (-180.0, 180.0)
+    } else {
+        (normalize_lng(largest_gap.1), normalize_lng(largest_gap.0))
+    };
+    Some(GeoBounds {
+        west,
+        south,
+        east,
+        north,
+    })
+}
+
+/// Select the cells within the bounds using a vectorized test of the cell centroids.
+///
+/// With `intersecting` set, cells whose centroid is outside of the bounds are selected when their
+/// boundary intersects the bounds. The returned mask is aligned with `cells` and null for
+/// invalid/empty values.
+pub fn cells_in_geo_bounds(
+    cells: &CellIndexArray,
+    bounds: GeoBounds,
+    intersecting: bool,
+) -> BooleanArray {
+    let rects = bounds.to_rects();
+    cells
+        .iter()
+        .map(|cell| {
+            cell.map(|cell| {
+                let ll = LatLng::from(cell);
+                rects.iter().any(|rect| {
+                    (rect.min().x..=rect.max().x).contains(&ll.lng())
+                        && (rect.min().y..=rect.max().y).contains(&ll.lat())
+                }) || (intersecting && cell_intersects_rects(cell, &rects))
+            })
+        })
+        .collect()
+}
+
+/// All cells of the given resolution intersecting the bounds - for example to partition a region
+/// into parent cells to be processed independently.
+///
+/// The cells are found by descending the hierarchy from the base cells while skipping the ones whose
+/// descendants can not reach the bounds, so no polygon of the bounds is tiled. With bounds spanning
+/// the whole globe this returns all cells of the resolution.
+pub fn cells_intersecting_geo_bounds(bounds: GeoBounds, resolution: Resolution) -> CellIndexArray {
+    let rects = bounds.to_rects();
+    let mut cells = vec![];
+    let mut stack: Vec<CellIndex> = CellIndex::base_cells().collect();
+    while let Some(cell) = stack.pop() {
+        if cell.resolution() == resolution {
+            if cell_intersects_rects(cell, &rects) {
+                cells.push(cell);
+            }
+            continue;
+        }
+        // descendants protrude slightly beyond the boundary of their ancestors, the margin of
+        // one edge length of the ancestor covers this.
+        let margin_deg = cell.resolution().edge_length_m() / METERS_PER_DEGREE;
+        let reach = expand_geo_bounds(cell_geo_bounds(cell), margin_deg).to_rects();
+        if reach
+            .iter()
+            .any(|reach_rect| rects.iter().any(|rect| reach_rect.intersects(rect)))
+        {
+            if let Some(child_resolution) = cell.resolution().succ() {
+                stack.extend(cell.children(child_resolution));
+            }
+        }
+    }
+    cells.sort_unstable();
+    cells.into()
+}
+
+/// Length of a degree of latitude on the sphere used by h3.
+const METERS_PER_DEGREE: f64 = 6_371_007.180_918_475 * std::f64::consts::PI / 180.0;
+
+/// Grow the bounds by `margin_deg` degrees of latitude in all directions.
+fn expand_geo_bounds(bounds: GeoBounds, margin_deg: f64) -> GeoBounds {
+    let south = (bounds.south - margin_deg).max(-90.0);
+    let north = (bounds.north + margin_deg).min(90.0);
+    let max_lat = south.abs().max(north.abs());
+    let width = if bounds.crosses_antimeridian() {
+        bounds.east - bounds.west + 360.0
+    } else {
+        bounds.east - bounds.west
+    };
+    // the degrees of longitude covering the margin grow towards the poles
+    let margin_lng = margin_deg / max_lat.to_radians().cos();
+    if max_lat >= 89.0 || width + 2.0 * margin_lng >= 360.0 {
+        return GeoBounds {
+            west: -180.0,
+            south,
+            east: 180.0,
+            north,
+        };
+    }
+    GeoBounds {
+        west: normalize_lng(bounds.west - margin_lng),
+        south,
+        east: normalize_lng(bounds.east + margin_lng),
+        north,
+    }
+}
+
+fn cell_intersects_rects(cell: CellIndex, rects: &[Rect]) -> bool {
+    let cell_bounds = cell_geo_bounds(cell);
+    let cell_rects = cell_bounds.to_rects();
+    if !cell_rects
+        .iter()
+        .any(|cell_rect| rects.iter().any(|rect| cell_rect.intersects(rect)))
+    {
+        return false;
+    }
+    if cell_bounds.west == -180.0 && cell_bounds.east == 180.0 {
+        // cells containing a pole are approximated by their bounds.
+        return true;
+    }
+
+    // unwrap the longitudes of the boundary, so cells crossing the antimeridian have a valid
+    // polygon. Such polygons may exceed the longitude range, so the rects are tested shifted by a
+    // full revolution as well.
+    let boundary = cell.boundary();
+    let mut coords: Vec<Coord> = Vec::with_capacity(boundary.len() + 1);
+    let mut prev: Option<(f64, f64)> = None;
+    for ll in boundary.iter() {
+        let lng = match prev {
+            Some((prev_lng, prev_unwrapped)) => prev_unwrapped + normalize_lng(ll.lng() - prev_lng),
+            None => ll.lng(),
+        };
+        prev = Some((ll.lng(), lng));
+        coords.push(Coord {
+            x: lng,
+            y: ll.lat(),
+        });
+    }
+    let polygon = Polygon::new(LineString::from(coords), vec![]);
+    rects.iter().any(|rect| {
+        [-360.0, 0.0, 360.0].iter().any(|offset| {
+            polygon.intersects(&Rect::new(
+                (rect.min().x + offset, rect.min().y),
+                (rect.max().x + offset, rect.max().y),
+            ))
+        })
+    })
+}
+
+/// Normalize a longitude or a longitude difference to the range -180..=180.
+fn normalize_lng(lng: f64) -> f64 {
+    if lng > 180.0 {
+        lng - 360.0
+    } else if lng < -180.0 {
+        lng + 360.0
+    } else {
+        lng
+    }
+}
+
 // todo: H3ListArray
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn cell_bounds_without_antimeridian() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let bounds = cell_geo_bounds(cell);
+        let rect = geo_types::LineString::from(cell.boundary())
+            .bounding_rect()
+            .unwrap();
+        assert!(!bounds.crosses_antimeridian());
+        assert_eq!(bounds.west, rect.min().x);
+        assert_eq!(bounds.east, rect.max().x);
+        assert_eq!(bounds.south, rect.min().y);
+        assert_eq!(bounds.north, rect.max().y);
+    }
+
+    #[test]
+    fn cell_bounds_crossing_antimeridian() {
+        let cell = LatLng::new(0.0, 180.0).unwrap().to_cell(Resolution::Two);
+        let bounds = cell_geo_bounds(cell);
+        assert!(bounds.crosses_antimeridian());
+        assert!(bounds.west > 170.0);
+        assert!(bounds.east < -170.0);
+    }
+
+    #[test]
+    fn cell_bounds_containing_pole() {
+        let cell = LatLng::new(90.0, 0.0).unwrap().to_cell(Resolution::One);
+        let bounds = cell_geo_bounds(cell);
+        assert_eq!(bounds.north, 90.0);
+        assert_eq!((bounds.west, bounds.east), (-180.0, 180.0));
+    }
+
+    #[test]
+    fn coverage_bounds_crossing_antimeridian() {
+        let cells: CellIndexArray = vec![
+            LatLng::new(10.0, 179.0).unwrap().to_cell(Resolution::Five),
+            LatLng::new(12.0, -179.0).unwrap().to_cell(Resolution::Five),
+        ]
+        .into();
+        let bounds = coverage_geo_bounds(&cells).unwrap();
+        assert!(bounds.crosses_antimeridian());
+        assert!(bounds.west > 178.0 && bounds.west < 179.0);
+        assert!(bounds.east > -179.0 && bounds.east < -178.0);
+        assert!(bounds.south < 10.0 && bounds.north > 12.0);
+    }
+
+    #[test]
+    fn cells_in_bounds() {
+        let inside = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let outside = LatLng::new(20.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let bounds = GeoBounds {
+            west: 9.0,
+            south: 9.0,
+            east: 11.0,
+            north: 11.0,
+        };
+        // a cell just outside of the bounds, but intersecting them
+        let edge = inside
+            .grid_disk::<Vec<_>>(20)
+            .into_iter()
+            .find(|cell| {
+                let ll = LatLng::from(*cell);
+                ll.lat() > 11.0
+                    && cell_geo_bounds(*cell).south < 11.0
+                    && (ll.lng() - 10.0).abs() < 0.5
+            })
+            .unwrap();
+        let cells: CellIndexArray = vec![inside, outside, edge].into();
+
+        let mask = cells_in_geo_bounds(&cells, bounds, false);
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false), Some(false)]
+        );
+
+        let mask = cells_in_geo_bounds(&cells, bounds, true);
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false), Some(true)]
+        );
+    }
+
+    #[test]
+    fn cells_in_bounds_crossing_antimeridian() {
+        let cells: CellIndexArray = vec![
+            LatLng::new(0.0, 179.5).unwrap().to_cell(Resolution::Five),
+            LatLng::new(0.0, -179.5).unwrap().to_cell(Resolution::Five),
+            LatLng::new(0.0, 0.0).unwrap().to_cell(Resolution::Five),
+        ]
+        .into();
+        let bounds = GeoBounds {
+            west: 179.0,
+            south: -1.0,
+            east: -179.0,
+            north: 1.0,
+        };
+        let mask = cells_in_geo_bounds(&cells, bounds, true);
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn coverage_bounds_empty() {
+        let cells: CellIndexArray = Vec::<CellIndex>::new().into();
+        assert_eq!(coverage_geo_bounds(&cells), None);
+    }
+
+    #[test]
+    fn cells_intersecting_bounds() {
+        let globe = GeoBounds {
+            west: -180.0,
+            south: -90.0,
+            east: 180.0,
+            north: 90.0,
+        };
+        assert_eq!(
+            cells_intersecting_geo_bounds(globe, Resolution::One).len(),
+            Resolution::One.cell_count() as usize
+        );
+
+        // the selection matches the exact test of all cells, also across the antimeridian
+        for bounds in [
+            GeoBounds {
+                west: 5.0,
+                south: 40.0,
+                east: 15.0,
+                north: 50.0,
+            },
+            GeoBounds {
+                west: 170.0,
+                south: -20.0,
+                east: -170.0,
+                north: -10.0,
+            },
+        ] {
+            let all_cells: CellIndexArray = CellIndex::base_cells()
+                .flat_map(|cell| cell.children(Resolution::Three))
+                .collect::<Vec<_>>()
+                .into();
+            let expected = cells_in_geo_bounds(&all_cells, bounds, true)
+                .iter()
+                .filter(|selected| *selected == Some(true))
+                .count();
+            let cells = cells_intersecting_geo_bounds(bounds, Resolution::Three);
+            assert_eq!(cells.len(), expected);
+            assert!(cells_in_geo_bounds(&cells, bounds, true)
+                .iter()
+                .all(|selected| selected == Some(true)));
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::Float64Array;
+use h3o::CellIndex;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Assignment of cells to the facility which can be reached with the lowest accumulated cost.
+pub struct Catchments {
+    pub cells: CellIndexArray,
+    pub facilities: CellIndexArray,
+    pub costs: Float64Array,
+}
+
+#[derive(PartialEq)]
+struct QueueItem {
+    cost: f64,
+    facility: CellIndex,
+    cell: CellIndex,
+}
+
+impl Eq for QueueItem {}
+
+impl Ord for QueueItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed to turn the max-heap of the std lib into a min-heap
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.facility.cmp(&self.facility))
+            .then_with(|| other.cell.cmp(&self.cell))
+    }
+}
+
+impl PartialOrd for QueueItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compute the catchment areas of `facilities` on a cost surface given by `cells` and `costs`.
+///
+/// Each cell reachable from a facility is assigned to the facility it can be reached from with the
+/// lowest accumulated cost. Moving between neighboring cells costs the mean of the costs of both
+/// cells. Only cells of the cost surface can be traversed, cells with a null cost are impassable.
+/// Cells with an accumulated cost above `max_cost` are omitted.
+///
+/// Ties are broken by preferring the facility with the lowest index value. Facilities outside of the
+/// cost surface are ignored.
+///
+/// With the `rayon` feature enabled, the facilities are searched in parallel.
+pub fn catchments(
+    cells: &CellIndexArray,
+    costs: &Float64Array,
+    facilities: &CellIndexArray,
+    max_cost: Option<f64>,
+) -> Result<Catchments, Error> {
+    if cells.len() != costs.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    let mut surface: HashMap<CellIndex, f64> = HashMap::with_capacity(cells.len());
+    for (cell, cost) in cells.iter().zip(costs.iter()) {
+        if let (Some(cell), Some(cost)) = (cell, cost) {
+            if cost.is_nan() || cost < 0.0 {
+                return Err(Error::InvalidCost(cost));
+            }
+            surface.insert(cell, cost);
+        }
+    }
+
+    let facilities: Vec<_> = facilities
+        .iter()
+        .flatten()
+        .filter(|facility| surface.contains_key(facility))
+        .collect();
+
+    #[cfg(not(feature = "rayon"))]
+    let settled = settle(&surface, facilities, max_cost, |_| false);
+
+    #[cfg(feature = "rayon")]
+    let settled = par_settle(&surface, facilities, max_cost);
+
+    let mut out_cells = Vec::with_capacity(settled.len());
+    let mut out_facilities = Vec::with_capacity(settled.len());
+    let mut out_costs = Vec::with_capacity(settled.len());
+    for (cell, (facility, cost)) in settled.into_iter() {
+        out_cells.push(cell);
+        out_facilities.push(facility);
+        out_costs.push(cost);
+    }
+
+    Ok(Catchments {
+        cells: out_cells.into(),
+        facilities: out_facilities.into(),
+        costs: out_costs.into(),
+    })
+}
+
+/// Multi-source dijkstra - the first time a cell is taken from the queue, it has been reached
+/// with the lowest possible cost.
+///
+/// Cells for which `prune` returns true are neither settled nor expanded.
+fn settle(
+    surface: &HashMap<CellIndex, f64>,
+    facilities: Vec<CellIndex>,
+    max_cost: Option<f64>,
+    mut prune: impl FnMut(&QueueItem) -> bool,
+) -> HashMap<CellIndex, (CellIndex, f64)> {
+    let mut queue: BinaryHeap<_> = facilities
+        .into_iter()
+        .map(|facility| QueueItem {
+            cost: 0.0,
+            facility,
+            cell: facility,
+        })
+        .collect();
+
+    let mut settled: HashMap<CellIndex, (CellIndex, f64)> = HashMap::new();
+    while let Some(item) = queue.pop() {
+        if settled.contains_key(&item.cell) || prune(&item) {
+            continue;
+        }
+        settled.insert(item.cell, (item.facility, item.cost));
+
+        let cell_cost = surface[&item.cell];
+        for neighbor in item.cell.grid_disk::<Vec<_>>(1) {
+            if settled.contains_key(&neighbor) {
+                continue;
+            }
+            if let Some(neighbor_cost) = surface.get(&neighbor) {
+                let cost = item.cost + (cell_cost + neighbor_cost) / 2.0;
+                if max_cost.map(|max_cost| cost <= max_cost).unwrap_or(true) {
+                    queue.push(QueueItem {
+                        cost,
+                        facility: item.facility,
+                        cell: neighbor,
+                    });
+                }
+            }
+        }
+    }
+    settled
+}
+
+/// Run a single-source dijkstra per facility in parallel and keep the facility with the lowest
+/// cost for each cell - the same result as the multi-source dijkstra of `settle`.
+///
+/// The searches share the lowest cost each cell has been settled with so far. A search does not
+/// expand cells another facility reaches with a lower cost, as that facility also reaches all
+/// cells behind them with a lower cost. This keeps the total work close to the work of the
+/// multi-source dijkstra.
+#[cfg(feature = "rayon")]
+fn par_settle(
+    surface: &HashMap<CellIndex, f64>,
+    mut facilities: Vec<CellIndex>,
+    max_cost: Option<f64>,
+) -> HashMap<CellIndex, (CellIndex, f64)> {
+    use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+    use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+    facilities.sort_unstable();
+    facilities.dedup();
+
+    // costs are never negative, so the order of their bits matches the order of their values.
+    let lowest_costs: HashMap<CellIndex, AtomicU64> = surface
+        .keys()
+        .map(|cell| (*cell, AtomicU64::new(f64::INFINITY.to_bits())))
+        .collect();
+
+    let searches: Vec<_> = facilities
+        .into_par_iter()
+        .map(|facility| {
+            settle(surface, vec![facility], max_cost, |item| {
+                let previous = lowest_costs[&item.cell]
+                    .fetch_min(item.cost.to_bits(), AtomicOrdering::Relaxed);
+                f64::from_bits(previous) < item.cost
+            })
+        })
+        .collect();
+
+    let mut settled: HashMap<CellIndex, (CellIndex, f64)> = HashMap::new();
+    for search in searches {
+        for (cell, (facility, cost)) in search {
+            settled
+                .entry(cell)
+                .and_modify(|current| {
+                    if cost
+                        .total_cmp(&current.1)
+                        .then_with(|| facility.cmp(&current.0))
+                        .is_lt()
+                    {
+                        *current = (facility, cost);
+                    }
+                })
+                .or_insert((facility, cost));
+        }
+    }
+    settled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn uniform_costs_equal_grid_distance() {
+        let facility_a = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let (facility_b, _) = facility_a
+            .grid_disk_distances::<Vec<_>>(5)
+            .into_iter()
+            .find(|(_, k)| *k == 5)
+            .unwrap();
+        let surface: CellIndexArray = facility_a.grid_disk::<Vec<_>>(7).into();
+        let facilities: CellIndexArray = vec![facility_a, facility_b].into();
+
+        let costs = Float64Array::from(vec![1.0; surface.len()]);
+
+        let catchments = catchments(&surface, &costs, &facilities, Some(3.0)).unwrap();
+        assert!(catchments.cells.len() < surface.len());
+
+        for ((cell, facility), cost) in catchments
+            .cells
+            .iter()
+            .flatten()
+            .zip(catchments.facilities.iter().flatten())
+            .zip(catchments.costs.iter().flatten())
+        {
+            assert!(cost <= 3.0);
+            assert_eq!(cell.grid_distance(facility).unwrap() as f64, cost);
+        }
+    }
+
+    #[test]
+    fn expensive_cells_are_avoided() {
+        let facility = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let surface: Vec<_> = facility.grid_disk::<Vec<_>>(2);
+        let costs: Float64Array = surface
+            .iter()
+            .map(|cell| {
+                if facility.grid_distance(*cell).unwrap() == 1 {
+                    Some(100.0)
+                } else {
+                    Some(1.0)
+                }
+            })
+            .collect();
+
+        let catchments =
+            catchments(&surface.into(), &costs, &vec![facility].into(), Some(10.0)).unwrap();
+        assert_eq!(catchments.cells.len(), 1);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_search_matches_multi_source_search() {
+        let center = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let surface: HashMap<CellIndex, f64> = center
+            .grid_disk::<Vec<_>>(12)
+            .into_iter()
+            // a few distinct costs to also produce ties
+            .map(|cell| (cell, (u64::from(cell) % 3) as f64))
+            .collect();
+        let facilities: Vec<_> = surface
+            .keys()
+            .copied()
+            .filter(|cell| u64::from(*cell) % 23 == 0)
+            .collect();
+        assert!(facilities.len() > 5);
+
+        for max_cost in [None, Some(4.0)] {
+            let expected = settle(&surface, facilities.clone(), max_cost, |_| false);
+            assert_eq!(par_settle(&surface, facilities.clone(), max_cost), expected);
+        }
+    }
+
+    #[test]
+    fn negative_costs_fail() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let cells: CellIndexArray = vec![cell].into();
+        assert!(catchments(&cells, &Float64Array::from(vec![-1.0]), &cells, None).is_err());
+    }
+}
@@ -2,6 +2,11 @@ use crate::array::CellIndexArray;
 use crate::error::Error;
 use ahash::HashSet;
 use h3o::{CellIndex, Resolution};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, OpenOptions};
+use std::io::{BufWriter, ErrorKind, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub trait CompactOp
 where
@@ -35,6 +40,210 @@ impl CompactOp for CellIndexArray {
     }
 }
 
+/// Compaction of a stream of cells which does not need to fit into memory at once.
+///
+/// The cells are kept in a separate hierarchy for each base cell. When more than `max_cells`
+/// cells are held in memory, the hierarchies are compacted. Branches which compacted to their
+/// base cell are final - they are emitted right away and all further cells of them are
+/// discarded. When compaction does not free enough memory, the largest branches are spilled to
+/// files in the spill directory and merged again when the stream is finished.
+///
+/// The files are written to a subdirectory of the spill directory which is exclusive to the
+/// compactor and removed when the compactor is dropped, so multiple compactors may share the
+/// same spill directory.
+///
+/// A single branch - all cells of a base cell - is compacted in memory when finishing, so the
+/// memory usage is bounded by the size of the largest branch.
+pub struct StreamingCompactor {
+    branches: BTreeMap<CellIndex, CellSet>,
+
+    /// base cells of the branches which received cells since they have been compacted last
+    touched: BTreeSet<CellIndex>,
+    finalized: HashSet<CellIndex>,
+    spilled: BTreeSet<CellIndex>,
+    spill_directory: PathBuf,
+
+    /// the exclusive subdirectory of `spill_directory`, created with the first spill
+    spill_subdirectory: Option<PathBuf>,
+    max_cells: usize,
+    num_cells: usize,
+}
+
+static SPILL_SUBDIRECTORY_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+impl StreamingCompactor {
+    pub fn new(max_cells: usize, spill_directory: impl Into<PathBuf>) -> Self {
+        Self {
+            branches: Default::default(),
+            touched: Default::default(),
+            finalized: Default::default(),
+            spilled: Default::default(),
+            spill_directory: spill_directory.into(),
+            spill_subdirectory: None,
+            max_cells: max_cells.max(1),
+            num_cells: 0,
+        }
+    }
+
+    /// Number of cells currently held in memory.
+    pub fn len(&self) -> usize {
+        self.num_cells
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_cells == 0
+    }
+
+    /// Add cells to the compaction.
+    ///
+    /// Returns the base cells of the branches which have been finalized by these cells.
+    pub fn push(&mut self, cells: &CellIndexArray) -> Result<CellIndexArray, Error> {
+        for cell in cells.iter().flatten() {
+            let base = base_cell(cell);
+            if self.finalized.contains(&base) {
+                continue;
+            }
+            self.branches.entry(base).or_default().insert(cell);
+            self.touched.insert(base);
+            self.num_cells += 1;
+        }
+        if self.num_cells <= self.max_cells {
+            return Ok(Vec::<CellIndex>::new().into());
+        }
+
+        // the other branches are compacted already
+        let mut finalized = vec![];
+        for base in std::mem::take(&mut self.touched) {
+            let Some(cellset) = self.branches.get_mut(&base) else {
+                continue;
+            };
+            self.num_cells -= cellset.len();
+            cellset.compact()?;
+            if cellset.cells_by_resolution[0].first() == Some(&base) {
+                finalized.push(base);
+            } else {
+                self.num_cells += cellset.len();
+            }
+        }
+        for base in finalized.iter() {
+            self.branches.remove(base);
+            self.finalized.insert(*base);
+            if self.spilled.remove(base) {
+                fs::remove_file(self.spill_path(*base)?)?;
+            }
+        }
+
+        // spill down to half of the limit to avoid spilling again with the next cells
+        while self.num_cells > self.max_cells / 2 {
+            let Some(base) = self
+                .branches
+                .iter()
+                .max_by_key(|(_, cellset)| cellset.len())
+                .map(|(base, _)| *base)
+            else {
+                break;
+            };
+            self.spill(base)?;
+        }
+        Ok(finalized.into())
+    }
+
+    /// Compact the next remaining branch - ordered by their base cells. Returns `None` once all
+    /// branches have been emitted.
+    pub fn next_branch(&mut self) -> Result<Option<CellIndexArray>, Error> {
+        let base = match (self.branches.keys().next(), self.spilled.first()) {
+            (Some(a), Some(b)) => *a.min(b),
+            (Some(a), None) => *a,
+            (None, Some(b)) => *b,
+            (None, None) => return Ok(None),
+        };
+
+        let mut cellset = self.branches.remove(&base).unwrap_or_default();
+        self.touched.remove(&base);
+        self.num_cells -= cellset.len();
+        if self.spilled.remove(&base) {
+            let path = self.spill_path(base)?;
+            let mut buf = vec![];
+            fs::File::open(&path)?.read_to_end(&mut buf)?;
+            for chunk in buf.chunks_exact(8) {
+                let value = u64::from_le_bytes(chunk.try_into().expect("chunks of eight bytes"));
+                cellset.insert(CellIndex::try_from(value)?);
+            }
+            fs::remove_file(path)?;
+        }
+        cellset.finalize(true)?;
+        self.finalized.insert(base);
+        Ok(Some(CellIndexArray::from_iter(cellset.iter_compacted())))
+    }
+
+    fn spill(&mut self, base: CellIndex) -> Result<(), Error> {
+        let Some(mut cellset) = self.branches.remove(&base) else {
+            return Ok(());
+        };
+        self.touched.remove(&base);
+        self.num_cells -= cellset.len();
+        cellset.finalize(true)?;
+
+        // the first spill of a branch replaces whatever the file contained before
+        let first_spill = !self.spilled.contains(&base);
+        let mut writer = BufWriter::new(
+            OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(!first_spill)
+                .truncate(first_spill)
+                .open(self.spill_path(base)?)?,
+        );
+        for cell in cellset.iter_compacted() {
+            writer.write_all(&u64::from(cell).to_le_bytes())?;
+        }
+        writer.flush()?;
+        self.spilled.insert(base);
+        Ok(())
+    }
+
+    fn spill_path(&mut self, base: CellIndex) -> Result<PathBuf, Error> {
+        let subdirectory = match &self.spill_subdirectory {
+            Some(subdirectory) => subdirectory,
+            None => self
+                .spill_subdirectory
+                .insert(create_spill_subdirectory(&self.spill_directory)?),
+        };
+        Ok(subdirectory.join(format!("{}.cells", base)))
+    }
+}
+
+impl Drop for StreamingCompactor {
+    fn drop(&mut self) {
+        if let Some(subdirectory) = self.spill_subdirectory.take() {
+            // errors can not be reported from drop, the files are temporary anyway
+            let _ = fs::remove_dir_all(subdirectory);
+        }
+    }
+}
+
+/// Create a new, empty subdirectory of `directory`.
+fn create_spill_subdirectory(directory: &std::path::Path) -> Result<PathBuf, Error> {
+    loop {
+        let path = directory.join(format!(
+            "h3arrow-compact-{}-{}",
+            std::process::id(),
+            SPILL_SUBDIRECTORY_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        match fs::create_dir(&path) {
+            Ok(()) => return Ok(path),
+            // left over from a previous process with the same id
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+fn base_cell(cell: CellIndex) -> CellIndex {
+    cell.parent(Resolution::Zero)
+        .expect("resolution zero is the coarsest resolution")
+}
+
 struct CellSet {
     pub(crate) modified_resolutions: [bool; 16],
 
@@ -112,7 +321,6 @@ impl CellSet {
         }))
     }
 
-    #[allow(unused)]
     pub fn len(&self) -> usize {
         self.cells_by_resolution.iter().map(|v| v.len()).sum()
     }
@@ -201,3 +409,132 @@ impl Default for CellSet {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::LatLng;
+
+    fn compact_stream(batches: &[Vec<CellIndex>], max_cells: usize) -> Vec<CellIndex> {
+        let directory = std::env::temp_dir().join(format!(
+            "h3arrow-compact-{}-{}",
+            std::process::id(),
+            max_cells
+        ));
+        fs::create_dir_all(&directory).unwrap();
+        let mut compactor = StreamingCompactor::new(max_cells, &directory);
+        let mut out = vec![];
+        for batch in batches {
+            let emitted = compactor.push(&batch.clone().into()).unwrap();
+            out.extend(emitted.iter().flatten());
+        }
+        while let Some(branch) = compactor.next_branch().unwrap() {
+            out.extend(branch.iter().flatten());
+        }
+        assert!(compactor.is_empty());
+        drop(compactor);
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 0);
+        fs::remove_dir(directory).unwrap();
+        out.sort_unstable();
+        out
+    }
+
+    #[test]
+    fn streaming_compaction_matches_compaction() {
+        let cells: Vec<_> = [(10.0, 10.0), (45.0, -30.0), (-20.0, 120.0)]
+            .into_iter()
+            .flat_map(|(lat, lng)| {
+                LatLng::new(lat, lng)
+                    .unwrap()
+                    .to_cell(Resolution::Five)
+                    .grid_disk::<Vec<_>>(4)
+            })
+            .flat_map(|cell| cell.children(Resolution::Seven))
+            .collect();
+        let mut expected: Vec<_> = CellIndexArray::from(cells.clone())
+            .compact()
+            .unwrap()
+            .iter()
+            .flatten()
+            .collect();
+        expected.sort_unstable();
+
+        let batches: Vec<Vec<_>> = cells.chunks(500).map(|chunk| chunk.to_vec()).collect();
+        // without and with spilling
+        assert_eq!(compact_stream(&batches, usize::MAX), expected);
+        assert_eq!(compact_stream(&batches, 300), expected);
+    }
+
+    #[test]
+    fn spill_directory_is_exclusive() {
+        let base = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Zero);
+        let directory = std::env::temp_dir().join(format!("h3arrow-shared-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        // a file left over from a crashed run
+        fs::write(
+            directory.join(format!("{}.cells", base)),
+            u64::from(base.center_child(Resolution::Three).unwrap()).to_le_bytes(),
+        )
+        .unwrap();
+
+        let children: Vec<_> = base
+            .center_child(Resolution::Two)
+            .unwrap()
+            .children(Resolution::Four)
+            .collect();
+        // both compactors receive different cells of the same base cell
+        let cells = [&children[..20], &children[20..40]];
+        let mut compactors = [
+            StreamingCompactor::new(5, &directory),
+            StreamingCompactor::new(5, &directory),
+        ];
+        // interleaved, so the spill files of both compactors are written and appended alternately
+        for half in [0..10, 10..20] {
+            for (compactor, cells) in compactors.iter_mut().zip(cells) {
+                compactor
+                    .push(&cells[half.clone()].to_vec().into())
+                    .unwrap();
+                assert!(compactor.is_empty());
+            }
+        }
+        // three entries: the left over file and the subdirectories of both compactors
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 3);
+
+        for (compactor, cells) in compactors.iter_mut().zip(cells) {
+            let mut expected: Vec<_> = CellIndexArray::from(cells.to_vec())
+                .compact()
+                .unwrap()
+                .iter()
+                .flatten()
+                .collect();
+            expected.sort_unstable();
+            let branch = compactor.next_branch().unwrap().unwrap();
+            let mut branch: Vec<_> = branch.iter().flatten().collect();
+            branch.sort_unstable();
+            assert_eq!(branch, expected);
+            assert!(compactor.next_branch().unwrap().is_none());
+        }
+        drop(compactors);
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 1);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn finalized_branches_are_emitted() {
+        let base = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Zero);
+        let directory = std::env::temp_dir().join(format!("h3arrow-final-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let mut compactor = StreamingCompactor::new(10, &directory);
+
+        let children: Vec<_> = base.children(Resolution::Two).collect();
+        let emitted = compactor.push(&children.into()).unwrap();
+        assert_eq!(emitted.iter().flatten().collect::<Vec<_>>(), vec![base]);
+        assert!(compactor.is_empty());
+
+        // further cells of the finalized branch are discarded
+        let child = base.center_child(Resolution::Five).unwrap();
+        compactor.push(&vec![child].into()).unwrap();
+        assert!(compactor.next_branch().unwrap().is_none());
+        fs::remove_dir(directory).unwrap();
+    }
+}
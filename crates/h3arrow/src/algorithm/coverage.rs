@@ -0,0 +1,302 @@
+//! Operations on coverages - sets of cells of possibly mixed resolutions describing an area, for
+//! example the output of `compact`.
+use ahash::HashSet;
+use arrow::array::BooleanArray;
+use h3o::{CellIndex, LatLng, Resolution};
+
+use crate::algorithm::CompactOp;
+use crate::array::CellIndexArray;
+use crate::error::Error;
+
+/// Hierarchical membership tests against a coverage.
+///
+/// A cell is contained in the coverage when it or one of its ancestors is part of the coverage.
+pub struct Coverage {
+    cells: HashSet<CellIndex>,
+
+    /// the resolutions present in the coverage, in ascending order
+    resolutions: Vec<Resolution>,
+}
+
+impl Coverage {
+    /// Build the coverage from the cells of `cells`. Invalid/empty values are omitted.
+    pub fn new(cells: &CellIndexArray) -> Self {
+        let cells: HashSet<CellIndex> = cells.iter().flatten().collect();
+        let mut present = [false; 16];
+        for cell in cells.iter() {
+            present[usize::from(cell.resolution())] = true;
+        }
+        let resolutions = Resolution::range(Resolution::Zero, Resolution::Fifteen)
+            .filter(|resolution| present[usize::from(*resolution)])
+            .collect();
+        Self { cells, resolutions }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The finest resolution of the cells of the coverage.
+    pub fn max_resolution(&self) -> Option<Resolution> {
+        self.resolutions.last().copied()
+    }
+
+    /// Check if `cell` is covered - it or one of its ancestors is part of the coverage.
+    ///
+    /// Cells which are only partially covered by finer cells of the coverage are not contained.
+    pub fn contains(&self, cell: CellIndex) -> bool {
+        self.resolutions
+            .iter()
+            .take_while(|resolution| **resolution <= cell.resolution())
+            .filter_map(|resolution| cell.parent(*resolution))
+            .any(|ancestor| self.cells.contains(&ancestor))
+    }
+
+    /// Check if the point is covered by testing its cell at `resolution`.
+    ///
+    /// The test is exact when `resolution` is at least the `max_resolution` of the coverage. With
+    /// coarser resolutions the finer cells of the coverage are not taken into account.
+    pub fn contains_latlng(&self, latlng: LatLng, resolution: Resolution) -> bool {
+        self.contains(latlng.to_cell(resolution))
+    }
+}
+
+/// Position of the first descendant of `cell` in the canonical order. The descendants of a cell
+/// occupy a contiguous range of this order, which starts at the cell itself.
+fn canonical_key(cell: CellIndex) -> (u64, Resolution) {
+    let first_descendant = cell
+        .center_child(Resolution::Fifteen)
+        .expect("resolution fifteen is the finest resolution");
+    (u64::from(first_descendant), cell.resolution())
+}
+
+/// Canonicalize a coverage for [`cellset_contains`]: compact it and sort it in the canonical order,
+/// which places the descendants of each cell right after it. Invalid/empty values are omitted.
+pub fn cellset_canonicalize(cells: &CellIndexArray) -> Result<CellIndexArray, Error> {
+    let mut cells: Vec<_> = cells
+        .compact_mixed_resolutions()?
+        .iter()
+        .flatten()
+        .collect();
+    cells.sort_unstable_by_key(|cell| canonical_key(*cell));
+    Ok(cells.into())
+}
+
+/// Check if the cells of `query` are covered by `cellset` - the cell or one of its ancestors is
+/// part of it.
+///
+/// `cellset` must be canonical as returned by [`cellset_canonicalize`] - sorted in the canonical
+/// order without duplicates or overlaps. Each lookup is a single binary search over the set, so
+/// unlike [`Coverage`] no hash set is built, which makes this a memory-lean alternative for
+/// large, read-mostly coverages. Null query cells are null in the output.
+pub fn cellset_contains(
+    cellset: &CellIndexArray,
+    query: &CellIndexArray,
+) -> Result<BooleanArray, Error> {
+    let cells: Vec<CellIndex> = cellset.iter().flatten().collect();
+    if !cells.windows(2).all(|pair| {
+        // the second cell must start after all descendants of the first one
+        canonical_key(pair[0]) < canonical_key(pair[1])
+            && pair[1].parent(pair[0].resolution()) != Some(pair[0])
+    }) {
+        return Err(Error::NotCanonical);
+    }
+
+    Ok(query
+        .iter()
+        .map(|cell| {
+            cell.map(|cell| {
+                let key = canonical_key(cell);
+                // the last cell of the set starting at or before the query cell
+                let position = cells.partition_point(|candidate| canonical_key(*candidate) <= key);
+                position > 0 && {
+                    let candidate = cells[position - 1];
+                    candidate.resolution() <= cell.resolution()
+                        && cell.parent(candidate.resolution()) == Some(candidate)
+                }
+            })
+        })
+        .collect())
+}
+
+/// Metrics comparing two coverages. See [`coverage_similarity`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageSimilarity {
+    /// number of cells of the first coverage after compacting it
+    pub n_cells_a: usize,
+
+    /// number of cells of the second coverage after compacting it
+    pub n_cells_b: usize,
+
+    /// number of cells of the intersection - the compacted cells of either coverage which are
+    /// covered by the other one
+    pub n_cells_intersection: usize,
+
+    pub area_a_km2: f64,
+    pub area_b_km2: f64,
+    pub intersection_area_km2: f64,
+    pub union_area_km2: f64,
+
+    /// area of the intersection divided by the area of the union. `None` when both coverages are
+    /// empty.
+    pub jaccard: Option<f64>,
+}
+
+/// Compare the areas covered by the cells of `a` and `b`.
+///
+/// Both coverages may contain cells of mixed resolutions, these are aligned hierarchically: a cell
+/// of one coverage contributes to the intersection when it or one of its ancestors is part of the
+/// other coverage. Duplicate and overlapping cells within a coverage are only counted once.
+/// Invalid/empty values are omitted.
+pub fn coverage_similarity(
+    a: &CellIndexArray,
+    b: &CellIndexArray,
+) -> Result<CoverageSimilarity, Error> {
+    let a = a.compact_mixed_resolutions()?;
+    let b = b.compact_mixed_resolutions()?;
+    let coverage_a = Coverage::new(&a);
+    let coverage_b = Coverage::new(&b);
+
+    // after compacting, a cell of a is either covered by b, contains cells of b or is disjoint.
+    let intersection: HashSet<CellIndex> = a
+        .iter()
+        .flatten()
+        .filter(|cell| coverage_b.contains(*cell))
+        .chain(b.iter().flatten().filter(|cell| coverage_a.contains(*cell)))
+        .collect();
+
+    let area_a_km2 = a.area_km2().iter().flatten().sum();
+    let area_b_km2 = b.area_km2().iter().flatten().sum();
+    let intersection_area_km2: f64 = intersection.iter().map(|cell| cell.area_km2()).sum();
+    let union_area_km2 = area_a_km2 + area_b_km2 - intersection_area_km2;
+
+    Ok(CoverageSimilarity {
+        n_cells_a: coverage_a.cells.len(),
+        n_cells_b: coverage_b.cells.len(),
+        n_cells_intersection: intersection.len(),
+        area_a_km2,
+        area_b_km2,
+        intersection_area_km2,
+        union_area_km2,
+        jaccard: (union_area_km2 > 0.0).then(|| intersection_area_km2 / union_area_km2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_mixed_resolutions() {
+        let coarse = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let fine = LatLng::new(20.0, 20.0).unwrap().to_cell(Resolution::Nine);
+        let coverage = Coverage::new(&vec![coarse, fine].into());
+        assert_eq!(coverage.max_resolution(), Some(Resolution::Nine));
+
+        assert!(coverage.contains(coarse));
+        assert!(coverage.contains(coarse.center_child(Resolution::Eight).unwrap()));
+        assert!(coverage.contains(fine));
+        // only partially covered
+        assert!(!coverage.contains(fine.parent(Resolution::Five).unwrap()));
+
+        assert!(coverage.contains_latlng(LatLng::from(coarse), Resolution::Nine));
+        assert!(coverage.contains_latlng(LatLng::from(fine), Resolution::Nine));
+        assert!(!coverage.contains_latlng(LatLng::new(-10.0, 10.0).unwrap(), Resolution::Nine));
+    }
+
+    #[test]
+    fn similarity_mixed_resolutions() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let children: Vec<_> = cell.children(Resolution::Six).collect();
+        let other = LatLng::new(20.0, 20.0).unwrap().to_cell(Resolution::Five);
+
+        // identical areas at different resolutions
+        let similarity = coverage_similarity(&vec![cell].into(), &children.clone().into()).unwrap();
+        assert_eq!(similarity.n_cells_a, 1);
+        assert_eq!(similarity.n_cells_b, 1);
+        assert!((similarity.jaccard.unwrap() - 1.0).abs() < 1e-9);
+
+        let similarity =
+            coverage_similarity(&vec![cell, other].into(), &children[..1].to_vec().into()).unwrap();
+        assert_eq!(similarity.n_cells_intersection, 1);
+        assert!((similarity.intersection_area_km2 - children[0].area_km2()).abs() < 1e-9);
+        assert!((similarity.union_area_km2 - similarity.area_a_km2).abs() < 1e-9);
+        let jaccard = similarity.jaccard.unwrap();
+        assert!(jaccard > 0.0 && jaccard < 0.1);
+
+        let similarity = coverage_similarity(&vec![cell].into(), &vec![other].into()).unwrap();
+        assert_eq!(similarity.n_cells_intersection, 0);
+        assert_eq!(similarity.jaccard, Some(0.0));
+    }
+
+    #[test]
+    fn similarity_empty() {
+        let empty: CellIndexArray = Vec::<CellIndex>::new().into();
+        assert_eq!(coverage_similarity(&empty, &empty).unwrap().jaccard, None);
+    }
+
+    #[test]
+    fn sorted_cellset_contains() {
+        let coarse = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let fine = LatLng::new(20.0, 20.0).unwrap().to_cell(Resolution::Nine);
+        let mut input: Vec<_> = coarse.children(Resolution::Seven).collect();
+        input.push(fine);
+        input.push(coarse.center_child(Resolution::Eight).unwrap());
+        let cellset = cellset_canonicalize(&input.into()).unwrap();
+        assert_eq!(cellset.len(), 2);
+
+        let outside = LatLng::new(-10.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let query: CellIndexArray = vec![
+            Some(coarse),
+            Some(coarse.center_child(Resolution::Twelve).unwrap()),
+            Some(fine),
+            Some(fine.parent(Resolution::Five).unwrap()),
+            Some(outside),
+            None,
+        ]
+        .into_iter()
+        .collect();
+        let contained = cellset_contains(&cellset, &query).unwrap();
+        assert_eq!(
+            contained.iter().collect::<Vec<_>>(),
+            vec![
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                None
+            ]
+        );
+
+        // the same answers as the hash-based coverage
+        let coverage = Coverage::new(&cellset);
+        for cell in query.iter().flatten() {
+            assert_eq!(
+                coverage.contains(cell),
+                cellset_contains(&cellset, &vec![cell].into())
+                    .unwrap()
+                    .value(0)
+            );
+        }
+    }
+
+    #[test]
+    fn sorted_cellset_not_canonical() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let child = cell.center_child(Resolution::Six).unwrap();
+        let query: CellIndexArray = vec![cell].into();
+        assert!(matches!(
+            cellset_contains(&vec![cell, child].into(), &query),
+            Err(Error::NotCanonical)
+        ));
+    }
+
+    #[test]
+    fn empty() {
+        let coverage = Coverage::new(&Vec::<CellIndex>::new().into());
+        assert!(coverage.is_empty());
+        assert_eq!(coverage.max_resolution(), None);
+        assert!(!coverage.contains(LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Five)));
+    }
+}
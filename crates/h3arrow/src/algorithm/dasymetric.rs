@@ -0,0 +1,143 @@
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::Float64Array;
+use h3o::{CellIndex, Resolution};
+
+/// Values redistributed to cells of a finer resolution.
+pub struct Resampled {
+    pub cells: CellIndexArray,
+    pub values: Float64Array,
+}
+
+/// Redistribute the extensive `values` of `cells` to their children at `target_resolution`
+/// proportionally to the auxiliary `weights` of the children - for example population counts.
+///
+/// The weights are given by `weight_cells` and `weights` and are looked up for the children at
+/// `target_resolution`. Children without a weight - or with a null, negative or NaN weight - receive
+/// nothing. When none of the children of a cell has a positive weight, the value is distributed
+/// uniformly over all children. The sum of the values of the children of a cell always equals the
+/// value of the cell.
+///
+/// Rows with a null cell or value are skipped. Cells of a finer resolution than `target_resolution`
+/// are rejected.
+pub fn dasymetric_resample(
+    cells: &CellIndexArray,
+    values: &Float64Array,
+    weight_cells: &CellIndexArray,
+    weights: &Float64Array,
+    target_resolution: Resolution,
+) -> Result<Resampled, Error> {
+    if cells.len() != values.len() || weight_cells.len() != weights.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    let mut weight_by_cell: HashMap<CellIndex, f64> = HashMap::with_capacity(weight_cells.len());
+    for (cell, weight) in weight_cells.iter().zip(weights.iter()) {
+        if let (Some(cell), Some(weight)) = (cell, weight) {
+            if weight > 0.0 {
+                *weight_by_cell.entry(cell).or_insert(0.0) += weight;
+            }
+        }
+    }
+
+    let mut out_cells = vec![];
+    let mut out_values = vec![];
+    let mut children = vec![];
+    for (cell, value) in cells.iter().zip(values.iter()) {
+        let (Some(cell), Some(value)) = (cell, value) else {
+            continue;
+        };
+        if cell.resolution() > target_resolution {
+            return Err(Error::ResolutionTooFine(
+                cell.resolution().into(),
+                target_resolution.into(),
+            ));
+        }
+
+        children.clear();
+        children.extend(cell.children(target_resolution).map(|child| {
+            let weight = weight_by_cell.get(&child).copied().unwrap_or(0.0);
+            (child, weight)
+        }));
+        let weight_sum: f64 = children.iter().map(|(_, weight)| weight).sum();
+
+        for (child, weight) in children.iter() {
+            out_cells.push(*child);
+            out_values.push(if weight_sum > 0.0 {
+                value * weight / weight_sum
+            } else {
+                value / children.len() as f64
+            });
+        }
+    }
+
+    Ok(Resampled {
+        cells: out_cells.into(),
+        values: out_values.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::LatLng;
+
+    #[test]
+    fn distributes_by_weight() {
+        let parent = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Six);
+        let children: Vec<_> = parent.children(Resolution::Seven).collect();
+
+        // all weight in the first two children
+        let weight_cells: CellIndexArray = vec![children[0], children[1]].into();
+        let weights = Float64Array::from(vec![3.0, 1.0]);
+
+        let resampled = dasymetric_resample(
+            &vec![parent].into(),
+            &Float64Array::from(vec![100.0]),
+            &weight_cells,
+            &weights,
+            Resolution::Seven,
+        )
+        .unwrap();
+        assert_eq!(resampled.cells.len(), children.len());
+
+        let values: HashMap<_, _> = resampled
+            .cells
+            .iter()
+            .flatten()
+            .zip(resampled.values.iter().flatten())
+            .collect();
+        assert_eq!(values[&children[0]], 75.0);
+        assert_eq!(values[&children[1]], 25.0);
+        assert_eq!(values[&children[2]], 0.0);
+    }
+
+    #[test]
+    fn uniform_without_weights() {
+        let parent = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Six);
+        let resampled = dasymetric_resample(
+            &vec![parent].into(),
+            &Float64Array::from(vec![70.0]),
+            &Vec::<CellIndex>::new().into(),
+            &Float64Array::from(Vec::<f64>::new()),
+            Resolution::Seven,
+        )
+        .unwrap();
+        assert_eq!(resampled.cells.len(), 7);
+        assert!(resampled.values.iter().flatten().all(|v| v == 10.0));
+    }
+
+    #[test]
+    fn finer_cells_fail() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Eight);
+        assert!(dasymetric_resample(
+            &vec![cell].into(),
+            &Float64Array::from(vec![1.0]),
+            &vec![cell].into(),
+            &Float64Array::from(vec![1.0]),
+            Resolution::Seven,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::array::CellIndexArray;
+use ahash::{HashSet, HashSetExt};
+use h3o::{CellIndex, Resolution};
+
+pub trait DifferenceOp {
+    /// Remove the area covered by the cells of `exclude` from the cells of `self`.
+    ///
+    /// The cells of both arrays may be of mixed resolutions. Cells which are equal to or descendants
+    /// of a cell of `exclude` are removed. Cells only partially covered by finer cells of `exclude`
+    /// are replaced by their children not covered by `exclude`, so the output may contain cells of
+    /// finer resolutions than the input.
+    ///
+    /// The order of the cells is preserved. Invalid/empty values are omitted.
+    fn without(&self, exclude: &Self) -> Self;
+}
+
+struct ExclusionSet {
+    /// cells to remove
+    excluded: HashSet<CellIndex>,
+
+    /// ancestors of the excluded cells - these are only partially covered.
+    partially_excluded: HashSet<CellIndex>,
+}
+
+impl ExclusionSet {
+    fn new(exclude: &CellIndexArray) -> Self {
+        let excluded: HashSet<CellIndex> = exclude.iter().flatten().collect();
+        let mut partially_excluded = HashSet::with_capacity(excluded.len());
+        for cell in excluded.iter() {
+            for resolution in Resolution::range(Resolution::Zero, cell.resolution()) {
+                if let Some(parent) = cell.parent(resolution) {
+                    if parent != *cell {
+                        partially_excluded.insert(parent);
+                    }
+                }
+            }
+        }
+        Self {
+            excluded,
+            partially_excluded,
+        }
+    }
+
+    fn is_excluded(&self, cell: CellIndex) -> bool {
+        Resolution::range(Resolution::Zero, cell.resolution())
+            .filter_map(|resolution| cell.parent(resolution))
+            .any(|ancestor| self.excluded.contains(&ancestor))
+    }
+
+    fn push_remainder(&self, cell: CellIndex, out: &mut Vec<CellIndex>) {
+        if self.excluded.contains(&cell) {
+            return;
+        }
+        if !self.partially_excluded.contains(&cell) {
+            out.push(cell);
+            return;
+        }
+        if let Some(child_resolution) = cell.resolution().succ() {
+            for child in cell.children(child_resolution) {
+                self.push_remainder(child, out);
+            }
+        }
+    }
+}
+
+impl DifferenceOp for CellIndexArray {
+    fn without(&self, exclude: &Self) -> Self {
+        let exclusion_set = ExclusionSet::new(exclude);
+
+        let mut out = Vec::with_capacity(self.len());
+        for cell in self.iter().flatten() {
+            if !exclusion_set.is_excluded(cell) {
+                exclusion_set.push_remainder(cell, &mut out);
+            }
+        }
+        out.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::LatLng;
+
+    #[test]
+    fn without_mixed_resolutions() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let other = LatLng::new(20.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let parent_of_other = other.parent(Resolution::Three).unwrap();
+        let grandchild = cell.center_child(Resolution::Seven).unwrap();
+
+        let cells: CellIndexArray = vec![cell, other].into();
+        let exclude: CellIndexArray = vec![parent_of_other, grandchild].into();
+
+        let remainder: Vec<_> = cells.without(&exclude).iter().flatten().collect();
+
+        // 6 children at res 6 and 6 grandchildren at res 7 remain
+        assert_eq!(remainder.len(), 12);
+        assert!(!remainder.contains(&grandchild));
+        assert!(remainder
+            .iter()
+            .all(|c| c.parent(Resolution::Five) == Some(cell)));
+    }
+}
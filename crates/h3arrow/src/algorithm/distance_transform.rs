@@ -0,0 +1,109 @@
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+use arrow::array::UInt32Array;
+use h3o::{CellIndex, Resolution};
+
+/// Compute the grid distance of each cell of `extent` to the nearest cell of `features` - the
+/// hex-grid analogue of a raster distance transform.
+///
+/// The distances are computed using a multi-source breadth-first search starting at all features at
+/// once. The search is not limited to the cells of the extent, so the distances equal the
+/// `grid_distance` to the nearest feature. The search stops after `max_k` rings or as soon as all
+/// cells of the extent have been reached.
+///
+/// The returned array is aligned with `extent`. Cells further than `max_k` from any feature as well
+/// as invalid/empty values are null. All cells must be of the same resolution.
+pub fn grid_distance_transform(
+    extent: &CellIndexArray,
+    features: &CellIndexArray,
+    max_k: u32,
+) -> Result<UInt32Array, Error> {
+    let mut resolution: Option<Resolution> = None;
+    for cell in extent.iter().flatten().chain(features.iter().flatten()) {
+        match resolution {
+            None => resolution = Some(cell.resolution()),
+            Some(r) if r != cell.resolution() => return Err(Error::MixedResolutions),
+            _ => (),
+        }
+    }
+
+    let mut unreached: HashSet<CellIndex> = extent.iter().flatten().collect();
+    let mut distances: HashMap<CellIndex, u32> = HashMap::with_capacity(unreached.len());
+
+    let mut visited: HashSet<CellIndex> = HashSet::new();
+    let mut frontier: Vec<CellIndex> = features
+        .iter()
+        .flatten()
+        .filter(|cell| visited.insert(*cell))
+        .collect();
+
+    let mut k = 0;
+    while !frontier.is_empty() && !unreached.is_empty() {
+        let mut next_frontier = vec![];
+        for cell in frontier {
+            if unreached.remove(&cell) {
+                distances.insert(cell, k);
+            }
+            if k < max_k {
+                for neighbor in cell.grid_disk::<Vec<_>>(1) {
+                    if visited.insert(neighbor) {
+                        next_frontier.push(neighbor);
+                    }
+                }
+            }
+        }
+        frontier = next_frontier;
+        k += 1;
+    }
+
+    Ok(extent
+        .iter()
+        .map(|cell| cell.and_then(|cell| distances.get(&cell).copied()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Array;
+    use h3o::LatLng;
+
+    #[test]
+    fn distances_equal_grid_distance() {
+        let feature_a = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let feature_b = LatLng::new(10.05, 10.05)
+            .unwrap()
+            .to_cell(Resolution::Seven);
+        let extent: CellIndexArray = feature_a.grid_disk::<Vec<_>>(6).into();
+        let features: CellIndexArray = vec![feature_a, feature_b].into();
+
+        let distances = grid_distance_transform(&extent, &features, 4).unwrap();
+        assert_eq!(distances.len(), extent.len());
+        assert!(distances.null_count() > 0);
+
+        for (cell, distance) in extent.iter().zip(distances.iter()) {
+            let cell = cell.unwrap();
+            let expected = cell
+                .grid_distance(feature_a)
+                .unwrap()
+                .min(cell.grid_distance(feature_b).unwrap()) as u32;
+            if expected <= 4 {
+                assert_eq!(distance, Some(expected));
+            } else {
+                assert_eq!(distance, None);
+            }
+        }
+    }
+
+    #[test]
+    fn mixed_resolutions() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let extent: CellIndexArray = vec![cell].into();
+        let features: CellIndexArray = vec![cell.parent(Resolution::Six).unwrap()].into();
+        assert!(matches!(
+            grid_distance_transform(&extent, &features, 1),
+            Err(Error::MixedResolutions)
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+//! Cells covering sectors and rectangles defined by geodesic distances - for example the
+//! footprints of radars or antennas.
+//!
+//! The shapes are evaluated analytically for the centroid of each candidate cell, so no polygon
+//! geometries are constructed. Cells are included when their centroid is located within the shape.
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::HashSet;
+use h3o::{LatLng, Resolution};
+use std::collections::VecDeque;
+
+use super::grid::latlng_azimuth_degrees;
+
+/// Mean earth radius as used by h3.
+const EARTH_RADIUS_M: f64 = 6_371_007.180_918_475;
+
+/// Cells within `radius_m` of `center` and within the azimuth range from `azimuth_min` to
+/// `azimuth_max` - in degrees clockwise from north.
+///
+/// Ranges crossing north are given with `azimuth_min` larger than `azimuth_max`, for example
+/// `(350.0, 10.0)`. A range spanning 360 degrees or more selects the complete circle. The cell
+/// containing the center is always included.
+pub fn sector_to_cells(
+    center: LatLng,
+    radius_m: f64,
+    azimuth_min: f64,
+    azimuth_max: f64,
+    resolution: Resolution,
+) -> Result<CellIndexArray, Error> {
+    check_distance(radius_m)?;
+    let full_circle = azimuth_max - azimuth_min >= 360.0;
+    let azimuth_min = azimuth_min.rem_euclid(360.0);
+    let azimuth_max = azimuth_max.rem_euclid(360.0);
+    let radius_rads = radius_m / EARTH_RADIUS_M;
+
+    Ok(fill(
+        center,
+        radius_m,
+        resolution,
+        |distance_rads, azimuth| {
+            if distance_rads > radius_rads {
+                return false;
+            }
+            full_circle
+                || if azimuth_min <= azimuth_max {
+                    azimuth_min <= azimuth && azimuth <= azimuth_max
+                } else {
+                    azimuth >= azimuth_min || azimuth <= azimuth_max
+                }
+        },
+    ))
+}
+
+/// Cells within the rectangle centered on `center` with the given width and height.
+///
+/// The height is measured along the great circle through the center in the direction of
+/// `azimuth` - in degrees clockwise from north - the width perpendicular to it. The cell
+/// containing the center is always included.
+pub fn geodesic_rect_to_cells(
+    center: LatLng,
+    width_m: f64,
+    height_m: f64,
+    azimuth: f64,
+    resolution: Resolution,
+) -> Result<CellIndexArray, Error> {
+    check_distance(width_m)?;
+    check_distance(height_m)?;
+    let half_width_rads = width_m / 2.0 / EARTH_RADIUS_M;
+    let half_height_rads = height_m / 2.0 / EARTH_RADIUS_M;
+
+    let reach_m = (width_m.powi(2) + height_m.powi(2)).sqrt() / 2.0;
+    Ok(fill(
+        center,
+        reach_m,
+        resolution,
+        |distance_rads, bearing| {
+            let relative = (bearing - azimuth).to_radians();
+            // right spherical triangle of the center, the point and its projection on the axis
+            let cross_track = (distance_rads.sin() * relative.sin()).asin();
+            let along_track = (distance_rads.sin() * relative.cos()).atan2(distance_rads.cos());
+            cross_track.abs() <= half_width_rads && along_track.abs() <= half_height_rads
+        },
+    ))
+}
+
+fn check_distance(distance_m: f64) -> Result<(), Error> {
+    if distance_m.is_finite() && distance_m > 0.0 {
+        Ok(())
+    } else {
+        Err(Error::InvalidDistance(distance_m))
+    }
+}
+
+/// Visit all cells within `reach_m` of the center - plus a margin keeping them connected - and
+/// select the ones whose centroid passes `contains`, which is given the distance in radians and
+/// the azimuth in degrees of the centroid as seen from the center.
+fn fill<F>(center: LatLng, reach_m: f64, resolution: Resolution, contains: F) -> CellIndexArray
+where
+    F: Fn(f64, f64) -> bool,
+{
+    let reach_rads = (reach_m + 2.0 * resolution.edge_length_m()) / EARTH_RADIUS_M;
+    let origin = center.to_cell(resolution);
+
+    let mut visited = HashSet::from_iter([origin]);
+    let mut queue = VecDeque::from([origin]);
+    let mut cells = vec![origin];
+    while let Some(cell) = queue.pop_front() {
+        for neighbor in cell.grid_disk::<Vec<_>>(1) {
+            if !visited.insert(neighbor) {
+                continue;
+            }
+            let centroid = LatLng::from(neighbor);
+            let distance_rads = center.distance_rads(centroid);
+            if distance_rads > reach_rads {
+                continue;
+            }
+            queue.push_back(neighbor);
+            if contains(distance_rads, latlng_azimuth_degrees(center, centroid)) {
+                cells.push(neighbor);
+            }
+        }
+    }
+    cells.sort_unstable();
+    CellIndexArray::from(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell_count(cells: &CellIndexArray) -> usize {
+        cells.iter().flatten().count()
+    }
+
+    #[test]
+    fn sector_area() {
+        let center = LatLng::new(45.0, 10.0).unwrap();
+        let resolution = Resolution::Ten;
+        let cell_area_m2 = center.to_cell(resolution).area_m2();
+
+        let circle = sector_to_cells(center, 2000.0, 0.0, 360.0, resolution).unwrap();
+        let expected = std::f64::consts::PI * 2000.0f64.powi(2) / cell_area_m2;
+        assert!((cell_count(&circle) as f64 / expected - 1.0).abs() < 0.05);
+
+        let quarter = sector_to_cells(center, 2000.0, 315.0, 45.0, resolution).unwrap();
+        assert!((cell_count(&quarter) as f64 / expected - 0.25).abs() < 0.03);
+        // the quarter points north
+        let origin = center.to_cell(resolution);
+        assert!(quarter
+            .iter()
+            .flatten()
+            .all(|cell| cell == origin || LatLng::from(cell).lat() > center.lat()));
+    }
+
+    #[test]
+    fn rect_area() {
+        let center = LatLng::new(45.0, 10.0).unwrap();
+        let resolution = Resolution::Ten;
+        let cell_area_m2 = center.to_cell(resolution).area_m2();
+
+        let rect = geodesic_rect_to_cells(center, 1000.0, 4000.0, 30.0, resolution).unwrap();
+        let expected = 1000.0 * 4000.0 / cell_area_m2;
+        assert!((cell_count(&rect) as f64 / expected - 1.0).abs() < 0.05);
+
+        assert!(matches!(
+            geodesic_rect_to_cells(center, 0.0, 4000.0, 30.0, resolution),
+            Err(Error::InvalidDistance(_))
+        ));
+    }
+}
@@ -1,11 +1,11 @@
 use crate::array::{CellIndexArray, H3Array, H3ListArray, H3ListArrayBuilder};
 use crate::error::Error;
-use ahash::{HashMap, HashMapExt};
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use arrow::array::{
     Array, GenericListArray, GenericListBuilder, OffsetSizeTrait, PrimitiveArray, UInt32Array,
     UInt32Builder,
 };
-use h3o::{max_grid_disk_size, CellIndex};
+use h3o::{max_grid_disk_size, CellIndex, LatLng};
 use std::cmp::{max, min};
 use std::collections::hash_map::Entry;
 
@@ -30,6 +30,14 @@ where
     Self: Sized,
 {
     fn grid_disk<O: OffsetSizeTrait>(&self, k: u32) -> Result<H3ListArray<CellIndex, O>, Error>;
+
+    /// The union of the disks of all cells.
+    ///
+    /// The disks are merged into a single set right away, so the list of disks is never
+    /// materialized. The returned cells are sorted and free of duplicates. Invalid/empty values are
+    /// ignored.
+    fn grid_disk_union(&self, k: u32) -> Result<CellIndexArray, Error>;
+
     fn grid_disk_distances<O: OffsetSizeTrait>(
         &self,
         k: u32,
@@ -44,6 +52,35 @@ where
         k: u32,
         k_agg_method: KAggregationMethod,
     ) -> Result<GridDiskAggregateK, Error>;
+
+    /// Cells within grid distance `k` whose azimuth - as seen from the origin cell - lies within
+    /// the sector from `azimuth_min` to `azimuth_max`.
+    ///
+    /// Azimuths are given in degrees clockwise from north. Sectors with `azimuth_min > azimuth_max`
+    /// wrap around north. The origin cell itself is always included.
+    fn grid_disk_sector_distances<O: OffsetSizeTrait>(
+        &self,
+        k: u32,
+        azimuth_min: f64,
+        azimuth_max: f64,
+    ) -> Result<GridDiskDistances<O>, Error>;
+
+    /// The cells along the line from each cell to the cell at the same position of
+    /// `destinations` - both included.
+    ///
+    /// The path of a pair is null when one of the cells is null, when the cells are of different
+    /// resolutions or when no path can be computed - for example across pentagon distortion.
+    fn grid_path_cells<O: OffsetSizeTrait>(
+        &self,
+        destinations: &Self,
+    ) -> Result<H3ListArray<CellIndex, O>, Error>;
+
+    /// The grid distance from each cell to the cell at the same position of `others`.
+    ///
+    /// The distance of a pair is null when one of the cells is null, when the cells are of
+    /// different resolutions or when the distance is undefined - for example across pentagon
+    /// distortion.
+    fn grid_distance(&self, others: &Self) -> Result<UInt32Array, Error>;
 }
 
 impl GridOp for H3Array<CellIndex> {
@@ -68,11 +105,21 @@ impl GridOp for H3Array<CellIndex> {
         builder.finish()
     }
 
+    fn grid_disk_union(&self, k: u32) -> Result<CellIndexArray, Error> {
+        let mut union = HashSet::with_capacity(self.len());
+        for cell in self.iter().flatten() {
+            union.extend(cell.grid_disk::<Vec<_>>(k));
+        }
+        let mut cells: Vec<_> = union.into_iter().collect();
+        cells.sort_unstable();
+        Ok(cells.into())
+    }
+
     fn grid_disk_distances<O: OffsetSizeTrait>(
         &self,
         k: u32,
     ) -> Result<GridDiskDistances<O>, Error> {
-        build_grid_disk(self, k, |_, _| true)
+        build_grid_disk(self, k, |_, _, _| true)
     }
 
     fn grid_ring_distances<O: OffsetSizeTrait>(
@@ -80,7 +127,7 @@ impl GridOp for H3Array<CellIndex> {
         k_min: u32,
         k_max: u32,
     ) -> Result<GridDiskDistances<O>, Error> {
-        build_grid_disk(self, k_max, |_, k| k >= k_min)
+        build_grid_disk(self, k_max, |_, _, k| k >= k_min)
     }
 
     fn grid_disk_aggregate_k(
@@ -118,6 +165,87 @@ impl GridOp for H3Array<CellIndex> {
             distances: PrimitiveArray::new(distances.into(), None),
         })
     }
+
+    fn grid_disk_sector_distances<O: OffsetSizeTrait>(
+        &self,
+        k: u32,
+        azimuth_min: f64,
+        azimuth_max: f64,
+    ) -> Result<GridDiskDistances<O>, Error> {
+        let full_circle = azimuth_max - azimuth_min >= 360.0;
+        let azimuth_min = azimuth_min.rem_euclid(360.0);
+        let azimuth_max = azimuth_max.rem_euclid(360.0);
+
+        build_grid_disk(self, k, |origin, grid_cell, _| {
+            if full_circle || origin == grid_cell {
+                return true;
+            }
+            let azimuth = azimuth_degrees(origin, grid_cell);
+            if azimuth_min <= azimuth_max {
+                azimuth_min <= azimuth && azimuth <= azimuth_max
+            } else {
+                azimuth >= azimuth_min || azimuth <= azimuth_max
+            }
+        })
+    }
+
+    fn grid_path_cells<O: OffsetSizeTrait>(
+        &self,
+        destinations: &Self,
+    ) -> Result<H3ListArray<CellIndex, O>, Error> {
+        if self.len() != destinations.len() {
+            return Err(Error::LengthMismatch);
+        }
+        let mut builder = H3ListArrayBuilder::with_capacity(self.len(), self.len());
+        for (origin, destination) in self.iter().zip(destinations.iter()) {
+            let path = origin.zip(destination).and_then(|(origin, destination)| {
+                origin
+                    .grid_path_cells(destination)
+                    .and_then(|path| path.collect::<Result<Vec<_>, _>>())
+                    .ok()
+            });
+            match path {
+                Some(path) => {
+                    builder.values().append_many(path);
+                    builder.append(true);
+                }
+                None => builder.append(false),
+            }
+        }
+        builder.finish()
+    }
+
+    fn grid_distance(&self, others: &Self) -> Result<UInt32Array, Error> {
+        if self.len() != others.len() {
+            return Err(Error::LengthMismatch);
+        }
+        Ok(self
+            .iter()
+            .zip(others.iter())
+            .map(|(a, b)| {
+                a.zip(b)
+                    .and_then(|(a, b)| a.grid_distance(b).ok())
+                    .map(|distance| distance as u32)
+            })
+            .collect())
+    }
+}
+
+/// Initial bearing of the great circle from the center of `from` to the center of `to` in degrees
+/// clockwise from north in the range `[0, 360)`.
+pub fn azimuth_degrees(from: CellIndex, to: CellIndex) -> f64 {
+    latlng_azimuth_degrees(LatLng::from(from), LatLng::from(to))
+}
+
+/// Initial bearing of the great circle from `from` to `to` in degrees clockwise from north in the
+/// range `[0, 360)`.
+pub(crate) fn latlng_azimuth_degrees(from: LatLng, to: LatLng) -> f64 {
+    let (lat1, lat2) = (from.lat_radians(), to.lat_radians());
+    let delta_lng = to.lng_radians() - from.lng_radians();
+
+    let y = delta_lng.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lng.cos();
+    y.atan2(x).to_degrees().rem_euclid(360.0)
 }
 
 fn build_grid_disk<F, O: OffsetSizeTrait>(
@@ -126,7 +254,7 @@ fn build_grid_disk<F, O: OffsetSizeTrait>(
     filter: F,
 ) -> Result<GridDiskDistances<O>, Error>
 where
-    F: Fn(CellIndex, u32) -> bool,
+    F: Fn(CellIndex, CellIndex, u32) -> bool,
 {
     let mut grid_cells_builder = H3ListArrayBuilder::with_capacity(
         cellindexarray.len(),
@@ -144,7 +272,7 @@ where
             Some(cell) => {
                 for (grid_cell, grid_distance) in cell.grid_disk_distances::<Vec<_>>(k).into_iter()
                 {
-                    if filter(grid_cell, grid_distance) {
+                    if filter(cell, grid_cell, grid_distance) {
                         grid_cells_builder.values().append_value(grid_cell);
                         grid_distancess_builder.values().append_value(grid_distance);
                     }
@@ -169,3 +297,123 @@ where
         distances: grid_distances,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::Resolution;
+
+    fn sector(cells: &CellIndexArray, azimuth_min: f64, azimuth_max: f64) -> CellIndexArray {
+        cells
+            .grid_disk_sector_distances::<i32>(3, azimuth_min, azimuth_max)
+            .unwrap()
+            .cells
+            .into_flattened()
+            .unwrap()
+    }
+
+    #[test]
+    fn grid_disk_sector() {
+        let origin = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let cells: CellIndexArray = vec![origin].into();
+
+        let n_disk = max_grid_disk_size(3) as usize;
+        let east = sector(&cells, 45.0, 135.0);
+        let north = sector(&cells, 315.0, 45.0);
+        assert_eq!(sector(&cells, 0.0, 360.0).len(), n_disk);
+        assert!(east.len() > 1 && east.len() < n_disk / 2);
+        assert!(north.len() > 1 && north.len() < n_disk / 2);
+
+        for cell in east.iter().flatten().filter(|cell| *cell != origin) {
+            let azimuth = azimuth_degrees(origin, cell);
+            assert!((45.0..=135.0).contains(&azimuth));
+        }
+        for cell in north.iter().flatten().filter(|cell| *cell != origin) {
+            let azimuth = azimuth_degrees(origin, cell);
+            assert!(!(45.0..315.0).contains(&azimuth));
+        }
+    }
+
+    #[test]
+    fn grid_path_cells() {
+        let origin = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let destination = LatLng::new(10.1, 10.1).unwrap().to_cell(Resolution::Seven);
+        let origins: CellIndexArray = vec![Some(origin), Some(origin), None].into();
+        let destinations: CellIndexArray = vec![
+            Some(destination),
+            Some(destination.parent(Resolution::Six).unwrap()),
+            Some(destination),
+        ]
+        .into();
+
+        let paths = origins.grid_path_cells::<i64>(&destinations).unwrap();
+        let paths = paths.listarray();
+        assert!(paths.is_valid(0));
+        assert!(paths.is_null(1));
+        assert!(paths.is_null(2));
+
+        let path = paths.value(0);
+        let path: Vec<_> = path
+            .as_any()
+            .downcast_ref::<arrow::array::UInt64Array>()
+            .unwrap()
+            .values()
+            .iter()
+            .map(|value| CellIndex::try_from(*value).unwrap())
+            .collect();
+        assert_eq!(
+            path.len() as i32,
+            origin.grid_distance(destination).unwrap() + 1
+        );
+        assert_eq!(path.first(), Some(&origin));
+        assert_eq!(path.last(), Some(&destination));
+        assert!(path
+            .windows(2)
+            .all(|pair| pair[0].is_neighbor_with(pair[1]).unwrap()));
+    }
+
+    #[test]
+    fn grid_distance() {
+        let origin = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let neighbor = origin.grid_ring_fast(3).flatten().next().unwrap();
+        let pentagon = CellIndex::base_cells()
+            .find(|cell| cell.is_pentagon())
+            .unwrap()
+            .center_child(Resolution::Seven)
+            .unwrap();
+        let origins: CellIndexArray =
+            vec![Some(origin), Some(origin), None, Some(origin), Some(origin)].into();
+        let others: CellIndexArray = vec![
+            Some(neighbor),
+            Some(origin),
+            Some(origin),
+            Some(origin.parent(Resolution::Six).unwrap()),
+            Some(pentagon),
+        ]
+        .into();
+
+        let distances = origins.grid_distance(&others).unwrap();
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances.value(0), 3);
+        assert_eq!(distances.value(1), 0);
+        assert!(distances.is_null(2));
+        assert!(distances.is_null(3));
+        // too far away
+        assert!(distances.is_null(4));
+    }
+
+    #[test]
+    fn grid_disk_union() {
+        let a = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let b = a.grid_ring_fast(1).flatten().next().unwrap();
+        let cells: CellIndexArray = vec![Some(a), None, Some(b), Some(a)].into();
+
+        let mut expected: Vec<_> = a.grid_disk::<Vec<_>>(2);
+        expected.extend(b.grid_disk::<Vec<_>>(2));
+        expected.sort_unstable();
+        expected.dedup();
+
+        let union: Vec<_> = cells.grid_disk_union(2).unwrap().iter().flatten().collect();
+        assert_eq!(union, expected);
+    }
+}
@@ -0,0 +1,167 @@
+//! Kernel density estimation on the grid.
+//!
+//! The counts of the cells are spread over the rings around them, weighted by a kernel of the
+//! geodesic distance between the cell centers. This is the hexagonal counterpart of a raster KDE.
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::Float64Array;
+use h3o::{CellIndex, LatLng};
+use std::f64::consts::PI;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Kernel {
+    /// Normal distribution with the bandwidth as standard deviation - truncated at three bandwidths.
+    Gaussian,
+
+    /// Parabolic kernel reaching zero at the bandwidth.
+    Epanechnikov,
+}
+
+impl Kernel {
+    /// Distance beyond which the kernel is treated as zero, in multiples of the bandwidth.
+    fn support(&self) -> f64 {
+        match self {
+            Self::Gaussian => 3.0,
+            Self::Epanechnikov => 1.0,
+        }
+    }
+
+    /// Two-dimensional kernel density at the distance `u` given in multiples of the bandwidth,
+    /// for a bandwidth of one.
+    fn density(&self, u: f64) -> f64 {
+        match self {
+            Self::Gaussian if u <= 3.0 => (-0.5 * u * u).exp() / (2.0 * PI),
+            Self::Epanechnikov if u < 1.0 => 2.0 / PI * (1.0 - u * u),
+            _ => 0.0,
+        }
+    }
+}
+
+/// Densities of the cells of a KDE.
+pub struct Density {
+    pub cells: CellIndexArray,
+
+    /// Densities in counts per square kilometer.
+    pub densities: Float64Array,
+}
+
+/// Smooth the `counts` of the `cells` using a kernel with the given bandwidth.
+///
+/// The output contains all cells within the support of the kernel around the cells with a
+/// non-zero count, ordered by their first occurrence. Cells given more than once have their counts
+/// added up, null cells and counts are skipped. All cells must be of the same resolution. The
+/// number of rings searched grows with the ratio of the bandwidth to the cell size, so the
+/// bandwidth should be chosen in proportion to the resolution.
+pub fn kde(
+    cells: &CellIndexArray,
+    counts: &Float64Array,
+    bandwidth_m: f64,
+    kernel: Kernel,
+) -> Result<Density, Error> {
+    if cells.len() != counts.len() {
+        return Err(Error::LengthMismatch);
+    }
+    if !(bandwidth_m.is_finite() && bandwidth_m > 0.0) {
+        return Err(Error::InvalidBandwidth(bandwidth_m));
+    }
+
+    let mut sources: HashMap<CellIndex, f64> = HashMap::with_capacity(cells.len());
+    let mut source_order = vec![];
+    let mut resolution = None;
+    for (cell, count) in cells.iter().zip(counts.iter()) {
+        let (Some(cell), Some(count)) = (cell, count) else {
+            continue;
+        };
+        if *resolution.get_or_insert(cell.resolution()) != cell.resolution() {
+            return Err(Error::MixedResolutions);
+        }
+        let sum = sources.entry(cell).or_insert_with(|| {
+            source_order.push(cell);
+            0.0
+        });
+        *sum += count;
+    }
+    let Some(resolution) = resolution else {
+        return Ok(Density {
+            cells: Vec::<CellIndex>::new().into(),
+            densities: Float64Array::from(Vec::<f64>::new()),
+        });
+    };
+
+    // the centers of neighboring cells are about sqrt(3) edge lengths apart. Cells are smaller
+    // than average towards the pentagons, so one additional ring is searched.
+    let spacing_m = resolution.edge_length_m() * 3f64.sqrt();
+    let k = (kernel.support() * bandwidth_m / spacing_m).ceil() as u32 + 1;
+    let bandwidth_km = bandwidth_m / 1000.0;
+    let normalization = 1.0 / (bandwidth_km * bandwidth_km);
+
+    let mut densities: HashMap<CellIndex, usize> = HashMap::new();
+    let mut out_cells = vec![];
+    let mut out_densities: Vec<f64> = vec![];
+    for source in source_order {
+        let count = sources[&source];
+        if count == 0.0 {
+            continue;
+        }
+        let source_latlng = LatLng::from(source);
+        for target in source.grid_disk::<Vec<_>>(k) {
+            let u = source_latlng.distance_m(LatLng::from(target)) / bandwidth_m;
+            let density = kernel.density(u);
+            if density <= 0.0 {
+                continue;
+            }
+            let index = *densities.entry(target).or_insert_with(|| {
+                out_cells.push(target);
+                out_densities.push(0.0);
+                out_cells.len() - 1
+            });
+            out_densities[index] += count * density * normalization;
+        }
+    }
+
+    Ok(Density {
+        cells: out_cells.into(),
+        densities: out_densities.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::Resolution;
+
+    #[test]
+    fn mass_is_preserved() {
+        let cell = LatLng::new(45.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let cells: CellIndexArray = vec![cell].into();
+        let counts = Float64Array::from(vec![100.0]);
+        let cell_area_km2 = cell.area_km2();
+
+        for kernel in [Kernel::Gaussian, Kernel::Epanechnikov] {
+            let density = kde(&cells, &counts, 1000.0, kernel).unwrap();
+            let mass: f64 = density.densities.values().iter().sum::<f64>() * cell_area_km2;
+            assert!((mass - 100.0).abs() < 2.0, "{:?}: {}", kernel, mass);
+
+            // the peak is at the source cell
+            let peak = density
+                .densities
+                .values()
+                .iter()
+                .cloned()
+                .fold(f64::MIN, f64::max);
+            assert_eq!(density.densities.value(0), peak);
+            assert_eq!(density.cells.iter().next(), Some(Some(cell)));
+        }
+    }
+
+    #[test]
+    fn invalid_bandwidth() {
+        let cells: CellIndexArray = Vec::<CellIndex>::new().into();
+        let counts = Float64Array::from(Vec::<f64>::new());
+        assert!(matches!(
+            kde(&cells, &counts, 0.0, Kernel::Gaussian),
+            Err(Error::InvalidBandwidth(_))
+        ));
+    }
+}
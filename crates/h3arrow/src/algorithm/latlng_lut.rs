@@ -0,0 +1,231 @@
+//! Lookup table accelerating the conversion of densely located coordinates to cells.
+//!
+//! The coordinates are binned into a regular grid of bins considerably smaller than the cells. Bins
+//! located completely within the inscribed circle of a single cell are resolved by a single hash
+//! lookup, all other coordinates fall back to `LatLng::to_cell`. The results are identical to
+//! `LatLng::to_cell` - the table only trades memory for throughput. This pays off when many
+//! coordinates share bins, for example GPS tracks or the events of a city. Sparse coordinates are
+//! converted slower than by `LatLng::to_cell` as each bin is only resolved once.
+use ahash::{HashMap, HashMapExt};
+use h3o::{CellIndex, LatLng, Resolution};
+
+/// Number of bins along the average edge length of the cells.
+const BINS_PER_EDGE: f64 = 4.0;
+
+/// Coordinates beyond this latitude - about 80 degrees, in radians - are always converted using
+/// `LatLng::to_cell` as the bins get too distorted towards the poles.
+const MAX_LAT_RADS: f64 = 1.396;
+
+/// Margins added to the distance of a bin to the center of the cell before comparing it to the
+/// inscribed circle of the cell, to stay clear of floating point inaccuracies.
+const RELATIVE_MARGIN: f64 = 1e-3;
+const ABSOLUTE_MARGIN_RADS: f64 = 1e-12;
+
+/// Default limit of the number of bins - roughly 40MB.
+pub const DEFAULT_MAX_BINS: usize = 1 << 20;
+
+/// Converts coordinates to cells of a single resolution, memoizing the cells of the visited bins.
+pub struct LatLngLut {
+    resolution: Resolution,
+
+    /// edge length of the bins in radians
+    bin_size: f64,
+
+    max_bins: usize,
+
+    /// The cell of each visited bin, `None` for bins not located within a single cell.
+    bins: HashMap<(i64, i64), Option<CellIndex>>,
+
+    /// Radius of the inscribed circle of the cells of the bins, in radians.
+    inradii: HashMap<CellIndex, f64>,
+}
+
+impl LatLngLut {
+    pub fn new(resolution: Resolution) -> Self {
+        Self::with_max_bins(resolution, DEFAULT_MAX_BINS)
+    }
+
+    /// Create a table holding at most `max_bins` bins. Coordinates of further bins are converted
+    /// using `LatLng::to_cell`.
+    pub fn with_max_bins(resolution: Resolution, max_bins: usize) -> Self {
+        Self {
+            resolution,
+            bin_size: resolution.edge_length_rads() / BINS_PER_EDGE,
+            max_bins,
+            bins: HashMap::new(),
+            inradii: HashMap::new(),
+        }
+    }
+
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+
+    /// The number of visited bins.
+    pub fn len(&self) -> usize {
+        self.bins.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bins.is_empty()
+    }
+
+    /// The cell containing `latlng` - identical to `latlng.to_cell(self.resolution())`.
+    pub fn to_cell(&mut self, latlng: LatLng) -> CellIndex {
+        let lat = latlng.lat_radians();
+        if lat.abs() > MAX_LAT_RADS {
+            return latlng.to_cell(self.resolution);
+        }
+        let key = (
+            (lat / self.bin_size).floor() as i64,
+            (latlng.lng_radians() / self.bin_size).floor() as i64,
+        );
+        let cell = match self.bins.get(&key) {
+            Some(cell) => *cell,
+            None if self.bins.len() < self.max_bins => {
+                let cell = self.bin_cell(key);
+                self.bins.insert(key, cell);
+                cell
+            }
+            None => None,
+        };
+        cell.unwrap_or_else(|| latlng.to_cell(self.resolution))
+    }
+
+    /// The cell the bin is located in completely, if any.
+    fn bin_cell(&mut self, (row, col): (i64, i64)) -> Option<CellIndex> {
+        let bin_size = self.bin_size;
+        let latlng = |row: f64, col: f64| LatLng::from_radians(row * bin_size, col * bin_size).ok();
+        let (row, col) = (row as f64, col as f64);
+        let center = latlng(row + 0.5, col + 0.5)?;
+        let corners = [
+            latlng(row, col)?,
+            latlng(row + 1.0, col)?,
+            latlng(row, col + 1.0)?,
+            latlng(row + 1.0, col + 1.0)?,
+        ];
+
+        let cell = center.to_cell(self.resolution);
+        if cell.is_pentagon() {
+            return None;
+        }
+        // for bins this small, the corners are the points of the bin farthest from its center
+        let bin_radius = corners
+            .iter()
+            .map(|corner| center.distance_rads(*corner))
+            .fold(0.0, f64::max);
+        let distance = LatLng::from(cell).distance_rads(center) + bin_radius;
+        let inradius = *self.inradii.entry(cell).or_insert_with(|| inradius(cell));
+        if distance * (1.0 + RELATIVE_MARGIN) + ABSOLUTE_MARGIN_RADS >= inradius {
+            return None;
+        }
+        // cross-check against the exact conversion
+        corners
+            .iter()
+            .all(|corner| corner.to_cell(self.resolution) == cell)
+            .then_some(cell)
+    }
+}
+
+/// Radius of the circle around the center of `cell` located completely within the cell, in
+/// radians - the smallest distance of the center to the great circles of the edges of the cell.
+fn inradius(cell: CellIndex) -> f64 {
+    let center = unit_vector(LatLng::from(cell));
+    let boundary = cell.boundary();
+    let vertices: Vec<[f64; 3]> = boundary.iter().map(|ll| unit_vector(*ll)).collect();
+    (0..vertices.len())
+        .filter_map(|i| {
+            let normal = cross(vertices[i], vertices[(i + 1) % vertices.len()]);
+            let norm = dot(normal, normal).sqrt();
+            (norm > f64::EPSILON).then(|| (dot(center, normal) / norm).abs().asin())
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn unit_vector(latlng: LatLng) -> [f64; 3] {
+    let (lat, lng) = (latlng.lat_radians(), latlng.lng_radians());
+    [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[cfg(test)]
+mod tests {
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    use super::LatLngLut;
+
+    /// A dense lattice of `steps` x `steps` coordinates spanning a few cells around `center`.
+    fn lattice(center: LatLng, resolution: Resolution, steps: usize) -> Vec<LatLng> {
+        let extent = resolution.edge_length_rads() * 3.0;
+        let mut coordinates = Vec::with_capacity(steps * steps);
+        for row in 0..steps {
+            for col in 0..steps {
+                let lat = center.lat_radians() + extent * (row as f64 / steps as f64 - 0.5);
+                let lng = center.lng_radians() + extent * (col as f64 / steps as f64 - 0.5);
+                coordinates.push(LatLng::from_radians(lat, lng).unwrap());
+            }
+        }
+        coordinates
+    }
+
+    #[test]
+    fn matches_to_cell() {
+        let mut centers = vec![
+            LatLng::new(45.0, 10.0).unwrap(),
+            LatLng::new(0.0, 0.0).unwrap(),
+            LatLng::new(-33.9, 18.4).unwrap(),
+            LatLng::new(79.0, -179.99).unwrap(),
+        ];
+        // the vertices of the icosahedron faces
+        centers.extend(
+            CellIndex::base_cells()
+                .filter(|cell| cell.is_pentagon())
+                .map(LatLng::from),
+        );
+
+        for resolution in [Resolution::Three, Resolution::Nine, Resolution::Twelve] {
+            let mut lut = LatLngLut::new(resolution);
+            for center in centers.iter() {
+                for latlng in lattice(*center, resolution, 61) {
+                    assert_eq!(lut.to_cell(latlng), latlng.to_cell(resolution));
+                }
+            }
+            assert!(lut.bins.values().any(|cell| cell.is_some()));
+            assert!(lut.bins.values().any(|cell| cell.is_none()));
+        }
+    }
+
+    #[test]
+    fn matches_to_cell_globally() {
+        let mut lut = LatLngLut::new(Resolution::Five);
+        for lat in (-10..=10).map(|i| i as f64 * 7.5) {
+            for lng in (-12..12).map(|i| i as f64 * 15.0) {
+                let center = LatLng::new(lat, lng).unwrap();
+                for latlng in lattice(center, Resolution::Five, 21) {
+                    assert_eq!(lut.to_cell(latlng), latlng.to_cell(Resolution::Five));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn max_bins() {
+        let mut lut = LatLngLut::with_max_bins(Resolution::Nine, 10);
+        let center = LatLng::new(45.0, 10.0).unwrap();
+        for latlng in lattice(center, Resolution::Nine, 61) {
+            assert_eq!(lut.to_cell(latlng), latlng.to_cell(Resolution::Nine));
+        }
+        assert_eq!(lut.len(), 10);
+    }
+}
@@ -1,13 +1,33 @@
+pub mod adjacency;
 pub mod bounding_rect;
+pub mod catchment;
 pub mod centroid;
 pub mod change_resolution;
 pub mod compact;
 pub mod concave_hull;
 pub mod convex_hull;
 pub mod coordinates;
+pub mod coverage;
+pub mod dasymetric;
+pub mod difference;
+pub mod distance_transform;
+pub mod footprint;
 pub mod grid;
+pub mod kde;
+#[cfg(feature = "lut")]
+pub mod latlng_lut;
 pub mod localij;
+pub mod morphology;
+pub mod outliers;
+pub mod overlap;
+pub mod path;
+pub mod payload;
+pub mod regionalize;
+pub mod skeleton;
 pub mod string;
+pub mod viewshed;
+pub mod voronoi;
+pub mod window;
 
 #[allow(unused_imports)]
 pub use bounding_rect::*;
@@ -24,6 +44,10 @@ pub use convex_hull::*;
 #[allow(unused_imports)]
 pub use coordinates::*;
 #[allow(unused_imports)]
+pub use coverage::*;
+#[allow(unused_imports)]
+pub use difference::*;
+#[allow(unused_imports)]
 pub use grid::*;
 #[allow(unused_imports)]
 pub use string::*;
@@ -0,0 +1,190 @@
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt, HashSet};
+use h3o::{CellIndex, Resolution};
+use std::collections::hash_map::Entry;
+
+/// Dilate the cells by `grow_k` rings and erode the result by `shrink_k` rings - a morphological
+/// closing when both are equal - in a single pass.
+///
+/// The dilation is computed using a breadth-first search which merges the rings into a set right
+/// away, so the potentially huge list of `grid_disk` results is never materialized. The search also
+/// tracks the distance of each cell to the nearest input cell. Cells within
+/// `grow_k - shrink_k` of an input cell are known to survive the erosion, so only the remaining
+/// cells close to the border of the dilated set need to be checked.
+///
+/// The returned cells are sorted and free of duplicates. Invalid/empty values are ignored. All
+/// cells must be of the same resolution.
+pub fn cells_expand_contract(
+    cells: &CellIndexArray,
+    grow_k: u32,
+    shrink_k: u32,
+) -> Result<CellIndexArray, Error> {
+    ensure_single_resolution(cells)?;
+
+    let mut distances: HashMap<CellIndex, u32> = HashMap::new();
+    let mut frontier: Vec<CellIndex> = cells
+        .iter()
+        .flatten()
+        .filter(|cell| distances.insert(*cell, 0).is_none())
+        .collect();
+
+    for k in 1..=grow_k {
+        let mut next_frontier = vec![];
+        for cell in frontier {
+            for neighbor in cell.grid_disk::<Vec<_>>(1) {
+                if let Entry::Vacant(entry) = distances.entry(neighbor) {
+                    entry.insert(k);
+                    next_frontier.push(neighbor);
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    let safe_distance = grow_k.saturating_sub(shrink_k);
+    let mut out: Vec<CellIndex> = distances
+        .iter()
+        .filter(|(cell, distance)| {
+            shrink_k == 0
+                || (shrink_k <= grow_k && **distance <= safe_distance)
+                || cell
+                    .grid_disk::<Vec<_>>(shrink_k)
+                    .iter()
+                    .all(|neighbor| distances.contains_key(neighbor))
+        })
+        .map(|(cell, _)| *cell)
+        .collect();
+    out.sort_unstable();
+    Ok(out.into())
+}
+
+/// Find the cells forming the boundary of the set of `cells`.
+///
+/// With `outside` set to `false`, the cells of the set having at least one neighbor outside the
+/// set are returned. Setting `outside` returns the ring of cells surrounding the set instead -
+/// the cells not part of the set, but having at least one neighbor in it.
+///
+/// The returned cells are sorted and free of duplicates. Invalid/empty values are ignored. All
+/// cells must be of the same resolution.
+pub fn cells_boundary(cells: &CellIndexArray, outside: bool) -> Result<CellIndexArray, Error> {
+    ensure_single_resolution(cells)?;
+
+    let set: HashSet<CellIndex> = cells.iter().flatten().collect();
+    let mut out: Vec<CellIndex> = if outside {
+        set.iter()
+            .flat_map(|cell| cell.grid_disk::<Vec<_>>(1))
+            .filter(|neighbor| !set.contains(neighbor))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect()
+    } else {
+        set.iter()
+            .filter(|cell| {
+                cell.grid_disk::<Vec<_>>(1)
+                    .iter()
+                    .any(|neighbor| !set.contains(neighbor))
+            })
+            .copied()
+            .collect()
+    };
+    out.sort_unstable();
+    Ok(out.into())
+}
+
+fn ensure_single_resolution(cells: &CellIndexArray) -> Result<(), Error> {
+    let mut resolution: Option<Resolution> = None;
+    for cell in cells.iter().flatten() {
+        match resolution {
+            None => resolution = Some(cell.resolution()),
+            Some(r) if r != cell.resolution() => return Err(Error::MixedResolutions),
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::LatLng;
+
+    fn disk(cell: CellIndex, k: u32) -> HashSet<CellIndex> {
+        cell.grid_disk::<Vec<_>>(k).into_iter().collect()
+    }
+
+    #[test]
+    fn closing_fills_gap() {
+        let center = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        // a ring with a hole in the middle
+        let ring: Vec<_> = center.grid_ring_fast(1).flatten().collect();
+        let closed = cells_expand_contract(&ring.clone().into(), 1, 1).unwrap();
+        let closed: HashSet<_> = closed.iter().flatten().collect();
+
+        assert!(closed.contains(&center));
+        for cell in ring {
+            assert!(closed.contains(&cell));
+        }
+    }
+
+    #[test]
+    fn matches_naive_dilate_erode() {
+        let a = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let b = LatLng::new(10.03, 10.02)
+            .unwrap()
+            .to_cell(Resolution::Seven);
+        let cells: CellIndexArray = vec![a, b].into();
+
+        for (grow_k, shrink_k) in [(2, 1), (1, 2), (3, 3), (2, 0)] {
+            let mut dilated = disk(a, grow_k);
+            dilated.extend(disk(b, grow_k));
+            let mut expected: Vec<_> = dilated
+                .iter()
+                .filter(|cell| disk(**cell, shrink_k).is_subset(&dilated))
+                .copied()
+                .collect();
+            expected.sort_unstable();
+
+            let result: Vec<_> = cells_expand_contract(&cells, grow_k, shrink_k)
+                .unwrap()
+                .iter()
+                .flatten()
+                .collect();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn boundary_of_disk() {
+        let center = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let cells: CellIndexArray = center.grid_disk::<Vec<_>>(2).into();
+
+        let mut expected_inside: Vec<_> = center.grid_ring_fast(2).flatten().collect();
+        expected_inside.sort_unstable();
+        let inside: Vec<_> = cells_boundary(&cells, false)
+            .unwrap()
+            .iter()
+            .flatten()
+            .collect();
+        assert_eq!(inside, expected_inside);
+
+        let mut expected_outside: Vec<_> = center.grid_ring_fast(3).flatten().collect();
+        expected_outside.sort_unstable();
+        let outside: Vec<_> = cells_boundary(&cells, true)
+            .unwrap()
+            .iter()
+            .flatten()
+            .collect();
+        assert_eq!(outside, expected_outside);
+    }
+
+    #[test]
+    fn mixed_resolutions() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let cells: CellIndexArray = vec![cell, cell.parent(Resolution::Six).unwrap()].into();
+        assert!(matches!(
+            cells_expand_contract(&cells, 1, 1),
+            Err(Error::MixedResolutions)
+        ));
+    }
+}
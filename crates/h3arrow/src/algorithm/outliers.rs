@@ -0,0 +1,162 @@
+//! Detection of cells whose value deviates from the values of their neighborhood.
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::{BooleanArray, Float64Array};
+use h3o::CellIndex;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum OutlierMethod {
+    /// Deviation from the mean of the neighborhood in standard deviations.
+    ZScore,
+
+    /// Distance beyond the quartiles of the neighborhood in interquartile ranges - Tukey's fences.
+    Iqr,
+}
+
+/// Outlier scores and flags aligned with the input cells.
+pub struct Outliers {
+    pub scores: Float64Array,
+    pub flags: BooleanArray,
+}
+
+/// Score each cell by how strongly its value deviates from the values of the cells within grid
+/// distance `k` - excluding the cell itself.
+///
+/// The scores are signed - negative for values below the neighborhood. Cells with an absolute score
+/// above `threshold` are flagged as outliers. Cells without a value or with less than two neighbors
+/// having a value are null in the output. A neighborhood without any spread scores values
+/// different from it as infinite.
+pub fn spatial_outliers(
+    cells: &CellIndexArray,
+    values: &Float64Array,
+    k: u32,
+    method: OutlierMethod,
+    threshold: f64,
+) -> Result<Outliers, Error> {
+    if cells.len() != values.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    let mut surface: HashMap<CellIndex, f64> = HashMap::with_capacity(cells.len());
+    for (cell, value) in cells.iter().zip(values.iter()) {
+        if let (Some(cell), Some(value)) = (cell, value) {
+            if !value.is_nan() {
+                surface.insert(cell, value);
+            }
+        }
+    }
+
+    let mut neighborhood = vec![];
+    let scores: Float64Array = cells
+        .iter()
+        .zip(values.iter())
+        .map(|(cell, value)| {
+            let (cell, value) = (cell?, value?);
+            if value.is_nan() {
+                return None;
+            }
+            neighborhood.clear();
+            neighborhood.extend(
+                cell.grid_disk::<Vec<_>>(k)
+                    .into_iter()
+                    .filter(|neighbor| *neighbor != cell)
+                    .filter_map(|neighbor| surface.get(&neighbor).copied()),
+            );
+            if neighborhood.len() < 2 {
+                return None;
+            }
+            Some(match method {
+                OutlierMethod::ZScore => zscore(value, &neighborhood),
+                OutlierMethod::Iqr => iqr_score(value, &mut neighborhood),
+            })
+        })
+        .collect();
+
+    let flags = scores
+        .iter()
+        .map(|score| score.map(|score| score.abs() > threshold))
+        .collect();
+    Ok(Outliers { scores, flags })
+}
+
+fn deviation_score(deviation: f64, spread: f64) -> f64 {
+    if deviation == 0.0 {
+        0.0
+    } else {
+        deviation / spread
+    }
+}
+
+fn zscore(value: f64, neighborhood: &[f64]) -> f64 {
+    let n = neighborhood.len() as f64;
+    let mean = neighborhood.iter().sum::<f64>() / n;
+    let variance = neighborhood.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    deviation_score(value - mean, variance.sqrt())
+}
+
+fn iqr_score(value: f64, neighborhood: &mut [f64]) -> f64 {
+    neighborhood.sort_unstable_by(f64::total_cmp);
+    let q1 = quantile(neighborhood, 0.25);
+    let q3 = quantile(neighborhood, 0.75);
+    let deviation = if value > q3 {
+        value - q3
+    } else if value < q1 {
+        value - q1
+    } else {
+        0.0
+    };
+    deviation_score(deviation, q3 - q1)
+}
+
+/// Linearly interpolated quantile of sorted values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn spike_is_flagged() {
+        let center = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let disk: Vec<_> = center.grid_disk_distances::<Vec<_>>(3);
+        let cells: CellIndexArray = disk
+            .iter()
+            .map(|(cell, _)| *cell)
+            .collect::<Vec<_>>()
+            .into();
+        let values: Float64Array = disk
+            .iter()
+            .map(|(cell, _)| Some(if *cell == center { 100.0 } else { 1.0 }))
+            .collect();
+
+        for (method, threshold) in [(OutlierMethod::ZScore, 3.0), (OutlierMethod::Iqr, 1.5)] {
+            let outliers = spatial_outliers(&cells, &values, 1, method, threshold).unwrap();
+            assert_eq!(outliers.scores.len(), cells.len());
+            for (i, (cell, _)) in disk.iter().enumerate() {
+                if *cell == center {
+                    assert!(outliers.flags.value(i));
+                    assert!(outliers.scores.value(i) > threshold);
+                }
+            }
+            assert_eq!(
+                outliers.flags.iter().flatten().filter(|flag| *flag).count(),
+                1,
+                "{:?}",
+                method
+            );
+        }
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_eq!(quantile(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.25), 2.0);
+        assert_eq!(quantile(&[1.0, 2.0, 3.0, 4.0], 0.75), 3.25);
+    }
+}
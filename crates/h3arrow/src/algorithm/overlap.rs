@@ -0,0 +1,114 @@
+//! Resolution of cells claimed by multiple geometries.
+//!
+//! The functions of this module operate on the rows of (geometry, cell) pairs as produced
+//! when converting multiple geometries to cells. Rows are addressed by their position.
+use ahash::{HashMap, HashMapExt};
+use geo::{Area, BooleanOps, LineString, Polygon};
+use geo_types::Geometry;
+use h3o::CellIndex;
+
+/// Area of the intersection of `cell` and the polygonal parts of `geometry`.
+///
+/// The area is calculated in the units of the coordinates - degrees - and is only meant to be
+/// compared with other areas of the same cell. Non-polygonal geometries have no area.
+pub fn cell_intersection_area(cell: CellIndex, geometry: &Geometry) -> f64 {
+    let cell_polygon = Polygon::new(LineString::from(cell.boundary()), vec![]);
+    match geometry {
+        Geometry::Polygon(polygon) => cell_polygon.intersection(polygon).unsigned_area(),
+        Geometry::MultiPolygon(multipolygon) => {
+            cell_polygon.intersection(multipolygon).unsigned_area()
+        }
+        Geometry::Rect(rect) => cell_polygon
+            .intersection(&rect.to_polygon())
+            .unsigned_area(),
+        Geometry::Triangle(triangle) => cell_polygon
+            .intersection(&triangle.to_polygon())
+            .unsigned_area(),
+        Geometry::GeometryCollection(collection) => collection
+            .iter()
+            .map(|geometry| cell_intersection_area(cell, geometry))
+            .sum(),
+        _ => 0.0,
+    }
+}
+
+/// Group the rows by their cell.
+///
+/// The groups are ordered by the first occurrence of their cell, the row positions within
+/// each group are ascending.
+pub fn group_rows_by_cell(cells: &[CellIndex]) -> Vec<(CellIndex, Vec<usize>)> {
+    let mut group_positions: HashMap<CellIndex, usize> = HashMap::with_capacity(cells.len());
+    let mut groups: Vec<(CellIndex, Vec<usize>)> = Vec::with_capacity(cells.len());
+
+    for (row, cell) in cells.iter().enumerate() {
+        let group_pos = *group_positions.entry(*cell).or_insert_with(|| {
+            groups.push((*cell, vec![]));
+            groups.len() - 1
+        });
+        groups[group_pos].1.push(row);
+    }
+    groups
+}
+
+/// Select a single row for each distinct cell - the one with the highest score.
+///
+/// Ties are broken deterministically by selecting the row with the lowest position. The
+/// returned row positions are ascending.
+pub fn select_rows_by_highest_score(cells: &[CellIndex], scores: &[f64]) -> Vec<usize> {
+    debug_assert_eq!(cells.len(), scores.len());
+
+    let mut selected: Vec<usize> = group_rows_by_cell(cells)
+        .into_iter()
+        .filter_map(|(_, rows)| {
+            rows.into_iter().reduce(|best, row| {
+                if scores[row] > scores[best] {
+                    row
+                } else {
+                    best
+                }
+            })
+        })
+        .collect();
+    selected.sort_unstable();
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::{polygon, Geometry};
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn largest_intersection_wins() {
+        let cell = LatLng::new(0.5, 0.5).unwrap().to_cell(Resolution::Five);
+        let small: Geometry = polygon![
+            (x: 0.0, y: 0.0), (x: 0.5, y: 0.0), (x: 0.5, y: 1.0), (x: 0.0, y: 1.0), (x: 0.0, y: 0.0)
+        ]
+        .into();
+        let large: Geometry = polygon![
+            (x: 0.49, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.49, y: 1.0), (x: 0.49, y: 0.0)
+        ]
+        .into();
+
+        let cells = vec![cell, cell];
+        let scores = vec![
+            cell_intersection_area(cell, &small),
+            cell_intersection_area(cell, &large),
+        ];
+        assert!(scores[0] > 0.0);
+        assert_eq!(select_rows_by_highest_score(&cells, &scores), vec![1]);
+    }
+
+    #[test]
+    fn ties_select_first_row() {
+        let cell_a = LatLng::new(0.5, 0.5).unwrap().to_cell(Resolution::Five);
+        let cell_b = LatLng::new(10.5, 0.5).unwrap().to_cell(Resolution::Five);
+        let cells = vec![cell_a, cell_b, cell_a, cell_b];
+        let scores = vec![1.0, 1.0, 1.0, 2.0];
+        assert_eq!(select_rows_by_highest_score(&cells, &scores), vec![0, 3]);
+
+        let groups = group_rows_by_cell(&cells);
+        assert_eq!(groups, vec![(cell_a, vec![0, 2]), (cell_b, vec![1, 3])]);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use h3o::{CellIndex, LatLng};
+
+/// Thin out a path of cells - for example the cells of a trajectory - using the
+/// Ramer-Douglas-Peucker algorithm on the great circles between the centroids of the cells.
+///
+/// A cell is dropped when its centroid is closer than `tolerance_cells` cells to the great circle
+/// arc between the centroids of the retained cells before and after it, and `grid_path_cells` is
+/// able to connect these retained cells. Connecting the returned cells using `grid_path_cells`
+/// yields a path deviating at most by roughly `tolerance_cells` cells from the input - connected
+/// when the input was connected. The first and the last cell are always retained.
+///
+/// Consecutive duplicates and invalid/empty values are removed. All cells must be of the same
+/// resolution.
+pub fn simplify_cell_path(
+    cells: &CellIndexArray,
+    tolerance_cells: f64,
+) -> Result<CellIndexArray, Error> {
+    if tolerance_cells.is_nan() || tolerance_cells < 0.0 {
+        return Err(Error::InvalidTolerance(tolerance_cells));
+    }
+
+    let mut path: Vec<CellIndex> = Vec::with_capacity(cells.len());
+    for cell in cells.iter().flatten() {
+        if let Some(last) = path.last() {
+            if last.resolution() != cell.resolution() {
+                return Err(Error::MixedResolutions);
+            }
+            if *last == cell {
+                continue;
+            }
+        }
+        path.push(cell);
+    }
+    if path.len() < 3 {
+        return Ok(path.into());
+    }
+
+    // the distance between the centroids of neighboring cells
+    let tolerance_rads = tolerance_cells * path[0].resolution().edge_length_rads() * 3f64.sqrt();
+    let points: Vec<_> = path
+        .iter()
+        .map(|cell| unit_vector(LatLng::from(*cell)))
+        .collect();
+
+    let mut retain = vec![false; path.len()];
+    retain[0] = true;
+    retain[path.len() - 1] = true;
+
+    let mut stack = vec![(0, path.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let farthest = (start + 1..end)
+            .map(|i| (i, arc_distance(&points[i], &points[start], &points[end])))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((i, distance)) = farthest {
+            if distance > tolerance_rads || !connectable(path[start], path[end]) {
+                retain[i] = true;
+                stack.push((start, i));
+                stack.push((i, end));
+            }
+        }
+    }
+
+    Ok(path
+        .into_iter()
+        .zip(retain)
+        .filter_map(|(cell, retain)| retain.then_some(cell))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+/// Whether `grid_path_cells` is able to connect both cells. It fails for cells too far apart or
+/// separated by pentagon distortion.
+fn connectable(a: CellIndex, b: CellIndex) -> bool {
+    a.grid_path_cells(b)
+        .is_ok_and(|mut cells| cells.all(|cell| cell.is_ok()))
+}
+
+type Vec3 = [f64; 3];
+
+fn unit_vector(ll: LatLng) -> Vec3 {
+    let (lat, lng) = (ll.lat_radians(), ll.lng_radians());
+    [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()]
+}
+
+fn dot(a: &Vec3, b: &Vec3) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: &Vec3, b: &Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn angle(a: &Vec3, b: &Vec3) -> f64 {
+    let c = cross(a, b);
+    dot(&c, &c).sqrt().atan2(dot(a, b))
+}
+
+/// Angular distance of `p` to the great circle arc from `a` to `b`.
+fn arc_distance(p: &Vec3, a: &Vec3, b: &Vec3) -> f64 {
+    let normal = cross(a, b);
+    let normal_length = dot(&normal, &normal).sqrt();
+    if normal_length < f64::EPSILON {
+        return angle(p, a).min(angle(p, b));
+    }
+    let normal = normal.map(|v| v / normal_length);
+
+    // the projection of p onto the great circle lies on the arc when it is on the inner side of
+    // both endpoints
+    let p_dot_n = dot(p, &normal);
+    let projected = [
+        p[0] - p_dot_n * normal[0],
+        p[1] - p_dot_n * normal[1],
+        p[2] - p_dot_n * normal[2],
+    ];
+    if dot(&cross(a, &projected), &normal) >= 0.0 && dot(&cross(&projected, b), &normal) >= 0.0 {
+        p_dot_n.abs().clamp(0.0, 1.0).asin()
+    } else {
+        angle(p, a).min(angle(p, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::Resolution;
+
+    #[test]
+    fn straight_path_is_reduced_to_endpoints() {
+        let start = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let end = LatLng::new(10.1, 10.1).unwrap().to_cell(Resolution::Nine);
+        let path: Vec<_> = start.grid_path_cells(end).unwrap().flatten().collect();
+        assert!(path.len() > 10);
+
+        let simplified = simplify_cell_path(&path.clone().into(), 1.0).unwrap();
+        let simplified: Vec<_> = simplified.iter().flatten().collect();
+        assert_eq!(simplified, vec![start, end]);
+
+        // no tolerance keeps the cells deviating from the great circle
+        let unsimplified = simplify_cell_path(&path.clone().into(), 0.0).unwrap();
+        assert!(unsimplified.len() > 2);
+    }
+
+    #[test]
+    fn corner_is_retained() {
+        let a = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let b = LatLng::new(10.0, 10.1).unwrap().to_cell(Resolution::Nine);
+        let c = LatLng::new(10.1, 10.1).unwrap().to_cell(Resolution::Nine);
+        let mut path: Vec<_> = a.grid_path_cells(b).unwrap().flatten().collect();
+        path.extend(b.grid_path_cells(c).unwrap().flatten().skip(1));
+
+        let simplified = simplify_cell_path(&path.into(), 2.0).unwrap();
+        let simplified: Vec<_> = simplified.iter().flatten().collect();
+        assert_eq!(simplified.len(), 3);
+        assert_eq!((simplified[0], simplified[2]), (a, c));
+        assert!(simplified[1].grid_distance(b).unwrap() <= 2);
+    }
+
+    #[test]
+    fn retained_cells_are_connectable() {
+        // a connected path of neighboring cells spanning a large part of the globe
+        let start = LatLng::new(10.0, 0.0).unwrap().to_cell(Resolution::Two);
+        let end = LatLng::new(10.0, 150.0).unwrap().to_cell(Resolution::Two);
+        let target = unit_vector(LatLng::from(end));
+        let mut path = vec![start];
+        while *path.last().unwrap() != end {
+            let next = path
+                .last()
+                .unwrap()
+                .grid_disk::<Vec<_>>(1)
+                .into_iter()
+                .max_by(|a, b| {
+                    dot(&unit_vector(LatLng::from(*a)), &target)
+                        .total_cmp(&dot(&unit_vector(LatLng::from(*b)), &target))
+                })
+                .unwrap();
+            path.push(next);
+        }
+        assert!(!connectable(start, end));
+
+        let simplified: Vec<_> = simplify_cell_path(&path.into(), 1000.0)
+            .unwrap()
+            .iter()
+            .flatten()
+            .collect();
+        assert!(simplified.len() > 2);
+        assert_eq!((simplified[0], *simplified.last().unwrap()), (start, end));
+        for pair in simplified.windows(2) {
+            assert!(connectable(pair[0], pair[1]));
+        }
+    }
+
+    #[test]
+    fn invalid_tolerance() {
+        let cells: CellIndexArray = Vec::<CellIndex>::new().into();
+        assert!(matches!(
+            simplify_cell_path(&cells, f64::NAN),
+            Err(Error::InvalidTolerance(_))
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+//! Packing of small integer payloads into the unused bits of cells.
+//!
+//! The index digits of an H3 cell below its resolution are unused and always set to `7`. This
+//! leaves `3 * (15 - resolution)` bits which can carry a payload - for example a category id -
+//! allowing to store (cell, payload) pairs in a single `UInt64` column.
+//!
+//! The encoded values are no valid cells anymore and need to be decoded before they can be
+//! used with any other H3 operation.
+use crate::array::{CellIndexArray, H3ArrayBuilder};
+use crate::error::Error;
+use arrow::array::{UInt64Array, UInt64Builder};
+use h3o::{CellIndex, Resolution};
+
+const RESOLUTION_OFFSET: u64 = 52;
+const RESOLUTION_MASK: u64 = 0b1111 << RESOLUTION_OFFSET;
+
+/// Number of bits available to store a payload in a cell of the given `resolution`.
+pub fn payload_capacity_bits(resolution: Resolution) -> u32 {
+    3 * (15 - u32::from(u8::from(resolution)))
+}
+
+#[inline]
+fn unused_bits_mask(resolution: u8) -> u64 {
+    // resolutions are limited to 0..=15 by the 4 bits used for storing them.
+    (1u64 << (3 * (15 - u32::from(resolution)))) - 1
+}
+
+/// Pack `payload` into the unused bits of `cell`.
+pub fn encode_payload(cell: CellIndex, payload: u64) -> Result<u64, Error> {
+    let mask = unused_bits_mask(u8::from(cell.resolution()));
+    if payload > mask {
+        return Err(Error::PayloadOverflow(
+            payload,
+            payload_capacity_bits(cell.resolution()),
+        ));
+    }
+    Ok((u64::from(cell) & !mask) | payload)
+}
+
+/// Split a value created by [`encode_payload`] into the cell and the payload.
+pub fn decode_payload(value: u64) -> Result<(CellIndex, u64), Error> {
+    let resolution = ((value & RESOLUTION_MASK) >> RESOLUTION_OFFSET) as u8;
+    let mask = unused_bits_mask(resolution);
+    let cell = CellIndex::try_from(value | mask)?;
+    Ok((cell, value & mask))
+}
+
+pub struct PayloadCellArrays {
+    pub cells: CellIndexArray,
+    pub payloads: UInt64Array,
+}
+
+impl PayloadCellArrays {
+    pub fn try_new(cells: CellIndexArray, payloads: UInt64Array) -> Result<Self, Error> {
+        let instance = Self { cells, payloads };
+        instance.validate()?;
+        Ok(instance)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.cells.len() != self.payloads.len() {
+            return Err(Error::LengthMismatch);
+        }
+        Ok(())
+    }
+
+    /// Pack the payloads into the cells.
+    ///
+    /// Fails when a payload does not fit into the unused bits of its cell. Positions where
+    /// either the cell or the payload is null are null in the output.
+    pub fn encode(&self) -> Result<UInt64Array, Error> {
+        self.validate()?;
+
+        let mut builder = UInt64Builder::with_capacity(self.cells.len());
+        for (cell, payload) in self.cells.iter().zip(self.payloads.iter()) {
+            match (cell, payload) {
+                (Some(cell), Some(payload)) => builder.append_value(encode_payload(cell, payload)?),
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Unpack values created by [`PayloadCellArrays::encode`].
+    ///
+    /// Fails when a value does not decode to a valid cell. Null values are preserved as such.
+    pub fn decode(encoded: &UInt64Array) -> Result<Self, Error> {
+        let mut cells = H3ArrayBuilder::<CellIndex>::with_capacity(encoded.len());
+        let mut payloads = UInt64Builder::with_capacity(encoded.len());
+        for value in encoded.iter() {
+            if let Some(value) = value {
+                let (cell, payload) = decode_payload(value)?;
+                cells.append_value(cell);
+                payloads.append_value(payload);
+            } else {
+                cells.append_null();
+                payloads.append_null();
+            }
+        }
+        Ok(Self {
+            cells: cells.finish(),
+            payloads: payloads.finish(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Array;
+    use h3o::LatLng;
+
+    #[test]
+    fn roundtrip() {
+        let cells: CellIndexArray = vec![
+            Some(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five)),
+            None,
+            Some(
+                LatLng::new(12.3, 0.5)
+                    .unwrap()
+                    .to_cell(Resolution::Fourteen),
+            ),
+        ]
+        .into();
+        let payloads = UInt64Array::from(vec![Some(1234), Some(3), Some(7)]);
+
+        let encoded = PayloadCellArrays::try_new(cells.clone(), payloads)
+            .unwrap()
+            .encode()
+            .unwrap();
+        assert!(encoded.is_valid(0));
+        assert!(encoded.is_null(1));
+        assert!(CellIndex::try_from(encoded.value(0)).is_err());
+
+        let decoded = PayloadCellArrays::decode(&encoded).unwrap();
+        assert!(decoded.cells == cells);
+        assert_eq!(
+            decoded.payloads,
+            UInt64Array::from(vec![Some(1234), None, Some(7)])
+        );
+    }
+
+    #[test]
+    fn payload_overflow() {
+        let cell = LatLng::new(23.4, 12.4)
+            .unwrap()
+            .to_cell(Resolution::Fourteen);
+        assert_eq!(payload_capacity_bits(Resolution::Fourteen), 3);
+        assert!(encode_payload(cell, 7).is_ok());
+        assert!(matches!(
+            encode_payload(cell, 8),
+            Err(Error::PayloadOverflow(8, 3))
+        ));
+
+        let cell = LatLng::new(23.4, 12.4)
+            .unwrap()
+            .to_cell(Resolution::Fifteen);
+        assert!(encode_payload(cell, 0).is_ok());
+        assert!(encode_payload(cell, 1).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+//! Partitioning of cells into contiguous regions of balanced weight.
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+use arrow::array::{Float64Array, UInt32Array};
+use h3o::CellIndex;
+use std::collections::VecDeque;
+
+/// Partition the cells into `n_regions` contiguous regions with approximately the same total
+/// weight - for example to design sales territories or service areas.
+///
+/// The regions are grown one after another from the cell with the fewest unassigned neighbors,
+/// adding cells in breadth-first order until the region reaches its share of the weight. Pockets of
+/// cells cut off from the remaining cells are merged into the lightest neighboring region. This
+/// greedy heuristic keeps all regions contiguous, but does not guarantee an optimal balance.
+///
+/// Each connected part of the cells receives at least one region, the remaining regions are
+/// distributed among the parts by their weight. Parts with fewer cells than regions result in
+/// fewer regions. Null and negative weights count as zero. All cells must be of the same
+/// resolution; a cell given more than once is weighted by its last weight.
+///
+/// Returns the region number of each cell, aligned with the input. Null cells are null.
+pub fn regionalize(
+    cells: &CellIndexArray,
+    weights: &Float64Array,
+    n_regions: usize,
+) -> Result<UInt32Array, Error> {
+    if cells.len() != weights.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    let mut cell_weights: HashMap<CellIndex, f64> = HashMap::with_capacity(cells.len());
+    let mut cell_order = vec![];
+    let mut resolution = None;
+    for (cell, weight) in cells.iter().zip(weights.iter()) {
+        let Some(cell) = cell else {
+            continue;
+        };
+        if *resolution.get_or_insert(cell.resolution()) != cell.resolution() {
+            return Err(Error::MixedResolutions);
+        }
+        let weight = weight.filter(|w| *w > 0.0).unwrap_or(0.0);
+        if cell_weights.insert(cell, weight).is_none() {
+            cell_order.push(cell);
+        }
+    }
+
+    let all_cells: HashSet<CellIndex> = cell_order.iter().copied().collect();
+    let parts = connected_parts(&cell_order, &all_cells);
+    if parts.len() > n_regions {
+        return Err(Error::InvalidRegionCount(n_regions, parts.len()));
+    }
+
+    // one region per part, the remaining ones by the largest weight per region
+    let part_weights: Vec<f64> = parts
+        .iter()
+        .map(|part| part.iter().map(|cell| cell_weights[cell]).sum())
+        .collect();
+    let mut allocated = vec![1usize; parts.len()];
+    for _ in parts.len()..n_regions {
+        if let Some(i) = (0..parts.len()).max_by(|a, b| {
+            (part_weights[*a] / allocated[*a] as f64)
+                .total_cmp(&(part_weights[*b] / allocated[*b] as f64))
+                .then_with(|| b.cmp(a))
+        }) {
+            allocated[i] += 1;
+        }
+    }
+
+    let mut regions: HashMap<CellIndex, u32> = HashMap::with_capacity(cell_order.len());
+    let mut next_region = 0u32;
+    for ((part, part_weight), n) in parts.iter().zip(part_weights).zip(allocated) {
+        grow_regions(
+            part,
+            &cell_weights,
+            part_weight / n as f64,
+            n,
+            &mut next_region,
+            &mut regions,
+        );
+    }
+
+    Ok(cells
+        .iter()
+        .map(|cell| cell.and_then(|cell| regions.get(&cell).copied()))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+fn neighbors(cell: CellIndex) -> impl Iterator<Item = CellIndex> {
+    cell.grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .filter(move |neighbor| *neighbor != cell)
+}
+
+/// Connected parts of `cells`, ordered by the first occurrence of their cells in `order`.
+fn connected_parts(order: &[CellIndex], cells: &HashSet<CellIndex>) -> Vec<Vec<CellIndex>> {
+    let mut visited = HashSet::with_capacity(cells.len());
+    let mut parts = vec![];
+    for start in order.iter().copied() {
+        if !cells.contains(&start) || !visited.insert(start) {
+            continue;
+        }
+        let mut part = vec![start];
+        let mut queue = VecDeque::from([start]);
+        while let Some(cell) = queue.pop_front() {
+            for neighbor in neighbors(cell) {
+                if cells.contains(&neighbor) && visited.insert(neighbor) {
+                    part.push(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        parts.push(part);
+    }
+    parts
+}
+
+fn grow_regions(
+    part: &[CellIndex],
+    cell_weights: &HashMap<CellIndex, f64>,
+    target: f64,
+    n: usize,
+    next_region: &mut u32,
+    regions: &mut HashMap<CellIndex, u32>,
+) {
+    let mut unassigned: HashSet<CellIndex> = part.iter().copied().collect();
+    let mut region_weights: HashMap<u32, f64> = HashMap::with_capacity(n);
+
+    for _ in 1..n {
+        // start at the periphery of the unassigned cells to avoid cutting off pockets
+        let Some(seed) = part
+            .iter()
+            .copied()
+            .filter(|cell| unassigned.contains(cell))
+            .min_by_key(|cell| {
+                neighbors(*cell)
+                    .filter(|neighbor| unassigned.contains(neighbor))
+                    .count()
+            })
+        else {
+            break;
+        };
+        let region = *next_region;
+        *next_region += 1;
+
+        let mut weight = 0.0;
+        let mut queue = VecDeque::from([seed]);
+        unassigned.remove(&seed);
+        while let Some(cell) = queue.pop_front() {
+            regions.insert(cell, region);
+            weight += cell_weights[&cell];
+            if weight >= target {
+                // return the queued cells
+                unassigned.extend(queue.drain(..));
+                break;
+            }
+            for neighbor in neighbors(cell) {
+                if unassigned.remove(&neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        region_weights.insert(region, weight);
+    }
+
+    // the largest remaining part forms the last region, the other parts are pockets
+    let mut remaining = connected_parts(part, &unassigned);
+    remaining.sort_by_key(|cells| std::cmp::Reverse(cells.len()));
+    let mut remaining = remaining.into_iter();
+    if let Some(last) = remaining.next() {
+        let region = *next_region;
+        *next_region += 1;
+        region_weights.insert(region, last.iter().map(|cell| cell_weights[cell]).sum());
+        for cell in last {
+            regions.insert(cell, region);
+        }
+    }
+    for pocket in remaining {
+        let region = pocket
+            .iter()
+            .flat_map(|cell| neighbors(*cell))
+            .filter_map(|neighbor| regions.get(&neighbor).copied())
+            .min_by(|a, b| {
+                region_weights[a]
+                    .total_cmp(&region_weights[b])
+                    .then_with(|| a.cmp(b))
+            })
+            .expect("pockets border on a region");
+        *region_weights.entry(region).or_insert(0.0) +=
+            pocket.iter().map(|cell| cell_weights[cell]).sum::<f64>();
+        for cell in pocket {
+            regions.insert(cell, region);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::{LatLng, Resolution};
+
+    fn disk(lat: f64, lng: f64, k: u32) -> Vec<CellIndex> {
+        LatLng::new(lat, lng)
+            .unwrap()
+            .to_cell(Resolution::Nine)
+            .grid_disk::<Vec<_>>(k)
+    }
+
+    #[test]
+    fn balanced_and_contiguous() {
+        let cells = disk(10.0, 10.0, 6);
+        let weights = Float64Array::from(vec![1.0; cells.len()]);
+        let regions = regionalize(&cells.clone().into(), &weights, 4).unwrap();
+
+        let mut by_region: HashMap<u32, Vec<CellIndex>> = HashMap::new();
+        for (cell, region) in cells.iter().zip(regions.iter()) {
+            by_region.entry(region.unwrap()).or_default().push(*cell);
+        }
+        assert_eq!(by_region.len(), 4);
+        for region_cells in by_region.values() {
+            let set: HashSet<_> = region_cells.iter().copied().collect();
+            assert_eq!(connected_parts(region_cells, &set).len(), 1);
+            let share = region_cells.len() as f64 / cells.len() as f64;
+            assert!((share - 0.25).abs() < 0.1, "{}", share);
+        }
+    }
+
+    #[test]
+    fn one_region_per_part() {
+        let mut cells = disk(10.0, 10.0, 2);
+        cells.extend(disk(20.0, 10.0, 2));
+        let weights = Float64Array::from(vec![1.0; cells.len()]);
+        let cellarray: CellIndexArray = cells.into();
+
+        assert!(matches!(
+            regionalize(&cellarray, &weights, 1),
+            Err(Error::InvalidRegionCount(1, 2))
+        ));
+        let regions = regionalize(&cellarray, &weights, 2).unwrap();
+        assert!(regions.values()[..19].iter().all(|r| *r == 0));
+        assert!(regions.values()[19..].iter().all(|r| *r == 1));
+    }
+}
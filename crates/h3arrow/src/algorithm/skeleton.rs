@@ -0,0 +1,155 @@
+use crate::algorithm::grid::azimuth_degrees;
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::HashSet;
+use h3o::{CellIndex, Resolution};
+
+/// Approximate the medial axis of each connected cluster of `cells` by iterative morphological
+/// thinning - for example to derive centerlines from rasterized corridors.
+///
+/// In each pass the cells at the boundary of the clusters are removed one after the other as long
+/// as removing them neither splits a cluster nor closes or opens a hole, and as long as they are
+/// not the end of a line. Passes are repeated until no further cell can be removed. The result is
+/// a thin, connected subset of each cluster. Clusters without holes which are not elongated shrink
+/// to a few cells.
+///
+/// All cells must be of the same resolution. Invalid/empty values and duplicates are ignored, the
+/// returned cells are sorted.
+pub fn cells_skeleton(cells: &CellIndexArray) -> Result<CellIndexArray, Error> {
+    let mut resolution: Option<Resolution> = None;
+    for cell in cells.iter().flatten() {
+        match resolution {
+            None => resolution = Some(cell.resolution()),
+            Some(r) if r != cell.resolution() => return Err(Error::MixedResolutions),
+            _ => (),
+        }
+    }
+
+    let mut remaining: HashSet<CellIndex> = cells.iter().flatten().collect();
+    loop {
+        let mut boundary: Vec<CellIndex> = remaining
+            .iter()
+            .copied()
+            .filter(|cell| {
+                cell.grid_disk::<Vec<_>>(1)
+                    .iter()
+                    .any(|neighbor| !remaining.contains(neighbor))
+            })
+            .collect();
+        boundary.sort_unstable();
+
+        let mut removed_any = false;
+        for cell in boundary {
+            if is_removable(&remaining, cell) {
+                remaining.remove(&cell);
+                removed_any = true;
+            }
+        }
+        if !removed_any {
+            break;
+        }
+    }
+
+    let mut skeleton: Vec<_> = remaining.into_iter().collect();
+    skeleton.sort_unstable();
+    Ok(skeleton.into())
+}
+
+/// A cell can be removed without changing the topology when its neighbors contained in the set
+/// form a single contiguous run around it. Ends of lines - cells with a single neighbor - are kept.
+fn is_removable(cells: &HashSet<CellIndex>, cell: CellIndex) -> bool {
+    let mut neighbors: Vec<(f64, bool)> = cell
+        .grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .filter(|neighbor| *neighbor != cell)
+        .map(|neighbor| (azimuth_degrees(cell, neighbor), cells.contains(&neighbor)))
+        .collect();
+    neighbors.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+    let contained = neighbors.iter().filter(|(_, contained)| *contained).count();
+    if contained < 2 || contained == neighbors.len() {
+        return false;
+    }
+    let runs = (0..neighbors.len())
+        .filter(|i| neighbors[*i].1 && !neighbors[(i + neighbors.len() - 1) % neighbors.len()].1)
+        .count();
+    runs == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ahash::HashSetExt;
+    use h3o::LatLng;
+
+    fn is_connected(cells: &HashSet<CellIndex>) -> bool {
+        let Some(start) = cells.iter().next() else {
+            return true;
+        };
+        let mut visited: HashSet<CellIndex> = HashSet::new();
+        let mut stack = vec![*start];
+        while let Some(cell) = stack.pop() {
+            if visited.insert(cell) {
+                stack.extend(
+                    cell.grid_disk::<Vec<_>>(1)
+                        .into_iter()
+                        .filter(|neighbor| cells.contains(neighbor)),
+                );
+            }
+        }
+        visited.len() == cells.len()
+    }
+
+    #[test]
+    fn corridor_is_thinned() {
+        let from = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let to = LatLng::new(10.0, 10.1).unwrap().to_cell(Resolution::Nine);
+        let corridor: HashSet<CellIndex> = from
+            .grid_path_cells(to)
+            .unwrap()
+            .flat_map(|cell| cell.unwrap().grid_disk::<Vec<_>>(1))
+            .collect();
+
+        let skeleton: HashSet<CellIndex> =
+            cells_skeleton(&corridor.iter().copied().collect::<Vec<_>>().into())
+                .unwrap()
+                .iter()
+                .flatten()
+                .collect();
+        assert!(!skeleton.is_empty());
+        assert!(skeleton.len() < corridor.len() / 2);
+        assert!(skeleton.is_subset(&corridor));
+        assert!(is_connected(&skeleton));
+    }
+
+    #[test]
+    fn holes_are_preserved() {
+        let center = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let ring: Vec<CellIndex> = center
+            .grid_disk_distances::<Vec<_>>(4)
+            .into_iter()
+            .filter(|(_, k)| *k >= 2)
+            .map(|(cell, _)| cell)
+            .collect();
+
+        let skeleton: HashSet<CellIndex> = cells_skeleton(&ring.into())
+            .unwrap()
+            .iter()
+            .flatten()
+            .collect();
+        assert!(is_connected(&skeleton));
+
+        // the hole must still be enclosed - the outside can not be reached from the center
+        // without crossing the skeleton.
+        let mut visited: HashSet<CellIndex> = HashSet::new();
+        let mut stack = vec![center];
+        while let Some(cell) = stack.pop() {
+            if skeleton.contains(&cell) || !visited.insert(cell) {
+                continue;
+            }
+            let k = center.grid_distance(cell).unwrap();
+            assert!(k <= 4);
+            stack.extend(cell.grid_disk::<Vec<_>>(1));
+        }
+    }
+}
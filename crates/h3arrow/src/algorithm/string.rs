@@ -17,6 +17,13 @@ use nom::IResult;
 
 use crate::error::Error;
 
+/// Number of hexadecimal digits of the canonical string representation of cells.
+const CELL_HEX_DIGITS: usize = 15;
+
+/// Number of hexadecimal digits of the canonical string representation of directed edges and
+/// vertexes.
+const EDGE_VERTEX_HEX_DIGITS: usize = 16;
+
 pub fn parse_cell(s: &str) -> Result<CellIndex, Error> {
     if let Ok(cell) = CellIndex::from_str(s) {
         return Ok(cell);
@@ -28,6 +35,12 @@ pub fn parse_cell(s: &str) -> Result<CellIndex, Error> {
         }
     }
 
+    if let Some(cell_int) = parse_hex_variant(s, CELL_HEX_DIGITS) {
+        if let Ok(cell) = CellIndex::try_from(cell_int) {
+            return Ok(cell);
+        }
+    }
+
     // attempt to parse as coordinate pair and resolution
     if let Ok((_, (coord, res))) = parse_coordinate_and_resolution(s) {
         return Ok(LatLng::new(coord.y, coord.x)?.to_cell(Resolution::try_from(res)?));
@@ -46,6 +59,12 @@ pub fn parse_directededge(s: &str) -> Result<DirectedEdgeIndex, Error> {
             return Ok(de);
         }
     }
+
+    if let Some(index_int) = parse_hex_variant(s, EDGE_VERTEX_HEX_DIGITS) {
+        if let Ok(de) = DirectedEdgeIndex::try_from(index_int) {
+            return Ok(de);
+        }
+    }
     Err(Error::NonParsableDirectedEdgeIndex)
 }
 
@@ -59,9 +78,42 @@ pub fn parse_vertex(s: &str) -> Result<VertexIndex, Error> {
             return Ok(vx);
         }
     }
+
+    if let Some(index_int) = parse_hex_variant(s, EDGE_VERTEX_HEX_DIGITS) {
+        if let Ok(vx) = VertexIndex::try_from(index_int) {
+            return Ok(vx);
+        }
+    }
     Err(Error::NonParsableVertexIndex)
 }
 
+/// Parse the hexadecimal representations written using [`HexFormat`] as well as those using a
+/// `0x` prefix.
+///
+/// Leading zeros are ignored, and representations with less than `n_digits` digits are padded
+/// with the `f`s of the unused resolution digits.
+fn parse_hex_variant(s: &str, n_digits: usize) -> Option<u64> {
+    let s = s.trim();
+    let s = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    let digits = s.trim_start_matches('0');
+    if digits.is_empty()
+        || digits.len() > n_digits
+        || !digits.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return None;
+    }
+    let value = u64::from_str_radix(digits, 16).ok()?;
+    let missing_bits = 4 * (n_digits - digits.len()) as u32;
+    if missing_bits == 0 {
+        Some(value)
+    } else {
+        Some((value << missing_bits) | (u64::MAX >> (64 - missing_bits)))
+    }
+}
+
 fn is_whitespace(c: char) -> bool {
     c.is_ascii_whitespace()
 }
@@ -90,8 +142,61 @@ fn parse_coordinate_and_resolution(s: &str) -> IResult<&str, (Coord, u8)> {
     Ok((s, (Coord::from((x, y)), r)))
 }
 
+/// Options for the hexadecimal string representation of H3 indexes.
+///
+/// The default is the canonical lowercase representation without padding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HexFormat {
+    uppercase: bool,
+    short: bool,
+    zero_pad: bool,
+}
+
+impl HexFormat {
+    /// Use uppercase hexadecimal digits.
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Strip the trailing `f`s of the unused resolution digits. The representation stays
+    /// lossless as the parsers pad the missing digits again.
+    pub fn short(mut self, short: bool) -> Self {
+        self.short = short;
+        self
+    }
+
+    /// Pad the representation with leading zeros to a fixed width of 16 characters - before
+    /// stripping the trailing `f`s when combined with [`HexFormat::short`].
+    pub fn zero_pad(mut self, zero_pad: bool) -> Self {
+        self.zero_pad = zero_pad;
+        self
+    }
+
+    fn write<W: Write>(&self, writer: &mut W, value: u64) -> std::io::Result<()> {
+        let mut digits = if self.zero_pad {
+            format!("{:016x}", value)
+        } else {
+            format!("{:x}", value)
+        };
+        if self.short {
+            let len = digits.trim_end_matches('f').len();
+            digits.truncate(len);
+        }
+        if self.uppercase {
+            digits.make_ascii_uppercase();
+        }
+        writer.write_all(digits.as_bytes())
+    }
+}
+
 pub trait ToGenericStringArray<O: OffsetSizeTrait> {
     fn to_genericstringarray(&self) -> Result<GenericStringArray<O>, Error>;
+
+    fn to_genericstringarray_with_format(
+        &self,
+        format: &HexFormat,
+    ) -> Result<GenericStringArray<O>, Error>;
 }
 
 impl<O: OffsetSizeTrait, IX> ToGenericStringArray<O> for H3Array<IX>
@@ -118,6 +223,29 @@ where
             self.primitive_array().nulls().cloned(),
         ))
     }
+
+    fn to_genericstringarray_with_format(
+        &self,
+        format: &HexFormat,
+    ) -> Result<GenericStringArray<O>, Error> {
+        let mut values: Vec<u8> = Vec::with_capacity(self.len() * 16);
+        let mut offsets: Vec<O> = Vec::with_capacity(self.len() + 1);
+        offsets.push(O::default());
+
+        for value in self.primitive_array().iter() {
+            if let Some(value) = value {
+                format.write(&mut values, value)?;
+            }
+            offsets.push(O::from_usize(values.len()).unwrap());
+        }
+        values.shrink_to_fit();
+
+        Ok(GenericStringArray::<O>::new(
+            OffsetBuffer::new(offsets.into()),
+            values.into(),
+            self.primitive_array().nulls().cloned(),
+        ))
+    }
 }
 
 impl<IX, O: OffsetSizeTrait> TryFrom<H3Array<IX>> for GenericStringArray<O>
@@ -206,7 +334,9 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::algorithm::{parse_cell, ParseGenericStringArray, ToGenericStringArray};
+    use crate::algorithm::{
+        parse_cell, parse_directededge, HexFormat, ParseGenericStringArray, ToGenericStringArray,
+    };
     use crate::array::{CellIndexArray, FromWithValidity};
     use arrow::array::{Array, GenericStringArray};
     use h3o::{CellIndex, LatLng, Resolution};
@@ -290,4 +420,28 @@ mod test {
         let arr2: CellIndexArray = stringarray.try_into().unwrap();
         assert!(arr == arr2);
     }
+
+    #[test]
+    fn to_stringarray_with_format_roundtrip() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let edge = cell.edges().next().unwrap();
+        let cells: CellIndexArray = vec![cell].into();
+
+        for (format, expected) in [
+            (HexFormat::default(), "855968a3fffffff"),
+            (HexFormat::default().uppercase(true), "855968A3FFFFFFF"),
+            (HexFormat::default().short(true), "855968a3"),
+            (HexFormat::default().zero_pad(true), "0855968a3fffffff"),
+            (HexFormat::default().short(true).zero_pad(true), "0855968a3"),
+        ] {
+            let stringarray: GenericStringArray<i32> =
+                cells.to_genericstringarray_with_format(&format).unwrap();
+            assert_eq!(stringarray.value(0), expected);
+            assert_eq!(parse_cell(expected).unwrap(), cell);
+        }
+
+        assert_eq!(parse_cell("0x855968a3fffffff").unwrap(), cell);
+        let short_edge = edge.to_string().trim_end_matches('f').to_uppercase();
+        assert_eq!(parse_directededge(&short_edge).unwrap(), edge);
+    }
 }
@@ -0,0 +1,145 @@
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::{Float64Array, UInt32Array};
+use h3o::{CellIndex, LatLng};
+
+/// Cells visible from observer cells.
+pub struct Viewshed {
+    pub observers: CellIndexArray,
+    pub cells: CellIndexArray,
+    pub distances: UInt32Array,
+}
+
+/// Compute the cells visible from each observer on an elevation surface given by `cells` and
+/// `elevations`.
+///
+/// The eye of an observer is located `observer_height` above the elevation of the observer cell. A
+/// cell within grid distance `max_k` is visible when no cell on the `grid_path_cells` line between
+/// the observer and the cell rises above the line of sight to the cell center. Distances are measured
+/// between the cell centers, the curvature of the earth is not taken into account.
+///
+/// Cells without elevation are neither visible nor do they block the view. Observers without an
+/// elevation are skipped. Cells which can not be connected to the observer using `grid_path_cells` -
+/// for example across pentagon distortions - are treated as not visible.
+pub fn viewshed(
+    cells: &CellIndexArray,
+    elevations: &Float64Array,
+    observers: &CellIndexArray,
+    max_k: u32,
+    observer_height: f64,
+) -> Result<Viewshed, Error> {
+    if cells.len() != elevations.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    let mut surface: HashMap<CellIndex, f64> = HashMap::with_capacity(cells.len());
+    for (cell, elevation) in cells.iter().zip(elevations.iter()) {
+        if let (Some(cell), Some(elevation)) = (cell, elevation) {
+            surface.insert(cell, elevation);
+        }
+    }
+
+    let mut out_observers = vec![];
+    let mut out_cells = vec![];
+    let mut out_distances = vec![];
+    for observer in observers.iter().flatten() {
+        let Some(observer_elevation) = surface.get(&observer) else {
+            continue;
+        };
+        let eye = observer_elevation + observer_height;
+        let observer_latlng = LatLng::from(observer);
+
+        for (target, k) in observer.grid_disk_distances::<Vec<_>>(max_k) {
+            let Some(target_elevation) = surface.get(&target) else {
+                continue;
+            };
+            if k > 0
+                && !is_visible(
+                    &surface,
+                    observer,
+                    observer_latlng,
+                    eye,
+                    target,
+                    *target_elevation,
+                )
+            {
+                continue;
+            }
+            out_observers.push(observer);
+            out_cells.push(target);
+            out_distances.push(k);
+        }
+    }
+
+    Ok(Viewshed {
+        observers: out_observers.into(),
+        cells: out_cells.into(),
+        distances: out_distances.into(),
+    })
+}
+
+fn is_visible(
+    surface: &HashMap<CellIndex, f64>,
+    observer: CellIndex,
+    observer_latlng: LatLng,
+    eye: f64,
+    target: CellIndex,
+    target_elevation: f64,
+) -> bool {
+    let slope = |cell: CellIndex, elevation: f64| {
+        (elevation - eye) / observer_latlng.distance_m(LatLng::from(cell))
+    };
+    let target_slope = slope(target, target_elevation);
+
+    let Ok(path) = observer.grid_path_cells(target) else {
+        return false;
+    };
+    for cell in path {
+        let Ok(cell) = cell else {
+            return false;
+        };
+        if cell == observer || cell == target {
+            continue;
+        }
+        if let Some(elevation) = surface.get(&cell) {
+            if slope(cell, *elevation) > target_slope {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::Resolution;
+
+    #[test]
+    fn wall_blocks_view() {
+        let observer = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Nine);
+        let surface: Vec<_> = observer.grid_disk_distances::<Vec<_>>(6);
+        let cells: CellIndexArray = surface
+            .iter()
+            .map(|(cell, _)| *cell)
+            .collect::<Vec<_>>()
+            .into();
+
+        // a ring shaped wall at k = 3
+        let elevations: Float64Array = surface
+            .iter()
+            .map(|(_, k)| Some(if *k == 3 { 100.0 } else { 0.0 }))
+            .collect();
+
+        let observers: CellIndexArray = vec![observer].into();
+        let viewshed = viewshed(&cells, &elevations, &observers, 6, 2.0).unwrap();
+        assert_eq!(viewshed.cells.len(), h3o::max_grid_disk_size(3) as usize);
+        assert!(viewshed.distances.iter().flatten().all(|k| k <= 3));
+
+        // flat terrain - everything is visible
+        let flat = Float64Array::from(vec![0.0; cells.len()]);
+        let viewshed = super::viewshed(&cells, &flat, &observers, 6, 2.0).unwrap();
+        assert_eq!(viewshed.cells.len(), cells.len());
+    }
+}
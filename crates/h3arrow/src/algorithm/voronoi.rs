@@ -0,0 +1,147 @@
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt, HashSet};
+use arrow::array::UInt32Array;
+use h3o::{CellIndex, Resolution};
+use std::collections::hash_map::Entry;
+
+/// Assignment of cells to their nearest seed cell.
+pub struct GridVoronoi {
+    pub cells: CellIndexArray,
+    pub seeds: CellIndexArray,
+    pub distances: UInt32Array,
+}
+
+/// Assign each cell of `extent` to its nearest cell of `seeds`.
+///
+/// The distance is the number of steps between neighboring cells required to get from the
+/// seed to the cell while only passing cells of the extent - so for convex extents
+/// this equals the grid distance. When a cell is equally far from multiple seeds, the seed
+/// with the lowest index value wins. Seeds outside of the extent are ignored, cells of the extent
+/// which can not be reached from any seed are omitted.
+///
+/// All cells must be of the same resolution. Invalid/empty values are ignored.
+pub fn grid_voronoi(seeds: &CellIndexArray, extent: &CellIndexArray) -> Result<GridVoronoi, Error> {
+    let mut resolution: Option<Resolution> = None;
+    for cell in seeds.iter().flatten().chain(extent.iter().flatten()) {
+        match resolution {
+            None => resolution = Some(cell.resolution()),
+            Some(r) if r != cell.resolution() => return Err(Error::MixedResolutions),
+            _ => (),
+        }
+    }
+
+    let extent: HashSet<CellIndex> = extent.iter().flatten().collect();
+    let mut assigned: HashMap<CellIndex, (CellIndex, u32)> = HashMap::with_capacity(extent.len());
+
+    let mut frontier: Vec<(CellIndex, CellIndex)> = {
+        let mut seeds: Vec<_> = seeds
+            .iter()
+            .flatten()
+            .filter(|seed| extent.contains(seed))
+            .collect();
+        seeds.sort_unstable();
+        seeds.dedup();
+        seeds.into_iter().map(|seed| (seed, seed)).collect()
+    };
+    for (cell, seed) in frontier.iter() {
+        assigned.insert(*cell, (*seed, 0));
+    }
+
+    let mut distance = 0u32;
+    while !frontier.is_empty() {
+        distance += 1;
+
+        // collect the cells of the next ring first to be able to break ties between
+        // seeds reaching a cell at the same distance.
+        let mut next: HashMap<CellIndex, CellIndex> = HashMap::new();
+        for (cell, seed) in frontier.iter() {
+            for neighbor in cell.grid_disk::<Vec<_>>(1) {
+                if !extent.contains(&neighbor) || assigned.contains_key(&neighbor) {
+                    continue;
+                }
+                match next.entry(neighbor) {
+                    Entry::Occupied(mut e) => {
+                        if seed < e.get() {
+                            e.insert(*seed);
+                        }
+                    }
+                    Entry::Vacant(e) => {
+                        e.insert(*seed);
+                    }
+                }
+            }
+        }
+
+        frontier = next.into_iter().collect();
+        for (cell, seed) in frontier.iter() {
+            assigned.insert(*cell, (*seed, distance));
+        }
+    }
+
+    let mut cells = Vec::with_capacity(assigned.len());
+    let mut seeds = Vec::with_capacity(assigned.len());
+    let mut distances = Vec::with_capacity(assigned.len());
+    for (cell, (seed, distance)) in assigned.into_iter() {
+        cells.push(cell);
+        seeds.push(seed);
+        distances.push(distance);
+    }
+
+    Ok(GridVoronoi {
+        cells: cells.into(),
+        seeds: seeds.into(),
+        distances: distances.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::LatLng;
+
+    #[test]
+    fn nearest_seed() {
+        let seed_a = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let (seed_b, _) = seed_a
+            .grid_disk_distances::<Vec<_>>(6)
+            .into_iter()
+            .find(|(_, k)| *k == 6)
+            .unwrap();
+        let extent: CellIndexArray = seed_a.grid_disk::<Vec<_>>(8).into();
+        let seeds: CellIndexArray = vec![seed_a, seed_b].into();
+
+        let voronoi = grid_voronoi(&seeds, &extent).unwrap();
+        assert_eq!(voronoi.cells.len(), extent.len());
+
+        for ((cell, seed), distance) in voronoi
+            .cells
+            .iter()
+            .flatten()
+            .zip(voronoi.seeds.iter().flatten())
+            .zip(voronoi.distances.iter().flatten())
+        {
+            let distance_a = cell.grid_distance(seed_a).unwrap() as u32;
+            let distance_b = cell.grid_distance(seed_b).unwrap() as u32;
+            assert_eq!(distance, distance_a.min(distance_b));
+            if distance_a < distance_b {
+                assert_eq!(seed, seed_a);
+            } else if distance_b < distance_a {
+                assert_eq!(seed, seed_b);
+            } else {
+                assert_eq!(seed, seed_a.min(seed_b));
+            }
+        }
+    }
+
+    #[test]
+    fn mixed_resolutions() {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let seeds: CellIndexArray = vec![cell].into();
+        let extent: CellIndexArray = vec![cell.parent(Resolution::Six).unwrap()].into();
+        assert!(matches!(
+            grid_voronoi(&seeds, &extent),
+            Err(Error::MixedResolutions)
+        ));
+    }
+}
@@ -0,0 +1,320 @@
+//! Window functions over rows of (origin cell, ring k, value).
+//!
+//! Such rows are typically the result of joining values to the output of `grid_disk_distances`.
+//! The rows are partitioned by their origin cell, the outputs are aligned with the input rows.
+//! Rows with a null origin or k are null in the output.
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::{Array, Float64Array, UInt32Array};
+use h3o::CellIndex;
+use std::collections::BTreeMap;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum CumulativeAggregation {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+/// Row positions partitioned by origin. Rows without origin or k are omitted.
+fn partition_by_origin(
+    origins: &CellIndexArray,
+    ks: &UInt32Array,
+) -> Result<HashMap<CellIndex, Vec<(u32, usize)>>, Error> {
+    if origins.len() != ks.len() {
+        return Err(Error::LengthMismatch);
+    }
+    let mut partitions: HashMap<CellIndex, Vec<(u32, usize)>> = HashMap::new();
+    for (row, (origin, k)) in origins.iter().zip(ks.iter()).enumerate() {
+        if let (Some(origin), Some(k)) = (origin, k) {
+            partitions.entry(origin).or_default().push((k, row));
+        }
+    }
+    Ok(partitions)
+}
+
+/// Aggregate the values of each origin cumulatively by increasing k.
+///
+/// The output of each row contains the aggregate of all values of the same origin with a k less than
+/// or equal to the k of the row - so all rows of the same ring share the same output. Null values are
+/// skipped, rows without any non-null value up to their ring are null.
+pub fn ring_cumulative(
+    origins: &CellIndexArray,
+    ks: &UInt32Array,
+    values: &Float64Array,
+    aggregation: CumulativeAggregation,
+) -> Result<Float64Array, Error> {
+    if origins.len() != values.len() {
+        return Err(Error::LengthMismatch);
+    }
+    let mut out: Vec<Option<f64>> = vec![None; origins.len()];
+
+    for (_, mut rows) in partition_by_origin(origins, ks)? {
+        rows.sort_unstable();
+
+        let mut count = 0usize;
+        let mut aggregated: Option<f64> = None;
+        for ring in rows.chunk_by(|a, b| a.0 == b.0) {
+            for (_, row) in ring.iter().filter(|(_, row)| values.is_valid(*row)) {
+                let value = values.value(*row);
+                count += 1;
+                aggregated = Some(match (aggregated, aggregation) {
+                    (None, _) => value,
+                    (Some(acc), CumulativeAggregation::Sum | CumulativeAggregation::Mean) => {
+                        acc + value
+                    }
+                    (Some(acc), CumulativeAggregation::Min) => acc.min(value),
+                    (Some(acc), CumulativeAggregation::Max) => acc.max(value),
+                });
+            }
+            let ring_output = match aggregation {
+                CumulativeAggregation::Mean => aggregated.map(|sum| sum / count as f64),
+                _ => aggregated,
+            };
+            for (_, row) in ring {
+                out[*row] = ring_output;
+            }
+        }
+    }
+    Ok(out.into())
+}
+
+/// Rank the values of each origin in descending order - the highest value gets the rank 1.
+///
+/// Equal values share the same rank and leave a gap in the ranks following them, matching the
+/// `RANK()` window function of SQL. Rows with a null value are null in the output.
+pub fn ring_rank(
+    origins: &CellIndexArray,
+    ks: &UInt32Array,
+    values: &Float64Array,
+) -> Result<UInt32Array, Error> {
+    if origins.len() != values.len() {
+        return Err(Error::LengthMismatch);
+    }
+    let mut out: Vec<Option<u32>> = vec![None; origins.len()];
+
+    for (_, rows) in partition_by_origin(origins, ks)? {
+        let mut ranked: Vec<(f64, usize)> = rows
+            .into_iter()
+            .filter(|(_, row)| values.is_valid(*row))
+            .map(|(_, row)| (values.value(row), row))
+            .collect();
+        ranked.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+
+        let mut rank = 0u32;
+        for (pos, (value, row)) in ranked.iter().enumerate() {
+            if pos == 0 || ranked[pos - 1].0 != *value {
+                rank = pos as u32 + 1;
+            }
+            out[*row] = Some(rank);
+        }
+    }
+    Ok(out.into())
+}
+
+/// Aggregation of the values of an origin and ring by [`ring_pivot`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RingAggregation {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Count,
+}
+
+/// Output of [`ring_pivot`]: one row per origin and one array of values per ring k.
+pub struct RingPivot {
+    pub origins: CellIndexArray,
+
+    /// the aggregated values by ring as pairs of `k` and values, ordered by `k`. Only the `k`s found
+    /// in the input are included.
+    pub rings: Vec<(u32, Float64Array)>,
+}
+
+/// Pivot the rows to a wide layout with one row per origin and one column per ring k - for
+/// example to build feature matrices from neighborhood aggregations.
+///
+/// The values of the rows sharing the same origin and k are aggregated. Null values are skipped,
+/// origins without any non-null value in a ring are null in the column of that ring - except for
+/// `Count`, which results in zero. The origins are ordered by their first occurrence. Rings are
+/// only created for the `k`s found in the input, so sparse or very large `k`s do not allocate
+/// the rings in between.
+pub fn ring_pivot(
+    origins: &CellIndexArray,
+    ks: &UInt32Array,
+    values: &Float64Array,
+    aggregation: RingAggregation,
+) -> Result<RingPivot, Error> {
+    if origins.len() != ks.len() || origins.len() != values.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    let mut origin_positions: HashMap<CellIndex, usize> = HashMap::new();
+    let mut out_origins: Vec<CellIndex> = vec![];
+    // (count, aggregate) by ring k and origin position
+    let mut rings: BTreeMap<u32, Vec<(usize, f64)>> = BTreeMap::new();
+    for ((origin, k), value) in origins.iter().zip(ks.iter()).zip(values.iter()) {
+        let (Some(origin), Some(k)) = (origin, k) else {
+            continue;
+        };
+        let pos = *origin_positions.entry(origin).or_insert_with(|| {
+            out_origins.push(origin);
+            out_origins.len() - 1
+        });
+        let ring = rings.entry(k).or_default();
+        let Some(value) = value else {
+            continue;
+        };
+        if ring.len() <= pos {
+            ring.resize(pos + 1, (0, 0.0));
+        }
+        let (count, aggregated) = &mut ring[pos];
+        *aggregated = match (*count, aggregation) {
+            (0, _) => value,
+            (_, RingAggregation::Sum | RingAggregation::Mean | RingAggregation::Count) => {
+                *aggregated + value
+            }
+            (_, RingAggregation::Min) => aggregated.min(value),
+            (_, RingAggregation::Max) => aggregated.max(value),
+        };
+        *count += 1;
+    }
+
+    let rings = rings
+        .into_iter()
+        .map(|(k, ring)| {
+            let values = (0..out_origins.len())
+                .map(|pos| {
+                    let (count, aggregated) = ring.get(pos).copied().unwrap_or((0, 0.0));
+                    match aggregation {
+                        RingAggregation::Count => Some(count as f64),
+                        _ if count == 0 => None,
+                        RingAggregation::Mean => Some(aggregated / count as f64),
+                        _ => Some(aggregated),
+                    }
+                })
+                .collect();
+            (k, values)
+        })
+        .collect();
+    Ok(RingPivot {
+        origins: out_origins.into(),
+        rings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::{LatLng, Resolution};
+
+    fn rows() -> (CellIndexArray, UInt32Array, Float64Array) {
+        let origin_a = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let origin_b = LatLng::new(20.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let origins: CellIndexArray = vec![
+            Some(origin_a),
+            Some(origin_b),
+            Some(origin_a),
+            Some(origin_a),
+            None,
+            Some(origin_a),
+        ]
+        .into();
+        let ks = UInt32Array::from(vec![2, 0, 0, 1, 0, 1]);
+        let values = Float64Array::from(vec![
+            Some(1.0),
+            Some(5.0),
+            Some(2.0),
+            Some(3.0),
+            Some(1.0),
+            None,
+        ]);
+        (origins, ks, values)
+    }
+
+    #[test]
+    fn cumulative_sum() {
+        let (origins, ks, values) = rows();
+        let out = ring_cumulative(&origins, &ks, &values, CumulativeAggregation::Sum).unwrap();
+        assert_eq!(
+            out,
+            Float64Array::from(vec![
+                Some(6.0),
+                Some(5.0),
+                Some(2.0),
+                Some(5.0),
+                None,
+                Some(5.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn cumulative_mean() {
+        let (origins, ks, values) = rows();
+        let out = ring_cumulative(&origins, &ks, &values, CumulativeAggregation::Mean).unwrap();
+        assert_eq!(
+            out,
+            Float64Array::from(vec![
+                Some(2.0),
+                Some(5.0),
+                Some(2.0),
+                Some(2.5),
+                None,
+                Some(2.5)
+            ])
+        );
+    }
+
+    #[test]
+    fn rank() {
+        let (origins, ks, values) = rows();
+        let out = ring_rank(&origins, &ks, &values).unwrap();
+        assert_eq!(
+            out,
+            UInt32Array::from(vec![Some(3), Some(1), Some(2), Some(1), None, None])
+        );
+    }
+
+    #[test]
+    fn pivot() {
+        let (origins, ks, values) = rows();
+        let pivot = ring_pivot(&origins, &ks, &values, RingAggregation::Sum).unwrap();
+        assert_eq!(pivot.origins.len(), 2);
+        assert_eq!(pivot.origins.get(0), origins.get(0));
+        assert_eq!(pivot.origins.get(1), origins.get(1));
+        assert_eq!(
+            pivot.rings,
+            vec![
+                (0, Float64Array::from(vec![Some(2.0), Some(5.0)])),
+                (1, Float64Array::from(vec![Some(3.0), None])),
+                (2, Float64Array::from(vec![Some(1.0), None])),
+            ]
+        );
+
+        let pivot = ring_pivot(&origins, &ks, &values, RingAggregation::Count).unwrap();
+        assert_eq!(
+            pivot.rings[1],
+            (1, Float64Array::from(vec![Some(1.0), Some(0.0)]))
+        );
+    }
+
+    #[test]
+    fn pivot_sparse_k() {
+        let origin = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        let origins: CellIndexArray = vec![origin, origin].into();
+        let ks = UInt32Array::from(vec![u32::MAX, 3]);
+        let values = Float64Array::from(vec![1.0, 2.0]);
+
+        let pivot = ring_pivot(&origins, &ks, &values, RingAggregation::Sum).unwrap();
+        assert_eq!(
+            pivot.rings,
+            vec![
+                (3, Float64Array::from(vec![2.0])),
+                (u32::MAX, Float64Array::from(vec![1.0])),
+            ]
+        );
+    }
+}
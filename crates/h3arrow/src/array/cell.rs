@@ -1,6 +1,10 @@
-use arrow::array::{Float64Array, UInt64Array};
-use h3o::{CellIndex, Resolution};
+use std::collections::BTreeMap;
 
+use arrow::array::{BooleanArray, Float64Array, ListArray, UInt64Array};
+use arrow::datatypes::{Float64Type, UInt8Type};
+use h3o::{CellIndex, LatLng, Resolution};
+
+use crate::algorithm::grid::latlng_azimuth_degrees;
 use crate::array::{CellIndexArray, H3ListArray, H3ListArrayBuilder, ResolutionArray};
 use crate::error::Error;
 
@@ -11,6 +15,34 @@ impl CellIndexArray {
             .collect()
     }
 
+    /// Keep only the cells having one of the given `resolutions`.
+    ///
+    /// Invalid/empty values are omitted.
+    pub fn filter_by_resolution(&self, resolutions: &[Resolution]) -> Self {
+        let mut keep = [false; 16];
+        for resolution in resolutions {
+            keep[usize::from(*resolution)] = true;
+        }
+        self.iter()
+            .flatten()
+            .filter(|cell| keep[usize::from(cell.resolution())])
+            .collect()
+    }
+
+    /// Split the cells into one array per contained resolution.
+    ///
+    /// The order of the cells within each array is preserved. Invalid/empty values are omitted.
+    pub fn split_by_resolution(&self) -> BTreeMap<Resolution, Self> {
+        let mut split: BTreeMap<Resolution, Vec<CellIndex>> = BTreeMap::new();
+        for cell in self.iter().flatten() {
+            split.entry(cell.resolution()).or_default().push(cell);
+        }
+        split
+            .into_iter()
+            .map(|(resolution, cells)| (resolution, cells.into()))
+            .collect()
+    }
+
     pub fn area_rads2(&self) -> Float64Array {
         self.iter()
             .map(|cell| cell.map(|cell| cell.area_rads2()))
@@ -29,6 +61,43 @@ impl CellIndexArray {
             .collect()
     }
 
+    /// The ids of the icosahedron faces intersected by each cell.
+    ///
+    /// Invalid/empty values result in null lists.
+    pub fn icosahedron_faces(&self) -> ListArray {
+        ListArray::from_iter_primitive::<UInt8Type, _, _>(self.iter().map(|cell| {
+            cell.map(|cell| {
+                cell.icosahedron_faces()
+                    .iter()
+                    .map(|face| Some(u8::from(face)))
+                    .collect::<Vec<_>>()
+            })
+        }))
+    }
+
+    pub fn is_pentagon(&self) -> BooleanArray {
+        self.iter()
+            .map(|cell| cell.map(|cell| cell.is_pentagon()))
+            .collect()
+    }
+
+    /// The azimuths from the center of each cell to its vertexes in degrees clockwise from north.
+    ///
+    /// The lists follow the order of the vertexes of the cell boundary and contain six values for
+    /// hexagons and five for pentagons. Only the topological vertexes are included, not the
+    /// additional distortion vertexes where a boundary crosses an icosahedron edge.
+    /// Invalid/empty values result in null lists.
+    pub fn vertex_azimuths(&self) -> ListArray {
+        ListArray::from_iter_primitive::<Float64Type, _, _>(self.iter().map(|cell| {
+            cell.map(|cell| {
+                let center = LatLng::from(cell);
+                cell.vertexes()
+                    .map(|vertex| Some(latlng_azimuth_degrees(center, LatLng::from(vertex))))
+                    .collect::<Vec<_>>()
+            })
+        }))
+    }
+
     pub fn parent(&self, resolution: Resolution) -> Self {
         self.iter()
             .map(|cell| cell.and_then(|cell| cell.parent(resolution)))
@@ -49,6 +118,14 @@ impl CellIndexArray {
         builder.finish()
     }
 
+    /// The center child of each cell at `resolution`. Null when `resolution` is coarser than the
+    /// resolution of the cell.
+    pub fn center_child(&self, resolution: Resolution) -> Self {
+        self.iter()
+            .map(|cell| cell.and_then(|cell| cell.center_child(resolution)))
+            .collect()
+    }
+
     pub fn children_count(&self, resolution: Resolution) -> UInt64Array {
         self.iter()
             .map(|cell| cell.map(|cell| cell.children_count(resolution)))
@@ -58,9 +135,66 @@ impl CellIndexArray {
 
 #[cfg(test)]
 mod test {
-    use h3o::{LatLng, Resolution};
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::{Float64Type, UInt8Type};
+    use h3o::{CellIndex, LatLng, Resolution};
 
-    use crate::array::CellIndexArray;
+    use crate::array::{CellIndexArray, CellIndexArrayBuilder};
+
+    #[test]
+    fn center_child() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let arr: CellIndexArray = vec![cell].into();
+
+        let center_children = arr.center_child(Resolution::Seven);
+        let center_child = center_children.iter().next().flatten().unwrap();
+        assert_eq!(center_child.resolution(), Resolution::Seven);
+        assert_eq!(center_child.parent(Resolution::Five), Some(cell));
+        assert_eq!(center_child, LatLng::from(cell).to_cell(Resolution::Seven));
+
+        assert_eq!(arr.center_child(Resolution::Four).iter().next(), Some(None));
+    }
+
+    #[test]
+    fn icosahedron_faces() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let arr: CellIndexArray = vec![Some(cell), None].into();
+
+        let faces = arr.icosahedron_faces();
+        assert_eq!(faces.len(), 2);
+        assert!(faces.is_null(1));
+        let expected: Vec<u8> = cell.icosahedron_faces().iter().map(u8::from).collect();
+        assert_eq!(
+            faces.value(0).as_primitive::<UInt8Type>().values().to_vec(),
+            expected
+        );
+    }
+
+    #[test]
+    fn vertex_azimuths() {
+        let hexagon = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+        let pentagon = CellIndex::base_cells()
+            .find(|cell| cell.is_pentagon())
+            .unwrap();
+        let arr: CellIndexArray = vec![Some(hexagon), Some(pentagon), None].into();
+
+        let azimuths = arr.vertex_azimuths();
+        assert_eq!(azimuths.value_length(0), 6);
+        assert_eq!(azimuths.value_length(1), 5);
+        assert!(azimuths.is_null(2));
+        let hexagon_azimuths = azimuths.value(0);
+        let hexagon_azimuths = hexagon_azimuths.as_primitive::<Float64Type>();
+        assert!(hexagon_azimuths
+            .values()
+            .iter()
+            .all(|azimuth| (0.0..360.0).contains(azimuth)));
+
+        let is_pentagon = arr.is_pentagon();
+        assert_eq!(
+            is_pentagon.iter().collect::<Vec<_>>(),
+            vec![Some(false), Some(true), None]
+        );
+    }
 
     #[test]
     fn construct_invalid_fails() {
@@ -89,6 +223,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn filter_and_split_by_resolution() {
+        let arr: CellIndexArray = vec![
+            Some(LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five)),
+            None,
+            Some(LatLng::new(12.3, 0.5).unwrap().to_cell(Resolution::Nine)),
+            Some(LatLng::new(12.3, 0.7).unwrap().to_cell(Resolution::Five)),
+        ]
+        .into();
+
+        let filtered = arr.filter_by_resolution(&[Resolution::Five, Resolution::Six]);
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered
+            .iter()
+            .all(|cell| cell.unwrap().resolution() == Resolution::Five));
+
+        let split = arr.split_by_resolution();
+        assert_eq!(
+            split.keys().copied().collect::<Vec<_>>(),
+            vec![Resolution::Five, Resolution::Nine]
+        );
+        assert_eq!(split[&Resolution::Five].len(), 2);
+        assert_eq!(split[&Resolution::Nine].len(), 1);
+    }
+
     #[test]
     fn children() {
         let arr: CellIndexArray = vec![
@@ -107,4 +266,23 @@ mod test {
             assert_eq!(arr.iter().next().flatten(), child.parent(Resolution::Five));
         }
     }
+
+    #[test]
+    fn builder() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+
+        let mut builder = CellIndexArrayBuilder::with_capacity(4);
+        builder.append_value(cell);
+        builder.append_null();
+        builder.extend(cell.grid_ring_fast(1));
+        assert_eq!(builder.len(), 8);
+
+        let cells = builder.finish();
+        assert_eq!(cells.len(), 8);
+        assert_eq!(cells.get(0), Some(cell));
+        assert_eq!(cells.get(1), None);
+        assert!(cells.iter().skip(2).all(|neighbor| neighbor
+            .map(|neighbor| neighbor.grid_distance(cell) == Ok(1))
+            .unwrap_or(false)));
+    }
 }
@@ -14,6 +14,24 @@ impl DirectedEdgeIndexArray {
             .collect()
     }
 
+    /// Canonical key of the edge regardless of its direction - for example to aggregate the flows
+    /// in both directions between two cells.
+    ///
+    /// Both directions of an edge result in the one of the two directed edges with the smaller
+    /// index value, so the keys are valid directed edges themselves.
+    pub fn undirected_key(&self) -> DirectedEdgeIndexArray {
+        self.iter()
+            .map(|edge| {
+                edge.map(|edge| {
+                    let (origin, destination) = edge.cells();
+                    destination
+                        .edge(origin)
+                        .map_or(edge, |reversed| edge.min(reversed))
+                })
+            })
+            .collect()
+    }
+
     pub fn length_rads(&self) -> Float64Array {
         self.iter()
             .map(|edge| edge.map(|edge| edge.length_rads()))
@@ -32,3 +50,28 @@ impl DirectedEdgeIndexArray {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::array::DirectedEdgeIndexArray;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn undirected_key() {
+        let cell = LatLng::new(45.0, 10.0).unwrap().to_cell(Resolution::Eight);
+        let mut edges = vec![];
+        for neighbor in cell.grid_disk::<Vec<_>>(1) {
+            if let Some(edge) = cell.edge(neighbor) {
+                edges.push(edge);
+                edges.push(neighbor.edge(cell).unwrap());
+            }
+        }
+        let keys = DirectedEdgeIndexArray::from(edges.clone()).undirected_key();
+        let keys: Vec<_> = keys.iter().flatten().collect();
+        assert_eq!(keys.len(), 12);
+        for pair in keys.chunks(2) {
+            assert_eq!(pair[0], pair[1]);
+        }
+        assert!(keys.iter().all(|key| edges.contains(key)));
+    }
+}
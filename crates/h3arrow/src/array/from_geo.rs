@@ -1,10 +1,18 @@
 use arrow::array::OffsetSizeTrait;
-use geo::HasDimensions;
+#[cfg(feature = "rayon")]
+use geo::{
+    line_intersection::line_intersection, BooleanOps, BoundingRect, ChamberlainDuquetteArea,
+    CoordsIter, LineIntersection,
+};
+use geo::{HasDimensions, Simplify};
 use geo_types::*;
 use h3o::geom::{ContainmentMode, Plotter, PlotterBuilder, Tiler, TilerBuilder};
 use h3o::{CellIndex, LatLng, Resolution};
 #[cfg(feature = "rayon")]
-use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use rayon::prelude::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
+};
+use std::borrow::Cow;
 
 use crate::array::list::H3ListArray;
 use crate::array::{CellIndexArray, H3ListArrayBuilder};
@@ -15,6 +23,7 @@ pub struct ToCellsOptions {
     pub(crate) h3_resolution: Resolution,
     pub(crate) containment_mode: ContainmentMode,
     pub(crate) compact: bool,
+    pub(crate) simplify_tolerance_m: Option<f64>,
 }
 
 impl ToCellsOptions {
@@ -23,6 +32,7 @@ impl ToCellsOptions {
             h3_resolution,
             containment_mode: ContainmentMode::ContainsCentroid,
             compact: false,
+            simplify_tolerance_m: None,
         }
     }
 
@@ -36,6 +46,14 @@ impl ToCellsOptions {
         self
     }
 
+    /// Simplify the rings of polygons using the Ramer–Douglas–Peucker algorithm before tiling
+    /// them. Removed vertices are at most `simplify_tolerance_m` meters away from the simplified
+    /// rings. Other geometry types are not simplified.
+    pub fn simplify_tolerance_m(mut self, simplify_tolerance_m: Option<f64>) -> Self {
+        self.simplify_tolerance_m = simplify_tolerance_m;
+        self
+    }
+
     pub(crate) fn tiler(&self) -> Tiler {
         TilerBuilder::new(self.h3_resolution)
             .containment_mode(self.containment_mode)
@@ -227,6 +245,7 @@ where
     }
 }
 
+#[cfg(any(feature = "rayon", feature = "geoarrow"))]
 pub(crate) fn cell_vecs_to_h3listarray<O: OffsetSizeTrait>(
     cell_vecs: Vec<Option<Vec<CellIndex>>>,
 ) -> Result<H3ListArray<CellIndex, O>, Error> {
@@ -311,8 +330,15 @@ pub fn geometry_to_cells(
         return Ok(vec![]);
     }
 
+    let geom = match options.simplify_tolerance_m {
+        Some(tolerance_m) if tolerance_m > 0.0 => {
+            simplify_polygons(geom, tolerance_m / METERS_PER_DEGREE_LATITUDE)
+        }
+        _ => Cow::Borrowed(geom),
+    };
+
     let mut cells = vec![];
-    geometry_to_cells_internal(geom, options, &mut cells)?;
+    geometry_to_cells_internal(&geom, options, &mut cells)?;
 
     // deduplicate, in the case of overlaps or lines
     cells.sort_unstable();
@@ -326,6 +352,28 @@ pub fn geometry_to_cells(
     Ok(cells)
 }
 
+/// Length of a degree of latitude. A degree of longitude is never longer, so a tolerance in degrees
+/// derived from this value never exceeds the tolerance in meters.
+const METERS_PER_DEGREE_LATITUDE: f64 = 111_320.0;
+
+fn simplify_polygons(geom: &Geometry, epsilon: f64) -> Cow<'_, Geometry> {
+    match geom {
+        Geometry::Polygon(polygon) => Cow::Owned(Geometry::Polygon(polygon.simplify(&epsilon))),
+        Geometry::MultiPolygon(multi_polygon) => {
+            Cow::Owned(Geometry::MultiPolygon(multi_polygon.simplify(&epsilon)))
+        }
+        Geometry::GeometryCollection(geometry_collection) => {
+            Cow::Owned(Geometry::GeometryCollection(GeometryCollection::new_from(
+                geometry_collection
+                    .iter()
+                    .map(|g| simplify_polygons(g, epsilon).into_owned())
+                    .collect(),
+            )))
+        }
+        _ => Cow::Borrowed(geom),
+    }
+}
+
 fn geometry_to_cells_internal(
     geom: &Geometry,
     options: &ToCellsOptions,
@@ -345,11 +393,7 @@ fn geometry_to_cells_internal(
             plotter.add_batch(line_string.lines())?;
             push_plotter_contents(out_cells, plotter)?;
         }
-        Geometry::Polygon(polygon) => {
-            let mut tiler = options.tiler();
-            tiler.add(polygon.clone())?;
-            out_cells.extend(tiler.into_coverage());
-        }
+        Geometry::Polygon(polygon) => tile_polygons(vec![polygon.clone()], options, out_cells)?,
         Geometry::MultiPoint(multi_point) => {
             out_cells.reserve(multi_point.len());
             for point in multi_point.iter() {
@@ -364,27 +408,270 @@ fn geometry_to_cells_internal(
             push_plotter_contents(out_cells, plotter)?;
         }
         Geometry::MultiPolygon(multi_polygon) => {
-            let mut tiler = options.tiler();
-            tiler.add_batch(multi_polygon.iter().cloned())?;
-            out_cells.extend(tiler.into_coverage());
+            tile_polygons(multi_polygon.0.clone(), options, out_cells)?
         }
         Geometry::GeometryCollection(geometry_collection) => geometry_collection
             .iter()
             .try_for_each(|g| geometry_to_cells_internal(g, options, out_cells))?,
-        Geometry::Rect(rect) => {
-            let mut tiler = options.tiler();
-            tiler.add(rect.to_polygon())?;
-            out_cells.extend(tiler.into_coverage());
-        }
+        Geometry::Rect(rect) => tile_polygons(vec![rect.to_polygon()], options, out_cells)?,
         Geometry::Triangle(triangle) => {
-            let mut tiler = options.tiler();
-            tiler.add(triangle.to_polygon())?;
-            out_cells.extend(tiler.into_coverage());
+            tile_polygons(vec![triangle.to_polygon()], options, out_cells)?
         }
     }
     Ok(())
 }
 
+/// Polygons expected to be covered by more cells than this are tiled in parallel.
+#[cfg(feature = "rayon")]
+const PARALLEL_TILING_MIN_CELLS: f64 = 250_000.0;
+
+/// Number of resolutions the covering used to split polygons for parallel tiling is coarser
+/// than the target resolution. Each coarse cell has about 7^5 children.
+#[cfg(feature = "rayon")]
+const PARALLEL_TILING_RESOLUTION_DELTA: u8 = 5;
+
+fn tile_polygons(
+    polygons: Vec<Polygon>,
+    options: &ToCellsOptions,
+    out_cells: &mut Vec<CellIndex>,
+) -> Result<(), Error> {
+    #[cfg(feature = "rayon")]
+    if let Some(coarse_resolution) = parallel_tiling_resolution(&polygons, options) {
+        return par_tile_polygons(&polygons, coarse_resolution, options, out_cells);
+    }
+
+    let mut tiler = options.tiler();
+    tiler.add_batch(polygons)?;
+    out_cells.extend(tiler.into_coverage());
+    Ok(())
+}
+
+/// The resolution of the coarse covering to split the polygons by, or `None` when the polygons
+/// are too small to benefit from parallel tiling or can not be split reliably.
+#[cfg(feature = "rayon")]
+fn parallel_tiling_resolution(
+    polygons: &[Polygon],
+    options: &ToCellsOptions,
+) -> Option<Resolution> {
+    let coarse_resolution = Resolution::try_from(
+        u8::from(options.h3_resolution).checked_sub(PARALLEL_TILING_RESOLUTION_DELTA)?,
+    )
+    .ok()?;
+
+    // splitting by rectangles does not work for polygons crossing the antimeridian.
+    let bbox = polygons
+        .iter()
+        .filter_map(|polygon| polygon.bounding_rect())
+        .reduce(|a, b| {
+            Rect::new(
+                (a.min().x.min(b.min().x), a.min().y.min(b.min().y)),
+                (a.max().x.max(b.max().x), a.max().y.max(b.max().y)),
+            )
+        })?;
+    if bbox.width() > 180.0 {
+        return None;
+    }
+
+    let area_m2: f64 = polygons
+        .iter()
+        .map(|polygon| polygon.chamberlain_duquette_unsigned_area())
+        .sum();
+    if area_m2 / options.h3_resolution.area_m2() < PARALLEL_TILING_MIN_CELLS {
+        return None;
+    }
+
+    // the boolean operations used to split the polygons are only reliable for valid polygons.
+    // Invalid polygons are left to the serial tiler, which is more forgiving.
+    polygons
+        .iter()
+        .all(polygon_is_valid)
+        .then_some(coarse_resolution)
+}
+
+/// Check the polygon for finite coordinates, closed rings of at least four coordinates and rings
+/// which neither intersect themselves nor each other - apart from the shared vertices of
+/// consecutive segments.
+///
+/// Rings touching each other in a single point are valid according to OGC, but are rejected
+/// here as well.
+#[cfg(feature = "rayon")]
+fn polygon_is_valid(polygon: &Polygon) -> bool {
+    // (segment, ring index, position within the ring, number of segments of the ring)
+    let mut segments = vec![];
+    for (ring_i, ring) in std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .enumerate()
+    {
+        if ring.0.len() < 4
+            || !ring.is_closed()
+            || !ring
+                .coords()
+                .all(|coord| coord.x.is_finite() && coord.y.is_finite())
+        {
+            return false;
+        }
+        // repeated coordinates result in segments without length, these are skipped
+        let ring_segments: Vec<_> = ring.lines().filter(|line| line.start != line.end).collect();
+        if ring_segments.len() < 3 {
+            return false;
+        }
+        let num_segments = ring_segments.len();
+        segments.extend(
+            ring_segments
+                .into_iter()
+                .enumerate()
+                .map(|(pos, line)| (line, ring_i, pos, num_segments)),
+        );
+    }
+
+    // sweep along the x-axis, only segments with overlapping x-ranges need to be compared.
+    segments.sort_unstable_by(|a, b| {
+        a.0.start
+            .x
+            .min(a.0.end.x)
+            .total_cmp(&b.0.start.x.min(b.0.end.x))
+    });
+    for (i, (line, ring_i, pos, num_segments)) in segments.iter().enumerate() {
+        let max_x = line.start.x.max(line.end.x);
+        for (other, other_ring_i, other_pos, _) in segments[i + 1..].iter() {
+            if other.start.x.min(other.end.x) > max_x {
+                break;
+            }
+            let distance = pos.abs_diff(*other_pos);
+            let consecutive =
+                ring_i == other_ring_i && (distance == 1 || distance == num_segments - 1);
+            match line_intersection(*line, *other) {
+                None => {}
+                Some(LineIntersection::SinglePoint {
+                    is_proper: false, ..
+                }) if consecutive => {}
+                Some(_) => return false,
+            }
+        }
+    }
+    true
+}
+
+/// Split the polygons into pieces using a covering of coarse cells and tile the pieces in
+/// parallel.
+///
+/// Each piece is the intersection of the polygons with the bounding box of a coarse cell,
+/// enlarged by half of its size. The pieces overlap, so cells close to the borders of the coarse
+/// cells are contained in the results of multiple pieces - these duplicates are removed by
+/// `geometry_to_cells`. The overlap guarantees that every cell fully contained in the polygons is
+/// also fully contained in at least one of the pieces.
+#[cfg(feature = "rayon")]
+fn par_tile_polygons(
+    polygons: &[Polygon],
+    coarse_resolution: Resolution,
+    options: &ToCellsOptions,
+    out_cells: &mut Vec<CellIndex>,
+) -> Result<(), Error> {
+    let mut coarse_tiler = TilerBuilder::new(coarse_resolution)
+        .containment_mode(ContainmentMode::Covers)
+        .build();
+    coarse_tiler.add_batch(polygons.iter().cloned())?;
+    let coarse_cells: Vec<_> = coarse_tiler.into_coverage().collect();
+
+    let pieces_cells = coarse_cells
+        .into_par_iter()
+        .map(|coarse_cell| {
+            let rect = enlarged_cell_rect(coarse_cell).to_polygon();
+            let piece: Vec<_> = polygons
+                .iter()
+                .flat_map(|polygon| polygon.intersection(&rect))
+                .collect();
+            if piece.is_empty() {
+                return Ok(vec![]);
+            }
+            let mut tiler = options.tiler();
+            tiler.add_batch(piece)?;
+            Ok(tiler.into_coverage().collect::<Vec<_>>())
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    out_cells.reserve(pieces_cells.iter().map(Vec::len).sum());
+    for piece_cells in pieces_cells {
+        out_cells.extend(piece_cells);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "rayon")]
+fn enlarged_cell_rect(cell: CellIndex) -> Rect {
+    let boundary = cell.boundary();
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for ll in boundary.iter() {
+        min_x = min_x.min(ll.lng());
+        min_y = min_y.min(ll.lat());
+        max_x = max_x.max(ll.lng());
+        max_y = max_y.max(ll.lat());
+    }
+    let (margin_x, margin_y) = ((max_x - min_x) / 2.0, (max_y - min_y) / 2.0);
+    Rect::new(
+        (min_x - margin_x, min_y - margin_y),
+        (max_x + margin_x, max_y + margin_y),
+    )
+}
+
+/// Rough estimate of the cost of converting the geometry to cells: the number of cells of the
+/// target resolution covering its bounding box plus the number of its vertices.
+#[cfg(feature = "rayon")]
+fn estimate_conversion_cost(geom: &Geometry, resolution: Resolution) -> f64 {
+    let n_cells = geom
+        .bounding_rect()
+        .map(|rect| {
+            let lat_factor = rect.center().y.to_radians().cos().max(0.01);
+            rect.width() * rect.height() * METERS_PER_DEGREE_LATITUDE.powi(2) * lat_factor
+                / resolution.area_m2()
+        })
+        .unwrap_or(0.0);
+    n_cells + geom.coords_count() as f64
+}
+
+/// Convert the geometries to cells in parallel.
+///
+/// The geometries are scheduled by their estimated cost - the most expensive ones first - and
+/// each geometry is a task on its own. This prevents a few large geometries which happen to be
+/// located next to each other in the input from ending up in the same chunk of work and
+/// dominating the runtime.
+#[cfg(feature = "rayon")]
+#[cfg_attr(not(feature = "geoarrow"), allow(dead_code))]
+pub(crate) fn par_geometries_to_cell_vecs(
+    geometries: &[Option<Geometry>],
+    options: &ToCellsOptions,
+) -> Result<Vec<Option<Vec<CellIndex>>>, Error> {
+    let costs: Vec<f64> = geometries
+        .par_iter()
+        .map(|geom| {
+            geom.as_ref()
+                .map(|geom| estimate_conversion_cost(geom, options.h3_resolution))
+                .unwrap_or(0.0)
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..geometries.len()).collect();
+    order.sort_unstable_by(|a, b| costs[*b].total_cmp(&costs[*a]));
+
+    let converted = order
+        .into_par_iter()
+        .with_max_len(1)
+        .map(|pos| {
+            let cells = geometries[pos]
+                .as_ref()
+                .map(|geom| geometry_to_cells(geom, options))
+                .transpose()?;
+            Ok((pos, cells))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut cell_vecs = vec![None; geometries.len()];
+    for (pos, cells) in converted {
+        cell_vecs[pos] = cells;
+    }
+    Ok(cell_vecs)
+}
+
 fn push_plotter_contents(out_cells: &mut Vec<CellIndex>, plotter: Plotter) -> Result<(), Error> {
     let cell_iter = plotter.plot();
     out_cells.reserve(cell_iter.size_hint().0);
@@ -422,4 +709,160 @@ mod tests {
             assert_eq!(r, Some(Resolution::Four));
         }
     }
+
+    #[test]
+    fn simplify_tolerance() {
+        use super::geometry_to_cells;
+        use geo_types::{Geometry, LineString, Polygon};
+
+        // a square with a noisy edge
+        let mut coords = vec![(10.0, 10.0)];
+        for i in 1..100 {
+            let x = 10.0 + i as f64 / 100.0;
+            coords.push((x, 10.0 + if i % 2 == 0 { 0.00001 } else { -0.00001 }));
+        }
+        coords.extend([(11.0, 10.0), (11.0, 11.0), (10.0, 11.0), (10.0, 10.0)]);
+        let geom = Geometry::Polygon(Polygon::new(LineString::from(coords), vec![]));
+
+        let options = ToCellsOptions::new(Resolution::Six);
+        let cells = geometry_to_cells(&geom, &options).unwrap();
+        let simplified_cells =
+            geometry_to_cells(&geom, &options.simplify_tolerance_m(Some(10.0))).unwrap();
+
+        // the noise is about 1m, so the coverage changes by a few cells along the edge at most
+        let unchanged = simplified_cells
+            .iter()
+            .filter(|cell| cells.binary_search(cell).is_ok())
+            .count();
+        assert!(cells.len().abs_diff(simplified_cells.len()) < 10);
+        assert!(simplified_cells.len() - unchanged < 10);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_geometries_to_cell_vecs_keeps_order() {
+        use super::{geometry_to_cells, par_geometries_to_cell_vecs};
+        use geo_types::{Geometry, Point};
+
+        let options = ToCellsOptions::from(Resolution::Six);
+        let geometries: Vec<Option<Geometry>> = vec![
+            Some(Point::new(10.0, 10.0).into()),
+            None,
+            Some(Rect::new((10., 10.), (12., 12.)).into()),
+            Some(Point::new(-20.0, 5.0).into()),
+        ];
+        let cell_vecs = par_geometries_to_cell_vecs(&geometries, &options).unwrap();
+        assert_eq!(cell_vecs.len(), geometries.len());
+        for (geom, cells) in geometries.iter().zip(cell_vecs.iter()) {
+            assert_eq!(
+                geom.as_ref()
+                    .map(|geom| geometry_to_cells(geom, &options).unwrap()),
+                *cells
+            );
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_tile_polygons_matches_tiler() {
+        use super::par_tile_polygons;
+        use geo_types::{LineString, Polygon};
+        use h3o::geom::ContainmentMode;
+
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (10., 10.),
+                (14., 10.5),
+                (13., 14.),
+                (10.5, 13.),
+                (10., 10.),
+            ]),
+            vec![LineString::from(vec![
+                (11., 11.),
+                (12., 11.),
+                (12., 12.),
+                (11., 11.),
+            ])],
+        );
+
+        for containment_mode in [
+            ContainmentMode::ContainsCentroid,
+            ContainmentMode::ContainsBoundary,
+            ContainmentMode::IntersectsBoundary,
+            ContainmentMode::Covers,
+        ] {
+            let options = ToCellsOptions::new(Resolution::Six).containment_mode(containment_mode);
+
+            let mut tiler = options.tiler();
+            tiler.add(polygon.clone()).unwrap();
+            let mut expected: Vec<_> = tiler.into_coverage().collect();
+            expected.sort_unstable();
+
+            let mut cells = vec![];
+            par_tile_polygons(
+                std::slice::from_ref(&polygon),
+                Resolution::Two,
+                &options,
+                &mut cells,
+            )
+            .unwrap();
+            cells.sort_unstable();
+            cells.dedup();
+
+            assert_eq!(cells, expected);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn polygon_validity() {
+        use super::polygon_is_valid;
+        use geo_types::{LineString, Polygon};
+
+        let exterior = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]);
+        let valid = Polygon::new(
+            exterior.clone(),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (2., 1.),
+                (2., 2.),
+                (1., 1.),
+            ])],
+        );
+        assert!(polygon_is_valid(&valid));
+
+        // repeated coordinates
+        let repeated = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 0.), (4., 4.), (0., 0.)]),
+            vec![],
+        );
+        assert!(polygon_is_valid(&repeated));
+
+        let bowtie = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 4.), (4., 0.), (0., 4.), (0., 0.)]),
+            vec![],
+        );
+        assert!(!polygon_is_valid(&bowtie));
+
+        let hole_crossing_exterior = Polygon::new(
+            exterior.clone(),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (5., 1.),
+                (5., 2.),
+                (1., 1.),
+            ])],
+        );
+        assert!(!polygon_is_valid(&hole_crossing_exterior));
+
+        let non_finite = Polygon::new(
+            LineString::from(vec![(0., 0.), (f64::NAN, 0.), (4., 4.), (0., 0.)]),
+            vec![],
+        );
+        assert!(!polygon_is_valid(&non_finite));
+        assert!(!polygon_is_valid(&Polygon::new(
+            LineString::new(vec![]),
+            vec![]
+        )));
+    }
 }
@@ -3,7 +3,10 @@ use super::from_geo::{
     ToCellListArray, ToCellsOptions,
 };
 use crate::algorithm::CompactOp;
+#[cfg(not(feature = "rayon"))]
 use crate::array::from_geo::geometry_to_cells;
+#[cfg(feature = "rayon")]
+use crate::array::from_geo::par_geometries_to_cell_vecs;
 use crate::array::{CellIndexArray, H3ListArray};
 use crate::error::Error;
 use arrow::array::OffsetSizeTrait;
@@ -51,12 +54,7 @@ impl<O: OffsetSizeTrait> ToCellListArray<O> for WKBArray<O> {
         options: &ToCellsOptions,
     ) -> Result<H3ListArray<CellIndex, O>, Error> {
         #[cfg(not(feature = "rayon"))]
-        let pos_iter = 0..self.len();
-
-        #[cfg(feature = "rayon")]
-        let pos_iter = (0..self.len()).into_par_iter();
-
-        let cell_vecs = pos_iter
+        let cell_vecs = (0..self.len())
             .map(|pos| {
                 self.get_as_geo(pos)
                     .map(|geom| geometry_to_cells(&geom, options))
@@ -64,6 +62,15 @@ impl<O: OffsetSizeTrait> ToCellListArray<O> for WKBArray<O> {
             })
             .collect::<Result<Vec<_>, _>>()?;
 
+        #[cfg(feature = "rayon")]
+        let cell_vecs = {
+            let geometries: Vec<_> = (0..self.len())
+                .into_par_iter()
+                .map(|pos| self.get_as_geo(pos))
+                .collect();
+            par_geometries_to_cell_vecs(&geometries, options)?
+        };
+
         cell_vecs_to_h3listarray(cell_vecs)
     }
 }
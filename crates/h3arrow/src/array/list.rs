@@ -1,6 +1,6 @@
 use crate::array::{H3Array, H3IndexArrayValue};
 use crate::error::Error;
-use arrow::array::{Array, GenericListBuilder, UInt64Array, UInt64Builder};
+use arrow::array::{Array, ArrayBuilder, GenericListBuilder, UInt64Array, UInt64Builder};
 use arrow::array::{GenericListArray, OffsetSizeTrait};
 use arrow::datatypes::DataType;
 use std::marker::PhantomData;
@@ -54,14 +54,8 @@ where
     pub(crate) fn from_genericlistarray_unvalidated(
         value: GenericListArray<O>,
     ) -> Result<H3ListArray<IX, O>, Error> {
-        if value.data_type() != &DataType::UInt64 {
-            return Err(Error::NotAUint64Array);
-        }
-
-        Ok(Self {
-            list_array: value,
-            h3index_phantom: PhantomData::<IX>,
-        })
+        // the datatype of the list itself is never UInt64, the values need to be checked
+        genericlistarray_to_h3listarray_unvalidated(value)
     }
 }
 
@@ -149,10 +143,35 @@ where
         }
     }
 
+    /// Finishes the current list - consisting of the values appended using [`Self::values`] since
+    /// the last call.
     pub fn append(&mut self, is_valid: bool) {
         self.builder.append(is_valid)
     }
 
+    /// Appends a complete list.
+    pub fn append_list<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = IX>,
+    {
+        self.values().append_many(values);
+        self.append(true);
+    }
+
+    /// Appends a null list.
+    pub fn append_null(&mut self) {
+        self.append(false);
+    }
+
+    /// Returns the number of lists appended so far.
+    pub fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.builder.is_empty()
+    }
+
     pub fn values(&mut self) -> H3ArrayBuilder<'_, IX> {
         H3ArrayBuilder {
             array_builder: self.builder.values(),
@@ -212,4 +231,22 @@ mod tests {
         let cells = list.into_flattened().unwrap();
         assert_eq!(cells.len(), 26);
     }
+
+    #[test]
+    fn append_list() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+
+        let mut builder = H3ListArrayBuilder::<CellIndex>::with_capacity(2, 8);
+        builder.append_list(cell.grid_disk::<Vec<_>>(1));
+        builder.append_null();
+        builder.append_list([cell]);
+        assert_eq!(builder.len(), 3);
+
+        let list = builder.finish().unwrap();
+        let lengths: Vec<_> = list
+            .iter_arrays()
+            .map(|array| array.map(|array| array.unwrap().len()))
+            .collect();
+        assert_eq!(lengths, vec![Some(7), None, Some(1)]);
+    }
 }
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 use std::mem::transmute;
 
-use arrow::array::{Array, ArrayIter, PrimitiveArray, UInt64Array, UInt64Builder};
+use arrow::array::{Array, ArrayBuilder, ArrayIter, PrimitiveArray, UInt64Array, UInt64Builder};
 use h3o::{CellIndex, DirectedEdgeIndex, VertexIndex};
 
 #[allow(unused_imports)]
@@ -25,6 +25,7 @@ pub mod to_geo;
 pub mod to_geoarrow;
 mod validity;
 mod vertex;
+pub mod wkb;
 
 pub trait H3IndexArrayValue: Into<u64> + TryFrom<u64> + Clone {
     fn transmute_from_u64(value: u64) -> Self;
@@ -70,7 +71,7 @@ where
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct H3Array<IX> {
     h3index_phantom: PhantomData<IX>,
     primitive_array: UInt64Array,
@@ -87,6 +88,22 @@ where
         }
     }
 
+    /// Create an array from `UInt64Array` without validating the contained values.
+    ///
+    /// Validation is a pass over all values, skipping it only makes sense when the values
+    /// are known to be valid - for example when they have been created by h3arrow before.
+    ///
+    /// # Safety
+    /// All non-null values must be valid indexes of type `IX`. The values are transmuted to
+    /// the h3o types when accessing them, which is undefined behavior for zero values and
+    /// leads to wrong results or panics for other invalid values.
+    pub unsafe fn new_unchecked(primitive_array: UInt64Array) -> Self {
+        Self {
+            h3index_phantom: Default::default(),
+            primitive_array,
+        }
+    }
+
     pub fn builder(capacity: usize) -> H3ArrayBuilder<IX> {
         H3ArrayBuilder::with_capacity(capacity)
     }
@@ -136,11 +153,19 @@ pub type CellIndexArray = H3Array<CellIndex>;
 pub type VertexIndexArray = H3Array<VertexIndex>;
 pub type DirectedEdgeIndexArray = H3Array<DirectedEdgeIndex>;
 
+/// Incrementally builds a [`H3Array`] from the h3o index types without going through
+/// intermediate vectors.
+///
+/// The values do not need to be validated again as the h3o types are valid by construction.
 pub struct H3ArrayBuilder<IX> {
     h3index_phantom: PhantomData<IX>,
     builder: UInt64Builder,
 }
 
+pub type CellIndexArrayBuilder = H3ArrayBuilder<CellIndex>;
+pub type VertexIndexArrayBuilder = H3ArrayBuilder<VertexIndex>;
+pub type DirectedEdgeIndexArrayBuilder = H3ArrayBuilder<DirectedEdgeIndex>;
+
 impl<IX> H3ArrayBuilder<IX>
 where
     IX: H3IndexArrayValue,
@@ -157,6 +182,15 @@ where
         self.builder.capacity()
     }
 
+    /// Returns the number of values appended so far
+    pub fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.builder.is_empty()
+    }
+
     /// Appends a value of type `T` into the builder
     #[inline]
     pub fn append_value(&mut self, v: IX) {
@@ -169,6 +203,12 @@ where
         self.builder.append_null();
     }
 
+    /// Appends a value or a null slot into the builder
+    #[inline]
+    pub fn append_option(&mut self, v: Option<IX>) {
+        self.builder.append_option(v.map(Into::into));
+    }
+
     /// Builds the [`H3Array`] and reset this builder.
     pub fn finish(&mut self) -> H3Array<IX> {
         H3Array {
@@ -178,6 +218,35 @@ where
     }
 }
 
+impl<IX> Default for H3ArrayBuilder<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    fn default() -> Self {
+        Self::with_capacity(1024)
+    }
+}
+
+impl<IX> Extend<IX> for H3ArrayBuilder<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    fn extend<T: IntoIterator<Item = IX>>(&mut self, iter: T) {
+        self.builder
+            .extend(iter.into_iter().map(|v| Some(v.into())));
+    }
+}
+
+impl<IX> Extend<Option<IX>> for H3ArrayBuilder<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    fn extend<T: IntoIterator<Item = Option<IX>>>(&mut self, iter: T) {
+        self.builder
+            .extend(iter.into_iter().map(|v| v.map(Into::into)));
+    }
+}
+
 /// Conversion corresponding to `From` with the difference that the validity mask
 /// is set accordingly to the validity to the contained values.
 pub trait FromWithValidity<T> {
@@ -0,0 +1,266 @@
+//! Rewriting of WKB geometries with Z and/or M coordinates to two dimensions.
+//!
+//! Both the ISO (type codes 1001, 2001, 3001, ...) and the EWKB flavour (high bits of the type
+//! code, including an embedded SRID) are understood. The rewritten geometries are always written
+//! as little-endian ISO WKB.
+
+use arrow::array::{
+    Array, Float64Array, GenericBinaryArray, GenericBinaryBuilder, OffsetSizeTrait,
+};
+
+use crate::error::Error;
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// A WKB geometry rewritten to two dimensions.
+pub struct Wkb2d {
+    pub wkb: Vec<u8>,
+
+    /// Mean of the Z coordinates of the geometry. `None` when the geometry has no Z coordinates.
+    pub mean_z: Option<f64>,
+}
+
+/// Check if the WKB geometry is plain two-dimensional WKB - without Z or M coordinates and
+/// without an embedded SRID. Invalid WKB is reported as two-dimensional, leaving the error to the
+/// parser.
+pub fn wkb_is_2d(wkb: &[u8]) -> bool {
+    let mut reader = Reader { wkb, pos: 0 };
+    reader
+        .header()
+        .map(|header| !(header.has_z || header.has_m || header.has_srid))
+        .unwrap_or(true)
+}
+
+/// Drop the Z and M coordinates of the WKB geometry.
+pub fn wkb_to_2d(wkb: &[u8]) -> Result<Wkb2d, Error> {
+    let mut reader = Reader { wkb, pos: 0 };
+    let mut out = Vec::with_capacity(wkb.len());
+    let mut z = ZStats::default();
+    reader.geometry(&mut out, &mut z)?;
+    Ok(Wkb2d {
+        wkb: out,
+        mean_z: (z.count > 0).then(|| z.sum / z.count as f64),
+    })
+}
+
+/// Drop the Z and M coordinates of all geometries of the array.
+///
+/// Returns the rewritten array and an array of the mean Z coordinate of each geometry - null for
+/// null geometries and geometries without Z coordinates.
+pub fn binaryarray_to_2d<O: OffsetSizeTrait>(
+    array: &GenericBinaryArray<O>,
+) -> Result<(GenericBinaryArray<O>, Float64Array), Error> {
+    let mut builder =
+        GenericBinaryBuilder::<O>::with_capacity(array.len(), array.value_data().len());
+    let mut mean_z = Vec::with_capacity(array.len());
+    for wkb in array.iter() {
+        match wkb {
+            Some(wkb) => {
+                let wkb2d = wkb_to_2d(wkb)?;
+                builder.append_value(wkb2d.wkb);
+                mean_z.push(wkb2d.mean_z);
+            }
+            None => {
+                builder.append_null();
+                mean_z.push(None);
+            }
+        }
+    }
+    Ok((builder.finish(), mean_z.into()))
+}
+
+#[derive(Default)]
+struct ZStats {
+    sum: f64,
+    count: usize,
+}
+
+struct Header {
+    little_endian: bool,
+    geometry_type: u32,
+    has_z: bool,
+    has_m: bool,
+    has_srid: bool,
+}
+
+struct Reader<'a> {
+    wkb: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let bytes = self
+            .wkb
+            .get(self.pos..self.pos + N)
+            .ok_or(Error::InvalidWKB)?;
+        self.pos += N;
+        Ok(bytes.try_into().expect("slice of length N"))
+    }
+
+    fn u32(&mut self, little_endian: bool) -> Result<u32, Error> {
+        let bytes = self.bytes::<4>()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self, little_endian: bool) -> Result<f64, Error> {
+        let bytes = self.bytes::<8>()?;
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn header(&mut self) -> Result<Header, Error> {
+        let little_endian = match self.bytes::<1>()?[0] {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::InvalidWKB),
+        };
+        let code = self.u32(little_endian)?;
+        let has_srid = code & EWKB_SRID != 0;
+        if has_srid {
+            // the SRID is not needed
+            self.u32(little_endian)?;
+        }
+        let ewkb_z = code & EWKB_Z != 0;
+        let ewkb_m = code & EWKB_M != 0;
+        let code = code & 0x0FFF_FFFF;
+        let (geometry_type, iso_dimensions) = (code % 1000, code / 1000);
+        Ok(Header {
+            little_endian,
+            geometry_type,
+            has_z: ewkb_z || iso_dimensions == 1 || iso_dimensions == 3,
+            has_m: ewkb_m || iso_dimensions == 2 || iso_dimensions == 3,
+            has_srid,
+        })
+    }
+
+    fn coords(
+        &mut self,
+        header: &Header,
+        n: u32,
+        out: &mut Vec<u8>,
+        z: &mut ZStats,
+    ) -> Result<(), Error> {
+        for _ in 0..n {
+            for _ in 0..2 {
+                out.extend_from_slice(&self.f64(header.little_endian)?.to_le_bytes());
+            }
+            if header.has_z {
+                let value = self.f64(header.little_endian)?;
+                if value.is_finite() {
+                    z.sum += value;
+                    z.count += 1;
+                }
+            }
+            if header.has_m {
+                self.f64(header.little_endian)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn count(&mut self, header: &Header, out: &mut Vec<u8>) -> Result<u32, Error> {
+        let n = self.u32(header.little_endian)?;
+        out.extend_from_slice(&n.to_le_bytes());
+        Ok(n)
+    }
+
+    fn geometry(&mut self, out: &mut Vec<u8>, z: &mut ZStats) -> Result<(), Error> {
+        let header = self.header()?;
+        out.push(1);
+        out.extend_from_slice(&header.geometry_type.to_le_bytes());
+        match header.geometry_type {
+            // Point
+            1 => self.coords(&header, 1, out, z)?,
+            // LineString
+            2 => {
+                let n = self.count(&header, out)?;
+                self.coords(&header, n, out, z)?;
+            }
+            // Polygon
+            3 => {
+                for _ in 0..self.count(&header, out)? {
+                    let n = self.count(&header, out)?;
+                    self.coords(&header, n, out, z)?;
+                }
+            }
+            // MultiPoint, MultiLineString, MultiPolygon, GeometryCollection
+            4..=7 => {
+                for _ in 0..self.count(&header, out)? {
+                    self.geometry(out, z)?;
+                }
+            }
+            _ => return Err(Error::InvalidWKB),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point_wkb(code: u32, coords: &[f64]) -> Vec<u8> {
+        let mut wkb = vec![1];
+        wkb.extend_from_slice(&code.to_le_bytes());
+        for c in coords {
+            wkb.extend_from_slice(&c.to_le_bytes());
+        }
+        wkb
+    }
+
+    #[test]
+    fn iso_point_z() {
+        let wkb = point_wkb(1001, &[10.0, 20.0, 5.0]);
+        assert!(!wkb_is_2d(&wkb));
+        let wkb2d = wkb_to_2d(&wkb).unwrap();
+        assert_eq!(wkb2d.wkb, point_wkb(1, &[10.0, 20.0]));
+        assert_eq!(wkb2d.mean_z, Some(5.0));
+    }
+
+    #[test]
+    fn ewkb_linestring_zm_big_endian() {
+        let mut wkb = vec![0];
+        wkb.extend_from_slice(&(2 | EWKB_Z | EWKB_M | EWKB_SRID).to_be_bytes());
+        wkb.extend_from_slice(&4326u32.to_be_bytes());
+        wkb.extend_from_slice(&2u32.to_be_bytes());
+        for c in [1.0, 2.0, 4.0, 0.5, 3.0, 4.0, 8.0, 0.5] {
+            wkb.extend_from_slice(&f64::to_be_bytes(c));
+        }
+        assert!(!wkb_is_2d(&wkb));
+
+        let wkb2d = wkb_to_2d(&wkb).unwrap();
+        let mut expected = vec![1];
+        expected.extend_from_slice(&2u32.to_le_bytes());
+        expected.extend_from_slice(&2u32.to_le_bytes());
+        for c in [1.0, 2.0, 3.0, 4.0] {
+            expected.extend_from_slice(&f64::to_le_bytes(c));
+        }
+        assert_eq!(wkb2d.wkb, expected);
+        assert_eq!(wkb2d.mean_z, Some(6.0));
+    }
+
+    #[test]
+    fn two_dimensional_unchanged() {
+        let wkb = point_wkb(1, &[10.0, 20.0]);
+        assert!(wkb_is_2d(&wkb));
+        let wkb2d = wkb_to_2d(&wkb).unwrap();
+        assert_eq!(wkb2d.wkb, wkb);
+        assert_eq!(wkb2d.mean_z, None);
+    }
+
+    #[test]
+    fn truncated() {
+        let wkb = point_wkb(1001, &[10.0, 20.0]);
+        assert!(wkb_to_2d(&wkb).is_err());
+    }
+}
@@ -51,6 +51,35 @@ pub enum Error {
     #[error("array length mismatch")]
     LengthMismatch,
 
+    #[error("invalid cost {0}: costs must not be negative or NaN")]
+    InvalidCost(f64),
+
+    #[error("invalid tolerance {0}: tolerances must not be negative or NaN")]
+    InvalidTolerance(f64),
+
+    #[error("invalid bandwidth {0}: bandwidths must be positive")]
+    InvalidBandwidth(f64),
+
+    #[error(
+        "{0} regions requested, but the cells form {1} unconnected parts requiring a region each"
+    )]
+    InvalidRegionCount(usize, usize),
+
+    #[error("invalid distance {0}: distances must be positive")]
+    InvalidDistance(f64),
+
+    #[error("all cells must be of the same resolution")]
+    MixedResolutions,
+
+    #[error("cells are not canonical: they must be sorted in the canonical order without duplicates or overlaps")]
+    NotCanonical,
+
+    #[error("cell resolution {0} is finer than the target resolution {1}")]
+    ResolutionTooFine(u8, u8),
+
+    #[error("payload {0} does not fit into the {1} unused bits of the cell")]
+    PayloadOverflow(u64, u32),
+
     #[error(transparent)]
     IO(#[from] std::io::Error),
 }
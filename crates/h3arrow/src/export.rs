@@ -1,5 +1,7 @@
 #[cfg(feature = "geoarrow")]
 pub use geoarrow;
+#[cfg(feature = "geoarrow")]
+pub use geozero;
 
 pub use arrow;
 pub use h3o;
@@ -6,5 +6,11 @@ pub mod array;
 pub mod error;
 pub mod export;
 
+#[cfg(feature = "serde")]
+mod serde;
+
 #[cfg(feature = "spatial_index")]
 pub mod spatial_index;
+
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategies;
@@ -0,0 +1,203 @@
+//! Serialization of the array types and algorithm outputs using serde.
+//!
+//! Arrays are represented as sequences of optional integers - null values become `None` -, list
+//! arrays as sequences of optional sequences of these. The H3 indexes are validated when
+//! deserializing.
+
+use std::marker::PhantomData;
+
+use arrow::array::{
+    Array, ArrowPrimitiveType, AsArray, GenericListArray, OffsetSizeTrait, PrimitiveArray,
+    UInt64Array,
+};
+use arrow::datatypes::{UInt32Type, UInt64Type};
+use h3o::CellIndex;
+use serde::de::Error as _;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::algorithm::{GridDiskAggregateK, GridDiskDistances};
+use crate::array::{H3Array, H3IndexArrayValue, H3ListArray};
+use crate::error::Error;
+
+struct PrimitiveSeq<'a, T: ArrowPrimitiveType>(&'a PrimitiveArray<T>);
+
+impl<T> Serialize for PrimitiveSeq<'_, T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+struct ListSeq<'a, O: OffsetSizeTrait, T>(&'a GenericListArray<O>, PhantomData<T>);
+
+impl<O, T> Serialize for ListSeq<'_, O, T>
+where
+    O: OffsetSizeTrait,
+    T: ArrowPrimitiveType,
+    T::Native: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for values in self.0.iter() {
+            seq.serialize_element(
+                &values
+                    .as_ref()
+                    .map(|values| PrimitiveSeq(values.as_primitive::<T>())),
+            )?;
+        }
+        seq.end()
+    }
+}
+
+type ListValues<N> = Vec<Option<Vec<Option<N>>>>;
+
+fn list_from_values<O, T>(values: ListValues<T::Native>) -> GenericListArray<O>
+where
+    O: OffsetSizeTrait,
+    T: ArrowPrimitiveType,
+{
+    GenericListArray::<O>::from_iter_primitive::<T, _, _>(values)
+}
+
+impl<IX> Serialize for H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PrimitiveSeq(self.primitive_array()).serialize(serializer)
+    }
+}
+
+impl<'de, IX> Deserialize<'de> for H3Array<IX>
+where
+    IX: H3IndexArrayValue,
+    H3Array<IX>: TryFrom<UInt64Array, Error = Error>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<Option<u64>>::deserialize(deserializer)?;
+        Self::try_from(UInt64Array::from(values)).map_err(D::Error::custom)
+    }
+}
+
+impl<IX, O> Serialize for H3ListArray<IX, O>
+where
+    IX: H3IndexArrayValue,
+    O: OffsetSizeTrait,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ListSeq::<O, UInt64Type>(&self.list_array, PhantomData).serialize(serializer)
+    }
+}
+
+impl<'de, IX, O> Deserialize<'de> for H3ListArray<IX, O>
+where
+    IX: H3IndexArrayValue,
+    O: OffsetSizeTrait,
+    H3Array<IX>: TryFrom<UInt64Array, Error = Error>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = ListValues::<u64>::deserialize(deserializer)?;
+        Self::try_from(list_from_values::<O, UInt64Type>(values)).map_err(D::Error::custom)
+    }
+}
+
+impl<O: OffsetSizeTrait> Serialize for GridDiskDistances<O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("GridDiskDistances", 2)?;
+        state.serialize_field("cells", &self.cells)?;
+        state.serialize_field(
+            "distances",
+            &ListSeq::<O, UInt32Type>(&self.distances, PhantomData),
+        )?;
+        state.end()
+    }
+}
+
+impl<'de, O: OffsetSizeTrait> Deserialize<'de> for GridDiskDistances<O> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(bound = "")]
+        struct Raw<O: OffsetSizeTrait> {
+            cells: H3ListArray<CellIndex, O>,
+            distances: ListValues<u32>,
+        }
+
+        let raw = Raw::<O>::deserialize(deserializer)?;
+        let distances = list_from_values::<O, UInt32Type>(raw.distances);
+        if raw.cells.len() != distances.len() {
+            return Err(D::Error::custom(Error::LengthMismatch));
+        }
+        Ok(Self {
+            cells: raw.cells,
+            distances,
+        })
+    }
+}
+
+impl Serialize for GridDiskAggregateK {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("GridDiskAggregateK", 2)?;
+        state.serialize_field("cells", &self.cells)?;
+        state.serialize_field("distances", &PrimitiveSeq(&self.distances))?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GridDiskAggregateK {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            cells: H3Array<CellIndex>,
+            distances: Vec<Option<u32>>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        if raw.cells.len() != raw.distances.len() {
+            return Err(D::Error::custom(Error::LengthMismatch));
+        }
+        Ok(Self {
+            cells: raw.cells,
+            distances: raw.distances.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::GridOp;
+    use crate::array::CellIndexArray;
+    use h3o::{LatLng, Resolution};
+
+    fn cells() -> CellIndexArray {
+        let cell = LatLng::new(10.0, 10.0).unwrap().to_cell(Resolution::Seven);
+        vec![Some(cell), None].into()
+    }
+
+    #[test]
+    fn array_roundtrip() {
+        let cells = cells();
+        let json = serde_json::to_string(&cells).unwrap();
+        assert_eq!(json, format!("[{},null]", u64::from(cells.get(0).unwrap())));
+        let deserialized: CellIndexArray = serde_json::from_str(&json).unwrap();
+        assert!(deserialized == cells);
+    }
+
+    #[test]
+    fn array_invalid_index() {
+        assert!(serde_json::from_str::<CellIndexArray>("[1]").is_err());
+    }
+
+    #[test]
+    fn grid_disk_distances_roundtrip() {
+        let distances = cells().grid_disk_distances::<i32>(1).unwrap();
+        let json = serde_json::to_string(&distances).unwrap();
+        let deserialized: GridDiskDistances<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.cells.listarray(), distances.cells.listarray());
+        assert_eq!(deserialized.distances, distances.distances);
+    }
+}
@@ -0,0 +1,138 @@
+//! [proptest](https://docs.rs/proptest) strategies generating H3 values.
+//!
+//! The strategies deliberately over-sample the edge cases of the grid - pentagons as well as the
+//! coarsest and finest resolutions.
+use crate::array::CellIndexArray;
+use h3o::{CellIndex, LatLng, Resolution};
+use proptest::prelude::*;
+
+/// Any resolution. Resolutions 0 and 15 are generated more often.
+pub fn resolution() -> impl Strategy<Value = Resolution> {
+    prop_oneof![
+        1 => Just(Resolution::Zero),
+        1 => Just(Resolution::Fifteen),
+        4 => (0u8..=15).prop_map(|r| Resolution::try_from(r).unwrap()),
+    ]
+}
+
+/// Any valid coordinate.
+pub fn latlng() -> impl Strategy<Value = LatLng> {
+    (-90.0f64..=90.0, -180.0f64..180.0).prop_map(|(lat, lng)| LatLng::new(lat, lng).unwrap())
+}
+
+/// Any of the 12 pentagons at `resolution`.
+pub fn pentagon_at(resolution: Resolution) -> impl Strategy<Value = CellIndex> {
+    let pentagons: Vec<_> = resolution.pentagons().collect();
+    prop::sample::select(pentagons)
+}
+
+/// Any cell at `resolution`. Pentagons are generated more often.
+pub fn cell_index_at(resolution: Resolution) -> impl Strategy<Value = CellIndex> {
+    prop_oneof![
+        1 => pentagon_at(resolution),
+        9 => latlng().prop_map(move |ll| ll.to_cell(resolution)),
+    ]
+}
+
+/// Any cell of any resolution.
+pub fn cell_index() -> impl Strategy<Value = CellIndex> {
+    resolution().prop_flat_map(cell_index_at)
+}
+
+/// Arrays of up to `max_len` cells of any resolution including null values.
+pub fn cell_index_array(max_len: usize) -> impl Strategy<Value = CellIndexArray> {
+    prop::collection::vec(prop::option::weighted(0.9, cell_index()), 0..=max_len)
+        .prop_map(CellIndexArray::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::{ChangeResolutionOp, CompactOp, GridOp};
+
+    fn coarser_and_finer() -> impl Strategy<Value = (CellIndex, Resolution)> {
+        (0u8..15)
+            .prop_flat_map(|r| (Just(r), (r + 1)..=(r + 3).min(15)))
+            .prop_flat_map(|(r, r_child)| {
+                (
+                    cell_index_at(Resolution::try_from(r).unwrap()),
+                    Just(Resolution::try_from(r_child).unwrap()),
+                )
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn parent_of_children_is_cell((cell, child_resolution) in coarser_and_finer()) {
+            let cells: CellIndexArray = vec![cell].into();
+            let children = cells.change_resolution(child_resolution).unwrap();
+            prop_assert_eq!(children.len() as u64, cell.children_count(child_resolution));
+
+            let parents = children.change_resolution(cell.resolution()).unwrap();
+            prop_assert!(parents.iter().all(|parent| parent == Some(cell)));
+        }
+
+        #[test]
+        fn compact_uncompact_roundtrip(
+            (cell, child_resolution) in coarser_and_finer(),
+            others in prop::collection::vec(latlng(), 0..20),
+        ) {
+            prop_assume!(cell.children_count(child_resolution) <= 400);
+            let mut cells: Vec<_> = cell.children(child_resolution).collect();
+            cells.extend(others.into_iter().map(|ll| ll.to_cell(child_resolution)));
+            cells.sort_unstable();
+            cells.dedup();
+
+            let compacted = CellIndexArray::from(cells.clone()).compact().unwrap();
+            prop_assert!(compacted.len() < cells.len());
+
+            let mut uncompacted: Vec<_> = compacted
+                .uncompact(child_resolution)
+                .iter()
+                .flatten()
+                .collect();
+            uncompacted.sort_unstable();
+            prop_assert_eq!(uncompacted, cells);
+        }
+
+        #[test]
+        fn grid_disk_size(cell in cell_index(), k in 0u32..5) {
+            let cells: CellIndexArray = vec![cell].into();
+            let disk = cells.grid_disk::<i64>(k).unwrap().into_flattened().unwrap();
+            let disk: Vec<_> = disk.iter().flatten().collect();
+
+            let max_size = h3o::max_grid_disk_size(k) as usize;
+            prop_assert!(disk.len() <= max_size);
+            prop_assert!(disk.contains(&cell));
+            if disk.iter().all(|cell| !cell.is_pentagon()) {
+                prop_assert_eq!(disk.len(), max_size);
+            }
+        }
+
+        #[test]
+        fn generated_arrays_are_valid(cells in cell_index_array(50)) {
+            prop_assert!(cells.len() <= 50);
+            prop_assert_eq!(cells.iter().count(), cells.len());
+        }
+    }
+
+    #[cfg(feature = "geoarrow")]
+    proptest! {
+        #[test]
+        fn wkb_points_roundtrip(cells in prop::collection::vec(cell_index(), 1..20)) {
+            use crate::array::from_geo::{ToCellListArray, ToCellsOptions};
+            use crate::array::to_geoarrow::ToWKBPoints;
+
+            for cell in cells {
+                let cells: CellIndexArray = vec![cell].into();
+                let wkb = cells.to_wkb_points::<i32>(true).unwrap();
+                let roundtripped = wkb
+                    .to_celllistarray(&ToCellsOptions::new(cell.resolution()))
+                    .unwrap()
+                    .into_flattened()
+                    .unwrap();
+                prop_assert_eq!(roundtripped.iter().collect::<Vec<_>>(), vec![Some(cell)]);
+            }
+        }
+    }
+}
@@ -1,8 +1,12 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any, Optional, Sequence, Union, cast
+import inspect
+import numbers
+from contextlib import contextmanager
+from functools import wraps
+from typing import TYPE_CHECKING, Any, Dict, Iterable, Iterator, List, Optional, Sequence, Union, cast
 
-from arro3.core import Array, ChunkedArray, DataType, RecordBatch
+from arro3.core import Array, ChunkedArray, DataType, Field, RecordBatch, Table
 from arro3.core.types import (
     ArrowArrayExportable,
     ArrowSchemaExportable,
@@ -10,6 +14,7 @@ from arro3.core.types import (
 )
 
 from . import h3ronpyrs as _native
+from .device import _device_kernel
 from .h3ronpyrs import (  # noqa: F401
     DEFAULT_CELL_COLUMN_NAME,
     ContainmentMode,
@@ -34,6 +39,25 @@ if not _native.is_release_build():
     )
 
 
+def has_raster_support() -> bool:
+    """
+    Check if h3ronpy was built with support for the `h3ronpy.raster` module.
+
+    The core functions operating on arrow arrays are always available. The raster module
+    can be disabled at build time, the GDAL-based ``rasterio`` library is only required by some of
+    its functions and can be installed using ``pip install h3ronpy[raster]``.
+    """
+    return hasattr(_native, "raster")
+
+
+def has_io_support() -> bool:
+    """
+    Check if h3ronpy was built with the ``io`` cargo feature providing the reading of GeoParquet files and the
+    gzip compression of written files. The feature is enabled by default and disabled for wasm32/pyodide builds.
+    """
+    return hasattr(_native.vector, "heatmap_from_geoparquet")
+
+
 def _to_arrow_array(
     arr: Union[ArrowArrayExportable, ArrowStreamExportable, pl.Series, Sequence[Any]],
     dtype: Optional[ArrowSchemaExportable] = None,
@@ -41,11 +65,9 @@ def _to_arrow_array(
     if hasattr(arr, "__arrow_c_array__"):
         array = Array.from_arrow(cast(ArrowArrayExportable, arr))
     elif hasattr(arr, "__arrow_c_stream__"):
-        ca = ChunkedArray.from_arrow(cast(ArrowStreamExportable, arr))
-        array = ca.combine_chunks()
+        array = _combine_chunks(ChunkedArray.from_arrow(cast(ArrowStreamExportable, arr)))
     elif hasattr(arr, "to_arrow"):
-        ca = ChunkedArray.from_arrow(arr.to_arrow())  # type: ignore
-        array = ca.combine_chunks()
+        array = _combine_chunks(ChunkedArray.from_arrow(arr.to_arrow()))  # type: ignore
     elif dtype is not None:
         # From arbitrary non-arrow input
         array = Array(cast(Sequence[Any], arr), type=dtype)
@@ -59,11 +81,199 @@ def _to_arrow_array(
     return array
 
 
+def _combine_chunks(ca: ChunkedArray) -> Array:
+    # streams of empty tables and series may not contain any chunk at all, which can not be concatenated.
+    if ca.num_chunks == 0:
+        return Array([], type=ca.type)
+    return ca.combine_chunks()
+
+
+def _to_list_offset(arr: Array) -> Array:
+    if not DataType.is_large_list(arr.type):
+        return arr
+    try:
+        return arr.cast(DataType.list(Field("item", arr.type.value_type, nullable=True)))
+    except Exception as e:
+        raise ValueError(f"the list array is too large for 32-bit offsets: {e}") from e
+
+
+def _with_list_offset(result, offset: str):
+    """
+    Convert the large list arrays of a result to lists with 32-bit offsets when `offset` is ``"small"`` - as
+    required by some downstream systems like Spark or older parquet readers. Raises a `ValueError` when the number
+    of list elements exceeds the range of 32-bit offsets.
+    """
+    if offset not in ("large", "small"):
+        raise ValueError(f"unsupported value for offset: {offset!r}")
+    if offset == "large":
+        return result
+    if isinstance(result, RecordBatch):
+        return RecordBatch.from_pydict(
+            {name: _to_list_offset(column) for name, column in zip(result.column_names, result.columns)}
+        )
+    return _to_list_offset(result)
+
+
+def _is_polars(value) -> bool:
+    return type(value).__module__.split(".")[0] == "polars"
+
+
+def _to_polars(result):
+    import polars as pl
+
+    if isinstance(result, (Array, ChunkedArray)):
+        return pl.Series(result)
+    if isinstance(result, RecordBatch):
+        return pl.DataFrame(Table.from_arrow(result))
+    if isinstance(result, Table):
+        return pl.DataFrame(result)
+    if isinstance(result, dict):
+        return {key: _to_polars(value) for key, value in result.items()}
+    return result
+
+
+def _polars_io(func):
+    """
+    Return polars objects from an operation when any of its inputs is a polars Series or DataFrame. The data is
+    exchanged using the Arrow C interface without involving pyarrow.
+    """
+
+    @wraps(func)
+    def wrapper(*args, **kwargs):
+        result = func(*args, **kwargs)
+        if any(_is_polars(value) for value in (*args, *kwargs.values())):
+            return _to_polars(result)
+        return result
+
+    return wrapper
+
+
 def _to_uint64_array(arr) -> Array:
     return _to_arrow_array(arr, DataType.uint64())
 
 
-def change_resolution(arr, resolution: int) -> Array:
+def _errors_as_table(errors: Optional[str]) -> bool:
+    if errors is None:
+        return False
+    if errors == "table":
+        return True
+    raise ValueError(f"unsupported value for errors: {errors!r}")
+
+
+@contextmanager
+def _validation_skipped(skip: bool) -> Iterator[None]:
+    if not skip:
+        yield
+        return
+    previous = _native.set_assume_valid(True)
+    try:
+        yield
+    finally:
+        _native.set_assume_valid(previous)
+
+
+def assume_valid():
+    """
+    Context manager to skip the validation of H3 indexes passed to the functions of this library.
+
+    Every array passed in is validated value by value before being used. For pipelines working with
+    indexes which are already known to be valid - for example because they have been created by
+    h3ronpy or validated using `cells_valid` before - this validation pass can be skipped for
+    better throughput.
+
+    This is an unsafe escape hatch: invalid values passed in while the validation is skipped lead
+    to wrong results or exceptions raised from within the native code. Zero values are still rejected.
+
+    The setting only applies to the current thread. Some of the frequently used functions also provide
+    an `assume_valid` parameter for the same purpose.
+
+    Example::
+
+        with h3ronpy.assume_valid():
+            parents = change_resolution(cells, 5)
+    """
+    return _validation_skipped(True)
+
+
+def _empty_like(value):
+    if isinstance(value, (str, bytes, int, float, bool)) or value is None:
+        return value
+    if hasattr(value, "__array__") and hasattr(value, "shape") and not hasattr(value, "slice"):
+        # numpy arrays
+        return value[:0]
+    if hasattr(value, "slice") and (
+        hasattr(value, "__arrow_c_array__") or hasattr(value, "__arrow_c_stream__") or hasattr(value, "to_arrow")
+    ):
+        return value.slice(0, 0)
+    return value
+
+
+def dry_run(func, *args, **kwargs):
+    """
+    Determine the type of the output of an operation without processing any data - for example to plan
+    pipelines or to create the tables the results will be written to.
+
+    `func` is called with all arrays, tables and dataframes of the arguments - arrow objects, numpy arrays and
+    polars series - replaced by empty slices of them. All operations return well-typed empty outputs for empty
+    inputs, so this is cheap and yields the same types as processing the complete data. Other arguments like
+    resolutions, bounding boxes or lists are passed on unchanged.
+
+    :param func: The operation, for example ``change_resolution``.
+    :return: The ``Schema`` of operations returning tables and record batches, the ``DataType`` of operations
+        returning arrays.
+    """
+    result = func(*(_empty_like(arg) for arg in args), **{key: _empty_like(value) for key, value in kwargs.items()})
+    if hasattr(result, "schema"):
+        return result.schema
+    if hasattr(result, "type"):
+        return result.type
+    raise TypeError(f"unable to determine the output type of {getattr(func, '__name__', func)}")
+
+
+_TABLE_COLUMN_DOC = """
+    Instead of an array, a table/dataframe can be passed together with the name of the `column` to process. The
+    table is returned with the derived column replacing `column` - or added as `output_column` when given. The
+    column is processed chunk by chunk, so neither the table nor the column is copied.
+"""
+
+
+def _table_column_op(func):
+    """
+    Allow passing a table together with a `column` to an operation deriving an array of the same length from an
+    array.
+    """
+
+    @wraps(func)
+    def wrapper(arr, *args, column: Optional[str] = None, output_column: Optional[str] = None, **kwargs):
+        if column is None:
+            if output_column is not None:
+                raise ValueError("output_column requires column to be set")
+            return func(arr, *args, **kwargs)
+
+        table = Table.from_arrow(arr)
+        if column not in table.column_names:
+            raise ValueError(f"column {column} not found")
+        chunks = table.column(column).chunks or [Array([], type=table.schema.field(column).type)]
+        derived = []
+        for chunk in chunks:
+            result = func(chunk, *args, **kwargs)
+            if not isinstance(result, Array) or len(result) != len(chunk):
+                raise ValueError(f"{func.__name__} does not derive a column of the same length from {column}")
+            derived.append(result)
+        derived = ChunkedArray(derived, type=derived[0].type)
+
+        output_column = output_column or column
+        field = Field(output_column, derived.type, nullable=True)
+        if output_column in table.column_names:
+            return table.set_column(table.column_names.index(output_column), field, derived)
+        return table.append_column(field, derived)
+
+    wrapper.__doc__ = (func.__doc__ or "").rstrip() + "\n" + _TABLE_COLUMN_DOC
+    return wrapper
+
+
+@_table_column_op
+def change_resolution(arr, resolution: int, assume_valid: bool = False) -> Array:
     """
     Change the H3 resolutions of all contained values to `resolution`.
 
@@ -71,11 +281,17 @@ def change_resolution(arr, resolution: int) -> Array:
     value may contain more indexes than the input.
 
     Invalid/empty values are omitted.
+
+    Setting `assume_valid` skips the validation of the input cells, see `assume_valid()`.
     """
-    return op.change_resolution(_to_uint64_array(arr), resolution)
+    kernel = _device_kernel("change_resolution", arr)
+    if kernel is not None:
+        return kernel(arr, resolution)
+    with _validation_skipped(assume_valid):
+        return op.change_resolution(_to_uint64_array(arr), resolution)
 
 
-def change_resolution_list(arr, resolution: int) -> Array:
+def change_resolution_list(arr, resolution: int, offset: str = "large") -> Array:
     """
     Change the H3 resolutions of all contained values to `resolution`.
 
@@ -83,8 +299,11 @@ def change_resolution_list(arr, resolution: int) -> Array:
     in input and output are corresponding to each other.
 
     Invalid/empty values are preserved as such.
+
+    :param offset: ``"large"`` returns list arrays with 64-bit offsets, ``"small"`` with 32-bit offsets. See
+        `grid_disk`.
     """
-    return op.change_resolution_list(_to_uint64_array(arr), resolution)
+    return _with_list_offset(op.change_resolution_list(_to_uint64_array(arr), resolution), offset)
 
 
 def change_resolution_paired(arr, resolution: int) -> RecordBatch:
@@ -98,18 +317,100 @@ def change_resolution_paired(arr, resolution: int) -> RecordBatch:
     return op.change_resolution_paired(_to_uint64_array(arr), resolution)
 
 
-def cells_resolution(arr) -> Array:
+@_table_column_op
+def cells_to_center_child(arr, resolution: int) -> Array:
+    """
+    The center child of each cell at `resolution`.
+
+    Null for cells of a finer resolution than `resolution`.
+    """
+    return op.cells_to_center_child(_to_uint64_array(arr), resolution)
+
+
+def cells_to_children_size(arr, resolution: int) -> Array:
+    """
+    The number of children of each cell at `resolution` - for example to allocate buffers before calling
+    `change_resolution`. This is computed without generating the children.
+
+    Zero for cells of a finer resolution than `resolution`.
+    """
+    return op.cells_to_children_size(_to_uint64_array(arr), resolution)
+
+
+def dasymetric_resample(
+    values_table,
+    weights_table,
+    target_resolution: int,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    value_column: str = "value",
+    weight_cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    weight_column: str = "weight",
+) -> RecordBatch:
+    """
+    Redistribute extensive values - for example counts - to the children of their cells at `target_resolution`
+    proportionally to an auxiliary weight of the children - for example the population - instead of uniformly
+    by the number of children.
+
+    Children without a positive weight receive nothing. When none of the children of a cell has a positive weight,
+    the value is distributed uniformly. The values of the children of a cell always sum up to the value of the cell.
+    Rows with a null cell or value are skipped.
+
+    :param values_table: Table/dataframe with the cells and values to redistribute. The cells must not be
+        of a finer resolution than `target_resolution`.
+    :param weights_table: Table/dataframe with the weights of cells at `target_resolution`.
+    :param target_resolution: The H3 resolution to redistribute to.
+    :param cell_column: Name of the column containing the cells in `values_table`.
+    :param value_column: Name of the column containing the values in `values_table`.
+    :param weight_cell_column: Name of the column containing the cells in `weights_table`.
+    :param weight_column: Name of the column containing the weights in `weights_table`.
+    :return: Table/dataframe with the columns `cell` and `value`.
+    """
+    return op.dasymetric_resample(
+        _to_uint64_array(values_table[cell_column]),
+        _to_arrow_array(values_table[value_column], DataType.float64()),
+        _to_uint64_array(weights_table[weight_cell_column]),
+        _to_arrow_array(weights_table[weight_column], DataType.float64()),
+        target_resolution,
+    )
+
+
+@_table_column_op
+def cells_resolution(arr, assume_valid: bool = False) -> Array:
     """
     Generates a new array containing the resolution of each cell of the
     input array.
 
     :param arr:
+    :param assume_valid: skip the validation of the input cells, see `assume_valid()`.
     :return:
     """
-    return op.cells_resolution(_to_uint64_array(arr))
+    with _validation_skipped(assume_valid):
+        return op.cells_resolution(_to_uint64_array(arr))
+
+
+def filter_by_resolution(arr, resolutions: Iterable[int]) -> Array:
+    """
+    Keep only the cells having one of the given H3 resolutions.
+
+    Invalid/empty values are omitted.
+    """
+    return op.filter_by_resolution(_to_uint64_array(arr), list(resolutions))
+
+
+def split_by_resolution(arr) -> Dict[int, Array]:
+    """
+    Split the cells into one array per contained H3 resolution. Useful when working with
+    compacted cells of mixed resolutions.
 
+    The order of the cells within each array is preserved. Invalid/empty values are omitted.
 
-def cells_parse(arr, set_failing_to_invalid: bool = False) -> Array:
+    :return: dictionary mapping the resolutions to the arrays of cells
+    """
+    return op.split_by_resolution(_to_uint64_array(arr))
+
+
+@_table_column_op
+def cells_parse(arr, set_failing_to_invalid: bool = False, errors: Optional[str] = None):
     """
     Parse H3 cells from string arrays.
 
@@ -117,68 +418,247 @@ def cells_parse(arr, set_failing_to_invalid: bool = False) -> Array:
     the successful parsing of an individual element. Having this set to false will cause the
     method to fail upon encountering the first unparsable value.
 
+    Setting `errors` to ``"table"`` sets failing values to null and returns a tuple of the parsed array and a
+    table of the failures with the columns `row` - the position in the input -, `value` - the offending string -
+    and `reason`. This allows quarantining bad rows instead of silently dropping them.
+
     This function is able to parse multiple representations of H3 cells:
 
-        * hexadecimal (Example: ``8552dc63fffffff``)
+        * hexadecimal (Example: ``8552dc63fffffff``), also in uppercase, with a ``0x`` prefix, zero-padded or
+          shortened as written by `cells_to_string` (Examples: ``0x8552DC63FFFFFFF``, ``08552dc63fffffff``,
+          ``8552dc63``)
         * numeric integer strings (Example: ``600436454824345599``)
         * strings like ``[x], [y], [resolution]`` or  ``[x]; [y]; [resolution]``. (Example: ``10.2,45.5,5``)
     """
+    if _errors_as_table(errors):
+        return op.cells_parse_with_errors(_to_arrow_array(arr, DataType.utf8()))
     return op.cells_parse(
         _to_arrow_array(arr, DataType.utf8()),
         set_failing_to_invalid=set_failing_to_invalid,
     )
 
 
-def vertexes_parse(arr, set_failing_to_invalid: bool = False) -> Array:
+def vertexes_parse(arr, set_failing_to_invalid: bool = False, errors: Optional[str] = None):
     """
     Parse H3 vertexes from string arrays.
 
     Setting `set_failing_to_invalid` to true will trigger setting the validity bitmap according
     the successful parsing of an individual element. Having this set to false will cause the
     method to fail upon encountering the first unparsable value.
+
+    Setting `errors` to ``"table"`` sets failing values to null and returns a tuple of the parsed array and a
+    table of the failures with the columns `row` - the position in the input -, `value` - the offending string -
+    and `reason`. This allows quarantining bad rows instead of silently dropping them.
     """
+    if _errors_as_table(errors):
+        return op.vertexes_parse_with_errors(_to_arrow_array(arr, DataType.utf8()))
     return op.vertexes_parse(
         _to_arrow_array(arr, DataType.utf8()),
         set_failing_to_invalid=set_failing_to_invalid,
     )
 
 
-def directededges_parse(arr, set_failing_to_invalid: bool = False) -> Array:
+def directededges_parse(arr, set_failing_to_invalid: bool = False, errors: Optional[str] = None):
     """
     Parse H3 directed edges from string arrays.
 
     Setting `set_failing_to_invalid` to true will trigger setting the validity bitmap according
     the successful parsing of an individual element. Having this set to false will cause the
     method to fail upon encountering the first unparsable value.
+
+    Setting `errors` to ``"table"`` sets failing values to null and returns a tuple of the parsed array and a
+    table of the failures with the columns `row` - the position in the input -, `value` - the offending string -
+    and `reason`. This allows quarantining bad rows instead of silently dropping them.
     """
+    if _errors_as_table(errors):
+        return op.directededges_parse_with_errors(_to_arrow_array(arr, DataType.utf8()))
     return op.directededges_parse(
         _to_arrow_array(arr, DataType.utf8()),
         set_failing_to_invalid=set_failing_to_invalid,
     )
 
 
-def compact(arr, mixed_resolutions: bool = False) -> Array:
+def compact(arr, mixed_resolutions: bool = False, assume_valid: bool = False) -> Array:
     """
     Compact the given cells
 
     The cells are expected to be of the same resolution, otherwise this operation will fail unless
     `mixed_resolutions` is set to True. Setting this may lead to slight slow-downs.
+
+    Setting `assume_valid` skips the validation of the input cells, see `assume_valid()`.
     """
-    return op.compact(_to_uint64_array(arr), mixed_resolutions=mixed_resolutions)
+    with _validation_skipped(assume_valid):
+        return op.compact(_to_uint64_array(arr), mixed_resolutions=mixed_resolutions)
 
 
-def uncompact(arr, target_resolution: int) -> Array:
+def uncompact(arr, target_resolution: int, assume_valid: bool = False) -> Array:
     """
     Uncompact the given cells to the resolution `target_resolution`.
 
     All higher resolution cells contained in the input array than the given `target_resolution` will
     be omitted from the output.
+
+    Setting `assume_valid` skips the validation of the input cells, see `assume_valid()`.
+    """
+    with _validation_skipped(assume_valid):
+        return op.uncompact(_to_uint64_array(arr), target_resolution)
+
+
+def _iter_cell_arrays(batches, column: str) -> Iterator[Array]:
+    if hasattr(batches, "__arrow_c_stream__"):
+        from arro3.core import ArrayReader
+
+        batches = ArrayReader.from_arrow(batches)
+    for batch in batches:
+        arr = _to_arrow_array(batch)
+        if DataType.is_struct(arr.type):
+            arr = RecordBatch.from_struct_array(arr)[column]
+        yield _to_uint64_array(arr)
+
+
+def compact_stream(
+    reader,
+    max_cells: int = 10_000_000,
+    spill_directory: Optional[str] = None,
+    column: str = DEFAULT_CELL_COLUMN_NAME,
+) -> Iterator[Array]:
+    """
+    Compact a stream of cells which does not need to fit into memory at once - for example the batches of a
+    parquet dataset.
+
+    The cells are kept in a hierarchy for each base cell. When more than `max_cells` cells are held in memory, the
+    hierarchies are compacted. Branches compacting to their base cell are final and emitted right away, further cells
+    within them are discarded. When compaction does not free enough memory, the largest branches are spilled to files
+    in `spill_directory` - a temporary directory by default - and merged again once the stream is exhausted. Only
+    a single branch - the cells within one base cell - needs to fit into memory at the end.
+
+    The cells may be of mixed resolutions.
+
+    :param reader: An arrow stream - for example a ``RecordBatchReader``, table or chunked array - or an iterable of
+        arrays or record batches.
+    :param max_cells: The number of cells to hold in memory before compacting and spilling.
+    :param spill_directory: The directory to spill branches to. The files are written to a subdirectory exclusive to
+        this stream, which is removed afterwards.
+    :param column: The column containing the cells when the stream consists of record batches.
+    :return: An iterator of arrays of compacted cells. Together they form the compaction of all cells of the stream.
+    """
+    if spill_directory is None:
+        import tempfile
+
+        with tempfile.TemporaryDirectory(prefix="h3ronpy-compact-") as directory:
+            yield from compact_stream(reader, max_cells=max_cells, spill_directory=directory, column=column)
+        return
+
+    compactor = op.StreamingCompactor(max_cells, spill_directory)
+    for cells in _iter_cell_arrays(reader, column):
+        finalized = compactor.push(cells)
+        if len(finalized):
+            yield finalized
+    while (branch := compactor.next_branch()) is not None:
+        yield branch
+
+
+def coverage_report(arr) -> RecordBatch:
+    """
+    Summarize the hierarchy of the cells of a coverage.
+
+    Returns a record batch with one row per resolution found in the input or in the compacted cells
+    with the columns `resolution`, `n_cells`, `area_km2` (the summed area of the input cells of that
+    resolution) and `n_cells_compacted` (the number of cells of that resolution after compacting).
+
+    The last row has a null `resolution` and holds the totals. Its `area_km2` is the area of the
+    compacted cells - overlapping and duplicate input cells are only counted once - and its
+    `compaction_ratio` is the number of compacted cells divided by the number of input cells.
+
+    Invalid/empty values are omitted.
+    """
+    return op.coverage_report(_to_uint64_array(arr))
+
+
+def cells_without(arr, exclude) -> Array:
+    """
+    Remove the area covered by the cells of `exclude` from the cells of `arr`.
+
+    Both arrays may contain cells of mixed resolutions - for example compacted cells - there is no need
+    to uncompact them first. Cells which are equal to or descendants of a cell of `exclude` are removed.
+    Cells only partially covered by finer cells of `exclude` are replaced by their children not covered by
+    `exclude`, so the output may contain cells of finer resolutions than the input.
+
+    The order of the cells is preserved. Invalid/empty values are omitted.
+    """
+    return op.cells_without(_to_uint64_array(arr), _to_uint64_array(exclude))
+
+
+def points_in_coverage(latarray, lngarray, coverage, resolution: Optional[int] = None, radians: bool = False) -> Array:
+    """
+    Test which points are located within the area covered by the cells of `coverage`.
+
+    This is a fast alternative to point-in-polygon tests for masks already expressed as cells. The
+    coverage may contain cells of mixed resolutions - for example compacted cells. The points are
+    converted to cells of `resolution` and a point is covered when its cell or one of the ancestors of
+    its cell is part of the coverage.
+
+    :param latarray: array of lat values
+    :param lngarray: array of lng values
+    :param coverage: array of the cells of the coverage
+    :param resolution: Resolution to convert the points to. Defaults to the finest resolution found in the coverage,
+        which makes the test exact in respect to the cells. Coarser resolutions ignore the finer cells of the coverage.
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    :return: boolean array. Null for null coordinates.
     """
-    return op.uncompact(_to_uint64_array(arr), target_resolution)
+    return op.points_in_coverage(
+        _to_arrow_array(latarray, DataType.float64()),
+        _to_arrow_array(lngarray, DataType.float64()),
+        _to_uint64_array(coverage),
+        resolution=resolution,
+        radians=radians,
+    )
+
+
+def coverage_similarity(a, b) -> Dict[str, Any]:
+    """
+    Compare the areas covered by the cells of `a` and `b` - for example to validate rasterization settings or
+    to compare the outputs of models.
+
+    Both coverages may contain cells of mixed resolutions, these are aligned hierarchically: a cell of one
+    coverage contributes to the intersection when it or one of its ancestors is part of the other coverage.
+    Duplicate and overlapping cells within a coverage are only counted once. Invalid/empty values are omitted.
+
+    :return: dict with the keys ``jaccard`` (the area of the intersection divided by the area of the union, None
+        when both coverages are empty), ``intersection_area_km2``, ``union_area_km2``, ``area_a_km2``, ``area_b_km2``
+        and the numbers of compacted cells ``n_cells_a``, ``n_cells_b`` and ``n_cells_intersection``.
+    """
+    return op.coverage_similarity(_to_uint64_array(a), _to_uint64_array(b))
+
+
+def cellset_canonicalize(arr) -> Array:
+    """
+    Canonicalize a coverage for `cellset_contains`: compact its cells and sort them in the canonical order, which
+    places the descendants of each cell right after it.
+
+    The cells may be of mixed resolutions. Invalid/empty values are omitted.
+    """
+    return op.cellset_canonicalize(_to_uint64_array(arr))
 
 
-def _make_h3index_valid_wrapper(fn, h3index_name, wrapper_name):
-    def valid_wrapper(arr, booleanarray: bool = False) -> Array:
+def cellset_contains(cellset, query) -> Array:
+    """
+    Test which cells of `query` are covered by `cellset` - the cell or one of its ancestors is part of it.
+
+    `cellset` must be canonical - compacted and sorted as returned by `cellset_canonicalize`, otherwise a
+    `ValueError` is raised. Each lookup is a single binary search, no hash set of the coverage is built. This makes
+    it a memory-lean alternative to `points_in_coverage` for large, read-mostly coverages which are canonicalized
+    once and stored.
+
+    :return: A boolean array aligned with `query`. Null query cells are null.
+    """
+    return op.cellset_contains(_to_uint64_array(cellset), _to_uint64_array(query))
+
+
+def _make_h3index_valid_wrapper(fn, fn_with_errors, h3index_name, wrapper_name):
+    def valid_wrapper(arr, booleanarray: bool = False, errors: Optional[str] = None):
+        if _errors_as_table(errors):
+            return fn_with_errors(_to_uint64_array(arr))
         return fn(_to_uint64_array(arr), booleanarray=booleanarray)
 
     valid_wrapper.__doc__ = f"""
@@ -187,22 +667,104 @@ def _make_h3index_valid_wrapper(fn, h3index_name, wrapper_name):
 
     If `booleanarray` is set to True, a boolean array describing the validity will be
     returned instead.
+
+    Setting `errors` to ``"table"`` returns a tuple of the validated array and a table of the
+    invalid values with the columns `row` - the position in the input -, `value` - the offending
+    value - and `reason`. This allows quarantining bad rows instead of silently dropping them.
     """
     valid_wrapper.__name__ = wrapper_name
     return valid_wrapper
 
 
-cells_valid = _make_h3index_valid_wrapper(op.cells_valid, "cell", "cells_valid")
-vertexes_valid = _make_h3index_valid_wrapper(op.cells_valid, "vertex", "vertexes_valid")
-directededges_valid = _make_h3index_valid_wrapper(op.cells_valid, "directed edge", "directededges_valid")
+cells_valid = _make_h3index_valid_wrapper(op.cells_valid, op.cells_valid_with_errors, "cell", "cells_valid")
+vertexes_valid = _make_h3index_valid_wrapper(op.cells_valid, op.vertexes_valid_with_errors, "vertex", "vertexes_valid")
+directededges_valid = _make_h3index_valid_wrapper(
+    op.cells_valid, op.directededges_valid_with_errors, "directed edge", "directededges_valid"
+)
+
+
+def grid_disk(cellarray, k: int, flatten: bool = False, assume_valid: bool = False, offset: str = "large") -> Array:
+    """
+    The cells within grid distance `k` of each cell as a list array, or as a flat array when `flatten` is set.
+
+    Setting `assume_valid` skips the validation of the input cells, see `assume_valid()`.
+
+    :param offset: The width of the offsets of the returned list array. ``"large"`` returns a `LargeList` array with
+        64-bit offsets, ``"small"`` a `List` array with 32-bit offsets for downstream systems not supporting large
+        lists - for example Spark. A `ValueError` is raised when the offsets exceed the 32-bit range.
+    """
+    with _validation_skipped(assume_valid):
+        return _with_list_offset(op.grid_disk(_to_uint64_array(cellarray), k, flatten=flatten), offset)
+
+
+def grid_disk_union(cellarray, k: int, assume_valid: bool = False) -> Array:
+    """
+    The union of the disks of all cells - equivalent to flattening the result of `grid_disk` and removing the
+    duplicates, but without materializing the disks of all cells first.
+
+    Setting `assume_valid` skips the validation of the input cells, see `assume_valid()`.
+
+    :return: The sorted cells without duplicates.
+    """
+    with _validation_skipped(assume_valid):
+        return op.grid_disk_union(_to_uint64_array(cellarray), k)
+
+
+def grid_path_cells(origins, destinations, offset: str = "large") -> Array:
+    """
+    The cells along the line from each origin to the destination at the same position - both included.
+
+    The path of a pair is null when one of the cells is null, when the cells are of different resolutions or when
+    no path can be computed - for example across pentagon distortion.
+
+    :param offset: ``"large"`` returns list arrays with 64-bit offsets, ``"small"`` with 32-bit offsets. See
+        `grid_disk`.
+    :return: A list array aligned with the inputs.
+    """
+    return _with_list_offset(op.grid_path_cells(_to_uint64_array(origins), _to_uint64_array(destinations)), offset)
+
+
+def grid_distance(cellarray_a, cellarray_b) -> Array:
+    """
+    The grid distance between the cells at the same positions of both arrays.
+
+    The distance of a pair is null when one of the cells is null, when the cells are of different resolutions or when
+    the distance is undefined - for example across pentagon distortion.
+
+    :return: A ``uint32`` array aligned with the inputs.
+    """
+    return op.grid_distance(_to_uint64_array(cellarray_a), _to_uint64_array(cellarray_b))
+
+
+def grid_disk_distances(cellarray, k: int, flatten: bool = False, offset: str = "large") -> RecordBatch:
+    """
+    Returns a table with the columns `cell` and `k`.
+
+    :param offset: ``"large"`` returns list arrays with 64-bit offsets, ``"small"`` with 32-bit offsets. See
+        `grid_disk`.
+    """
+    return _with_list_offset(op.grid_disk_distances(_to_uint64_array(cellarray), k, flatten=flatten), offset)
 
 
-def grid_disk(cellarray, k: int, flatten: bool = False) -> Array:
-    return op.grid_disk(_to_uint64_array(cellarray), k, flatten=flatten)
+def grid_disk_sector(
+    cellarray, k: int, azimuth_min: float, azimuth_max: float, flatten: bool = False, offset: str = "large"
+) -> RecordBatch:
+    """
+    Cells within grid distance `k` whose azimuth - the initial bearing of the great circle from the center of the
+    origin cell to the center of the cell - lies within the sector from `azimuth_min` to `azimuth_max`.
+
+    Azimuths are given in degrees clockwise from north. Sectors with `azimuth_min` > `azimuth_max` wrap around
+    north, so ``grid_disk_sector(cells, k, 315.0, 45.0)`` returns the northern quarter. The origin cell itself is
+    always included.
 
+    Returns a table with the columns `cell` and `k` shaped like the output of `grid_disk_distances`.
 
-def grid_disk_distances(cellarray, k: int, flatten: bool = False) -> RecordBatch:
-    return op.grid_disk_distances(_to_uint64_array(cellarray), k, flatten=flatten)
+    :param offset: ``"large"`` returns list arrays with 64-bit offsets, ``"small"`` with 32-bit offsets. See
+        `grid_disk`.
+    """
+    return _with_list_offset(
+        op.grid_disk_sector(_to_uint64_array(cellarray), k, azimuth_min, azimuth_max, flatten=flatten), offset
+    )
 
 
 def grid_disk_aggregate_k(cellarray, k: int, aggregation_method: str) -> RecordBatch:
@@ -212,32 +774,488 @@ def grid_disk_aggregate_k(cellarray, k: int, aggregation_method: str) -> RecordB
     return op.grid_disk_aggregate_k(_to_uint64_array(cellarray), k, aggregation_method)
 
 
-def grid_ring_distances(cellarray, k_min: int, k_max: int, flatten: bool = False) -> RecordBatch:
-    return op.grid_ring_distances(_to_uint64_array(cellarray), k_min, k_max, flatten=flatten)
+def grid_ring_distances(cellarray, k_min: int, k_max: int, flatten: bool = False, offset: str = "large") -> RecordBatch:
+    """
+    Returns a table with the columns `cell` and `k`.
+
+    :param offset: ``"large"`` returns list arrays with 64-bit offsets, ``"small"`` with 32-bit offsets. See
+        `grid_disk`.
+    """
+    return _with_list_offset(op.grid_ring_distances(_to_uint64_array(cellarray), k_min, k_max, flatten=flatten), offset)
+
 
+def grid_voronoi(seeds, extent) -> RecordBatch:
+    """
+    Assign each cell of the extent to its nearest seed cell. This is a pure-grid alternative to
+    geometric voronoi diagrams - for example for service area analysis.
 
+    The distance is measured in grid steps between neighboring cells while only passing cells of the
+    extent. When a cell is equally far from multiple seeds, the seed with the lowest index value wins.
+
+    :param seeds: The seed cells. All seeds must have the same resolution.
+    :param extent: Either an array of cells of the resolution of the seeds, or a bounding box
+        as a tuple `(minx, miny, maxx, maxy)` in degrees which is covered with cells.
+    :return: Table/dataframe with the columns `cell`, `seed` and `k`. Cells which can not be reached from
+        any seed are omitted.
+    """
+    if not isinstance(extent, tuple):
+        extent = _to_uint64_array(extent)
+    return op.grid_voronoi(_to_uint64_array(seeds), extent)
+
+
+def grid_distance_transform(extent_cells, feature_cells, max_k: int) -> Array:
+    """
+    Compute the grid distance of each cell of the extent to the nearest feature cell - the hex-grid analogue of
+    a raster distance transform.
+
+    The distances are computed using a multi-source breadth-first search starting from all features at once. In
+    contrast to `grid_voronoi` the search is not limited to the cells of the extent.
+
+    :param extent_cells: The cells to compute the distances for.
+    :param feature_cells: The feature cells. All cells must be of the same resolution.
+    :param max_k: Maximum grid distance to search for features.
+    :return: ``UInt32`` array of distances aligned with `extent_cells`. Cells without a feature within `max_k`
+        are null.
+    """
+    return op.grid_distance_transform(_to_uint64_array(extent_cells), _to_uint64_array(feature_cells), max_k)
+
+
+def kde(
+    cell_counts,
+    bandwidth_m: float,
+    kernel: str = "gaussian",
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    count_column: str = "count",
+) -> RecordBatch:
+    """
+    Kernel density estimation on the grid - the hexagonal alternative to a raster KDE.
+
+    The count of each cell is spread over the rings around it, weighted by a kernel of the geodesic distance between
+    the cell centers. Supported kernels:
+
+    * ``gaussian``: Normal distribution with `bandwidth_m` as standard deviation, truncated at three bandwidths.
+    * ``epanechnikov``: Parabolic kernel reaching zero at `bandwidth_m`.
+
+    The number of rings to search grows with the ratio of the bandwidth to the edge length of the cells, so the
+    bandwidth should be chosen in proportion to the resolution.
+
+    :param cell_counts: Table/dataframe with the cells and their counts - for example the output of
+        ``heatmap_from_geoparquet``. All cells must be of the same resolution.
+    :param bandwidth_m: The bandwidth of the kernel in meters.
+    :param kernel: The kernel to use. See above.
+    :param cell_column: Name of the column containing the cells.
+    :param count_column: Name of the column containing the counts. Null counts are skipped.
+    :return: Record batch with the columns `cell` and `density` - the density in counts per square kilometer - for all
+        cells within the reach of the kernel around the cells with a non-zero count.
+    """
+    return op.kde(
+        _to_uint64_array(cell_counts[cell_column]),
+        _to_arrow_array(cell_counts[count_column], DataType.float64()),
+        bandwidth_m,
+        kernel=kernel,
+    )
+
+
+def spatial_outliers(
+    cellarray, values, k: int, method: str = "zscore", threshold: Optional[float] = None
+) -> RecordBatch:
+    """
+    Flag cells whose value deviates strongly from the values of their neighborhood - a common quality check after
+    gridding sensor data.
+
+    Each cell is compared to the values of the cells within grid distance `k`, excluding the cell itself. Supported
+    methods:
+
+    * ``zscore``: The deviation from the mean of the neighborhood in standard deviations. The default `threshold`
+      is 3.
+    * ``iqr``: The distance beyond the quartiles of the neighborhood in interquartile ranges - Tukey's fences. The
+      default `threshold` is 1.5.
+
+    :param cellarray: The cells.
+    :param values: The values of the cells. Null and NaN values are skipped.
+    :param k: The grid distance defining the neighborhood.
+    :param method: The method to score the deviation. See above.
+    :param threshold: Cells with an absolute score above this threshold are flagged as outliers.
+    :return: Record batch aligned with `cellarray` with the columns ``score`` - signed, negative for values below the
+        neighborhood - and ``outlier``. Cells without a value or with less than two neighbors having a value are null.
+    """
+    if threshold is None:
+        threshold = 1.5 if method == "iqr" else 3.0
+    return op.spatial_outliers(
+        _to_uint64_array(cellarray),
+        _to_arrow_array(values, DataType.float64()),
+        k,
+        method,
+        threshold,
+    )
+
+
+def region_adjacency(cell_table, label_col: str, cell_column: str = DEFAULT_CELL_COLUMN_NAME):
+    """
+    Build the adjacency graph of the regions formed by the cells sharing the same label - for example as input for
+    community detection or regionalization with a graph library.
+
+    The nodes of the graph are the labels. Every pair of neighboring cells with different labels contributes to the
+    edge between both labels, so the count of an edge is a measure of the length of the shared boundary.
+
+    Requires ``pyarrow`` to be installed.
+
+    :param cell_table: Table/dataframe with the cells and their labels. All cells must be of the same resolution.
+    :param label_col: Name of the column containing the labels. Cells without a label are skipped.
+    :param cell_column: Name of the column containing the cells.
+    :return: ``pyarrow.Table`` edge list with the columns ``source``, ``target`` - the labels - and ``count``, the
+        number of pairs of neighboring cells between both regions. Each edge is included once.
+    """
+    import pyarrow as pa
+
+    table = pa.table(cell_table)
+    labels = table[label_col].combine_chunks()
+    if not pa.types.is_dictionary(labels.type):
+        labels = labels.dictionary_encode()
+    edges = pa.table(op.region_adjacency(_to_uint64_array(table[cell_column]), labels.indices.cast(pa.uint32())))
+    return pa.table(
+        {
+            "source": labels.dictionary.take(edges["label_a"].combine_chunks()),
+            "target": labels.dictionary.take(edges["label_b"].combine_chunks()),
+            "count": edges["count"],
+        }
+    )
+
+
+def regionalize(
+    cell_table,
+    weight_col: str,
+    n_regions: int,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    region_column: str = "region",
+):
+    """
+    Partition the cells into `n_regions` contiguous regions with approximately the same total weight - for example
+    to design sales territories or service areas.
+
+    The regions are grown one after another from the periphery of the remaining cells, adding cells in breadth-first
+    order until the region reaches its share of the total weight. Pockets of cells cut off by a region are merged
+    into their lightest neighboring region. This greedy heuristic keeps all regions contiguous, but does not
+    guarantee an optimal balance.
+
+    Each connected part of the cells receives at least one region, so `n_regions` must not be less than the number
+    of connected parts. The remaining regions are distributed among the parts by their weight.
+
+    Requires ``pyarrow`` to be installed.
+
+    :param cell_table: Table/dataframe with the cells and their weights. All cells must be of the same resolution.
+    :param weight_col: Name of the column containing the weights. Null and negative weights count as zero.
+    :param n_regions: Number of regions to create.
+    :param cell_column: Name of the column containing the cells.
+    :param region_column: Name of the column to add.
+    :return: ``pyarrow.Table`` with the ``uint32`` region number of each row appended as `region_column`.
+    """
+    import pyarrow as pa
+
+    table = pa.table(cell_table)
+    regions = op.regionalize(
+        _to_uint64_array(table[cell_column]),
+        _to_arrow_array(table[weight_col], DataType.float64()),
+        n_regions,
+    )
+    return table.append_column(region_column, pa.array(regions))
+
+
+def cells_skeleton(cellarray) -> Array:
+    """
+    Approximate the medial axis of each connected cluster of cells by iterative morphological thinning - for
+    example to derive centerlines from rasterized corridors.
+
+    Cells at the boundary of the clusters are removed as long as this neither splits a cluster nor changes its
+    holes, and as long as they are not the end of a line. The result is a thin, connected subset of each cluster.
+
+    :param cellarray: The cells. All cells must be of the same resolution.
+    :return: The sorted cells of the skeleton.
+    """
+    return op.cells_skeleton(_to_uint64_array(cellarray))
+
+
+def cells_expand_contract(cellarray, grow_k: int, shrink_k: int) -> Array:
+    """
+    Dilate the cells by `grow_k` rings and erode the result by `shrink_k` rings - a morphological closing when both
+    are equal, which fills gaps and small holes in a coverage without changing its resolution.
+
+    Both steps are computed in a single pass, so the ``grid_disk`` of all cells - which may be huge for larger
+    `grow_k` - is never materialized.
+
+    :param cellarray: The cells. All cells must be of the same resolution.
+    :param grow_k: Number of rings to dilate by.
+    :param shrink_k: Number of rings to erode the dilated cells by.
+    :return: The sorted cells without duplicates.
+    """
+    return op.cells_expand_contract(_to_uint64_array(cellarray), grow_k, shrink_k)
+
+
+def coverage_boundary_cells(cellarray, outside: bool = False) -> Array:
+    """
+    Find the cells at the boundary of a coverage - for example to correct edge effects of neighborhood
+    statistics or to buffer a coverage by a single ring.
+
+    :param cellarray: The cells. All cells must be of the same resolution.
+    :param outside: Return the ring of cells surrounding the coverage instead of the cells of the coverage
+        having at least one neighbor outside of it.
+    :return: The sorted cells without duplicates.
+    """
+    return op.coverage_boundary_cells(_to_uint64_array(cellarray), outside=outside)
+
+
+def simplify_cell_path(patharray, tolerance_cells: float) -> Array:
+    """
+    Thin out a path of cells - for example the cells of a trajectory - to reduce the storage required for it
+    while preserving its shape.
+
+    The Ramer-Douglas-Peucker algorithm is applied to the great circles between the centroids of the cells: a
+    cell is dropped when it is closer than `tolerance_cells` cells to the great circle arc between the retained
+    cells before and after it, and ``grid_path_cells`` is able to connect these retained cells. Connecting the
+    returned cells using ``grid_path_cells`` restores a path deviating roughly at most `tolerance_cells` cells
+    from the input - connected when the input was connected. The first and the last cell are always retained.
+
+    :param patharray: The cells of the path in order. All cells must be of the same resolution.
+    :param tolerance_cells: The tolerance in multiples of the distance between neighboring cells.
+    :return: The retained cells in order. Consecutive duplicates and invalid/empty values are removed.
+    """
+    return op.simplify_cell_path(_to_uint64_array(patharray), tolerance_cells)
+
+
+def catchments(
+    cost_table,
+    facilities,
+    max_cost: Optional[float] = None,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    cost_column: str = "cost",
+) -> RecordBatch:
+    """
+    Compute the catchment areas of facilities on a cost surface.
+
+    Each cell of the cost surface which is reachable from a facility is assigned to the facility it can be reached
+    from with the lowest accumulated cost. Moving between neighboring cells costs the mean of the costs
+    of both cells. Cells with a null cost are impassable. Ties are broken by preferring the facility with the lowest
+    index value.
+
+    :param cost_table: Table/dataframe defining the cost surface. Only cells contained in this table can be traversed.
+    :param facilities: The facility cells.
+    :param max_cost: Omit cells with an accumulated cost above this value.
+    :param cell_column: Name of the column containing the cells in `cost_table`.
+    :param cost_column: Name of the column containing the non-negative costs in `cost_table`.
+    :return: Table/dataframe with the columns `cell`, `facility` and `cost`.
+    """
+    return op.catchments(
+        _to_uint64_array(cost_table[cell_column]),
+        _to_arrow_array(cost_table[cost_column], DataType.float64()),
+        _to_uint64_array(facilities),
+        max_cost=max_cost,
+    )
+
+
+def viewshed(
+    elevation_table,
+    observers,
+    max_k: int,
+    observer_height: float = 0.0,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    elevation_column: str = "elevation",
+) -> RecordBatch:
+    """
+    Compute the cells visible from each of the observer cells on an elevation surface.
+
+    The eye of an observer is located `observer_height` above the elevation of its cell. A cell within grid
+    distance `max_k` of the observer is visible when no cell on the line of cells between both - as returned
+    by H3s ``gridPathCells`` - rises above the line of sight to the center of the cell. The curvature of the earth
+    is not taken into account.
+
+    Cells without elevation are neither visible nor do they block the view. Observers without elevation are skipped.
+
+    :param elevation_table: Table/dataframe defining the elevation surface.
+    :param observers: The observer cells.
+    :param max_k: Maximum grid distance of visible cells from the observer.
+    :param observer_height: Height of the eye of the observer above the elevation of the observer cell.
+    :param cell_column: Name of the column containing the cells in `elevation_table`.
+    :param elevation_column: Name of the column containing the elevations in `elevation_table`.
+    :return: Table/dataframe with the columns `observer`, `cell` and `k`.
+    """
+    return op.viewshed(
+        _to_uint64_array(elevation_table[cell_column]),
+        _to_arrow_array(elevation_table[elevation_column], DataType.float64()),
+        _to_uint64_array(observers),
+        max_k,
+        observer_height=observer_height,
+    )
+
+
+def ring_window(
+    table,
+    function: str,
+    origin_column: str = "origin",
+    k_column: str = "k",
+    value_column: str = "value",
+) -> Array:
+    """
+    Window functions over rows of (origin cell, ring k, value) - for example the output of `grid_disk_distances`
+    joined with values of the neighboring cells.
+
+    The rows are partitioned by their origin cell. Supported functions:
+
+    * ``cumsum``, ``cummean``, ``cummin``, ``cummax``: Cumulative aggregate of the values by increasing k. Each row
+      receives the aggregate of all values of its origin with a k less than or equal to its own k.
+    * ``rank``: Rank of the value within its origin in descending order. Equal values share the same rank.
+
+    Null values are skipped. Rows with a null origin or k are null in the output.
+
+    :param table: Table/dataframe with the rows.
+    :param function: The window function to apply. See above.
+    :param origin_column: Name of the column containing the origin cells.
+    :param k_column: Name of the column containing the ring k.
+    :param value_column: Name of the column containing the values.
+    :return: Array aligned with the rows of `table`. ``Float64`` for the cumulative functions, ``UInt32`` for ``rank``.
+    """
+    return op.ring_window(
+        _to_uint64_array(table[origin_column]),
+        _to_arrow_array(table[k_column], DataType.uint32()),
+        _to_arrow_array(table[value_column], DataType.float64()),
+        function,
+    )
+
+
+def ring_pivot(
+    table,
+    aggregation: str = "sum",
+    origin_column: str = "origin",
+    k_column: str = "k",
+    value_column: str = "value",
+    prefix: Optional[str] = None,
+) -> RecordBatch:
+    """
+    Pivot rows of (origin cell, ring k, value) to a wide table with one row per origin and one column per k - for
+    example to directly produce feature matrices for machine learning from neighborhood aggregations.
+
+    The values of rows sharing the same origin and k are aggregated using `aggregation` - one of ``sum``,
+    ``mean``, ``min``, ``max`` and ``count``. Null values are skipped, origins without any value in a ring are null
+    in the column of that ring (zero for ``count``). The origins are ordered by their first occurrence.
+
+    :param table: Table/dataframe with the rows.
+    :param aggregation: The aggregation to apply to the values of an origin and ring.
+    :param origin_column: Name of the column containing the origin cells. Also used for the output.
+    :param k_column: Name of the column containing the ring k.
+    :param value_column: Name of the column containing the values.
+    :param prefix: Prefix of the names of the ring columns, which are suffixed with k. Defaults to ``<value_column>_k``,
+        resulting in ``value_k0``, ``value_k1``, ...
+    :return: Record batch with the origin column and one ``Float64`` column per k found, ordered by k.
+    """
+    if prefix is None:
+        prefix = f"{value_column}_k"
+    return op.ring_pivot(
+        _to_uint64_array(table[origin_column]),
+        _to_arrow_array(table[k_column], DataType.uint32()),
+        _to_arrow_array(table[value_column], DataType.float64()),
+        aggregation,
+        origin_column,
+        prefix,
+    )
+
+
+@_table_column_op
 def cells_area_m2(cellarray) -> Array:
     return op.cells_area_m2(_to_uint64_array(cellarray))
 
 
+@_table_column_op
 def cells_area_km2(cellarray) -> Array:
     return op.cells_area_km2(_to_uint64_array(cellarray))
 
 
+@_table_column_op
 def cells_area_rads2(cellarray) -> Array:
     return op.cells_area_rads2(_to_uint64_array(cellarray))
 
 
-def cells_to_string(cellarray) -> Array:
-    return op.cells_to_string(_to_uint64_array(cellarray))
+def cells_faces(cellarray) -> Array:
+    """
+    The ids (0-19) of the icosahedron faces intersected by each cell.
 
+    Returns a list array with one list per cell. Invalid/empty cells result in null values.
+    """
+    return op.cells_faces(_to_uint64_array(cellarray))
 
-def vertexes_to_string(vertexesarray) -> Array:
-    return op.vertexes_to_string(_to_uint64_array(vertexesarray))
 
+def cells_vertex_angles(cellarray) -> RecordBatch:
+    """
+    The orientation of the cells, for example to rotate symbols or to draw hexagons without
+    extracting the boundary geometries.
 
-def directededges_to_string(directededgearray) -> Array:
-    return op.directededges_to_string(_to_uint64_array(directededgearray))
+    Returns a record batch with one row per cell and the columns `azimuths` - a list of the azimuths
+    from the cell center to its vertexes in degrees clockwise from north, following the order of the
+    cell boundary - and `is_pentagon`. Pentagons have five vertexes, hexagons six. Distortion vertexes
+    of cells crossing an icosahedron edge are not included.
+
+    Invalid/empty cells result in null values.
+    """
+    return op.cells_vertex_angles(_to_uint64_array(cellarray))
+
+
+@_table_column_op
+def cells_to_string(
+    cellarray, assume_valid: bool = False, uppercase: bool = False, short: bool = False, zero_pad: bool = False
+) -> Array:
+    """
+    Convert the cells to their hexadecimal string representation.
+
+    :param uppercase: Use uppercase hexadecimal digits.
+    :param short: Strip the trailing ``f`` digits of the unused resolution digits. The parsing functions
+        accept these shortened forms.
+    :param zero_pad: Pad the strings with leading zeros to a fixed width of 16 characters.
+
+    Setting `assume_valid` skips the validation of the input cells, see `assume_valid()`.
+    """
+    with _validation_skipped(assume_valid):
+        return op.cells_to_string(_to_uint64_array(cellarray), uppercase=uppercase, short=short, zero_pad=zero_pad)
+
+
+def vertexes_to_string(vertexesarray, uppercase: bool = False, short: bool = False, zero_pad: bool = False) -> Array:
+    """
+    Convert the vertexes to their hexadecimal string representation. See `cells_to_string` for the options.
+    """
+    return op.vertexes_to_string(_to_uint64_array(vertexesarray), uppercase=uppercase, short=short, zero_pad=zero_pad)
+
+
+def directededges_to_string(
+    directededgearray, uppercase: bool = False, short: bool = False, zero_pad: bool = False
+) -> Array:
+    """
+    Convert the directed edges to their hexadecimal string representation. See `cells_to_string` for the
+    options.
+    """
+    return op.directededges_to_string(
+        _to_uint64_array(directededgearray), uppercase=uppercase, short=short, zero_pad=zero_pad
+    )
+
+
+def directededges_to_undirected_key(directededgearray) -> Array:
+    """
+    A canonical key for each directed edge which is identical for both directions of the edge - for example to
+    aggregate the flows in both directions between neighboring cells using a single group-by.
+
+    The key is the one of the two directed edges with the smaller index value, so it is a valid directed edge
+    itself. Null for null edges.
+    """
+    return op.directededges_to_undirected_key(_to_uint64_array(directededgearray))
+
+
+def _localij_anchor(anchor):
+    # single cells may also be given as numpy integers or arrow scalars, which would otherwise be
+    # taken as arrays of length one
+    if isinstance(anchor, numbers.Integral):
+        return int(anchor)
+    if hasattr(anchor, "as_py") and not hasattr(anchor, "__len__"):
+        value = anchor.as_py()
+        if value is None:
+            raise ValueError("the anchor cell must not be null")
+        return int(value)
+    return _to_uint64_array(anchor)
 
 
 def cells_to_localij(cellarray, anchor, set_failing_to_invalid: bool = False) -> RecordBatch:
@@ -256,8 +1274,7 @@ def cells_to_localij(cellarray, anchor, set_failing_to_invalid: bool = False) ->
     successfully. When `set_failing_to_invalid` is set to True, only the failing positions
     of the output arrays will be set to null.
     """
-    if type(anchor) is not int:
-        anchor = _to_uint64_array(anchor)
+    anchor = _localij_anchor(anchor)
     return op.cells_to_localij(
         _to_uint64_array(cellarray),
         anchor,
@@ -269,12 +1286,14 @@ def localij_to_cells(anchor, i, j, set_failing_to_invalid: bool = False) -> Arra
     """
     Produces cells from `i` and `j` coordinates and an `anchor` cell.
 
+    The parameter `anchor` can be a single cell or an array of cells which serve as anchor for the
+    coordinates at the same positions of `i` and `j`.
+
     The default behavior is for this function to fail when a single transformation can not be completed
     successfully. When `set_failing_to_invalid` is set to True, only the failing positions
     of the output arrays will be set to null.
     """
-    if type(anchor) is not int:
-        anchor = _to_uint64_array(anchor)
+    anchor = _localij_anchor(anchor)
     return op.localij_to_cells(
         anchor,
         _to_arrow_array(i, DataType.int32()),
@@ -283,33 +1302,495 @@ def localij_to_cells(anchor, i, j, set_failing_to_invalid: bool = False) -> Arra
     )
 
 
+def cells_encode_payload(cellarray, payloads) -> Array:
+    """
+    Packs small integer payloads - for example category ids - into the unused bits of the cells.
+
+    A cell of resolution ``r`` provides ``3 * (15 - r)`` bits for the payload. Encoding fails when
+    a payload does not fit into the bits available in its cell. Positions where either the cell or the
+    payload is null are null in the output.
+
+    The returned values are not valid cells anymore. Use :py:func:`cells_decode_payload` to
+    restore the cells and the payloads.
+    """
+    return op.cells_encode_payload(_to_uint64_array(cellarray), _to_arrow_array(payloads, DataType.uint64()))
+
+
+def cells_decode_payload(arr) -> RecordBatch:
+    """
+    Splits values created by :py:func:`cells_encode_payload` into the columns ``cell`` and ``payload``.
+
+    Fails when a value does not decode to a valid cell.
+    """
+    return op.cells_decode_payload(_to_arrow_array(arr, DataType.uint64()))
+
+
+def apply_per_cell(cellarray, fn, output_type: ArrowSchemaExportable, batch_size: int = 65536) -> Array:
+    """
+    Apply a python function to the cells in batches - an extension point for operations not shipped with
+    h3ronpy.
+
+    `fn` is called with ``uint64`` numpy arrays of up to `batch_size` cells and must return an array of the same
+    length - a numpy array, a list or any arrow array. The returned arrays are cast to `output_type` and
+    assembled into a single array. As `fn` is called once per batch instead of once per cell, the overhead of
+    calling into python is small.
+
+    Null cells are not passed to `fn` and are null in the output.
+
+    :param cellarray: The cells.
+    :param fn: The function to apply.
+    :param output_type: The data type of the output, for example ``arro3.core.DataType.float64()``.
+    :param batch_size: The maximum number of cells passed to `fn` at once.
+    """
+
+    def call(cells):
+        return _to_arrow_array(fn(cells), output_type)
+
+    return op.apply_per_cell(_to_uint64_array(cellarray), call, output_type, batch_size=batch_size)
+
+
+@_table_column_op
+def cells_to_int64(cellarray) -> Array:
+    """
+    Reinterpret the bits of the cells as signed 64bit integers - the representation of H3 cells used by Spark
+    and some databases. In contrast to a cast the values survive the round trip via `int64_to_cells` exactly.
+    """
+    return op.cells_to_int64(_to_uint64_array(cellarray))
+
+
+@_table_column_op
+def int64_to_cells(arr, set_failing_to_invalid: bool = False) -> Array:
+    """
+    Reinterpret the bits of signed 64bit integers as cells. This is the inverse of `cells_to_int64`.
+
+    The default behavior is for this function to fail when encountering a value which is not a valid cell. When
+    `set_failing_to_invalid` is set to True, such values are set to null instead.
+    """
+    return op.int64_to_cells(_to_arrow_array(arr, DataType.int64()), set_failing_to_invalid=set_failing_to_invalid)
+
+
+@_table_column_op
+def cells_hash(cellarray, algorithm: str = "xxhash64", seed: int = 0) -> Array:
+    """
+    Hash the cells using a stable, non-cryptographic hash function - for example to shard or bucket data
+    deterministically across systems using ``hash % n_buckets``.
+
+    The hashed input are the 8 bytes of the cell index in little-endian byte order, so the hashes can be reproduced
+    by other implementations of the algorithms. Null cells result in null hashes.
+
+    Algorithms:
+
+    * "xxhash64": XXH64, returns ``uint64`` hashes.
+    * "xxhash32": XXH32, returns ``uint32`` hashes. The seed must fit into 32 bits.
+    * "xxh3": 64bit XXH3, returns ``uint64`` hashes.
+
+    :param cellarray: The cell array
+    :param algorithm: The hash algorithm, see above.
+    :param seed: Seed of the hash function.
+    """
+    return op.cells_hash(_to_uint64_array(cellarray), algorithm=algorithm, seed=seed)
+
+
+_H3_KINDS = (
+    ("cell", op.cells_valid, op.cells_parse),
+    ("directededge", op.directededges_valid, op.directededges_parse),
+    ("vertex", op.vertexes_valid, op.vertexes_parse),
+)
+
+
+def detect_h3_columns(table, sample_size: int = 1000, min_confidence: float = 0.5) -> List[Dict[str, Any]]:
+    """
+    Detect columns likely containing H3 cells, directed edges or vertexes - for example when ingesting data
+    of unknown origin.
+
+    Evenly spaced rows of each column are sampled and scored by the fraction of the non-null values being valid
+    H3 indexes of a kind. Integer columns are checked as 64bit H3 indexes - signed integers are reinterpreted
+    bit-wise -, string columns as hexadecimal H3 indexes.
+
+    Requires ``pyarrow`` to be installed.
+
+    :param table: Table/dataframe to inspect.
+    :param sample_size: Maximum number of rows to sample from each column.
+    :param min_confidence: Minimum fraction of valid values for a column to be reported.
+    :return: List of candidates sorted by descending confidence. Each candidate is a dict with the keys
+        ``column``, ``kind`` (``cell``, ``directededge`` or ``vertex``), ``encoding`` (``uint64`` or ``string``)
+        and ``confidence``.
+    """
+    import pyarrow as pa
+    import pyarrow.compute as pc
+
+    table = pa.table(table)
+    if table.num_rows > sample_size:
+        step = table.num_rows / sample_size
+        table = table.take([int(i * step) for i in range(sample_size)])
+
+    candidates = []
+    for name in table.column_names:
+        column = table[name].combine_chunks()
+        if pa.types.is_dictionary(column.type):
+            column = column.dictionary_decode()
+        column = column.drop_null()
+        if len(column) == 0:
+            continue
+
+        if pa.types.is_integer(column.type) and column.type.bit_width == 64:
+            encoding = "uint64"
+            values = column.view(pa.uint64()) if pa.types.is_signed_integer(column.type) else column
+        elif pa.types.is_string(column.type) or pa.types.is_large_string(column.type):
+            encoding = "string"
+            # only hexadecimal strings count, the parser also accepts other representations.
+            values = column.filter(pc.match_substring_regex(column, r"^\s*[0-9a-fA-F]{15,16}\s*$"))
+            values = pc.utf8_trim_whitespace(values.cast(pa.large_utf8()))
+        else:
+            continue
+
+        for kind, valid_fn, parse_fn in _H3_KINDS:
+            if len(values) == 0:
+                break
+            if encoding == "uint64":
+                valid = pa.array(valid_fn(_to_uint64_array(values), booleanarray=True))
+            else:
+                parsed = parse_fn(_to_arrow_array(values, DataType.large_utf8()), set_failing_to_invalid=True)
+                valid = pa.array(parsed).is_valid()
+            confidence = pc.sum(valid.cast(pa.uint64())).as_py() / len(column)
+            if confidence >= min_confidence:
+                candidates.append({"column": name, "kind": kind, "encoding": encoding, "confidence": confidence})
+
+    return sorted(candidates, key=lambda candidate: candidate["confidence"], reverse=True)
+
+
+def deduplicate_table(table, keys: Optional[Sequence[str]] = None, keep: str = "first"):
+    """
+    Remove rows with duplicate keys from a table/dataframe - for example to make repeated ingestion runs
+    idempotent before appending their results.
+
+    Null values are considered equal to each other. The order of the remaining rows is preserved. A single
+    ``uint64`` key column - the cells - is hashed directly without any conversion.
+
+    Requires ``pyarrow`` to be installed.
+
+    :param table: Table/dataframe to deduplicate.
+    :param keys: Names of the columns forming the key. Defaults to the `cell` column.
+    :param keep: Which of the duplicate rows to keep - ``first`` or ``last``.
+    :return: ``pyarrow.Table``
+    """
+    import pyarrow as pa
+
+    if keep not in ("first", "last"):
+        raise ValueError(f"unsupported value for keep: {keep!r}, expected 'first' or 'last'")
+    if keys is None:
+        keys = [DEFAULT_CELL_COLUMN_NAME]
+
+    table = pa.table(table)
+    if table.num_rows == 0:
+        return table
+    indexes = op.unique_row_indices([table[key].combine_chunks() for key in keys], keep_last=keep == "last")
+    return table.take(pa.array(indexes))
+
+
+def merge_cell_tables(a, b, on: Union[str, Sequence[str]] = DEFAULT_CELL_COLUMN_NAME, conflict: str = "sum"):
+    """
+    Merge two cell-keyed tables/dataframes - for example to fold the results of an incremental run into an
+    existing dataset.
+
+    The rows are joined on the key columns in a single hash join. Rows found in only one of the tables are kept
+    as they are, rows found in both tables are merged column by column according to `conflict`:
+
+    * ``sum``: Numeric columns are summed up - null values count as missing. All other columns take the values of `b`.
+    * ``prefer_b``: All columns take the values of `b`.
+    * ``error``: Raise a ``ValueError``.
+
+    The keys must be unique within each table. The columns of `b` are cast to the types of the columns of `a`.
+    Columns only found in one of the tables are null for the rows of the other table.
+
+    Requires ``pyarrow`` to be installed.
+
+    :param a: The first table - for example the existing dataset.
+    :param b: The second table - for example the new increment.
+    :param on: Name or names of the key columns. Defaults to the `cell` column.
+    :param conflict: The rule to resolve the rows found in both tables - ``sum``, ``prefer_b`` or ``error``.
+    :return: ``pyarrow.Table`` with the rows of `a` in their order followed by the rows only found in `b`.
+    """
+    import pyarrow as pa
+    import pyarrow.compute as pc
+
+    if conflict not in ("sum", "prefer_b", "error"):
+        raise ValueError(f"unsupported value for conflict: {conflict!r}, expected 'sum', 'prefer_b' or 'error'")
+    keys = [on] if isinstance(on, str) else list(on)
+
+    a = pa.table(a)
+    b = pa.table(b)
+    indexes = pa.record_batch(
+        op.merge_row_indices(
+            [a[key].combine_chunks() for key in keys],
+            [b[key].combine_chunks().cast(a.schema.field(key).type) for key in keys],
+        )
+    )
+    in_b = indexes["b"].is_valid()
+    if conflict == "error":
+        conflicts = pc.sum(pc.and_(indexes["a"].is_valid(), in_b).cast(pa.uint64())).as_py()
+        if conflicts:
+            raise ValueError(f"{conflicts} keys are found in both tables")
+
+    a_rows = a.take(indexes["a"])
+    b_rows = b.take(indexes["b"])
+    names = a.column_names + [name for name in b.column_names if name not in a.column_names]
+    columns = []
+    for name in names:
+        if name not in b.column_names:
+            columns.append(a_rows[name].combine_chunks())
+            continue
+        b_column = b_rows[name].combine_chunks()
+        if name not in a.column_names:
+            columns.append(b_column)
+            continue
+        field_type = a.schema.field(name).type
+        a_column = a_rows[name].combine_chunks()
+        b_column = b_column.cast(field_type)
+        if name in keys:
+            columns.append(pc.coalesce(a_column, b_column))
+        elif conflict == "sum" and (pa.types.is_integer(field_type) or pa.types.is_floating(field_type)):
+            columns.append(pc.coalesce(pc.add(a_column, b_column), a_column, b_column))
+        else:
+            columns.append(pc.if_else(in_b, b_column, a_column))
+    return pa.table(columns, names=names)
+
+
+def aggregate_by(
+    table,
+    cell_resolution: int,
+    keys: Optional[List[str]] = None,
+    aggs: Optional[Dict[str, Union[str, List[str]]]] = None,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+):
+    """
+    Group the rows of a table by the parent cells at `cell_resolution` and further key columns, and aggregate
+    value columns per group.
+
+    The parent cells are derived while grouping, so there is no need to change the resolution of the cells first.
+
+    Example: ``aggregate_by(table, 7, keys=["category"], aggs={"population": ["sum", "mean"]})``.
+
+    :param table: Table/dataframe with the cells and the columns to group by and aggregate.
+    :param cell_resolution: Resolution of the parent cells to group by. The cells must not be of a coarser resolution.
+    :param keys: Further columns to group by.
+    :param aggs: Mapping of the names of numeric columns to one or a list of the aggregations
+        ``count`` (the number of non-null values), ``sum``, ``mean``, ``min`` and ``max``.
+    :param cell_column: Name of the column containing the cells.
+    :return: ``pyarrow.Table`` with one row per group and the columns `cell` (the parent cell), the key columns,
+        ``count`` (the number of rows of the group) and one column named ``<column>_<aggregation>`` per
+        aggregation. Aggregations of float32 columns are float32, all others are float64. Rows with null cells are
+        skipped, null values are not included in the aggregations. The groups are ordered by their first occurrence.
+    """
+    import pyarrow as pa
+
+    table = pa.table(table)
+    keys = list(keys or [])
+    value_columns = []
+    agg_specs = []
+    names = [cell_column] + keys + ["count"]
+    for column, column_aggs in (aggs or {}).items():
+        if isinstance(column_aggs, str):
+            column_aggs = [column_aggs]
+        value_columns.append(column)
+        for agg in column_aggs:
+            agg_specs.append((len(value_columns) - 1, agg))
+            names.append(f"{column}_{agg}")
+
+    aggregated = op.aggregate_by(
+        _to_uint64_array(table[cell_column]),
+        [table[key].combine_chunks() for key in keys],
+        [_float_values(table[column].combine_chunks()) for column in value_columns],
+        agg_specs,
+        cell_resolution,
+    )
+    return pa.table(aggregated).rename_columns(names)
+
+
+def _float_values(array):
+    """
+    Cast `array` to float64 unless it already is float32 or float64. Float32 is kept to not double the
+    memory requirements of the aggregations.
+    """
+    import pyarrow as pa
+
+    if array.type in (pa.float32(), pa.float64()):
+        return array
+    return array.cast(pa.float64())
+
+
+def decay_merge(
+    current,
+    incoming,
+    half_life,
+    timestamp_col: str = "timestamp",
+    value_columns: Optional[List[str]] = None,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    prune_below: Optional[float] = None,
+):
+    """
+    Merge a new batch of per-cell values - for example counts of events - into an existing state, decaying all
+    values exponentially over time. Repeatedly merging the batches of a stream maintains a rolling hotspot surface.
+
+    All values are decayed to the latest time found in either of the tables: a value observed at `time` is weighted
+    by ``0.5 ^ ((now - time) / half_life)``. The weighted values are summed up per cell, so the new batch may
+    contain the same cell more than once.
+
+    Example: ``state = decay_merge(state, batch, datetime.timedelta(hours=1))``.
+
+    Requires ``pyarrow`` to be installed.
+
+    :param current: The state - a table/dataframe as returned by this function, or ``None`` to start a new state.
+    :param incoming: The new batch with the cells, the values and their times.
+    :param half_life: The half-life of the values - a ``datetime.timedelta`` for timestamp columns, otherwise a number
+        in the unit of the `timestamp_col` column.
+    :param timestamp_col: Name of the column containing the times - timestamps or numbers.
+    :param value_columns: Names of the value columns. Defaults to all numeric columns of `incoming`.
+    :param cell_column: Name of the column containing the cells.
+    :param prune_below: Drop cells with all their decayed values below this threshold - keeping the state from
+        growing indefinitely.
+    :return: ``pyarrow.Table`` with the columns `cell`, the value columns as float64 and `timestamp_col` holding
+        the time the values have been decayed to. The cells of the state come first in their order, followed by the
+        cells only found in the new batch. Rows with null cells are skipped, null values count as zero.
+    """
+    import datetime
+
+    import pyarrow as pa
+    import pyarrow.compute as pc
+
+    incoming = pa.table(incoming)
+    timestamp_type = incoming.schema.field(timestamp_col).type
+    if value_columns is None:
+        value_columns = [
+            field.name
+            for field in incoming.schema
+            if field.name not in (cell_column, timestamp_col)
+            and (pa.types.is_integer(field.type) or pa.types.is_floating(field.type))
+        ]
+    if current is None:
+        current = incoming.select([cell_column, timestamp_col] + value_columns).slice(0, 0)
+    current = pa.table(current)
+
+    if pa.types.is_timestamp(timestamp_type):
+        if not isinstance(half_life, datetime.timedelta):
+            raise ValueError("half_life must be a datetime.timedelta for timestamp columns")
+        half_life = half_life / datetime.timedelta(microseconds=1)
+    elif isinstance(half_life, datetime.timedelta):
+        raise ValueError("half_life must be a number for numeric time columns")
+
+    def times(table):
+        column = table[timestamp_col].combine_chunks().cast(timestamp_type)
+        if pa.types.is_timestamp(timestamp_type):
+            column = column.cast(pa.timestamp("us", tz=timestamp_type.tz)).cast(pa.int64())
+        return column.cast(pa.float64())
+
+    def values(table):
+        return [table[column].combine_chunks().cast(pa.float64()) for column in value_columns]
+
+    now = pc.max(pa.chunked_array([times(current), times(incoming)], type=pa.float64())).as_py()
+    if now is None:
+        # neither of the tables has any rows
+        return current
+    timestamps = current[timestamp_col].cast(timestamp_type).chunks + incoming[timestamp_col].chunks
+    now_timestamp = pc.max(pa.chunked_array(timestamps, type=timestamp_type))
+
+    merged = pa.table(
+        op.decay_merge(
+            _to_uint64_array(current[cell_column]),
+            times(current),
+            values(current),
+            _to_uint64_array(incoming[cell_column]),
+            times(incoming),
+            values(incoming),
+            float(half_life),
+            now,
+            prune_below=prune_below,
+        )
+    ).rename_columns([cell_column] + value_columns)
+    return merged.append_column(timestamp_col, pa.repeat(now_timestamp, merged.num_rows))
+
+
 __all__ = [
     "H3_CRS",
     "DEFAULT_CELL_COLUMN_NAME",
     ContainmentMode.__name__,
     version.__name__,
+    has_raster_support.__name__,
+    has_io_support.__name__,
+    assume_valid.__name__,
+    dry_run.__name__,
     change_resolution.__name__,
     change_resolution_list.__name__,
     change_resolution_paired.__name__,
+    cells_to_center_child.__name__,
+    cells_to_children_size.__name__,
+    dasymetric_resample.__name__,
     cells_resolution.__name__,
+    filter_by_resolution.__name__,
+    split_by_resolution.__name__,
     cells_parse.__name__,
     vertexes_parse.__name__,
     directededges_parse.__name__,
     compact.__name__,
+    compact_stream.__name__,
     uncompact.__name__,
+    coverage_report.__name__,
+    cells_without.__name__,
+    points_in_coverage.__name__,
+    coverage_similarity.__name__,
+    cellset_canonicalize.__name__,
+    cellset_contains.__name__,
     cells_valid.__name__,
     vertexes_valid.__name__,
     directededges_valid.__name__,
     grid_disk.__name__,
+    grid_disk_union.__name__,
+    grid_path_cells.__name__,
+    grid_distance.__name__,
     grid_disk_distances.__name__,
     grid_ring_distances.__name__,
+    grid_disk_sector.__name__,
     grid_disk_aggregate_k.__name__,
+    grid_voronoi.__name__,
+    grid_distance_transform.__name__,
+    kde.__name__,
+    spatial_outliers.__name__,
+    region_adjacency.__name__,
+    regionalize.__name__,
+    cells_skeleton.__name__,
+    cells_expand_contract.__name__,
+    coverage_boundary_cells.__name__,
+    simplify_cell_path.__name__,
+    catchments.__name__,
+    viewshed.__name__,
+    ring_window.__name__,
+    ring_pivot.__name__,
     cells_area_m2.__name__,
     cells_area_km2.__name__,
     cells_area_rads2.__name__,
+    cells_faces.__name__,
+    cells_vertex_angles.__name__,
     cells_to_string.__name__,
     vertexes_to_string.__name__,
     directededges_to_string.__name__,
+    directededges_to_undirected_key.__name__,
     cells_to_localij.__name__,
     localij_to_cells.__name__,
+    cells_encode_payload.__name__,
+    cells_decode_payload.__name__,
+    apply_per_cell.__name__,
+    cells_to_int64.__name__,
+    int64_to_cells.__name__,
+    cells_hash.__name__,
+    detect_h3_columns.__name__,
+    deduplicate_table.__name__,
+    merge_cell_tables.__name__,
+    decay_merge.__name__,
+    aggregate_by.__name__,
 ]
+
+# return polars objects for polars inputs from all operations of this module
+for _name in __all__:
+    _value = globals()[_name]
+    if inspect.isfunction(_value) and _value.__module__ == __name__ and _value is not assume_valid:
+        globals()[_name] = _polars_io(_value)
@@ -0,0 +1,93 @@
+"""
+On-disk cache of the results of converting geometries to cells - so repeatedly converting the same geometries, for
+example administrative boundaries across notebook runs, does not need to repeat the conversion.
+
+The cache is a directory of Arrow IPC files keyed by a hash of the WKB geometries and of the conversion parameters.
+Entries are never invalidated, delete the directory to clear the cache.
+
+Example::
+
+    from h3ronpy.cache import PolyfillCache
+    from h3ronpy.vector import wkb_to_cells
+
+    cells = wkb_to_cells(boundaries, 9, cache=PolyfillCache("/tmp/h3ronpy-cache"))
+
+Requires ``pyarrow`` to be installed.
+"""
+
+import hashlib
+import os
+import struct
+import tempfile
+from typing import Optional
+
+from arro3.core import Array
+
+#: Incremented when the layout of the cache entries changes.
+CACHE_FORMAT_VERSION = 1
+
+
+class PolyfillCache:
+    """
+    Directory of cached conversion results.
+
+    :param directory: Directory to store the cached results in. Created when it does not exist.
+    """
+
+    def __init__(self, directory):
+        self.directory = os.fspath(directory)
+        os.makedirs(self.directory, exist_ok=True)
+
+    def key(self, wkbs, **params) -> str:
+        """
+        Hash of the WKB geometries and the conversion parameters.
+        """
+        import pyarrow as pa
+
+        h = hashlib.sha256()
+        h.update(repr((CACHE_FORMAT_VERSION, sorted(params.items()))).encode("utf8"))
+        for wkb in pa.array(wkbs).to_pylist():
+            if wkb is None:
+                h.update(struct.pack("<q", -1))
+            else:
+                h.update(struct.pack("<q", len(wkb)))
+                h.update(wkb)
+        return h.hexdigest()
+
+    def _path(self, key: str) -> str:
+        return os.path.join(self.directory, f"{key}.arrow")
+
+    def get(self, key: str) -> Optional[Array]:
+        """
+        The cached cells for `key`, or None when there is no cached result.
+        """
+        import pyarrow.ipc as ipc
+
+        path = self._path(key)
+        if not os.path.exists(path):
+            return None
+        with ipc.open_file(path) as reader:
+            return Array.from_arrow(reader.read_all()["cells"].combine_chunks())
+
+    def put(self, key: str, cells) -> None:
+        """
+        Store the cells for `key`. The file is written atomically, so concurrent readers never see partial entries.
+        """
+        import pyarrow as pa
+        import pyarrow.ipc as ipc
+
+        table = pa.table({"cells": pa.array(cells)})
+        fd, tmp_path = tempfile.mkstemp(dir=self.directory, suffix=".tmp")
+        try:
+            with os.fdopen(fd, "wb") as sink, ipc.new_file(sink, table.schema) as writer:
+                writer.write_table(table)
+            os.replace(tmp_path, self._path(key))
+        except BaseException:
+            os.unlink(tmp_path)
+            raise
+
+
+__all__ = [
+    "CACHE_FORMAT_VERSION",
+    PolyfillCache.__name__,
+]
@@ -0,0 +1,98 @@
+"""
+Mapping of values to colors for visualizations - for example using `lonboard <https://developmentseed.org/lonboard/>`_
+or the H3HexagonLayer of `deck.gl <https://deck.gl/docs/api-reference/geo-layers/h3-hexagon-layer>`_.
+
+Colors are returned as ``UInt32`` arrays with each value containing the packed RGBA color. The bytes of each value
+are ordered R, G, B, A in memory, so the numpy representation of such an array can be viewed as an ``(N, 4)``
+``uint8`` array using `rgba_to_numpy`.
+"""
+
+from typing import Optional, Sequence, Tuple, Union
+
+import numpy as np
+from arro3.core import Array, DataType
+
+from . import DEFAULT_CELL_COLUMN_NAME, _to_arrow_array, cells_to_string
+from .h3ronpyrs import op
+
+#: Names of the built-in colormaps
+COLORMAPS = ("viridis", "magma", "gray")
+
+
+def _colormap_arg(cmap) -> Union[str, Sequence[Tuple[int, int, int, int]]]:
+    if isinstance(cmap, str):
+        return cmap
+    if callable(cmap) and hasattr(cmap, "N"):
+        # matplotlib colormap
+        cmap = cmap(np.linspace(0.0, 1.0, min(cmap.N, 256)))
+    colors = np.asarray(cmap)
+    if colors.ndim != 2 or colors.shape[1] not in (3, 4):
+        raise ValueError("colormap must be a name or a sequence of RGB or RGBA colors")
+    if colors.dtype.kind == "f":
+        colors = np.round(colors * 255.0)
+    colors = colors.astype(np.uint8)
+    if colors.shape[1] == 3:
+        colors = np.hstack([colors, np.full((len(colors), 1), 255, dtype=np.uint8)])
+    return [tuple(int(c) for c in color) for color in colors]
+
+
+def colorize(values, cmap="viridis", vmin: Optional[float] = None, vmax: Optional[float] = None) -> Array:
+    """
+    Map numeric values to RGBA colors.
+
+    :param values: The numeric values.
+    :param cmap: The colormap. Either the name of a built-in colormap (see `COLORMAPS`), a matplotlib colormap
+            or a sequence of RGB or RGBA colors given as floats in the range 0-1 or as integers in the range 0-255.
+            Values are linearly interpolated between the colors.
+    :param vmin: The value mapped to the first color of the colormap. Defaults to the minimum of `values`.
+    :param vmax: The value mapped to the last color of the colormap. Defaults to the maximum of `values`.
+    :return: ``UInt32`` array of packed RGBA colors. Null and NaN values are null.
+    """
+    return op.colorize(_to_arrow_array(values, DataType.float64()), _colormap_arg(cmap), vmin=vmin, vmax=vmax)
+
+
+def rgba_to_numpy(colors) -> np.ndarray:
+    """
+    Convert the packed colors returned by `colorize` to an ``(N, 4)`` ``uint8`` numpy array. Null values
+    become fully transparent.
+
+    Requires ``pyarrow`` to be installed.
+    """
+    import pyarrow as pa
+
+    packed = pa.array(_to_arrow_array(colors, DataType.uint32())).fill_null(0).to_numpy()
+    return np.ascontiguousarray(packed, dtype="<u4").view(np.uint8).reshape(-1, 4)
+
+
+def cells_to_lonboard_layer(
+    table,
+    value_column: str,
+    cmap="viridis",
+    vmin: Optional[float] = None,
+    vmax: Optional[float] = None,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+) -> dict:
+    """
+    Prepare the buffers for a deck.gl H3HexagonLayer - as used by lonboard - from a table of cells and values.
+
+    :param table: Table/dataframe containing the cells and values.
+    :param value_column: Name of the column containing the values to colorize.
+    :param cmap: The colormap. See `colorize`.
+    :param vmin: See `colorize`.
+    :param vmax: See `colorize`.
+    :param cell_column: Name of the column containing the cells.
+    :return: dict with the keys ``hexagons`` - the cells as strings - and ``fill_colors`` - the ``(N, 4)``
+            ``uint8`` RGBA colors.
+    """
+    return {
+        "hexagons": cells_to_string(table[cell_column]),
+        "fill_colors": rgba_to_numpy(colorize(table[value_column], cmap=cmap, vmin=vmin, vmax=vmax)),
+    }
+
+
+__all__ = [
+    "COLORMAPS",
+    colorize.__name__,
+    rgba_to_numpy.__name__,
+    cells_to_lonboard_layer.__name__,
+]
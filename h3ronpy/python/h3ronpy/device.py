@@ -0,0 +1,146 @@
+"""
+Experimental support for arrays residing on non-CPU devices.
+
+Arrays implementing the `Arrow C device data interface <https://arrow.apache.org/docs/format/CDeviceDataInterface.html>`_
+(``__arrow_c_device_array__``) and residing on a device other than the CPU can not be processed by the kernels
+of h3ronpy. For a small set of operations this module allows registering external kernels which will get
+called with the unmodified input instead. This allows plugging in - for example - CUDA implementations
+without changing the API used by the code calling h3ronpy.
+
+Operations supporting dispatching to registered kernels:
+
+* ``coordinates_to_cells``: called with the same arguments as :py:func:`h3ronpy.vector.coordinates_to_cells`.
+* ``change_resolution``: called with the same arguments as :py:func:`h3ronpy.change_resolution`.
+
+The default CPU code path is not affected by this module.
+
+When h3ronpy has been built with the ``cuda`` feature (``maturin build --features cuda``), arrays residing on CUDA
+devices are handled by default kernels which copy the input to host memory using :py:func:`to_host` and run the CPU
+implementation. The results of these kernels reside on the host. Registered kernels take precedence over the default
+kernels.
+
+.. warning::
+
+    This API is experimental and may change without prior notice.
+"""
+
+from typing import Callable, Dict, Optional, Tuple
+
+from . import h3ronpyrs as _native
+from .h3ronpyrs import array_device
+
+DEVICE_CPU = 1
+DEVICE_CUDA = 2
+DEVICE_CUDA_HOST = 3
+DEVICE_OPENCL = 4
+DEVICE_VULKAN = 7
+DEVICE_METAL = 8
+DEVICE_VPI = 9
+DEVICE_ROCM = 10
+DEVICE_ROCM_HOST = 11
+DEVICE_EXT_DEV = 12
+DEVICE_CUDA_MANAGED = 13
+DEVICE_ONEAPI = 14
+DEVICE_WEBGPU = 15
+DEVICE_HEXAGON = 16
+
+SUPPORTED_OPS = ("coordinates_to_cells", "change_resolution")
+
+_KERNELS: Dict[Tuple[str, int], Callable] = {}
+
+
+def has_cuda_support() -> bool:
+    """
+    Returns True when h3ronpy has been built with the ``cuda`` feature.
+    """
+    return hasattr(_native, "cuda_array_to_host")
+
+
+def to_host(arr):
+    """
+    Copy an array residing on a CUDA device to host memory.
+
+    Arrays residing on the CPU are returned unchanged. Only arrays of fixed-width primitive types are supported.
+    Requires h3ronpy to be built with the ``cuda`` feature.
+    """
+    if not hasattr(arr, "__arrow_c_device_array__") or device_of(arr)[0] == DEVICE_CPU:
+        return arr
+    if not has_cuda_support():
+        raise NotImplementedError("h3ronpy has been built without the cuda feature")
+    return _native.cuda_array_to_host(arr)
+
+
+def _to_host_coordinates_to_cells(latarray, lngarray, resarray, radians: bool = False):
+    from .vector import coordinates_to_cells
+
+    return coordinates_to_cells(to_host(latarray), to_host(lngarray), to_host(resarray), radians=radians)
+
+
+def _to_host_change_resolution(arr, resolution: int):
+    from . import change_resolution
+
+    return change_resolution(to_host(arr), resolution)
+
+
+_DEFAULT_KERNELS: Dict[Tuple[str, int], Callable] = {}
+if has_cuda_support():
+    _DEFAULT_KERNELS[("coordinates_to_cells", DEVICE_CUDA)] = _to_host_coordinates_to_cells
+    _DEFAULT_KERNELS[("change_resolution", DEVICE_CUDA)] = _to_host_change_resolution
+
+
+def register_kernel(op_name: str, device_type: int, kernel: Callable):
+    """
+    Register a kernel to be called for arrays of the operation ``op_name`` residing
+    on the device ``device_type``.
+
+    An already registered kernel for the same operation and device will be replaced.
+    """
+    if op_name not in SUPPORTED_OPS:
+        raise ValueError(f"operation {op_name} does not support device kernels")
+    if device_type == DEVICE_CPU:
+        raise ValueError("kernels for the CPU can not be replaced")
+    _KERNELS[(op_name, device_type)] = kernel
+
+
+def unregister_kernel(op_name: str, device_type: int):
+    """
+    Remove a kernel registered using :py:func:`register_kernel`. Default kernels are used again afterwards.
+    """
+    _KERNELS.pop((op_name, device_type), None)
+
+
+def device_of(arr) -> Tuple[int, int]:
+    """
+    Returns the device type and device id of an array.
+
+    Objects not implementing ``__arrow_c_device_array__`` are assumed to reside on the CPU.
+    """
+    return array_device(arr)
+
+
+def _device_kernel(op_name: str, arr) -> Optional[Callable]:
+    """
+    Returns the kernel to use for an array not residing on the CPU, or `None`
+    when the default code path shall be used.
+    """
+    if not hasattr(arr, "__arrow_c_device_array__"):
+        return None
+    device_type, _ = device_of(arr)
+    if device_type == DEVICE_CPU:
+        return None
+    kernel = _KERNELS.get((op_name, device_type)) or _DEFAULT_KERNELS.get((op_name, device_type))
+    if kernel is None:
+        raise NotImplementedError(f"{op_name} has no kernel registered for arrays on device type {device_type}")
+    return kernel
+
+
+__all__ = [
+    register_kernel.__name__,
+    unregister_kernel.__name__,
+    device_of.__name__,
+    has_cuda_support.__name__,
+    to_host.__name__,
+    "SUPPORTED_OPS",
+    "DEVICE_CPU",
+    "DEVICE_CUDA",
+]
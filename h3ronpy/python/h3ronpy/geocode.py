@@ -0,0 +1,155 @@
+"""
+Conversion of countries to cells using the boundaries of the `Natural Earth <https://www.naturalearthdata.com/>`_
+dataset - a convenience for demos and for filtering.
+
+The ``110m`` scale is bundled with h3ronpy - reduced to the ``NAME`` and ISO code properties - and works offline.
+The more detailed scales are downloaded from a pinned release of Natural Earth on first use, verified against the
+SHA-256 checksum passed by the caller and cached in the directory given by the ``H3RONPY_CACHE_DIR`` environment
+variable - defaulting to ``~/.cache/h3ronpy``. The parsed boundaries are kept in memory for subsequent calls.
+Natural Earth is in the public domain.
+
+Example::
+
+    from h3ronpy.geocode import country_to_cells
+
+    cells = country_to_cells("DE", 5)
+"""
+
+import functools
+import gzip
+import hashlib
+import json
+import os
+import tempfile
+import urllib.request
+from typing import Any, Dict, List, Optional
+
+from arro3.core import Array
+
+from .vector import ContainmentMode, geometry_to_cells
+
+#: Scales of the Natural Earth dataset. Larger scales are more detailed, but larger downloads.
+SCALES = ("110m", "50m", "10m")
+
+#: Scale of the Natural Earth dataset bundled with h3ronpy.
+BUNDLED_SCALE = "110m"
+
+#: Release of the Natural Earth dataset which is downloaded for the scales which are not bundled.
+NATURAL_EARTH_VERSION = "v5.1.2"
+
+_URL_TEMPLATE = (
+    "https://raw.githubusercontent.com/nvkelso/natural-earth-vector/{version}/geojson/"
+    "ne_{scale}_admin_0_countries.geojson"
+)
+
+_BUNDLED_PATH = os.path.join(os.path.dirname(__file__), "data", "ne_110m_admin_0_countries.geojson.gz")
+
+
+class _Geometry:
+    def __init__(self, geojson: Dict[str, Any]):
+        self.__geo_interface__ = geojson
+
+
+def _cache_dir() -> str:
+    return os.environ.get("H3RONPY_CACHE_DIR", os.path.join(os.path.expanduser("~"), ".cache", "h3ronpy"))
+
+
+def _download(url: str, path: str, sha256: str) -> None:
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    fd, tmp_path = tempfile.mkstemp(dir=os.path.dirname(path), suffix=".tmp")
+    try:
+        digest = hashlib.sha256()
+        with os.fdopen(fd, "wb") as sink, urllib.request.urlopen(url) as response:
+            for chunk in iter(lambda: response.read(1 << 20), b""):
+                digest.update(chunk)
+                sink.write(chunk)
+        if digest.hexdigest() != sha256.lower():
+            raise IOError(f"checksum mismatch for {url}: expected {sha256}, found {digest.hexdigest()}")
+        os.replace(tmp_path, path)
+    except BaseException:
+        os.unlink(tmp_path)
+        raise
+
+
+@functools.lru_cache(maxsize=None)
+def _read_features(path: str) -> List[Dict[str, Any]]:
+    opener = gzip.open if path.endswith(".gz") else open
+    with opener(path, "rt", encoding="utf8") as f:
+        return json.load(f)["features"]
+
+
+def load_countries(
+    scale: str = "110m", path: Optional[str] = None, sha256: Optional[str] = None
+) -> List[Dict[str, Any]]:
+    """
+    The features of the countries dataset as GeoJSON dicts.
+
+    The features are parsed once per file and shared between calls, so they must not be modified.
+
+    :param scale: Scale of the dataset. See `SCALES`.
+    :param path: Path of a GeoJSON file to use instead of the Natural Earth dataset. The features need the
+            ``ISO_A2`` and ``ISO_A3`` properties.
+    :param sha256: Expected SHA-256 checksum of the downloaded file. Required for the scales which are not bundled,
+            unless the file has already been downloaded.
+    """
+    if path is None:
+        if scale not in SCALES:
+            raise ValueError(f"unsupported scale {scale}, expected one of {', '.join(SCALES)}")
+        if scale == BUNDLED_SCALE:
+            path = _BUNDLED_PATH
+        else:
+            path = os.path.join(_cache_dir(), NATURAL_EARTH_VERSION, f"ne_{scale}_admin_0_countries.geojson")
+            if not os.path.exists(path):
+                if sha256 is None:
+                    raise ValueError(f"the {scale} scale is downloaded, pass its checksum using the sha256 parameter")
+                _download(_URL_TEMPLATE.format(version=NATURAL_EARTH_VERSION, scale=scale), path, sha256)
+
+    return _read_features(os.path.abspath(str(path)))
+
+
+def _country_codes(properties: Dict[str, Any]) -> List[str]:
+    # ISO_A2 is "-99" for some countries in Natural Earth. ISO_A2_EH contains the code for these.
+    codes = [properties.get(name) for name in ("ISO_A2", "ISO_A2_EH", "ISO_A3", "ISO_A3_EH")]
+    return [code.upper() for code in codes if code and code != "-99"]
+
+
+def country_to_cells(
+    code: str,
+    resolution: int,
+    containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
+    compact: bool = False,
+    scale: str = "110m",
+    path: Optional[str] = None,
+    sha256: Optional[str] = None,
+) -> Array:
+    """
+    Convert the boundary of a country to cells.
+
+    :param code: ISO 3166-1 alpha-2 or alpha-3 code of the country, for example "DE" or "DEU".
+    :param resolution: H3 resolution
+    :param containment_mode: Containment mode used to decide if a cell is contained in the country or not.
+            See the ContainmentMode class.
+    :param compact: Compact the returned cells.
+    :param scale: Scale of the dataset. See `SCALES`.
+    :param path: See `load_countries`.
+    :param sha256: See `load_countries`.
+    """
+    code = code.upper()
+    for feature in load_countries(scale=scale, path=path, sha256=sha256):
+        if code in _country_codes(feature.get("properties") or {}):
+            return geometry_to_cells(
+                _Geometry(feature["geometry"]),
+                resolution,
+                containment_mode=containment_mode,
+                compact=compact,
+            )
+    raise ValueError(f"unknown country code: {code}")
+
+
+__all__ = [
+    "SCALES",
+    "BUNDLED_SCALE",
+    "NATURAL_EARTH_VERSION",
+    load_countries.__name__,
+    country_to_cells.__name__,
+]
@@ -0,0 +1,155 @@
+"""
+Conversion of `GTFS <https://gtfs.org/schedule/reference/>`_ feeds to H3 cells.
+
+Feeds may be given as the path of a zip file or of a directory containing the extracted ``.txt`` files.
+
+Requires ``pyarrow`` to be installed.
+"""
+
+import os
+import tempfile
+import zipfile
+from contextlib import contextmanager
+from typing import Iterator
+
+import numpy as np
+from arro3.core import RecordBatch, Table
+
+from .vector import coordinates_to_cells, geometries_to_cells_with_attributes
+
+
+@contextmanager
+def _feed_file(feed, name: str) -> Iterator[str]:
+    """Path of the file `name` of the feed - extracted to a temporary directory for zipped feeds."""
+    feed = os.fspath(feed)
+    if os.path.isdir(feed):
+        path = os.path.join(feed, name)
+        if not os.path.exists(path):
+            raise FileNotFoundError(f"GTFS feed {feed} does not contain {name}")
+        yield path
+        return
+
+    with zipfile.ZipFile(feed) as zf:
+        if name not in zf.namelist():
+            raise FileNotFoundError(f"GTFS feed {feed} does not contain {name}")
+        with tempfile.TemporaryDirectory() as tmpdir:
+            yield zf.extract(name, tmpdir)
+
+
+def _has_feed_file(feed, name: str) -> bool:
+    feed = os.fspath(feed)
+    if os.path.isdir(feed):
+        return os.path.exists(os.path.join(feed, name))
+    with zipfile.ZipFile(feed) as zf:
+        return name in zf.namelist()
+
+
+def _read_feed_table(feed, name: str, columns):
+    import pyarrow as pa
+    import pyarrow.csv as pacsv
+
+    with _feed_file(feed, name) as path:
+        # ids are always read as strings to avoid losing leading zeros.
+        return pacsv.read_csv(
+            path,
+            convert_options=pacsv.ConvertOptions(
+                include_columns=columns,
+                include_missing_columns=True,
+                column_types={column: pa.string() for column in columns if column.endswith("_id")},
+            ),
+        )
+
+
+def _wkb_linestrings(lngs: np.ndarray, lats: np.ndarray, lengths: np.ndarray):
+    """
+    Build WKB linestrings from consecutive runs of `lengths` coordinates each - by writing the headers and the
+    coordinates into a single buffer instead of looping over the linestrings or their points.
+    """
+    import pyarrow as pa
+
+    header = np.zeros(len(lengths), dtype=np.dtype([("byte_order", "u1"), ("type", "<u4"), ("n_points", "<u4")]))
+    header["byte_order"] = 1  # little endian
+    header["type"] = 2  # linestring
+    header["n_points"] = lengths
+    coords = np.empty((len(lngs), 2), dtype="<f8")
+    coords[:, 0] = lngs
+    coords[:, 1] = lats
+
+    offsets = np.zeros(len(lengths) + 1, dtype=np.int64)
+    np.cumsum(header.itemsize + coords.itemsize * 2 * lengths.astype(np.int64), out=offsets[1:])
+    data = np.empty(offsets[-1], dtype=np.uint8)
+    data[(offsets[:-1, None] + np.arange(header.itemsize)).ravel()] = header.view(np.uint8)
+    # the coordinates of each linestring follow its header
+    line_of_point = np.repeat(np.arange(len(lengths)), lengths)
+    point_positions = coords.itemsize * 2 * np.arange(len(coords)) + header.itemsize * (line_of_point + 1)
+    data[(point_positions[:, None] + np.arange(coords.itemsize * 2)).ravel()] = coords.view(np.uint8).ravel()
+    return pa.Array.from_buffers(pa.large_binary(), len(lengths), [None, pa.py_buffer(offsets), pa.py_buffer(data)])
+
+
+def gtfs_stops_to_cells(feed, resolution: int) -> Table:
+    """
+    Bin the stops of a GTFS feed into cells.
+
+    :param feed: Path of the GTFS feed.
+    :param resolution: H3 resolution
+    :return: Table with the columns `stop_id`, `stop_name` and `cell`. Stops without coordinates have a null cell.
+    """
+    import pyarrow as pa
+
+    stops = _read_feed_table(feed, "stops.txt", ["stop_id", "stop_name", "stop_lat", "stop_lon"])
+    cells = coordinates_to_cells(
+        stops["stop_lat"].combine_chunks().cast(pa.float64()),
+        stops["stop_lon"].combine_chunks().cast(pa.float64()),
+        resolution,
+    )
+    return Table.from_arrow(stops.select(["stop_id", "stop_name"]).append_column("cell", pa.array(cells)))
+
+
+def gtfs_shapes_to_cells(feed, resolution: int) -> RecordBatch:
+    """
+    Convert the shapes of a GTFS feed to the cells traversed by them.
+
+    The route of each shape is taken from the first trip using the shape. Shapes not used by any trip have a
+    null route.
+
+    :param feed: Path of the GTFS feed.
+    :param resolution: H3 resolution
+    :return: Record batch with the columns `shape_id`, `route_id` and `cell`.
+    """
+    import pyarrow as pa
+    import pyarrow.compute as pc
+
+    shapes = (
+        _read_feed_table(feed, "shapes.txt", ["shape_id", "shape_pt_lat", "shape_pt_lon", "shape_pt_sequence"])
+        .drop_null()
+        .sort_by([("shape_id", "ascending"), ("shape_pt_sequence", "ascending")])
+    )
+    shape_ids = shapes["shape_id"].combine_chunks()
+    changes = pc.not_equal(shape_ids[1:], shape_ids[:-1]).to_numpy(zero_copy_only=False)
+    starts = np.flatnonzero(np.r_[len(shape_ids) > 0, changes])
+    lengths = np.diff(np.r_[starts, len(shape_ids)])
+    # linestrings require at least two points
+    keep = lengths >= 2
+    points = np.repeat(keep, lengths)
+    wkbs = _wkb_linestrings(
+        shapes["shape_pt_lon"].combine_chunks().cast(pa.float64()).to_numpy(zero_copy_only=False)[points],
+        shapes["shape_pt_lat"].combine_chunks().cast(pa.float64()).to_numpy(zero_copy_only=False)[points],
+        lengths[keep],
+    )
+    shape_ids = shape_ids.take(pa.array(starts[keep], type=pa.int64()))
+
+    route_ids = pa.nulls(len(shape_ids), type=pa.string())
+    if _has_feed_file(feed, "trips.txt"):
+        trips = _read_feed_table(feed, "trips.txt", ["route_id", "shape_id"])
+        # index_in returns the position of the first trip using the shape
+        first_trips = pc.index_in(shape_ids, value_set=trips["shape_id"].combine_chunks())
+        route_ids = trips["route_id"].combine_chunks().take(first_trips)
+
+    attributes = pa.table({"shape_id": shape_ids, "route_id": route_ids})
+    return geometries_to_cells_with_attributes(wkbs, attributes, resolution)
+
+
+__all__ = [
+    gtfs_stops_to_cells.__name__,
+    gtfs_shapes_to_cells.__name__,
+]
@@ -0,0 +1,206 @@
+"""
+Vectorized functions named after the API of `h3-py <https://uber.github.io/h3-py/>`_ v4 to ease the migration of
+existing code bases.
+
+In contrast to h3-py all functions accept and return arrays. Cells are returned as ``uint64`` numpy arrays, functions
+returning multiple values per cell return lists of numpy arrays. Invalid input values raise a ``ValueError``.
+
+Example::
+
+    from h3ronpy import h3compat as h3
+
+    cells = h3.latlng_to_cell(lats, lngs, 7)
+    parents = h3.cell_to_parent(cells, 5)
+
+Requires ``pyarrow`` to be installed.
+"""
+
+from typing import List, Optional, Tuple
+
+import numpy as np
+
+from . import (
+    cells_area_km2,
+    cells_area_m2,
+    cells_area_rads2,
+    cells_parse,
+    cells_resolution,
+    cells_to_center_child,
+    cells_to_children_size,
+    cells_to_localij,
+    cells_to_string,
+    cells_valid,
+    change_resolution,
+    change_resolution_list,
+    compact,
+    grid_disk as _grid_disk,
+    localij_to_cells,
+    uncompact,
+)
+from .vector import cells_to_coordinates, coordinates_to_cells
+
+
+def _to_numpy(arr) -> np.ndarray:
+    import pyarrow as pa
+
+    return pa.array(arr).to_numpy(zero_copy_only=False)
+
+
+def _split_lists(listarray) -> List[np.ndarray]:
+    import pyarrow as pa
+
+    listarray = pa.array(listarray)
+    offsets = listarray.offsets.to_numpy()
+    values = listarray.values.to_numpy(zero_copy_only=False)
+    return [values[start:end] for start, end in zip(offsets[:-1], offsets[1:])]
+
+
+def _origin(origin):
+    # a single origin must be passed as a python int
+    return int(origin) if np.isscalar(origin) else origin
+
+
+def latlng_to_cell(lat, lng, res: int) -> np.ndarray:
+    return _to_numpy(coordinates_to_cells(lat, lng, res))
+
+
+def cell_to_latlng(cells) -> Tuple[np.ndarray, np.ndarray]:
+    coordinates = cells_to_coordinates(cells)
+    return _to_numpy(coordinates["lat"]), _to_numpy(coordinates["lng"])
+
+
+def get_resolution(cells) -> np.ndarray:
+    return _to_numpy(cells_resolution(cells))
+
+
+def is_valid_cell(cells) -> np.ndarray:
+    return _to_numpy(cells_valid(cells, booleanarray=True))
+
+
+def int_to_str(cells) -> np.ndarray:
+    return _to_numpy(cells_to_string(cells))
+
+
+def str_to_int(strings) -> np.ndarray:
+    return _to_numpy(cells_parse(strings))
+
+
+def cell_to_parent(cells, res: Optional[int] = None) -> np.ndarray:
+    """
+    Parents of the cells. Without `res` the parent of each cell is at the next coarser resolution.
+    """
+    if res is not None:
+        return _to_numpy(change_resolution(cells, res))
+
+    cells = np.asarray(cells, dtype=np.uint64)
+    resolutions = get_resolution(cells)
+    if np.any(resolutions == 0):
+        raise ValueError("cells of resolution 0 have no parent")
+    parents = np.empty_like(cells)
+    for resolution in np.unique(resolutions):
+        mask = resolutions == resolution
+        parents[mask] = _to_numpy(change_resolution(cells[mask], int(resolution) - 1))
+    return parents
+
+
+def cell_to_children(cells, res: Optional[int] = None) -> List[np.ndarray]:
+    """
+    Children of the cells. Without `res` the children of each cell are at the next finer resolution.
+    """
+    if res is not None:
+        return _split_lists(change_resolution_list(cells, res))
+
+    cells = np.asarray(cells, dtype=np.uint64)
+    resolutions = get_resolution(cells)
+    if np.any(resolutions == 15):
+        raise ValueError("cells of resolution 15 have no children")
+    children: List[np.ndarray] = [np.empty(0, dtype=np.uint64)] * len(cells)
+    for resolution in np.unique(resolutions):
+        positions = np.flatnonzero(resolutions == resolution)
+        for position, cell_children in zip(
+            positions, _split_lists(change_resolution_list(cells[positions], int(resolution) + 1))
+        ):
+            children[position] = cell_children
+    return children
+
+
+def cell_to_center_child(cells, res: Optional[int] = None) -> np.ndarray:
+    """
+    Center children of the cells. Without `res` the center child of each cell is at the next finer resolution.
+    """
+    if res is not None:
+        return _to_numpy(cells_to_center_child(cells, res))
+
+    cells = np.asarray(cells, dtype=np.uint64)
+    resolutions = get_resolution(cells)
+    if np.any(resolutions == 15):
+        raise ValueError("cells of resolution 15 have no children")
+    center_children = np.empty_like(cells)
+    for resolution in np.unique(resolutions):
+        mask = resolutions == resolution
+        center_children[mask] = _to_numpy(cells_to_center_child(cells[mask], int(resolution) + 1))
+    return center_children
+
+
+def cell_to_children_size(cells, res: Optional[int] = None) -> np.ndarray:
+    """
+    Number of children of the cells. Without `res` the children at the next finer resolution are counted.
+    """
+    if res is not None:
+        return _to_numpy(cells_to_children_size(cells, res))
+
+    cells = np.asarray(cells, dtype=np.uint64)
+    resolutions = get_resolution(cells)
+    sizes = np.zeros(len(cells), dtype=np.uint64)
+    for resolution in np.unique(resolutions[resolutions < 15]):
+        mask = resolutions == resolution
+        sizes[mask] = _to_numpy(cells_to_children_size(cells[mask], int(resolution) + 1))
+    return sizes
+
+
+def grid_disk(cells, k: int = 1) -> List[np.ndarray]:
+    return _split_lists(_grid_disk(cells, k))
+
+
+def compact_cells(cells) -> np.ndarray:
+    return _to_numpy(compact(cells, mixed_resolutions=True))
+
+
+def uncompact_cells(cells, res: int) -> np.ndarray:
+    return _to_numpy(uncompact(cells, res))
+
+
+def cell_area(cells, unit: str = "km^2") -> np.ndarray:
+    area_fns = {"km^2": cells_area_km2, "m^2": cells_area_m2, "rads^2": cells_area_rads2}
+    if unit not in area_fns:
+        raise ValueError(f"unsupported unit: {unit}")
+    return _to_numpy(area_fns[unit](cells))
+
+
+def cell_to_local_ij(origin, cells) -> Tuple[np.ndarray, np.ndarray]:
+    localij = cells_to_localij(cells, _origin(origin))
+    return _to_numpy(localij["i"]), _to_numpy(localij["j"])
+
+
+def local_ij_to_cell(origin, i, j) -> np.ndarray:
+    return _to_numpy(localij_to_cells(_origin(origin), i, j))
+
+
+__all__ = [
+    latlng_to_cell.__name__,
+    cell_to_latlng.__name__,
+    get_resolution.__name__,
+    is_valid_cell.__name__,
+    int_to_str.__name__,
+    str_to_int.__name__,
+    cell_to_parent.__name__,
+    cell_to_children.__name__,
+    cell_to_center_child.__name__,
+    cell_to_children_size.__name__,
+    grid_disk.__name__,
+    compact_cells.__name__,
+    uncompact_cells.__name__,
+    cell_area.__name__,
+    cell_to_local_ij.__name__,
+    local_ij_to_cell.__name__,
+]
@@ -0,0 +1,108 @@
+"""
+Export of cell tables for `kepler.gl <https://kepler.gl/>`_.
+
+Requires ``pyarrow`` to be installed.
+"""
+
+from typing import Any, Dict, Optional, Tuple
+
+from . import DEFAULT_CELL_COLUMN_NAME, cells_to_string
+
+#: Default upper limit of the number of rows to export. kepler.gl renders all data in the browser, so
+#: larger datasets are better aggregated to coarser resolutions first.
+DEFAULT_MAX_ROWS = 1_000_000
+
+
+def _kepler_column(column) -> Tuple[str, list]:
+    import pyarrow as pa
+    import pyarrow.compute as pc
+
+    t = column.type
+    if pa.types.is_integer(t):
+        return "integer", column.to_pylist()
+    if pa.types.is_floating(t) or pa.types.is_decimal(t):
+        return "real", pc.cast(column, pa.float64()).to_pylist()
+    if pa.types.is_boolean(t):
+        return "boolean", column.to_pylist()
+    if pa.types.is_timestamp(t) or pa.types.is_date(t):
+        return "timestamp", [None if v is None else v.isoformat() for v in column.to_pylist()]
+    if pa.types.is_string(t) or pa.types.is_large_string(t):
+        return "string", column.to_pylist()
+    return "string", [None if v is None else str(v) for v in column.to_pylist()]
+
+
+def to_keplergl(
+    table,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    config: Optional[Dict[str, Any]] = None,
+    dataset_id: str = "h3ronpy",
+    max_rows: Optional[int] = DEFAULT_MAX_ROWS,
+) -> Tuple[Dict[str, Any], Dict[str, Any]]:
+    """
+    Convert a table of cells to a kepler.gl dataset and a map config with a H3 layer displaying the cells.
+
+    The cells are converted to hex strings as expected by kepler.gl. Other columns are converted to the kepler.gl
+    field types - integers, reals (including decimals), booleans, timestamps (as ISO 8601 strings) and strings. Columns
+    of all other types are converted to their string representation.
+
+    :param table: Table/dataframe containing the cells.
+    :param cell_column: Name of the column containing the cells.
+    :param config: Optional kepler.gl map config to use instead of the generated one. The H3 layer is only generated
+            when no config is given.
+    :param dataset_id: Id of the dataset, referenced by the layer in the map config.
+    :param max_rows: Raise a ``ValueError`` when the table contains more rows. Set to None to disable the limit.
+    :return: Tuple of the dataset - in the format accepted by ``KeplerGl.add_data`` and the ``addDataToMap``
+            action - and the map config.
+    """
+    import pyarrow as pa
+
+    table = pa.table(table)
+    if max_rows is not None and table.num_rows > max_rows:
+        raise ValueError(
+            f"table contains {table.num_rows} rows which exceeds the limit of {max_rows} rows. "
+            "Consider aggregating to a coarser resolution."
+        )
+
+    fields = []
+    columns = []
+    for name in table.column_names:
+        if name == cell_column:
+            fields.append({"name": name, "type": "string"})
+            columns.append(pa.array(cells_to_string(table[name])).to_pylist())
+        else:
+            field_type, values = _kepler_column(table[name])
+            fields.append({"name": name, "type": field_type})
+            columns.append(values)
+
+    dataset = {
+        "info": {"id": dataset_id, "label": dataset_id},
+        "data": {"fields": fields, "rows": [list(row) for row in zip(*columns)]},
+    }
+
+    if config is None:
+        config = {
+            "version": "v1",
+            "config": {
+                "visState": {
+                    "layers": [
+                        {
+                            "id": f"{dataset_id}-h3",
+                            "type": "hexagonId",
+                            "config": {
+                                "dataId": dataset_id,
+                                "label": cell_column,
+                                "columns": {"hex_id": cell_column},
+                                "isVisible": True,
+                            },
+                        }
+                    ]
+                }
+            },
+        }
+    return dataset, config
+
+
+__all__ = [
+    "DEFAULT_MAX_ROWS",
+    to_keplergl.__name__,
+]
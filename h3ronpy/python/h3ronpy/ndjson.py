@@ -0,0 +1,83 @@
+"""
+Streaming export of cell tables to newline-delimited JSON - the input format of loaders like those of
+Elasticsearch, OpenSearch or BigQuery.
+
+The JSON is written from Rust batch by batch, so the rows never need to be converted to python objects.
+"""
+
+import os
+from typing import Optional, Union
+
+from . import DEFAULT_CELL_COLUMN_NAME
+from .h3ronpyrs import op
+
+
+def _to_stream(table):
+    if hasattr(table, "__arrow_c_stream__"):
+        return table
+
+    import pyarrow as pa
+
+    return pa.table(table)
+
+
+def write_ndjson(
+    table,
+    path: Union[str, os.PathLike],
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    gzip: bool = False,
+):
+    """
+    Write a cell table as newline-delimited JSON with one object per row.
+
+    The cells are written as hexadecimal strings, all other columns are written as they are. Null values
+    are omitted from the objects.
+
+    :param table: Table/dataframe/record batch reader with the cells and their attributes. Anything
+        implementing the Arrow PyCapsule stream interface is consumed batch by batch, other objects are
+        converted using ``pyarrow.table``.
+    :param path: The file to write to.
+    :param cell_column: Name of the ``uint64`` column containing the cells.
+    :param gzip: Compress the output using gzip. Requires h3ronpy to be built with the ``io`` feature.
+    """
+    op.write_ndjson(_to_stream(table), os.fspath(path), cell_column=cell_column, gzip=gzip)
+
+
+def write_elasticsearch_bulk(
+    table,
+    path: Union[str, os.PathLike],
+    index: Optional[str] = None,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    geometry_field: str = "geometry",
+    gzip: bool = False,
+):
+    """
+    Write a cell table in the NDJSON format of the bulk API of Elasticsearch and OpenSearch.
+
+    Each row becomes an ``index`` action using the hexadecimal cell as ``_id`` - so repeated imports update
+    the existing documents -, followed by the document. The document contains the attributes, the
+    hexadecimal cell and the hexagon of the cell as GeoJSON polygon in `geometry_field`, which should be
+    mapped as ``geo_shape``. Rows without a cell are skipped.
+
+    :param table: Table/dataframe/record batch reader with the cells and their attributes, see `write_ndjson`.
+    :param path: The file to write to.
+    :param index: Name of the index to write to. When not set, the index needs to be given in the URL of the
+        bulk request.
+    :param cell_column: Name of the ``uint64`` column containing the cells.
+    :param geometry_field: Name of the field to write the hexagons to.
+    :param gzip: Compress the output using gzip. Requires h3ronpy to be built with the ``io`` feature.
+    """
+    op.write_elasticsearch_bulk(
+        _to_stream(table),
+        os.fspath(path),
+        index=index,
+        cell_column=cell_column,
+        geometry_field=geometry_field,
+        gzip=gzip,
+    )
+
+
+__all__ = [
+    write_ndjson.__name__,
+    write_elasticsearch_bulk.__name__,
+]
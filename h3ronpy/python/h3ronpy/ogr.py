@@ -0,0 +1,365 @@
+"""
+Conversion of vector datasets to cells using OGR - the vector part of GDAL - and writing of cells to
+datasets supported by OGR.
+
+The features of a layer are read in batches and converted to cells batch by batch, so even layers
+of multi-GB files can be converted without loading them completely into memory - for example using
+GeoPandas - first.
+
+Requires the GDAL python bindings (``osgeo``) and ``pyarrow`` to be installed.
+"""
+
+import os
+from typing import Iterator, List, Optional, Union
+
+import pyarrow as pa
+from arro3.core import RecordBatch
+
+from . import DEFAULT_CELL_COLUMN_NAME, cells_resolution, cells_to_string
+from .sql import IF_EXISTS_MODES
+from .vector import ContainmentMode, cells_to_wkb_polygons, geometries_to_cells_with_attributes, wkb_to_cells
+
+
+def _open_layer(path, layer: Optional[Union[str, int]]):
+    from osgeo import gdal, ogr
+
+    gdal.UseExceptions()
+    dataset = ogr.Open(str(path))
+    if dataset is None:
+        raise IOError(f"unable to open {path}")
+    ogr_layer = dataset.GetLayer() if layer is None else dataset.GetLayer(layer)
+    if ogr_layer is None:
+        raise ValueError(f"layer {layer!r} not found in {path}")
+    # the dataset has to be kept alive as long as the layer is used
+    return dataset, ogr_layer
+
+
+def _wgs84_transformation(ogr_layer):
+    from osgeo import osr
+
+    source = ogr_layer.GetSpatialRef()
+    if source is None:
+        # assume WGS84
+        return None
+    target = osr.SpatialReference()
+    target.ImportFromEPSG(4326)
+    for srs in (source, target):
+        srs.SetAxisMappingStrategy(osr.OAMS_TRADITIONAL_GIS_ORDER)
+    if source.IsSame(target):
+        return None
+    return osr.CoordinateTransformation(source, target)
+
+
+def ogr_layer_to_cells(
+    path,
+    resolution: int,
+    layer: Optional[Union[str, int]] = None,
+    fields: Optional[List[str]] = None,
+    containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
+    compact: bool = False,
+    batch_size: int = 10_000,
+) -> Iterator[RecordBatch]:
+    """
+    Convert the features of an OGR layer to cells and stream the cells together with the selected attributes
+    of their features.
+
+    The geometries are reprojected to WGS84 when the layer uses a different spatial reference system. Features
+    without a geometry do not produce any rows.
+
+    :param path: Path of the dataset - anything OGR is able to open.
+    :param resolution: H3 resolution
+    :param layer: Name or index of the layer. Defaults to the first layer.
+    :param fields: Names of the attribute fields to carry through to the cells. Defaults to all fields.
+    :param containment_mode: Containment mode used to decide if a cell is contained in a polygon or not.
+            See the ContainmentMode class.
+    :param compact: Compact the cells of each feature.
+    :param batch_size: Number of features to convert at once.
+    :return: Iterator of record batches with the columns of `fields` and the `cell` column.
+    """
+    if batch_size < 1:
+        raise ValueError("batch_size must be larger than 0")
+    dataset, ogr_layer = _open_layer(path, layer)
+    definition = ogr_layer.GetLayerDefn()
+    available = [definition.GetFieldDefn(i).GetName() for i in range(definition.GetFieldCount())]
+    if fields is None:
+        fields = available
+    missing = [field for field in fields if field not in available]
+    if missing:
+        raise ValueError(f"fields not found in layer: {', '.join(missing)}")
+    transformation = _wgs84_transformation(ogr_layer)
+
+    def convert(geometries, attributes):
+        geometries = pa.array(geometries, type=pa.binary())
+        if not fields:
+            cells = wkb_to_cells(
+                geometries, resolution, containment_mode=containment_mode, compact=compact, flatten=True
+            )
+            return RecordBatch.from_arrow(pa.record_batch({DEFAULT_CELL_COLUMN_NAME: pa.array(cells)}))
+        return geometries_to_cells_with_attributes(
+            geometries,
+            pa.table(attributes),
+            resolution,
+            containment_mode=containment_mode,
+            compact=compact,
+        )
+
+    geometries = []
+    attributes = {field: [] for field in fields}
+    ogr_layer.ResetReading()
+    for feature in ogr_layer:
+        geometry = feature.GetGeometryRef()
+        if geometry is None:
+            continue
+        geometry = geometry.Clone()
+        geometry.FlattenTo2D()
+        if transformation is not None:
+            geometry.Transform(transformation)
+        geometries.append(bytes(geometry.ExportToIsoWkb()))
+        for field in fields:
+            attributes[field].append(feature.GetField(field))
+
+        if len(geometries) >= batch_size:
+            yield convert(geometries, attributes)
+            geometries = []
+            attributes = {field: [] for field in fields}
+    if geometries:
+        yield convert(geometries, attributes)
+    del dataset
+
+
+def _ogr_field_type(t):
+    from osgeo import ogr
+
+    if pa.types.is_boolean(t):
+        return ogr.OFTInteger, ogr.OFSTBoolean
+    if pa.types.is_integer(t):
+        return ogr.OFTInteger64, ogr.OFSTNone
+    if pa.types.is_floating(t):
+        return ogr.OFTReal, ogr.OFSTNone
+    if pa.types.is_date(t):
+        return ogr.OFTDate, ogr.OFSTNone
+    if pa.types.is_timestamp(t):
+        return ogr.OFTDateTime, ogr.OFSTNone
+    # everything else is written as its string representation
+    return ogr.OFTString, ogr.OFSTNone
+
+
+def _field_value(value):
+    if value is None or isinstance(value, (bool, int, float, str)):
+        return value
+    if hasattr(value, "isoformat"):
+        return value.isoformat()
+    return str(value)
+
+
+def _split_table(table: pa.Table, split_by: Optional[str], split_by_resolution: bool, cell_column: str):
+    if split_by is not None and split_by_resolution:
+        raise ValueError("split_by and split_by_resolution are mutually exclusive")
+    if split_by_resolution:
+        keys = pa.array(cells_resolution(table[cell_column]))
+    elif split_by is not None:
+        if split_by not in table.column_names:
+            raise ValueError(f"split_by column {split_by!r} not found")
+        keys = table[split_by].combine_chunks()
+    else:
+        yield None, table
+        return
+    keys = keys.dictionary_encode()
+    indices = keys.indices.to_numpy(zero_copy_only=False)
+    for i, value in enumerate(keys.dictionary.to_pylist()):
+        yield value, table.filter(pa.array(indices == i))
+    if keys.null_count:
+        yield None, table.filter(keys.is_null())
+
+
+def _field_definitions(table: pa.Table, cell_column: str):
+    from osgeo import ogr
+
+    for field in table.schema:
+        if field.name == cell_column:
+            yield field.name, ogr.OFTString, ogr.OFSTNone
+        else:
+            yield (field.name, *_ogr_field_type(field.type))
+
+
+def _output_srs(output_epsg: Optional[int]):
+    from osgeo import osr
+
+    srs = osr.SpatialReference()
+    srs.ImportFromEPSG(4326 if output_epsg is None else int(output_epsg))
+    srs.SetAxisMappingStrategy(osr.OAMS_TRADITIONAL_GIS_ORDER)
+    return srs
+
+
+def _check_layer_schema(ogr_layer, table: pa.Table, cell_column: str, output_epsg: Optional[int]):
+    from osgeo import ogr
+
+    definition = ogr_layer.GetLayerDefn()
+    existing = {}
+    for i in range(definition.GetFieldCount()):
+        field_definition = definition.GetFieldDefn(i)
+        existing[field_definition.GetName()] = field_definition.GetType()
+    problems = []
+    for name, field_type, _ in _field_definitions(table, cell_column):
+        if name not in existing:
+            problems.append(f"field {name!r} is missing")
+        elif existing[name] != field_type:
+            problems.append(
+                f"field {name!r} is of type {ogr.GetFieldTypeName(existing[name])}, "
+                f"expected {ogr.GetFieldTypeName(field_type)}"
+            )
+    if ogr.GT_Flatten(ogr_layer.GetGeomType()) not in (ogr.wkbPolygon, ogr.wkbUnknown):
+        problems.append(f"geometry type is {ogr.GeometryTypeToName(ogr_layer.GetGeomType())}, expected polygons")
+    layer_srs = ogr_layer.GetSpatialRef()
+    if layer_srs is not None and not layer_srs.IsSame(_output_srs(output_epsg)):
+        problems.append(f"spatial reference is {layer_srs.GetName()!r}, expected EPSG:{output_epsg or 4326}")
+    if problems:
+        raise ValueError(f"can not append to layer {ogr_layer.GetName()!r}: {'; '.join(problems)}")
+
+
+def _write_layer(
+    dataset,
+    name: str,
+    table: pa.Table,
+    cell_column: str,
+    if_exists: str,
+    batch_size: int,
+    output_epsg: Optional[int],
+):
+    from osgeo import ogr, osr
+
+    ogr_layer = dataset.GetLayerByName(name)
+    if ogr_layer is not None and if_exists == "replace":
+        dataset.DeleteLayer(name)
+        ogr_layer = None
+
+    srs = _output_srs(output_epsg)
+    transformation = None
+    if output_epsg is not None:
+        transformation = osr.CoordinateTransformation(_output_srs(None), srs)
+
+    if ogr_layer is None:
+        ogr_layer = dataset.CreateLayer(name, srs, ogr.wkbPolygon)
+        if ogr_layer is None:
+            raise IOError(f"unable to create layer {name!r}")
+        for field_name, field_type, sub_type in _field_definitions(table, cell_column):
+            definition = ogr.FieldDefn(field_name, field_type)
+            definition.SetSubType(sub_type)
+            ogr_layer.CreateField(definition)
+
+    transactions = dataset.TestCapability(ogr.ODsCTransactions)
+    definition = ogr_layer.GetLayerDefn()
+    for offset in range(0, table.num_rows, batch_size):
+        batch = table.slice(offset, batch_size)
+        cells = batch[cell_column]
+        geometries = pa.array(cells_to_wkb_polygons(cells)).to_pylist()
+        columns = {
+            column: (pa.array(cells_to_string(cells)) if column == cell_column else batch[column]).to_pylist()
+            for column in batch.column_names
+        }
+
+        if transactions:
+            dataset.StartTransaction()
+        try:
+            for row, wkb in enumerate(geometries):
+                feature = ogr.Feature(definition)
+                for column, values in columns.items():
+                    value = _field_value(values[row])
+                    if value is not None:
+                        feature.SetField(column, value)
+                if wkb is not None:
+                    geometry = ogr.CreateGeometryFromWkb(wkb)
+                    if transformation is not None:
+                        geometry.Transform(transformation)
+                    feature.SetGeometry(geometry)
+                ogr_layer.CreateFeature(feature)
+        except BaseException:
+            if transactions:
+                dataset.RollbackTransaction()
+            raise
+        if transactions:
+            dataset.CommitTransaction()
+
+
+def write_to_ogr_dataset(
+    table,
+    path,
+    layer: str = "cells",
+    driver: str = "GPKG",
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    split_by: Optional[str] = None,
+    split_by_resolution: bool = False,
+    layer_name_template: str = "{layer}_{value}",
+    if_exists: str = "fail",
+    batch_size: int = 10_000,
+    output_epsg: Optional[int] = None,
+) -> List[str]:
+    """
+    Write cells - including their polygons - and their attributes to a dataset supported by OGR, for example a
+    GeoPackage.
+
+    The cells are written as strings in their hexadecimal representation, the other columns are written as
+    attribute fields. Columns of types without a matching OGR field type are written as strings.
+
+    The rows can be split into one layer per distinct value of a column or per resolution of the cells, so GIS
+    users receive layers which are ready to be styled. The layers are named using `layer_name_template`, in which
+    ``{layer}`` is replaced by `layer` and ``{value}`` by the value of the column or the resolution. Rows with a null
+    value are written to a layer named like the value ``None``.
+
+    :param table: The table/dataframe to write.
+    :param path: Path of the dataset. Existing datasets are opened to add the layers to them.
+    :param layer: Name of the layer, or the base name of the layers when splitting.
+    :param driver: Name of the OGR driver used to create the dataset.
+    :param cell_column: Name of the column containing the cells.
+    :param split_by: Name of the column to write one layer per distinct value of.
+    :param split_by_resolution: Write one layer per resolution of the cells.
+    :param layer_name_template: Template for the names of the layers when splitting.
+    :param if_exists: What to do when a layer already exists: ``fail``, ``replace`` it or ``append`` to it.
+        Appending requires the layer to have fields of the same types for all columns, otherwise a `ValueError` is
+        raised before anything is written. This allows accumulating the results of successive conversions in a
+        single dataset.
+    :param batch_size: Number of features to write within a single transaction. When a batch fails, its
+        transaction is rolled back, so a layer never contains a part of a batch. Drivers without transaction
+        support write the features directly.
+    :param output_epsg: EPSG code of the CRS to write the polygons in - for example ``3857``. The polygons are
+        reprojected using the PROJ library bundled with GDAL. Defaults to WGS84.
+    :return: The names of the written layers.
+    """
+    from osgeo import gdal, ogr
+
+    gdal.UseExceptions()
+    if if_exists not in IF_EXISTS_MODES:
+        raise ValueError(f"unsupported if_exists mode {if_exists}, expected one of {', '.join(IF_EXISTS_MODES)}")
+    if batch_size < 1:
+        raise ValueError("batch_size must be larger than 0")
+    table = pa.table(table)
+    if cell_column not in table.column_names:
+        raise ValueError(f"cell column {cell_column!r} not found")
+    parts = list(_split_table(table, split_by, split_by_resolution, cell_column))
+    if split_by is None and not split_by_resolution:
+        names = [layer]
+    else:
+        names = [layer_name_template.format(layer=layer, value=value) for value, _ in parts]
+    if len(set(names)) != len(names):
+        raise ValueError("layer_name_template does not produce distinct layer names")
+
+    if os.path.exists(str(path)):
+        dataset = ogr.Open(str(path), update=1)
+    else:
+        dataset = ogr.GetDriverByName(driver).CreateDataSource(str(path))
+    if dataset is None:
+        raise IOError(f"unable to open {path}")
+    # check all layers before writing to any of them
+    for name, (_, part) in zip(names, parts):
+        ogr_layer = dataset.GetLayerByName(name)
+        if ogr_layer is None:
+            continue
+        if if_exists == "fail":
+            raise ValueError(f"layer {name!r} already exists")
+        if if_exists == "append":
+            _check_layer_schema(ogr_layer, part, cell_column, output_epsg)
+    for name, (_, part) in zip(names, parts):
+        _write_layer(dataset, name, part, cell_column, if_exists, batch_size, output_epsg)
+    dataset.FlushCache()
+    del dataset
+    return names
@@ -0,0 +1,99 @@
+"""
+Conversion of the ways of `OpenStreetMap <https://www.openstreetmap.org>`_ extracts to H3 cells.
+
+The extracts are read using the OSM driver of OGR, which supports ``.osm.pbf`` as well as ``.osm`` XML files.
+The ways are streamed in batches, so even extracts of whole countries can be converted without loading them
+completely into memory.
+
+Requires the GDAL python bindings (``osgeo``) and ``pyarrow`` to be installed.
+"""
+
+from typing import Iterator, Optional, Sequence
+
+from arro3.core import RecordBatch
+
+from .vector import geometries_to_cells_with_attributes
+
+
+def _highway_filter(highway_types: Optional[Sequence[str]]) -> str:
+    if highway_types is None:
+        return "highway IS NOT NULL"
+    if not highway_types:
+        raise ValueError("highway_types must not be empty")
+    values = ", ".join("'{}'".format(highway_type.replace("'", "''")) for highway_type in highway_types)
+    return f"highway IN ({values})"
+
+
+def osm_highways_to_cells(
+    path,
+    resolution: int,
+    highway_types: Optional[Sequence[str]] = None,
+    tags: Sequence[str] = ("name",),
+    batch_size: int = 10_000,
+) -> Iterator[RecordBatch]:
+    """
+    Convert the highway ways of an OSM extract to the cells traversed by them.
+
+    :param path: Path of the ``.osm.pbf`` or ``.osm`` file.
+    :param resolution: H3 resolution
+    :param highway_types: Values of the ``highway`` tag to convert - for example ``["motorway", "primary"]``.
+        Defaults to all ways having a ``highway`` tag.
+    :param tags: Further tags to carry through to the cells. Only the tags which are attribute fields of the
+        ``lines`` layer of the OGR OSM driver - configured in its ``osmconf.ini`` - are available. All other tags
+        are found in the ``other_tags`` field.
+    :param batch_size: Number of ways to convert at once.
+    :return: Iterator of record batches with the columns `osm_id`, `highway`, the columns of `tags` and `cell`.
+    """
+    if batch_size < 1:
+        raise ValueError("batch_size must be larger than 0")
+    where = _highway_filter(highway_types)
+    fields = ["osm_id", "highway", *[tag for tag in tags if tag not in ("osm_id", "highway")]]
+    return _read_lines(path, resolution, fields, where, batch_size)
+
+
+def _read_lines(path, resolution: int, fields, where: str, batch_size: int) -> Iterator[RecordBatch]:
+    import pyarrow as pa
+    from osgeo import gdal, ogr
+
+    gdal.UseExceptions()
+    dataset = ogr.Open(str(path))
+    if dataset is None:
+        raise IOError(f"unable to open {path}")
+    lines = dataset.GetLayerByName("lines")
+    if lines is None:
+        raise ValueError(f"{path} has no lines layer - it is not read using the OSM driver")
+    definition = lines.GetLayerDefn()
+    available = [definition.GetFieldDefn(i).GetName() for i in range(definition.GetFieldCount())]
+    missing = [field for field in fields if field not in available]
+    if missing:
+        raise ValueError(f"tags not available as fields of the lines layer: {', '.join(missing)}")
+
+    def convert(geometries, attributes):
+        # all fields of the OSM driver are strings
+        attributes = pa.table({field: pa.array(values, type=pa.string()) for field, values in attributes.items()})
+        return geometries_to_cells_with_attributes(pa.array(geometries, type=pa.binary()), attributes, resolution)
+
+    geometries = []
+    attributes = {field: [] for field in fields}
+    lines.SetAttributeFilter(where)
+    lines.ResetReading()
+    for feature in lines:
+        geometry = feature.GetGeometryRef()
+        if geometry is None:
+            continue
+        geometries.append(bytes(geometry.ExportToIsoWkb()))
+        for field in fields:
+            attributes[field].append(feature.GetField(field))
+
+        if len(geometries) >= batch_size:
+            yield convert(geometries, attributes)
+            geometries = []
+            attributes = {field: [] for field in fields}
+    if geometries:
+        yield convert(geometries, attributes)
+    del dataset
+
+
+__all__ = [
+    osm_highways_to_cells.__name__,
+]
@@ -1,9 +1,13 @@
 """
 API to use `h3ronpy` with the `pandas dataframe library <https://pandas.pydata.org>`_ including `geopandas <https://geopandas.org>`_.
 
+Importing this module registers the ``h3`` accessor with pandas ``Series``, see `h3ronpy.pandas.accessor`.
+
 .. warning::
 
     To avoid pulling in unused dependencies, `h3ronpy` does not declare a dependency to `pandas` and `geopandas`. These
     packages need to be installed separately.
 
 """
+
+from . import accessor  # noqa: F401
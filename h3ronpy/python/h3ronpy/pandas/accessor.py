@@ -0,0 +1,90 @@
+"""
+The ``h3`` accessor of pandas ``Series`` containing H3 cells - registered when importing ``h3ronpy.pandas``.
+
+.. code-block:: python
+
+    import h3ronpy.pandas  # noqa: F401
+
+    df["parent"] = df["cell"].h3.to_parent(7)
+    df[["lat", "lng"]] = df["cell"].h3.latlng()
+
+Series of the ``uint64`` dtype are passed to the Rust kernels without copying them. The results are aligned with
+the index of the series.
+"""
+
+import numpy as np
+import pandas as pd
+import pyarrow as pa
+import pyarrow.compute as pc
+
+import h3ronpy
+import h3ronpy.vector as _hv
+
+
+def _to_series(arr, like: pd.Series, name=None) -> pd.Series:
+    return pd.Series(pa.array(arr).to_pandas(), index=like.index, name=name)
+
+
+@pd.api.extensions.register_series_accessor("h3")
+class H3SeriesAccessor:
+    """
+    H3 functionality for pandas Series containing cells.
+
+    The methods mirror the functions of the ``h3ronpy`` module. Please refer to them for more documentation.
+    """
+
+    def __init__(self, series: pd.Series):
+        self._series = series
+
+    def _cells(self):
+        if self._series.dtype == np.uint64:
+            return self._series.to_numpy()
+        # nullable and object dtypes
+        return pa.array(self._series, type=pa.uint64(), from_pandas=True)
+
+    def is_valid(self) -> pd.Series:
+        """Check which values are valid cells."""
+        return _to_series(h3ronpy.cells_valid(self._cells(), booleanarray=True), self._series, "is_valid")
+
+    def resolution(self) -> pd.Series:
+        return _to_series(h3ronpy.cells_resolution(self._cells()), self._series, "resolution")
+
+    def to_parent(self, resolution: int) -> pd.Series:
+        """
+        The parent of each cell at `resolution`. `resolution` must not be finer than the resolutions of the
+        cells.
+        """
+        cells = self._cells()
+        min_resolution = pc.min(pa.array(h3ronpy.cells_resolution(cells))).as_py()
+        if min_resolution is not None and resolution > min_resolution:
+            raise ValueError("resolution must not be finer than the resolutions of the cells")
+        # a single parent per list, null for null cells
+        parents = pc.list_element(pa.array(h3ronpy.change_resolution_list(cells, resolution)), 0)
+        return _to_series(parents, self._series, "parent")
+
+    def to_string(self) -> pd.Series:
+        return _to_series(h3ronpy.cells_to_string(self._cells()), self._series, self._series.name)
+
+    def latlng(self) -> pd.DataFrame:
+        """The coordinates of the cell centroids as a dataframe with the columns ``lat`` and ``lng`` in degrees."""
+        df = pa.record_batch(_hv.cells_to_coordinates(self._cells())).to_pandas()
+        df.index = self._series.index
+        return df
+
+    def area_km2(self) -> pd.Series:
+        return _to_series(h3ronpy.cells_area_km2(self._cells()), self._series, "area_km2")
+
+    def area_m2(self) -> pd.Series:
+        return _to_series(h3ronpy.cells_area_m2(self._cells()), self._series, "area_m2")
+
+    def grid_disk(self, k: int) -> pd.Series:
+        """The cells within grid distance `k` of each cell as a series of lists."""
+        return _to_series(h3ronpy.grid_disk(self._cells(), k), self._series, self._series.name)
+
+    def to_polygons(self):
+        """The cell polygons as a ``GeoSeries``. Requires ``geopandas``."""
+        from .vector import cells_to_polygons
+
+        geoseries = cells_to_polygons(self._cells())
+        geoseries.index = self._series.index
+        return geoseries
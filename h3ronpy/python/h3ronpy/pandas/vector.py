@@ -77,6 +77,7 @@ def geodataframe_to_cells(
     containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
     compact: bool = False,
     cell_column_name: str = DEFAULT_CELL_COLUMN_NAME,
+    simplify_tolerance_m: Optional[float] = None,
 ) -> pd.DataFrame:
     """
     Convert a `GeoDataFrame` to H3 cells while exploding all other columns according to the number of cells derived
@@ -94,6 +95,8 @@ def geodataframe_to_cells(
     :param compact: Compact the returned cells by replacing cells with their parent cells when all children
             of that cell are part of the set.
     :param cell_column_name:
+    :param simplify_tolerance_m: Simplify the rings of polygons before converting them to cells. See
+            ``h3ronpy.vector.wkb_to_cells``.
     :return:
     """
     cells = _hv.wkb_to_cells(
@@ -102,6 +105,7 @@ def geodataframe_to_cells(
         containment_mode=containment_mode,
         compact=compact,
         flatten=False,
+        simplify_tolerance_m=simplify_tolerance_m,
     )
     table = pa.Table.from_pandas(pd.DataFrame(gdf.drop(columns=gdf.geometry.name))).append_column(
         cell_column_name, cells
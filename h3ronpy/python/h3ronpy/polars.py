@@ -26,8 +26,7 @@ def _wrap(func: typing.Callable[..., ArrowArrayExportable]):
     def wrapper(*args, **kw):
         # This _should_ always be a contiguous single-chunk Series already, because
         # we're inside map_batches. So combine_chunks should be free.
-        ca = ChunkedArray.from_arrow(args[0])
-        array = ca.combine_chunks()
+        array = h3ronpy._combine_chunks(ChunkedArray.from_arrow(args[0]))
         new_args = list(args)
         new_args[0] = array
         result = func(*new_args, **kw)
@@ -66,6 +65,10 @@ class H3Expr:
     def change_resolution_list(self, resolution: int) -> pl.Expr:
         return self.__expr_map_series(lambda s: h3ronpy.change_resolution_list(s, resolution))
 
+    def filter_by_resolution(self, resolutions: typing.Iterable[int]) -> pl.Expr:
+        resolutions = list(resolutions)
+        return self.__expr_map_series(lambda s: h3ronpy.filter_by_resolution(s, resolutions))
+
     def cells_parse(self, set_failing_to_invalid: bool = False) -> pl.Expr:
         return self.__expr_map_series(
             lambda s: h3ronpy.cells_parse(s, set_failing_to_invalid=set_failing_to_invalid)
@@ -139,6 +142,9 @@ class H3SeriesShortcuts:
     def change_resolution_list(self, resolution: int) -> pl.Series:
         return _wrap(h3ronpy.change_resolution_list)(self._s, resolution)
 
+    def filter_by_resolution(self, resolutions: typing.Iterable[int]) -> pl.Series:
+        return _wrap(h3ronpy.filter_by_resolution)(self._s, list(resolutions))
+
     def cells_parse(self, set_failing_to_invalid: bool = False) -> pl.Series:
         return _wrap(h3ronpy.cells_parse)(self._s, set_failing_to_invalid=set_failing_to_invalid)
 
@@ -0,0 +1,134 @@
+"""
+Writing of cell tables together with aggregated overview tables at coarser resolutions - a resolution pyramid.
+
+Viewers can render the overviews when zoomed out instead of loading all cells of the full resolution. Each
+overview table contains the parent cells at its resolution, the number of cells of the full resolution table within
+each parent (``count``) and the mean of each value column (``<column>_mean``).
+
+The tables are written next to each other:
+
+* Parquet: one file per table in the directory `path` - ``<layer>.parquet`` for the cells and
+  ``<layer>_r<resolution>.parquet`` for the overviews.
+* OGR datasets - for example GeoPackages: one layer per table, named like the Parquet files without the extension.
+
+Requires ``pyarrow`` to be installed. Writing to OGR datasets additionally requires the GDAL python bindings.
+"""
+
+import os
+from typing import List, Optional, Sequence
+
+import pyarrow as pa
+import pyarrow.compute as pc
+
+from . import DEFAULT_CELL_COLUMN_NAME, aggregate_by, cells_resolution
+
+#: Accepted values of the `if_exists` parameter
+IF_EXISTS_MODES = ("fail", "replace")
+
+
+def overview_resolutions_for(resolution: int, levels: int = 3, step: int = 2) -> List[int]:
+    """
+    The resolutions of `levels` overviews for cells of `resolution`, each `step` resolutions coarser than the
+    previous one. Resolutions coarser than 0 are omitted.
+    """
+    return [r for r in range(resolution - step, resolution - step * (levels + 1), -step) if r >= 0]
+
+
+def _overview_name(layer: str, resolution: int) -> str:
+    return f"{layer}_r{resolution}"
+
+
+def write_pyramid(
+    table,
+    path,
+    layer: str = "cells",
+    driver: str = "parquet",
+    overview_resolutions: Optional[Sequence[int]] = None,
+    value_columns: Optional[List[str]] = None,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    if_exists: str = "fail",
+    **kw,
+) -> List[str]:
+    """
+    Write the cells of `table` together with overview tables aggregated to coarser resolutions.
+
+    Example: ``write_pyramid(table, "out.gpkg", driver="GPKG", overview_resolutions=[6, 4])``.
+
+    :param table: The table/dataframe to write.
+    :param path: The directory to write the Parquet files to, or the path of the OGR dataset.
+    :param layer: Name of the table with the cells, the overviews are named ``<layer>_r<resolution>``.
+    :param driver: ``parquet`` or the name of an OGR driver, for example ``GPKG``.
+    :param overview_resolutions: The resolutions of the overviews. Defaults to three overviews, each two
+        resolutions coarser than the previous one - see `overview_resolutions_for`. All resolutions must be coarser
+        than the coarsest resolution of the cells.
+    :param value_columns: Names of the numeric columns to aggregate in the overviews. Defaults to all numeric columns
+        except the cell column.
+    :param cell_column: Name of the column containing the cells.
+    :param if_exists: What to do when a table already exists: ``fail`` or ``replace`` it. Appending is not supported
+        as the overviews are aggregated from the given cells only.
+    :param kw: Additional keyword arguments passed on to ``pyarrow.parquet.write_table``, respectively
+        `h3ronpy.ogr.write_to_ogr_dataset`.
+    :return: The names of the written tables - the cells first, followed by the overviews from the finest to the
+        coarsest resolution.
+    """
+    if if_exists not in IF_EXISTS_MODES:
+        raise ValueError(f"unsupported if_exists mode {if_exists}, expected one of {', '.join(IF_EXISTS_MODES)}")
+    table = pa.table(table)
+    if cell_column not in table.column_names:
+        raise ValueError(f"cell column {cell_column!r} not found")
+    if value_columns is None:
+        value_columns = [
+            field.name
+            for field in table.schema
+            if field.name != cell_column and (pa.types.is_integer(field.type) or pa.types.is_floating(field.type))
+        ]
+
+    min_resolution = pc.min(pa.array(cells_resolution(table[cell_column]))).as_py()
+    if min_resolution is None:
+        raise ValueError("the table contains no cells")
+    if overview_resolutions is None:
+        overview_resolutions = overview_resolutions_for(min_resolution)
+    overview_resolutions = sorted(set(overview_resolutions), reverse=True)
+    for resolution in overview_resolutions:
+        if not 0 <= resolution < min_resolution:
+            raise ValueError(
+                f"overview resolution {resolution} is not coarser than the coarsest resolution of the cells "
+                f"({min_resolution})"
+            )
+
+    tables = {layer: table}
+    for resolution in overview_resolutions:
+        tables[_overview_name(layer, resolution)] = aggregate_by(
+            table,
+            resolution,
+            aggs={column: "mean" for column in value_columns},
+            cell_column=cell_column,
+        )
+
+    if driver.lower() == "parquet":
+        _write_parquet(tables, path, if_exists, **kw)
+    else:
+        from .ogr import write_to_ogr_dataset
+
+        for name, t in tables.items():
+            write_to_ogr_dataset(t, path, layer=name, driver=driver, cell_column=cell_column, if_exists=if_exists, **kw)
+    return list(tables.keys())
+
+
+def _write_parquet(tables, path, if_exists: str, **kw):
+    import pyarrow.parquet as pq
+
+    os.makedirs(path, exist_ok=True)
+    paths = {name: os.path.join(path, f"{name}.parquet") for name in tables.keys()}
+    if if_exists == "fail":
+        for file_path in paths.values():
+            if os.path.exists(file_path):
+                raise ValueError(f"{file_path} already exists")
+    for name, t in tables.items():
+        pq.write_table(t, paths[name], **kw)
+
+
+__all__ = [
+    overview_resolutions_for.__name__,
+    write_pyramid.__name__,
+]
@@ -21,6 +21,10 @@ of the cell into account. When the data shall be aggregated, use any of these me
 2. Scale the raster down using an interpolation algorithm. After that use method 1. This can save a lot of memory, but may
    not be applicable to all datasets - for example dataset with absolute values per pixel like population counts.
 
+This module is only available when h3ronpy was built with the ``raster`` cargo feature - which is the case
+for the published wheels. Functions interacting with GDAL-based libraries like `rasterize_cells` additionally
+require ``rasterio`` to be installed, for example using ``pip install h3ronpy[raster]``.
+
 Resolution search modes of `nearest_h3_resolution`:
 
 * "min_diff": chose the H3 resolution where the difference in the area of a pixel and the h3index is as small as possible.
@@ -28,14 +32,28 @@ Resolution search modes of `nearest_h3_resolution`:
 
 """
 
+import json
 import typing
 
 import numpy as np
 import pyarrow as pa
+from arro3.core import DataType, RecordBatch, Schema
+
+from h3ronpy import (
+    DEFAULT_CELL_COLUMN_NAME,
+    _to_arrow_array,
+    _to_uint64_array,
+    cells_resolution,
+    change_resolution,
+)
+from h3ronpy.vector import cells_bounds, cells_bounds_arrays, cells_to_wkb_polygons, coordinates_to_cells
 
-from h3ronpy import DEFAULT_CELL_COLUMN_NAME, _to_arrow_array, _to_uint64_array
-from h3ronpy.h3ronpyrs import raster
-from h3ronpy.vector import cells_bounds, cells_to_wkb_polygons
+try:
+    from h3ronpy.h3ronpyrs import raster
+except ImportError as e:
+    raise ImportError(
+        "h3ronpy was built without raster support. Rebuild it with the 'raster' cargo feature enabled to use h3ronpy.raster."
+    ) from e
 
 try:
     # affine library is used by rasterio
@@ -47,6 +65,12 @@ except ImportError:
 
 Transform = raster.Transform
 
+#: Field metadata key of the JSON-encoded mapping of class values to their labels
+CATEGORIES_METADATA_KEY = "h3ronpy:categories"
+
+#: Field metadata key of the JSON-encoded mapping of class values to their colors as hex strings
+COLORS_METADATA_KEY = "h3ronpy:colors"
+
 
 def _get_transform(t):
     if isinstance(t, Transform):
@@ -74,16 +98,167 @@ def nearest_h3_resolution(shape, transform, axis_order="yx", search_mode="min_di
     return raster.nearest_h3_resolution(shape, _get_transform(transform), axis_order, search_mode)
 
 
-def raster_to_dataframe(
+def _raster_to_h3_func(dtype):
+    if dtype == np.uint8:
+        return raster.raster_to_h3_u8
+    elif dtype == np.int8:
+        return raster.raster_to_h3_i8
+    elif dtype == np.uint16:
+        return raster.raster_to_h3_u16
+    elif dtype == np.int16:
+        return raster.raster_to_h3_i16
+    elif dtype == np.uint32:
+        return raster.raster_to_h3_u32
+    elif dtype == np.int32:
+        return raster.raster_to_h3_i32
+    elif dtype == np.uint64:
+        return raster.raster_to_h3_u64
+    elif dtype == np.int64:
+        return raster.raster_to_h3_i64
+    elif dtype == np.float32:
+        return raster.raster_to_h3_f32
+    elif dtype == np.float64:
+        return raster.raster_to_h3_f64
+    else:
+        raise NotImplementedError(f"no raster_to_h3 implementation for dtype {dtype.name}")
+
+
+def _value_metadata(categories, colors) -> typing.Optional[typing.Dict[str, str]]:
+    metadata = {}
+    if categories:
+        metadata[CATEGORIES_METADATA_KEY] = json.dumps({str(int(k)): str(v) for k, v in categories.items()})
+    if colors:
+        metadata[COLORS_METADATA_KEY] = json.dumps(
+            {str(int(k)): "#" + "".join(f"{int(c):02x}" for c in color) for k, color in colors.items()}
+        )
+    return metadata or None
+
+
+def _cast_raster(in_raster: np.ndarray, nodata_value, value_dtype):
+    """
+    Cast the raster and the nodata value to `value_dtype`. Casting the nodata value as well
+    ensures it still matches the - possibly less precise - values of the cast raster.
+    """
+    if value_dtype is None:
+        return in_raster, nodata_value
+    value_dtype = np.dtype(value_dtype)
+    in_raster = in_raster.astype(value_dtype, copy=False)
+    if nodata_value is not None:
+        nodata_value = np.array(nodata_value).astype(value_dtype).item()
+    return in_raster, nodata_value
+
+
+class ConvertedRaster:
+    """
+    The cells and values resulting from converting a raster.
+
+    The conversion itself has already been performed, but all exports to other representations - including
+    the generation of geometries - only happen when the corresponding method is called. This allows
+    inspecting the result before paying for its materialization.
+    """
+
+    def __init__(self, inner: raster.ConvertedRaster):
+        self._inner = inner
+
+    def __len__(self) -> int:
+        return len(self._inner)
+
+    @property
+    def n_cells(self) -> int:
+        """The number of cells"""
+        return self._inner.n_cells
+
+    @property
+    def resolutions(self) -> typing.List[int]:
+        """The distinct H3 resolutions of the cells in ascending order"""
+        return self._inner.resolutions
+
+    @property
+    def coverage_mask(self) -> typing.Optional[np.ndarray]:
+        """
+        A ``uint8`` array of the shape of the input raster marking the pixels which contributed to any cell
+        with 1. This allows checking for gaps caused by the sampling of the pixels at the cell centroids or
+        the nodata configuration. Only available when `convert_raster` was called with ``coverage_mask=True``.
+        """
+        return self._inner.coverage_mask
+
+    @property
+    def attribute_schema(self) -> Schema:
+        """The schema of the attributes stored for each cell"""
+        return self._inner.attribute_schema
+
+    def to_arrow(self) -> RecordBatch:
+        """
+        Materialize as a record batch with the columns ``value`` and ``cell``.
+        """
+        return self._inner.to_arrow()
+
+    def to_geopandas(self):
+        """
+        Materialize as a geopandas ``GeoDataFrame`` including the cell polygons.
+
+        Requires ``geopandas`` and ``pyarrow`` to be installed.
+        """
+        from .pandas.vector import cells_dataframe_to_geodataframe
+
+        return cells_dataframe_to_geodataframe(pa.table(self.to_arrow()).to_pandas())
+
+    def write_parquet(self, path, **kw):
+        """
+        Write the cells and values to a parquet file.
+
+        Additional keyword arguments are passed on to ``pyarrow.parquet.write_table``.
+        """
+        import pyarrow.parquet as pq
+
+        pq.write_table(pa.table(self.to_arrow()), path, **kw)
+
+    def write_ogr(self, path, layer: typing.Optional[str] = None, driver: typing.Optional[str] = None, **kw):
+        """
+        Write the cells - including their polygons - and values to a dataset supported by OGR.
+
+        Additional keyword arguments are passed on to ``geopandas.GeoDataFrame.to_file``.
+        """
+        self.to_geopandas().to_file(path, layer=layer, driver=driver, **kw)
+
+    def write_to_sql(self, connection_uri: str, table: str, batch_size: int = 100_000, if_exists: str = "fail"):
+        """
+        Write the cells and values into a database table - see ``h3ronpy.sql`` for the supported databases.
+
+        The rows are written in batches of `batch_size` rows, so neither a complete table nor any geometries are
+        materialized. The cells are stored as integers.
+
+        :param connection_uri: URI of the database, for example ``sqlite:///cells.db`` or ``duckdb:///cells.duckdb``.
+        :param table: Name of the table to write to.
+        :param batch_size: Number of rows to write at once.
+        :param if_exists: What to do when the table already exists: ``fail``, ``replace`` it or ``append`` to it.
+        """
+        from .sql import write_batches_to_sql
+
+        write_batches_to_sql(
+            self._inner.to_batches(batch_size),
+            pa.schema(self.attribute_schema).append(pa.field(DEFAULT_CELL_COLUMN_NAME, pa.uint64())),
+            connection_uri,
+            table,
+            if_exists=if_exists,
+        )
+
+
+def convert_raster(
     in_raster: np.ndarray,
     transform,
     h3_resolution: int,
     nodata_value=None,
     axis_order: str = "yx",
     compact: bool = True,
-) -> pa.Table:
+    value_dtype=None,
+    coverage_mask: bool = False,
+    categories: typing.Optional[typing.Mapping[int, str]] = None,
+    colors: typing.Optional[typing.Mapping[int, typing.Tuple[int, ...]]] = None,
+    category_metadata: typing.Optional[typing.Mapping[int, str]] = None,
+) -> ConvertedRaster:
     """
-    Convert a raster/array to a pandas `DataFrame` containing H3 cell indexes
+    Convert a raster/array to H3 cells.
 
     This function is parallelized and uses the available CPUs by distributing tiles to a thread pool.
 
@@ -96,49 +271,360 @@ def raster_to_dataframe(
     :param h3_resolution: Target h3 resolution
     :param compact: Return compacted h3 indexes (see H3 docs). This results in mixed H3 resolutions, but also can
             reduce the amount of required memory.
-    :return: Tuple of arrow arrays
+    :param value_dtype: Numpy dtype to cast the values to before the conversion - for example ``np.float32``
+            to halve the memory requirements of ``float64`` rasters. By default, the dtype of the input is preserved.
+    :param coverage_mask: Also build a mask of the pixels which contributed to any cell, see
+            `ConvertedRaster.coverage_mask`.
+    :param categories: For categorical rasters like land cover: A mapping of the class values to their labels.
+            When given, the ``value`` column is a dictionary array of the labels instead of the numeric codes.
+            Values without a label raise a ``ValueError``.
+    :param colors: A mapping of class values to RGB(A) tuples - like the color table of a classified raster.
+            Stored in the field metadata of the ``value`` column under `COLORS_METADATA_KEY`.
+    :param category_metadata: A mapping of class values to labels which is only stored in the field metadata of the
+            ``value`` column under `CATEGORIES_METADATA_KEY` while keeping the numeric codes as values. Labels passed
+            using `categories` are always stored there. See `read_raster_categories` to obtain both from the raster
+            attribute table and color table of a file.
+    :return: ConvertedRaster
     """
-
-    dtype = in_raster.dtype
-    func = None
-    if dtype == np.uint8:
-        func = raster.raster_to_h3_u8
-    elif dtype == np.int8:
-        func = raster.raster_to_h3_i8
-    elif dtype == np.uint16:
-        func = raster.raster_to_h3_u16
-    elif dtype == np.int16:
-        func = raster.raster_to_h3_i16
-    elif dtype == np.uint32:
-        func = raster.raster_to_h3_u32
-    elif dtype == np.int32:
-        func = raster.raster_to_h3_i32
-    elif dtype == np.uint64:
-        func = raster.raster_to_h3_u64
-    elif dtype == np.int64:
-        func = raster.raster_to_h3_i64
-    elif dtype == np.float32:
-        func = raster.raster_to_h3_f32
-    elif dtype == np.float64:
-        func = raster.raster_to_h3_f64
-    else:
-        raise NotImplementedError(f"no raster_to_h3 implementation for dtype {dtype.name}")
-
-    return pa.Table.from_arrays(
-        arrays=func(
+    in_raster, nodata_value = _cast_raster(in_raster, nodata_value, value_dtype)
+    func = _raster_to_h3_func(in_raster.dtype)
+    return ConvertedRaster(
+        func(
             in_raster,
             _get_transform(transform),
             h3_resolution,
             axis_order,
             compact,
             nodata_value,
-        ),
-        names=["value", DEFAULT_CELL_COLUMN_NAME],
+            coverage_mask=coverage_mask,
+            categories=None if categories is None else {int(k): str(v) for k, v in categories.items()},
+            value_metadata=_value_metadata(categories or category_metadata, colors),
+        )
+    )
+
+
+def _read_window(raster_path, band: int, bounds: typing.Tuple[float, float, float, float]):
+    """
+    Read the pixels of a band covering the bounds. Returns the array, its rasterio-style transform and the nodata
+    value of the file.
+
+    At least one pixel is read, so bounds outside of the raster result in an array of the dtype of the band whose
+    cells are located outside of the bounds.
+    """
+    try:
+        import rasterio
+        from rasterio.windows import Window
+    except ImportError:
+        rasterio = None
+
+    if rasterio is not None:
+        dataset = rasterio.open(raster_path)
+        t = dataset.transform
+        coefficients = [t.a, t.b, t.c, t.d, t.e, t.f]
+        shape = (dataset.height, dataset.width)
+    else:
+        # the pure-rust reader does not support windowed reads, so the whole band is read
+        band_array, transform, nodata = read_geotiff(raster_path, band)
+        coefficients = transform.to_rasterio()
+        shape = band_array.shape
+
+    a, b, c, d, e, f = coefficients
+    if b != 0.0 or d != 0.0:
+        raise ValueError("rotated rasters are not supported")
+    minx, miny, maxx, maxy = bounds
+    cols = sorted(((minx - c) / a, (maxx - c) / a))
+    rows = sorted(((maxy - f) / e, (miny - f) / e))
+    col0 = min(max(int(np.floor(cols[0])), 0), shape[1] - 1)
+    col1 = max(min(int(np.ceil(cols[1])), shape[1]), col0 + 1)
+    row0 = min(max(int(np.floor(rows[0])), 0), shape[0] - 1)
+    row1 = max(min(int(np.ceil(rows[1])), shape[0]), row0 + 1)
+
+    if rasterio is not None:
+        with dataset:
+            window_array = dataset.read(band, window=Window(col0, row0, col1 - col0, row1 - row0))
+            nodata = dataset.nodata
+    else:
+        window_array = band_array[row0:row1, col0:col1]
+    return window_array, [a, b, c + col0 * a, d, e, f + row0 * e], nodata
+
+
+def convert_partition(
+    raster_path,
+    parent_cell: int,
+    resolution: int,
+    band: int = 1,
+    nodata_value=None,
+    value_dtype=None,
+    categories: typing.Optional[typing.Mapping[int, str]] = None,
+) -> pa.Table:
+    """
+    Convert the part of a raster file located within a parent cell to H3 cells - the per-worker entry point
+    for splitting a global raster among distributed workers, for example using Spark, Dask or ray. See
+    `h3ronpy.vector.partition_globe` to obtain the parent cells.
+
+    Only the pixels within the bounds of the parent cell are read. Of the converted cells only the children of
+    `parent_cell` are returned, so cells along the borders of the parents are returned by exactly one worker and
+    the results of all parents can be concatenated without duplicates or gaps. The cells are not compacted, as
+    compacting across the borders of the parents would not be deterministic.
+
+    The raster must be in WGS84 and must not be rotated. Reading windows requires ``rasterio``, without it the band
+    is read completely using `read_geotiff`.
+
+    :param raster_path: Path of the raster file
+    :param parent_cell: The parent cell of this partition
+    :param resolution: Target h3 resolution - must not be coarser than the resolution of `parent_cell`.
+    :param band: Number of the band to read, starting at 1
+    :param nodata_value: The nodata value. Defaults to the nodata value of the file.
+    :param value_dtype: Numpy dtype to cast the values to, see `convert_raster`.
+    :param categories: A mapping of class values to labels, see `convert_raster`.
+    :return: Table with the columns ``value`` and ``cell``.
+    """
+    parent_cell = int(parent_cell)
+    parent = _to_uint64_array([parent_cell])
+    parent_resolution = int(cells_resolution(parent).to_numpy()[0])
+    if resolution < parent_resolution:
+        raise ValueError("resolution must not be coarser than the resolution of the parent cell")
+
+    bounds = pa.record_batch(cells_bounds_arrays(parent))
+    minx, miny, maxx, maxy = (bounds.column(name)[0].as_py() for name in ("minx", "miny", "maxx", "maxy"))
+    # descendants protrude slightly beyond the boundary of their parent
+    margin_y = 0.5 * (maxy - miny)
+    margin_x = 0.5 * ((maxx - minx) % 360.0)
+    miny, maxy = max(miny - margin_y, -90.0), min(maxy + margin_y, 90.0)
+    if minx > maxx:
+        # crossing the antimeridian
+        parts = [(minx - margin_x, miny, 180.0, maxy), (-180.0, miny, maxx + margin_x, maxy)]
+    else:
+        parts = [(minx - margin_x, miny, maxx + margin_x, maxy)]
+
+    tables = []
+    for part in parts:
+        window_array, coefficients, file_nodata = _read_window(raster_path, band, part)
+        converted = convert_raster(
+            window_array,
+            Transform.from_rasterio(coefficients),
+            resolution,
+            nodata_value=file_nodata if nodata_value is None else nodata_value,
+            compact=False,
+            value_dtype=value_dtype,
+            categories=categories,
+        )
+        table = pa.table(converted.to_arrow())
+        cells = table[DEFAULT_CELL_COLUMN_NAME]
+        parents = change_resolution(cells, parent_resolution).to_numpy()
+        tables.append(table.filter(pa.array(parents == parent_cell)))
+    return pa.concat_tables(tables)
+
+
+def bin_points_weighted_by_raster(
+    lat,
+    lng,
+    resolution: int,
+    raster_path,
+    band: int = 1,
+    nodata_value=None,
+) -> RecordBatch:
+    """
+    Bin points to cells, weighting each point with the value of the raster pixel at its location - for example to
+    build exposure-weighted heatmaps without a separate sampling stage.
+
+    Only the pixels within the bounds of the points are read. Points located outside of the raster or on nodata
+    pixels are skipped. The raster must be in WGS84 and must not be rotated. Reading windows requires ``rasterio``,
+    without it the band is read completely using `read_geotiff`.
+
+    :param lat: Array of the latitudes of the points in degrees
+    :param lng: Array of the longitudes of the points in degrees
+    :param resolution: H3 resolution
+    :param raster_path: Path of the raster file
+    :param band: Number of the band to read, starting at 1
+    :param nodata_value: The nodata value. Defaults to the nodata value of the file.
+    :return: Record batch with the columns `cell`, `count` - the number of weighted points - and `weight` - the sum
+        of their weights. The weights are ``float32`` for ``float32`` rasters and ``float64`` otherwise.
+    """
+    lat = np.asarray(_to_arrow_array(lat, DataType.float64()).to_numpy(), dtype=np.float64)
+    lng = np.asarray(_to_arrow_array(lng, DataType.float64()).to_numpy(), dtype=np.float64)
+    if len(lat) != len(lng):
+        raise ValueError("lat and lng must be of the same length")
+
+    weights = np.full(len(lat), np.nan)
+    weight_dtype = np.float64
+    finite = np.isfinite(lat) & np.isfinite(lng)
+    if finite.any():
+        # pad the bounds to include the pixels of points located on the border of a pixel
+        bounds = (lng[finite].min(), lat[finite].min(), lng[finite].max(), lat[finite].max())
+        window_array, (a, _, c, _, e, f), file_nodata = _read_window(
+            raster_path, band, (bounds[0] - 1e-9, bounds[1] - 1e-9, bounds[2] + 1e-9, bounds[3] + 1e-9)
+        )
+        if window_array.dtype == np.float32:
+            weight_dtype = np.float32
+        cols = np.floor((lng - c) / a)
+        rows = np.floor((lat - f) / e)
+        inside = finite & (cols >= 0) & (cols < window_array.shape[1]) & (rows >= 0) & (rows < window_array.shape[0])
+        weights[inside] = window_array[rows[inside].astype(np.intp), cols[inside].astype(np.intp)]
+        nodata = file_nodata if nodata_value is None else nodata_value
+        if nodata is not None:
+            weights[weights == nodata] = np.nan
+
+    valid = ~np.isnan(weights)
+    cells = coordinates_to_cells(pa.array(lat[valid]), pa.array(lng[valid]), resolution).to_numpy()
+    unique_cells, inverse = np.unique(cells, return_inverse=True)
+    return RecordBatch.from_arrow(
+        pa.record_batch(
+            {
+                DEFAULT_CELL_COLUMN_NAME: pa.array(unique_cells, type=pa.uint64()),
+                "count": pa.array(np.bincount(inverse, minlength=len(unique_cells)), type=pa.uint64()),
+                "weight": pa.array(
+                    np.bincount(inverse, weights=weights[valid], minlength=len(unique_cells)).astype(weight_dtype)
+                ),
+            }
+        )
+    )
+
+
+def raster_to_dataframe(
+    in_raster: np.ndarray,
+    transform,
+    h3_resolution: int,
+    nodata_value=None,
+    axis_order: str = "yx",
+    compact: bool = True,
+    value_dtype=None,
+    categories: typing.Optional[typing.Mapping[int, str]] = None,
+) -> pa.Table:
+    """
+    Convert a raster/array to a pandas `DataFrame` containing H3 cell indexes
+
+    This function is parallelized and uses the available CPUs by distributing tiles to a thread pool.
+
+    The input geometry must be in WGS84.
+
+    :param in_raster: Input 2D array
+    :param transform:  The affine transformation
+    :param nodata_value: The nodata value. For these cells of the array there will be no h3 indexes generated
+    :param axis_order: Axis order of the 2d array. Either "xy" or "yx"
+    :param h3_resolution: Target h3 resolution
+    :param compact: Return compacted h3 indexes (see H3 docs). This results in mixed H3 resolutions, but also can
+            reduce the amount of required memory.
+    :param value_dtype: Numpy dtype to cast the values to before the conversion - for example ``np.float32``
+            to halve the memory requirements of ``float64`` rasters. By default, the dtype of the input is preserved.
+    :param categories: A mapping of class values to labels, see `convert_raster`.
+    :return: Tuple of arrow arrays
+    """
+    converted = convert_raster(
+        in_raster,
+        transform,
+        h3_resolution,
+        nodata_value=nodata_value,
+        axis_order=axis_order,
+        compact=compact,
+        value_dtype=value_dtype,
+        categories=categories,
+    )
+    return pa.table(converted.to_arrow())
+
+
+def read_raster_categories(
+    path, band: int = 1
+) -> typing.Tuple[typing.Dict[int, str], typing.Dict[int, typing.Tuple[int, ...]]]:
+    """
+    Read the class labels from the raster attribute table (RAT) - or the category names - and the color table of
+    a band of a classified raster.
+
+    The result can be passed to `convert_raster` as `categories` or `category_metadata` and `colors`. Reading
+    the labels requires the GDAL python bindings (``osgeo``), without them only the color table is read using
+    ``rasterio``.
+
+    :param path: Path of the raster file
+    :param band: Number of the band, starting at 1
+    :return: Tuple of the mapping of class values to labels and the mapping of class values to RGBA tuples. Both are
+            empty when the file does not define them.
+    """
+    try:
+        from osgeo import gdal
+    except ImportError:
+        try:
+            import rasterio
+        except ImportError as e:
+            raise ImportError("read_raster_categories requires the GDAL python bindings or rasterio") from e
+        with rasterio.open(path) as dataset:
+            try:
+                return {}, dict(dataset.colormap(band))
+            except ValueError:
+                # no colormap
+                return {}, {}
+
+    dataset = gdal.Open(str(path))
+    if dataset is None:
+        raise IOError(f"unable to open {path}")
+    raster_band = dataset.GetRasterBand(band)
+
+    categories = {}
+    rat = raster_band.GetDefaultRAT()
+    if rat is not None:
+        value_column, name_column = None, None
+        for column in range(rat.GetColumnCount()):
+            usage = rat.GetUsageOfCol(column)
+            if usage in (gdal.GFU_MinMax, gdal.GFU_Min) and value_column is None:
+                value_column = column
+            elif usage == gdal.GFU_Name and name_column is None:
+                name_column = column
+        if name_column is not None:
+            for row in range(rat.GetRowCount()):
+                value = rat.GetValueAsInt(row, value_column) if value_column is not None else row
+                categories[value] = rat.GetValueAsString(row, name_column)
+    if not categories:
+        categories = {value: name for value, name in enumerate(raster_band.GetCategoryNames() or []) if name}
+
+    colors = {}
+    color_table = raster_band.GetColorTable()
+    if color_table is not None:
+        colors = {value: tuple(color_table.GetColorEntry(value)) for value in range(color_table.GetCount())}
+    return categories, colors
+
+
+def normalize_band(
+    in_raster: np.ndarray,
+    method: str,
+    nodata_value=None,
+    percentiles: typing.Tuple[float, float] = (2.0, 98.0),
+    tile_size: typing.Optional[int] = None,
+) -> np.ndarray:
+    """
+    Normalize the values of a band before converting it using `convert_raster` - for example for imagery
+    whose raw digital numbers need to be made comparable. Multiband rasters are normalized band by band.
+
+    Methods:
+
+    * "percentile_clip": Clip the values to the `percentiles` and scale them to the range 0..1.
+    * "log": Natural logarithm of ``1 + value``. Negative values become NaN.
+    * "standardize": Subtract the mean and divide by the standard deviation.
+
+    :param in_raster: Input 2D array
+    :param method: The normalization method, see above.
+    :param nodata_value: The nodata value. These pixels are excluded from the statistics and become NaN.
+    :param percentiles: Lower and upper percentile used by "percentile_clip".
+    :param tile_size: Calculate the statistics per tile of ``tile_size x tile_size`` pixels instead of over the
+            whole band.
+    :return: 2D ``float32`` array for ``float32`` input, ``float64`` otherwise. Use NaN as `nodata_value` when
+            converting it.
+    """
+    in_raster = np.asarray(in_raster)
+    if in_raster.dtype == np.float32:
+        func = raster.normalize_band_f32
+    else:
+        in_raster = in_raster.astype(np.float64, copy=False)
+        func = raster.normalize_band_f64
+    return func(
+        in_raster,
+        method,
+        nodata_value=None if nodata_value is None else float(nodata_value),
+        percentiles=percentiles,
+        tile_size=tile_size,
     )
 
 
 def rasterize_cells(
-    cells, values, size: typing.Union[int, typing.Tuple[int, int]], nodata_value=0
+    cells, values, size: typing.Union[int, typing.Tuple[int, int]], nodata_value=0, dtype=None
 ) -> typing.Tuple[np.ndarray, typing.Tuple[float, float, float, float, float, float]]:
     """
     Generate a raster numpy array from arrays of cells and values.
@@ -150,12 +636,18 @@ def rasterize_cells(
     :param size: The desired output size of the raster. Maybe a tuple of ints (width, height) or a single int. In case
             of the latter, the other dimension is interpolated from the bounds of the input data.
     :param nodata_value: The nodata value for the output array
-    :return: 2D numpy array typed accordingly to the passed in values array, and the geotransform (WGS84 coordinate
-            system, ordering used by the affine library and rasterio)
+    :param dtype: Numpy dtype of the output array. Defaults to the type of the passed in values array.
+    :return: 2D numpy array typed accordingly to the passed in values array or `dtype`, and the geotransform (WGS84
+            coordinate system, ordering used by the affine library and rasterio)
     """
-    import shapely
-    from rasterio.features import rasterize
-    from rasterio.transform import from_bounds
+    try:
+        import shapely
+        from rasterio.features import rasterize
+        from rasterio.transform import from_bounds
+    except ImportError as e:
+        raise ImportError(
+            "rasterize_cells requires rasterio and shapely. Install them using 'pip install h3ronpy[raster]'."
+        ) from e
 
     cells = _to_uint64_array(cells)
     values = _to_arrow_array(values, None)
@@ -191,7 +683,7 @@ def rasterize_cells(
     del values
 
     values_array = grouped["values"].to_numpy()
-    rasterized = np.full(size, nodata_value, dtype=values_array.dtype)
+    rasterized = np.full(size, nodata_value, dtype=values_array.dtype if dtype is None else dtype)
 
     for cells, value in zip(grouped["cells_distinct"], grouped["values"]):
         value = value.as_py()
@@ -211,3 +703,60 @@ def rasterize_cells(
         )
 
     return rasterized, transform
+
+
+def cells_to_image(
+    cells,
+    values,
+    bbox: typing.Optional[typing.Tuple[float, float, float, float]],
+    width: int,
+    height: int,
+) -> typing.Tuple[np.ndarray, typing.Tuple[float, float, float, float, float, float]]:
+    """
+    Paint the hexagons of the cells into a float64 numpy image - for example for plotting.
+
+    Contrary to `rasterize_cells` this does not require any geometry objects or additional libraries and
+    is suitable for millions of cells. Pixels are painted when their center is located within a cell. Pixels
+    not covered by any cell are NaN. Overlapping cells are painted in the order of the input.
+
+    :param cells: array with H3 cells
+    :param values: array with the numeric values of the cells
+    :param bbox: The area to paint as a tuple of (minx, miny, maxx, maxy) in WGS84 coordinates. When set to None,
+            the bounds of the cells are used.
+    :param width: Width of the image in pixels
+    :param height: Height of the image in pixels
+    :return: 2D numpy array of the shape (height, width) with the northernmost row first, and the geotransform
+            (WGS84 coordinate system, ordering used by the affine library and rasterio)
+    """
+    cells = _to_uint64_array(cells)
+    if bbox is None:
+        bbox = cells_bounds(cells)
+        if bbox is None:
+            raise ValueError("no bbox given and no valid cells to derive it from")
+    minx, miny, maxx, maxy = bbox
+    image = raster.cells_to_image(cells, _to_arrow_array(values, DataType.float64()), bbox, width, height)
+    transform = ((maxx - minx) / width, 0.0, minx, 0.0, -(maxy - miny) / height, maxy)
+    return image, transform
+
+
+def read_geotiff(path, band: int = 1) -> typing.Tuple[np.ndarray, Transform, typing.Optional[float]]:
+    """
+    Read a band of a GeoTIFF without requiring GDAL or rasterio.
+
+    Striped and tiled files using deflate, LZW or PackBits compression are supported. The
+    result can be passed directly to `convert_raster` or `raster_to_dataframe`. Files this reader
+    can not handle need to be read using `rasterio`.
+
+    This function is only available when h3ronpy was built with the ``pure-rust`` feature, which is
+    enabled by default.
+
+    :param path: Path of the GeoTIFF file
+    :param band: Number of the band to read, starting at 1
+    :return: Tuple of the 2D numpy array with the axis order "yx", the `Transform` and the nodata value
+            of the file - `None` when the file does not define one.
+    """
+    if not hasattr(raster, "read_geotiff"):
+        raise NotImplementedError(
+            "h3ronpy was built without the pure-rust GeoTIFF reader (feature 'pure-rust'). Use rasterio instead."
+        )
+    return raster.read_geotiff(str(path), band)
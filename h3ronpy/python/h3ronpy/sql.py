@@ -0,0 +1,261 @@
+"""
+Transfer of cell tables from and to databases.
+
+The database is selected by the scheme of the connection URI:
+
+* ``sqlite:///path/to/file.db`` - SQLite databases using `ADBC <https://arrow.apache.org/adbc/>`_. Requires the
+  ``adbc-driver-sqlite`` package. The written tables are plain attribute tables - use
+  ``h3ronpy.ogr.write_to_ogr_dataset`` to write cells to GeoPackages.
+* ``duckdb:///path/to/file.duckdb`` - DuckDB databases. Requires the ``duckdb`` package.
+* ``postgresql://...`` - PostgreSQL using `ADBC <https://arrow.apache.org/adbc/>`_. Requires the
+  ``adbc-driver-postgresql`` package.
+
+Requires ``pyarrow`` to be installed.
+"""
+
+import itertools
+import os
+from typing import Iterable, Iterator
+from urllib.parse import urlparse
+
+from arro3.core import RecordBatchReader
+
+from . import DEFAULT_CELL_COLUMN_NAME, cells_parse, int64_to_cells
+
+#: Accepted values of the `if_exists` parameter
+IF_EXISTS_MODES = ("fail", "replace", "append")
+
+
+def _scheme(connection_uri: str) -> str:
+    scheme = urlparse(connection_uri).scheme
+    if scheme in ("postgres", "postgresql"):
+        return "postgresql"
+    if scheme in ("sqlite", "duckdb"):
+        return scheme
+    raise ValueError(f"unsupported connection uri: {connection_uri}")
+
+
+def _file_path(connection_uri: str) -> str:
+    # sqlite:///relative.db and sqlite:////absolute.db - following the SQLAlchemy convention
+    path = connection_uri.split("://", 1)[1]
+    return path[1:] if path.startswith("/") else path
+
+
+def _quote_identifier(name: str) -> str:
+    return '"' + name.replace('"', '""') + '"'
+
+
+#: `if_exists` modes mapped to the ingestion modes of ADBC
+_ADBC_INGEST_MODES = {"fail": "create", "replace": "replace", "append": "create_append"}
+
+
+def _int64_reader(batches, schema):
+    import pyarrow as pa
+
+    # neither postgresql nor sqlite have unsigned 64bit integers. Cells always fit into the signed type.
+    int64_schema = pa.schema([field.with_type(pa.int64()) if field.type == pa.uint64() else field for field in schema])
+    return pa.RecordBatchReader.from_batches(int64_schema, (batch.cast(int64_schema) for batch in batches))
+
+
+def _write_adbc_sqlite(batches, schema, path: str, table: str, if_exists: str):
+    import adbc_driver_sqlite.dbapi
+
+    with adbc_driver_sqlite.dbapi.connect(path) as connection:
+        with connection.cursor() as cursor:
+            cursor.execute("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?", (table,))
+            if cursor.fetchone() is not None and if_exists == "fail":
+                raise ValueError(f"table {table} already exists")
+            cursor.adbc_ingest(table, _int64_reader(batches, schema), mode=_ADBC_INGEST_MODES[if_exists])
+        connection.commit()
+
+
+def _write_duckdb(batches, schema, path: str, table: str, if_exists: str):
+    import duckdb
+    import pyarrow as pa
+
+    quoted_table = _quote_identifier(table)
+    with duckdb.connect(path) as connection:
+        exists = (
+            connection.execute(
+                "SELECT 1 FROM information_schema.tables WHERE table_name = ?",
+                [table],
+            ).fetchone()
+            is not None
+        )
+        if exists and if_exists == "fail":
+            raise ValueError(f"table {table} already exists")
+        if not exists or if_exists == "replace":
+            connection.register("_h3ronpy_empty", pa.Table.from_batches([], schema=schema))
+            connection.execute(f"CREATE OR REPLACE TABLE {quoted_table} AS SELECT * FROM _h3ronpy_empty")
+            connection.unregister("_h3ronpy_empty")
+        for batch in batches:
+            connection.register("_h3ronpy_batch", pa.Table.from_batches([batch]))
+            connection.execute(f"INSERT INTO {quoted_table} SELECT * FROM _h3ronpy_batch")
+            connection.unregister("_h3ronpy_batch")
+
+
+def _write_adbc_postgresql(batches, schema, connection_uri: str, table: str, if_exists: str):
+    import adbc_driver_postgresql.dbapi
+
+    with adbc_driver_postgresql.dbapi.connect(connection_uri) as connection:
+        with connection.cursor() as cursor:
+            cursor.adbc_ingest(table, _int64_reader(batches, schema), mode=_ADBC_INGEST_MODES[if_exists])
+        connection.commit()
+
+
+def write_batches_to_sql(batches: Iterable, schema, connection_uri: str, table: str, if_exists: str = "fail"):
+    """
+    Write record batches into a database table - one batch at a time, so the complete table is never
+    materialized.
+
+    :param batches: Iterable of record batches supporting the arrow PyCapsule interface.
+    :param schema: The schema of the batches.
+    :param connection_uri: URI of the database. See the module documentation for the supported databases.
+    :param table: Name of the table to write to.
+    :param if_exists: What to do when the table already exists: ``fail``, ``replace`` it or ``append`` to it.
+    """
+    import pyarrow as pa
+
+    if if_exists not in IF_EXISTS_MODES:
+        raise ValueError(f"unsupported if_exists mode {if_exists}, expected one of {', '.join(IF_EXISTS_MODES)}")
+    schema = pa.schema(schema)
+    batches = (pa.record_batch(batch) for batch in batches)
+
+    scheme = _scheme(connection_uri)
+    if scheme == "sqlite":
+        _write_adbc_sqlite(batches, schema, _file_path(connection_uri), table, if_exists)
+    elif scheme == "duckdb":
+        _write_duckdb(batches, schema, _file_path(connection_uri), table, if_exists)
+    else:
+        _write_adbc_postgresql(batches, schema, connection_uri, table, if_exists)
+
+
+def _batches_or_empty(reader) -> Iterator:
+    empty = True
+    for batch in reader:
+        empty = False
+        yield batch
+    if empty:
+        yield _empty_batch(reader.schema)
+
+
+def _empty_batch(schema):
+    import pyarrow as pa
+
+    return pa.RecordBatch.from_arrays([pa.array([], type=field.type) for field in schema], schema=schema)
+
+
+def _read_adbc_sqlite(query: str, path: str, batch_size: int) -> Iterator:
+    import adbc_driver_sqlite.dbapi
+    from adbc_driver_sqlite import StatementOptions
+
+    with adbc_driver_sqlite.dbapi.connect(path) as connection:
+        with connection.cursor() as cursor:
+            # the driver infers the types of the columns from the values of the first batch
+            cursor.adbc_statement.set_options(**{StatementOptions.BATCH_ROWS.value: str(batch_size)})
+            cursor.execute(query)
+            yield from _batches_or_empty(cursor.fetch_record_batch())
+
+
+def _read_duckdb(query: str, path: str, batch_size: int) -> Iterator:
+    import duckdb
+
+    with duckdb.connect(path, read_only=True) as connection:
+        yield from _batches_or_empty(connection.execute(query).fetch_record_batch(batch_size))
+
+
+def _read_adbc_postgresql(query: str, connection_uri: str) -> Iterator:
+    import adbc_driver_postgresql.dbapi
+
+    with adbc_driver_postgresql.dbapi.connect(connection_uri) as connection:
+        with connection.cursor() as cursor:
+            cursor.execute(query)
+            yield from _batches_or_empty(cursor.fetch_record_batch())
+
+
+def _to_cells(column, set_failing_to_invalid: bool):
+    import pyarrow as pa
+    import pyarrow.compute as pc
+
+    if pa.types.is_null(column.type):
+        return pa.nulls(len(column), type=pa.uint64())
+    if pa.types.is_string(column.type) or pa.types.is_large_string(column.type):
+        return pa.array(cells_parse(column, set_failing_to_invalid=set_failing_to_invalid))
+    if pa.types.is_integer(column.type):
+        try:
+            column = pc.cast(column, pa.int64())
+        except pa.ArrowInvalid as e:
+            # only uint64 values exceeding the int64 range fail, these are never valid cells.
+            raise ValueError(f"invalid cell values: {e}") from e
+        return pa.array(int64_to_cells(column, set_failing_to_invalid=set_failing_to_invalid))
+    raise ValueError(f"unsupported type of the cell column: {column.type}")
+
+
+def _with_cells(batch, cell_column: str, set_failing_to_invalid: bool):
+    import pyarrow as pa
+
+    batch = pa.record_batch(batch)
+    if cell_column not in batch.schema.names:
+        raise ValueError(f"query results do not contain the cell column {cell_column}")
+    position = batch.schema.get_field_index(cell_column)
+    columns = list(batch.columns)
+    columns[position] = _to_cells(columns[position], set_failing_to_invalid)
+    return pa.RecordBatch.from_arrays(columns, names=batch.schema.names)
+
+
+def read_sql(
+    query: str,
+    connection_uri: str,
+    cell_column: str = DEFAULT_CELL_COLUMN_NAME,
+    batch_size: int = 100_000,
+    set_failing_to_invalid: bool = False,
+) -> RecordBatchReader:
+    """
+    Stream the results of a query as record batches with a validated cell column.
+
+    The results are transferred in batches of `batch_size` rows and the cells of each batch are validated on arrival,
+    so the results never need to fit into memory at once. Cells may be stored as integers - signed integers are
+    reinterpreted bit by bit, see ``int64_to_cells`` - or as strings in any of the formats supported by
+    ``cells_parse``.
+
+    The first batch is read right away. Errors in later batches are raised while consuming the reader.
+
+    :param query: The SQL query.
+    :param connection_uri: URI of the database. See the module documentation for the supported databases.
+    :param cell_column: Name of the column containing the cells.
+    :param batch_size: Number of rows to transfer at once. Ignored for PostgreSQL, where the driver
+            decides on the size of the batches.
+    :param set_failing_to_invalid: Set invalid cells to null instead of failing.
+    :return: Reader of record batches with the cell column converted to ``uint64``.
+    """
+    import pyarrow as pa
+
+    scheme = _scheme(connection_uri)
+    if scheme == "sqlite":
+        batches = _read_adbc_sqlite(query, _file_path(connection_uri), batch_size)
+    elif scheme == "duckdb":
+        batches = _read_duckdb(query, _file_path(connection_uri), batch_size)
+    else:
+        batches = _read_adbc_postgresql(query, connection_uri)
+
+    batches = (_with_cells(batch, cell_column, set_failing_to_invalid) for batch in batches)
+    # the schema of the converted batches is only known after converting the first one
+    first = next(batches)
+    return RecordBatchReader.from_arrow(
+        pa.RecordBatchReader.from_batches(first.schema, itertools.chain([first], batches))
+    )
+
+
+def sqlite_uri(path) -> str:
+    """
+    The connection URI of the SQLite database at `path`.
+    """
+    return "sqlite:///" + os.fspath(path)
+
+
+__all__ = [
+    "IF_EXISTS_MODES",
+    write_batches_to_sql.__name__,
+    read_sql.__name__,
+    sqlite_uri.__name__,
+]
@@ -1,30 +1,56 @@
-from typing import Optional, Tuple
+from typing import List, Optional, Tuple, Union
 
-from arro3.core import Array, DataType, RecordBatch
+from arro3.core import Array, ChunkedArray, DataType, RecordBatch, Table
 
 from h3ronpy import ContainmentMode
 
-from . import _to_arrow_array, _to_uint64_array
+from . import _errors_as_table, _to_arrow_array, _to_uint64_array, _validation_skipped, _with_list_offset
+from .device import _device_kernel
 from .h3ronpyrs import vector
 
 
-def cells_to_coordinates(arr, radians: bool = False) -> RecordBatch:
+def cells_to_coordinates(
+    arr, radians: bool = False, assume_valid: bool = False, precision: Optional[int] = None, float32: bool = False
+) -> RecordBatch:
     """
     convert to point coordinates in degrees
+
+    Setting `assume_valid` skips the validation of the input cells, see `h3ronpy.assume_valid()`.
+
+    :param precision: Round the coordinates to this number of decimals. 7 decimals of degrees are
+        roughly 1cm at the equator.
+    :param float32: Return the coordinates as float32 instead of float64.
     """
-    return vector.cells_to_coordinates(_to_uint64_array(arr), radians=radians)
+    with _validation_skipped(assume_valid):
+        return vector.cells_to_coordinates(_to_uint64_array(arr), radians=radians, precision=precision, float32=float32)
 
 
-def coordinates_to_cells(latarray, lngarray, resarray, radians: bool = False) -> Array:
+def coordinates_to_cells(
+    latarray, lngarray, resarray, radians: bool = False, strict: bool = False, errors: Optional[str] = None
+) -> Union[Array, Tuple[Array, Table]]:
     """
     Convert coordinates arrays to cells.
 
+    Coordinates which are not finite, outside of the valid ranges or located at "null island" (0, 0) -
+    a common placeholder for missing coordinates - are most likely bogus. By default these are converted
+    like any other coordinate. Setting `strict` to true raises a `ValueError` on the first of these,
+    setting `errors` to ``"table"`` sets them to null and returns a tuple of the cell array and a table
+    with the columns ``row``, ``value`` (the coordinate) and ``reason``.
+
     :param latarray: array of lat values
     :param lngarray: array of lng values
     :param resarray: Either an array of resolutions or a single resolution as an integer to apply to all coordinates.
     :param radians: Set to True to pass `lat` and `lng` in radians
-    :return: cell array
+    :param strict: Raise on bogus coordinates.
+    :param errors: Set to ``"table"`` to collect bogus coordinates in an errors table.
+    :return: cell array. With ``errors="table"`` a tuple of the cell array and the errors table.
     """
+    errors_table = _errors_as_table(errors)
+    if not (strict or errors_table):
+        kernel = _device_kernel("coordinates_to_cells", latarray)
+        if kernel is not None:
+            return kernel(latarray, lngarray, resarray, radians=radians)
+
     if type(resarray) in (int, float):
         res = int(resarray)
     else:
@@ -34,6 +60,89 @@ def coordinates_to_cells(latarray, lngarray, resarray, radians: bool = False) ->
         _to_arrow_array(lngarray, DataType.float64()),
         res,
         radians=radians,
+        strict=strict,
+        errors_table=errors_table,
+    )
+
+
+def read_csv_to_cells(
+    path,
+    lat_column: str,
+    lng_column: str,
+    resolution: int,
+    columns: Optional[List[str]] = None,
+    delimiter: str = ",",
+    has_header: bool = True,
+    batch_size: int = 65536,
+) -> Table:
+    """
+    Read a CSV file and derive the H3 cell of each row from its coordinate columns while reading.
+
+    The file is parsed natively in batches, so the cells are computed without first loading the whole
+    file into a dataframe. The schema is inferred from the first rows of the file.
+
+    :param path: Path of the CSV file.
+    :param lat_column: Name of the column containing the latitudes in degrees.
+    :param lng_column: Name of the column containing the longitudes in degrees.
+    :param resolution: H3 resolution
+    :param columns: Names of the columns to include in the output. Defaults to all columns.
+    :param delimiter: The field delimiter - for example ``"\t"`` for TSV files.
+    :param has_header: Set to False when the file has no header row. The columns are then named
+            ``column_1``, ``column_2``, ... .
+    :param batch_size: Number of rows per record batch.
+    :return: Table with the selected columns and the `cell` column. Rows with null coordinates have a null cell.
+    """
+    return vector.read_csv_to_cells(
+        str(path),
+        lat_column,
+        lng_column,
+        resolution,
+        columns=columns,
+        delimiter=delimiter,
+        has_header=has_header,
+        batch_size=batch_size,
+    )
+
+
+def heatmap_from_geoparquet(
+    path,
+    resolution: int,
+    value: Optional[str] = None,
+    bbox: Optional[Tuple[float, float, float, float]] = None,
+    geometry_column: Optional[str] = None,
+    batch_size: int = 65536,
+) -> RecordBatch:
+    """
+    Count the points of a GeoParquet file per cell while reading the file.
+
+    Only the geometry column - and the `value` column - are read, and the points are aggregated batch by
+    batch, so files much larger than the available memory can be processed. When a `bbox` is given and the
+    file has a bbox covering column (GeoParquet 1.1), row groups located completely outside of the bounding box
+    are skipped using the column statistics.
+
+    Geometries may be encoded as WKB or as GeoArrow points. The coordinates are expected to be longitudes and
+    latitudes in degrees. Null geometries are skipped, any other geometry type than points is an error.
+
+    :param path: Path of the GeoParquet file.
+    :param resolution: H3 resolution
+    :param value: Name of a numeric column to sum up per cell.
+    :param bbox: Only count the points within this bounding box - a tuple `(minx, miny, maxx, maxy)` in degrees.
+            Bounding boxes crossing the antimeridian are given with `minx` larger than `maxx`.
+    :param geometry_column: Name of the geometry column. Defaults to the primary column of the GeoParquet metadata.
+    :param batch_size: Number of rows read at once.
+    :return: Record batch with the columns `cell` and `count` - and `value` holding the sums when `value` is given.
+    """
+    if not hasattr(vector, "heatmap_from_geoparquet"):
+        raise NotImplementedError(
+            "h3ronpy was built without GeoParquet support. Rebuild it with the 'io' cargo feature enabled."
+        )
+    return vector.heatmap_from_geoparquet(
+        str(path),
+        resolution,
+        value_column=value,
+        bbox=None if bbox is None else tuple(bbox),
+        geometry_column=geometry_column,
+        batch_size=batch_size,
     )
 
 
@@ -44,15 +153,98 @@ def cells_bounds(arr) -> Optional[Tuple]:
     return vector.cells_bounds(_to_uint64_array(arr))
 
 
+def coverage_bounds(arr) -> Optional[Tuple]:
+    """
+    The smallest envelope containing all cells of the array as a tuple `(minx, miny, maxx, maxy)` - for example to
+    fit a map viewport - without generating any geometries.
+
+    In contrast to `cells_bounds` the envelope is aware of the antimeridian: Following the convention of
+    RFC 7946 (GeoJSON) `minx` is larger than `maxx` when the envelope crosses the antimeridian. Returns None when the
+    array does not contain any valid cells.
+    """
+    return vector.coverage_bounds(_to_uint64_array(arr))
+
+
+def filter_cells_by_bbox(arr, bbox: Tuple[float, float, float, float], intersecting: bool = False) -> Array:
+    """
+    Select the cells located within a bounding box - for example to filter a table of cells to the viewport of a map.
+
+    Cells are selected when their centroid is located within the bounding box. This test is cheap and vectorized, no
+    geometries are generated.
+
+    :param arr: The cell array.
+    :param bbox: The bounding box as a tuple `(minx, miny, maxx, maxy)` in degrees. Bounding boxes crossing the
+            antimeridian are given with `minx` larger than `maxx` - see `coverage_bounds`.
+    :param intersecting: Additionally select the cells whose centroid is outside of the bounding box, but whose
+            boundary intersects it.
+    :return: Boolean mask aligned with `arr` - to be used with the ``filter`` method of tables. Null for null cells.
+    """
+    return vector.filter_cells_by_bbox(_to_uint64_array(arr), tuple(bbox), intersecting=intersecting)
+
+
+def partition_globe(parent_resolution: int) -> RecordBatch:
+    """
+    All cells of `parent_resolution` together with their bounds - for example to orchestrate distributed jobs
+    where each worker handles the region of one parent cell.
+
+    :param parent_resolution: H3 resolution of the parent cells.
+    :return: Table/dataframe with the columns `cell`, `minx`, `miny`, `maxx` and `maxy`. The bounds are in degrees
+            (WGS84) and aware of the antimeridian - see `coverage_bounds`. The cells are ordered by their index, so
+            the partitioning is deterministic.
+    """
+    return vector.partition_cells(parent_resolution)
+
+
+def partition_bbox(bbox: Tuple[float, float, float, float], parent_resolution: int) -> RecordBatch:
+    """
+    The cells of `parent_resolution` intersecting a bounding box together with their bounds. See `partition_globe`.
+
+    The cells are found by descending the H3 hierarchy, so no geometry of the bounding box is tiled. In contrast to
+    `filter_cells_by_bbox` all cells whose boundary intersects the bounding box are included, so the parent cells
+    cover the whole bounding box.
+
+    :param bbox: The bounding box as a tuple `(minx, miny, maxx, maxy)` in degrees. Bounding boxes crossing the
+            antimeridian are given with `minx` larger than `maxx`.
+    :param parent_resolution: H3 resolution of the parent cells.
+    :return: Table/dataframe with the columns `cell`, `minx`, `miny`, `maxx` and `maxy`.
+    """
+    return vector.partition_cells(parent_resolution, bbox=tuple(bbox))
+
+
 def cells_bounds_arrays(arr) -> RecordBatch:
     """
     Build a table/dataframe with the columns `minx`, `miny`, `maxx` and `maxy` containing the bounds of the individual
     cells from the input array.
+
+    For cells crossing the antimeridian `minx` is larger than `maxx` - see `coverage_bounds`.
     """
     return vector.cells_bounds_arrays(_to_uint64_array(arr))
 
 
-def cells_to_wkb_polygons(arr, radians: bool = False, link_cells: bool = False) -> Array:
+def has_proj_support() -> bool:
+    """
+    Check if h3ronpy was built with the ``proj`` cargo feature, which is required to reproject geometries using the
+    `output_epsg` parameter of the functions generating geometries.
+    """
+    return hasattr(vector, "wkb_reproject")
+
+
+def _reproject(arr: Array, radians: bool, output_epsg: Optional[int]) -> Array:
+    if output_epsg is None:
+        return arr
+    if radians:
+        raise ValueError("radians and output_epsg are mutually exclusive")
+    if not has_proj_support():
+        raise NotImplementedError(
+            "h3ronpy was built without reprojection support. Rebuild it with the 'proj' cargo feature enabled to use "
+            "output_epsg."
+        )
+    return vector.wkb_reproject(arr, int(output_epsg))
+
+
+def cells_to_wkb_polygons(
+    arr, radians: bool = False, link_cells: bool = False, output_epsg: Optional[int] = None
+) -> Array:
     """
     Convert cells to polygons.
 
@@ -62,11 +254,14 @@ def cells_to_wkb_polygons(arr, radians: bool = False, link_cells: bool = False)
     :param: arr: The cell array
     :param radians: Generate geometries using radians instead of degrees
     :param link_cells: Combine neighboring cells into a single polygon geometry. All cell indexes must have the same resolution.
+    :param output_epsg: EPSG code of the CRS to reproject the geometries to - for example ``3857``. Requires h3ronpy
+        to be built with the ``proj`` feature.
     """
-    return vector.cells_to_wkb_polygons(_to_uint64_array(arr), radians=radians, link_cells=link_cells)
+    polygons = vector.cells_to_wkb_polygons(_to_uint64_array(arr), radians=radians, link_cells=link_cells)
+    return _reproject(polygons, radians, output_epsg)
 
 
-def cells_to_wkb_points(arr, radians: bool = False) -> Array:
+def cells_to_wkb_points(arr, radians: bool = False, output_epsg: Optional[int] = None) -> Array:
     """
     Convert cells to points using their centroids.
 
@@ -74,11 +269,13 @@ def cells_to_wkb_points(arr, radians: bool = False) -> Array:
 
     :param: arr: The cell array
     :param radians: Generate geometries using radians instead of degrees
+    :param output_epsg: EPSG code of the CRS to reproject the geometries to - for example ``3857``. Requires h3ronpy
+        to be built with the ``proj`` feature.
     """
-    return vector.cells_to_wkb_points(_to_uint64_array(arr), radians=radians)
+    return _reproject(vector.cells_to_wkb_points(_to_uint64_array(arr), radians=radians), radians, output_epsg)
 
 
-def vertexes_to_wkb_points(arr, radians: bool = False) -> Array:
+def vertexes_to_wkb_points(arr, radians: bool = False, output_epsg: Optional[int] = None) -> Array:
     """
     Convert vertexes to points.
 
@@ -86,11 +283,13 @@ def vertexes_to_wkb_points(arr, radians: bool = False) -> Array:
 
     :param: arr: The vertex array
     :param radians: Generate geometries using radians instead of degrees
+    :param output_epsg: EPSG code of the CRS to reproject the geometries to - for example ``3857``. Requires h3ronpy
+        to be built with the ``proj`` feature.
     """
-    return vector.vertexes_to_wkb_points(_to_uint64_array(arr), radians=radians)
+    return _reproject(vector.vertexes_to_wkb_points(_to_uint64_array(arr), radians=radians), radians, output_epsg)
 
 
-def directededges_to_wkb_linestrings(arr, radians: bool = False) -> Array:
+def directededges_to_wkb_linestrings(arr, radians: bool = False, output_epsg: Optional[int] = None) -> Array:
     """
     Convert directed edges to linestrings.
 
@@ -98,8 +297,12 @@ def directededges_to_wkb_linestrings(arr, radians: bool = False) -> Array:
 
     :param: arr: The directed edge array
     :param radians: Generate geometries using radians instead of degrees
+    :param output_epsg: EPSG code of the CRS to reproject the geometries to - for example ``3857``. Requires h3ronpy
+        to be built with the ``proj`` feature.
     """
-    return vector.directededges_to_wkb_linestrings(_to_uint64_array(arr), radians=radians)
+    return _reproject(
+        vector.directededges_to_wkb_linestrings(_to_uint64_array(arr), radians=radians), radians, output_epsg
+    )
 
 
 def wkb_to_cells(
@@ -108,7 +311,12 @@ def wkb_to_cells(
     containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
     compact: bool = False,
     flatten: bool = False,
-) -> Array:
+    errors: Optional[str] = None,
+    simplify_tolerance_m: Optional[float] = None,
+    cache=None,
+    on_invalid: str = "error",
+    offset: str = "large",
+):
     """
     Convert a Series/Array/List of WKB values to H3 cells.
 
@@ -122,14 +330,220 @@ def wkb_to_cells(
     :param compact: Compact the returned cells by replacing cells with their parent cells when all children
             of that cell are part of the set.
     :param flatten: Return a non-nested cell array instead of a list array.
+    :param errors: Set to ``"table"`` to convert each geometry on its own instead of failing on the first
+            unparsable WKB value or unconvertible geometry. The failing geometries are null in the output and a
+            tuple of the cells and a table of the failures with the columns `row` - the position in the input -,
+            `value` - the offending WKB value - and `reason` is returned.
+    :param simplify_tolerance_m: Simplify the rings of polygons before converting them to cells. Removed vertices
+            are at most this many meters away from the simplified rings. This can drastically speed up the conversion
+            of noisy geometries with many vertices - like coastlines - at the cost of a coverage error bounded by the
+            tolerance.
+    :param cache: A ``h3ronpy.cache.PolyfillCache`` - or the path of its directory - to look up the result in
+            before converting and to store the result in afterwards. Not supported together with ``errors="table"``
+            or `on_invalid`.
+    :param on_invalid: How to handle geometries which can not be converted - for example because of invalid rings:
+            ``"error"`` fails on the first of these, ``"skip"`` behaves like ``errors="table"``. ``"repair"`` retries
+            the conversion of polygons after repairing them in a ``buffer(0)``-like fashion by resolving
+            self-intersections. Both ``"skip"`` and ``"repair"`` return a tuple of the cells and the table of the
+            failures, which also lists the repaired geometries with a `reason` starting with ``repaired:``.
+    :param offset: ``"large"`` returns list arrays with 64-bit offsets, ``"small"`` with 32-bit offsets for
+            downstream systems not supporting large lists. Has no effect when `flatten` is set.
     """
+    if on_invalid not in ("error", "skip", "repair"):
+        raise ValueError(f"unsupported value for on_invalid: {on_invalid!r}")
     arr = _to_arrow_array(arr, DataType.binary())
-    return vector.wkb_to_cells(
+    if cache is not None:
+        from .cache import PolyfillCache
+
+        if _errors_as_table(errors) or on_invalid != "error":
+            raise ValueError('cache is not supported together with errors="table" or on_invalid')
+        if not isinstance(cache, PolyfillCache):
+            cache = PolyfillCache(cache)
+        key = cache.key(
+            arr,
+            resolution=resolution,
+            containment_mode=repr(containment_mode),
+            compact=compact,
+            flatten=flatten,
+            simplify_tolerance_m=simplify_tolerance_m,
+        )
+        cells = cache.get(key)
+        if cells is None:
+            cells = wkb_to_cells(
+                arr,
+                resolution,
+                containment_mode=containment_mode,
+                compact=compact,
+                flatten=flatten,
+                simplify_tolerance_m=simplify_tolerance_m,
+            )
+            cache.put(key, cells)
+        return _with_list_offset(cells, offset)
+
+    if _errors_as_table(errors) or on_invalid != "error":
+        cells, failures = vector.wkb_to_cells_with_errors(
+            arr,
+            resolution,
+            containment_mode=containment_mode,
+            compact=compact,
+            flatten=flatten,
+            simplify_tolerance_m=simplify_tolerance_m,
+            repair=on_invalid == "repair",
+        )
+        return _with_list_offset(cells, offset), failures
+    return _with_list_offset(
+        vector.wkb_to_cells(
+            arr,
+            resolution,
+            containment_mode=containment_mode,
+            compact=compact,
+            flatten=flatten,
+            simplify_tolerance_m=simplify_tolerance_m,
+        ),
+        offset,
+    )
+
+
+def _to_table(obj) -> Table:
+    if hasattr(obj, "__arrow_c_stream__"):
+        return obj
+    return Table.from_batches([RecordBatch.from_arrow(obj)])
+
+
+def wkb_mean_z(arr) -> Array:
+    """
+    The mean of the Z coordinates of each WKB geometry - for example the height of LiDAR-derived building
+    footprints.
+
+    Geometries with Z and/or M coordinates are accepted by all functions converting WKB to cells, these
+    coordinates are dropped for the conversion. This function allows to keep the Z coordinates as an attribute.
+
+    :param arr: The input array of WKB geometries.
+    :return: ``float64`` array. Null for null geometries and geometries without Z coordinates.
+    """
+    return vector.wkb_mean_z(_to_arrow_array(arr, DataType.binary()))
+
+
+def geometries_to_cells_with_ids(
+    arr,
+    id_arr,
+    resolution: int,
+    containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
+    compact: bool = False,
+    overlap: str = "keep_all",
+    priority_arr=None,
+    attributes=None,
+    simplify_tolerance_m: Optional[float] = None,
+) -> RecordBatch:
+    """
+    Convert a Series/Array/List of WKB values to H3 cells and carry the id of each geometry through to
+    its cells.
+
+    All parts of MultiPolygons - including their holes - are respected. This avoids looping over
+    the features or exploding list arrays when converting a collection of features.
+
+    Cells claimed by multiple geometries are handled according to the ``overlap`` strategy:
+
+    * ``keep_all``: Keep a row for every geometry claiming the cell. The output may contain duplicate cells.
+    * ``largest_intersection``: Assign the cell to the geometry having the largest intersection with the cell.
+    * ``priority``: Assign the cell to the geometry with the highest value in ``priority_arr``.
+    * ``merge``: Return a single row per cell. The ``id`` column becomes a list column containing the ids of all
+      geometries claiming the cell.
+
+    Ties are broken deterministically by preferring the geometry located first in the input array.
+
+    :param arr: The input array of WKB geometries.
+    :param id_arr: The ids of the geometries. Must have the same length as `arr`. The type is preserved.
+    :param resolution: H3 resolution
+    :param containment_mode: Containment mode used to decide if a cell is contained in a polygon or not.
+            See the ContainmentMode class.
+    :param compact: Compact the returned cells by replacing cells with their parent cells when all children
+            of that cell are part of the set. Only supported with the ``keep_all`` overlap strategy.
+    :param overlap: Strategy to handle cells claimed by multiple geometries. See above.
+    :param priority_arr: Numeric priorities of the geometries. Required by the ``priority`` overlap strategy.
+    :param attributes: Optional table or record batch with further attributes of the geometries to pass through to
+            their cells. Must have the same number of rows as `arr` and must not contain an `id` or `cell` column.
+            Columns of any type are supported. Not supported with the ``merge`` overlap strategy.
+    :param simplify_tolerance_m: Simplify the rings of polygons before converting them to cells. See `wkb_to_cells`.
+    :return: Table/dataframe with the columns `id`, the columns of `attributes` and `cell`. Null geometries do not
+            produce any rows.
+    """
+    return vector.geometries_to_cells_with_ids(
+        _to_arrow_array(arr, DataType.binary()),
+        _to_arrow_array(id_arr),
+        resolution,
+        containment_mode=containment_mode,
+        compact=compact,
+        overlap=overlap,
+        priority_array=None if priority_arr is None else _to_arrow_array(priority_arr, DataType.float64()),
+        attributes=None if attributes is None else _to_table(attributes),
+        simplify_tolerance_m=simplify_tolerance_m,
+    )
+
+
+def geometries_to_cells_with_attributes(
+    arr,
+    attributes,
+    resolution: int,
+    containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
+    compact: bool = False,
+    simplify_tolerance_m: Optional[float] = None,
+    mean_z_column: Optional[str] = None,
+) -> RecordBatch:
+    """
+    Convert a Series/Array/List of WKB values to H3 cells and replicate the row of the attribute table
+    belonging to each geometry for all of its cells.
+
+    The attribute columns are replicated using the arrow ``take`` kernel, so columns of any type - including
+    decimals, timestamps, durations and nested lists - are passed through unchanged.
+
+    :param arr: The input array of WKB geometries.
+    :param attributes: Table or record batch with the attributes of the geometries. Must have the same number
+            of rows as `arr` and must not contain a `cell` column.
+    :param resolution: H3 resolution
+    :param containment_mode: Containment mode used to decide if a cell is contained in a polygon or not.
+            See the ContainmentMode class.
+    :param compact: Compact the returned cells by replacing cells with their parent cells when all children
+            of that cell are part of the set.
+    :param simplify_tolerance_m: Simplify the rings of polygons before converting them to cells. See `wkb_to_cells`.
+    :param mean_z_column: Name of a column to add to the attributes containing the mean of the Z coordinates of
+            each geometry - see `wkb_mean_z`. The Z coordinates are dropped for the conversion to cells in any case.
+    :return: Table/dataframe with the columns of `attributes` and the `cell` column. Null geometries do not
+            produce any rows.
+    """
+    arr = _to_arrow_array(arr, DataType.binary())
+    attributes = _to_table(attributes)
+    if mean_z_column is not None:
+        attributes = Table.from_arrow(attributes).append_column(mean_z_column, ChunkedArray([wkb_mean_z(arr)]))
+    return vector.geometries_to_cells_with_attributes(
         arr,
+        attributes,
         resolution,
         containment_mode=containment_mode,
         compact=compact,
-        flatten=flatten,
+        simplify_tolerance_m=simplify_tolerance_m,
+    )
+
+
+def tessellate_exclusive(arr, id_arr, resolution: int) -> RecordBatch:
+    """
+    Build a gapless and non-overlapping partition of the area covered by the polygons of `arr` from H3 cells.
+
+    Every cell intersecting any of the polygons is assigned to exactly one polygon - the one having the largest
+    intersection with the cell. Ties are broken deterministically by preferring the polygon located first in the
+    input array.
+
+    :param arr: The input array of WKB polygons.
+    :param id_arr: The ids of the polygons. Must have the same length as `arr`.
+    :param resolution: H3 resolution
+    :return: Table/dataframe with the columns `id` and `cell`.
+    """
+    return geometries_to_cells_with_ids(
+        arr,
+        id_arr,
+        resolution,
+        containment_mode=ContainmentMode.Covers,
+        overlap="largest_intersection",
     )
 
 
@@ -138,6 +552,7 @@ def geometry_to_cells(
     resolution: int,
     containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
     compact: bool = False,
+    simplify_tolerance_m: Optional[float] = None,
 ) -> Array:
     """
     Convert a single object which supports the python `__geo_interface__` protocol to H3 cells
@@ -148,19 +563,124 @@ def geometry_to_cells(
             See the ContainmentMode class.
     :param compact: Compact the returned cells by replacing cells with their parent cells when all children
             of that cell are part of the set.
+    :param simplify_tolerance_m: Simplify the rings of polygons before converting them to cells. See `wkb_to_cells`.
+    """
+    return vector.geometry_to_cells(
+        geom,
+        resolution,
+        containment_mode=containment_mode,
+        compact=compact,
+        simplify_tolerance_m=simplify_tolerance_m,
+    )
+
+
+def _center_lnglat(center) -> Tuple[float, float]:
+    if hasattr(center, "__geo_interface__"):
+        geo = center.__geo_interface__
+        if geo.get("type") != "Point":
+            raise ValueError("center must be a point")
+        center = geo["coordinates"]
+    lng, lat = center[0], center[1]
+    return float(lng), float(lat)
+
+
+def sector_to_cells(center, radius_m: float, az_min: float, az_max: float, resolution: int) -> Array:
+    """
+    Cells covering a circular sector - for example the footprint of a radar or an antenna.
+
+    The sector is evaluated analytically for the centroids of the cells, no polygon geometry is constructed.
+    Cells are included when their centroid is located within the sector. The cell containing the center is
+    always included.
+
+    :param center: The center as a `(lng, lat)` tuple in degrees or a point supporting the `__geo_interface__`
+            protocol.
+    :param radius_m: Geodesic radius of the sector in meters.
+    :param az_min: Start of the azimuth range in degrees clockwise from north.
+    :param az_max: End of the azimuth range in degrees clockwise from north. Ranges crossing north are given
+            with `az_min` larger than `az_max`, for example `(350, 10)`. A range of 360 degrees selects the
+            complete circle.
+    :param resolution: H3 resolution
+    """
+    lng, lat = _center_lnglat(center)
+    return vector.sector_to_cells(lat, lng, radius_m, az_min, az_max, resolution)
+
+
+def geodesic_rect_to_cells(center, width_m: float, height_m: float, azimuth: float, resolution: int) -> Array:
+    """
+    Cells covering a rectangle defined by geodesic distances - for example a swath or a runway.
+
+    The height is measured along the great circle through the center in the direction of `azimuth`, the
+    width perpendicular to it. As with `sector_to_cells`, cells are included when their centroid is located within
+    the rectangle and no polygon geometry is constructed.
+
+    :param center: The center as a `(lng, lat)` tuple in degrees or a point supporting the `__geo_interface__`
+            protocol.
+    :param width_m: Width of the rectangle in meters.
+    :param height_m: Height of the rectangle in meters.
+    :param azimuth: Orientation of the height axis in degrees clockwise from north.
+    :param resolution: H3 resolution
+    """
+    lng, lat = _center_lnglat(center)
+    return vector.geodesic_rect_to_cells(lat, lng, width_m, height_m, azimuth, resolution)
+
+
+def random_points_in_cells(arr, n_per_cell: int, seed: Optional[int] = None) -> RecordBatch:
+    """
+    Generate random points uniformly distributed within each cell. The distribution is uniform with respect
+    to the area on the sphere, not to the lat/lng coordinate space.
+
+    Useful for dot-density maps and for generating synthetic data. Invalid/empty cells are omitted.
+
+    :param arr: The cell array
+    :param n_per_cell: Number of points to generate for each cell
+    :param seed: Seed for the random number generator to obtain reproducible results
+    :return: Table/dataframe with the columns `cell`, `lat` and `lng`. The coordinates are in degrees.
+    """
+    return vector.random_points_in_cells(_to_uint64_array(arr), n_per_cell, seed=seed)
+
+
+def representative_point_in_intersection(arr, geom, radians: bool = False) -> Array:
+    """
+    Generate a point for each cell which is guaranteed to be located within the intersection
+    of the cell and the polygon `geom`.
+
+    In contrast to the centroids of the cells, these points can be used for label placement and sampling
+    of cells which only partially overlap the polygon - for example after polyfilling with
+    ``ContainmentMode.IntersectsBoundary``.
+
+    The returned geometries in the output array will match the order of the input array. Positions of
+    cells which do not intersect the polygon are null.
+
+    :param arr: The cell array
+    :param geom: A Polygon or MultiPolygon supporting the python `__geo_interface__` protocol
+    :param radians: Generate geometries using radians instead of degrees
     """
-    return vector.geometry_to_cells(geom, resolution, containment_mode=containment_mode, compact=compact)
+    return vector.representative_point_in_intersection(_to_uint64_array(arr), geom, radians=radians)
 
 
 __all__ = [
     cells_to_coordinates.__name__,
     coordinates_to_cells.__name__,
+    read_csv_to_cells.__name__,
+    heatmap_from_geoparquet.__name__,
     cells_bounds.__name__,
     cells_bounds_arrays.__name__,
+    coverage_bounds.__name__,
+    filter_cells_by_bbox.__name__,
+    partition_globe.__name__,
+    partition_bbox.__name__,
     cells_to_wkb_polygons.__name__,
     cells_to_wkb_points.__name__,
     vertexes_to_wkb_points.__name__,
     directededges_to_wkb_linestrings.__name__,
     wkb_to_cells.__name__,
+    wkb_mean_z.__name__,
     geometry_to_cells.__name__,
+    sector_to_cells.__name__,
+    geodesic_rect_to_cells.__name__,
+    geometries_to_cells_with_ids.__name__,
+    geometries_to_cells_with_attributes.__name__,
+    tessellate_exclusive.__name__,
+    random_points_in_cells.__name__,
+    representative_point_in_intersection.__name__,
 ]
@@ -1,6 +1,10 @@
-use arrow::array::{Array, UInt64Array};
-use pyo3_arrow::PyArray;
+use arrow::array::{Array, ArrayRef, RecordBatch, StringArray, UInt64Array};
+use arrow::compute::take;
+use arrow::datatypes::{DataType, Field, Schema};
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::{PyArray, PyRecordBatch};
 use std::any::{type_name, Any};
+use std::cell::Cell;
 use std::sync::Arc;
 
 use h3arrow::array::{
@@ -21,6 +25,33 @@ where
     PyArray::from_array_ref(Arc::new(pa)).to_arro3(py)
 }
 
+/// Build the table of the failed rows of an operation run with `errors="table"`.
+///
+/// The table has the columns `row` - the position in the input -, `value` - the offending input
+/// value - and `reason`.
+pub(crate) fn errors_to_pyrecordbatch(
+    py: Python,
+    values: &dyn Array,
+    rows: Vec<u64>,
+    reasons: Vec<String>,
+) -> PyArrowResult<PyObject> {
+    let rows = UInt64Array::from(rows);
+    let offending_values = take(values, &rows, None)?;
+
+    let schema = Schema::new(vec![
+        Field::new("row", DataType::UInt64, false),
+        Field::new("value", values.data_type().clone(), true),
+        Field::new("reason", DataType::Utf8, false),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(rows),
+        offending_values,
+        Arc::new(StringArray::from(reasons)),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
 pub(crate) fn pyarray_to_native<T: Any + Array + Clone>(obj: &Bound<PyAny>) -> PyResult<T> {
     let array = obj.extract::<PyArray>()?;
     let (array, _field) = array.into_inner();
@@ -42,28 +73,51 @@ pub(crate) fn pyarray_to_native<T: Any + Array + Clone>(obj: &Bound<PyAny>) -> P
 }
 
 pub(crate) fn pyarray_to_cellindexarray(obj: &Bound<PyAny>) -> PyResult<CellIndexArray> {
-    pyarray_to_h3array::<CellIndexArray>(obj)
+    pyarray_to_h3array(obj)
 }
 
 pub(crate) fn pyarray_to_vertexindexarray(obj: &Bound<PyAny>) -> PyResult<VertexIndexArray> {
-    pyarray_to_h3array::<VertexIndexArray>(obj)
+    pyarray_to_h3array(obj)
 }
 
 pub(crate) fn pyarray_to_directededgeindexarray(
     obj: &Bound<PyAny>,
 ) -> PyResult<DirectedEdgeIndexArray> {
-    pyarray_to_h3array::<DirectedEdgeIndexArray>(obj)
+    pyarray_to_h3array(obj)
 }
 
 pub(crate) fn pyarray_to_uint64array(obj: &Bound<PyAny>) -> PyResult<UInt64Array> {
     pyarray_to_native::<UInt64Array>(obj)
 }
 
+thread_local! {
+    static ASSUME_VALID: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Skip the validation of H3 indexes when converting arrays passed in from python.
+///
+/// The setting only affects the current thread. Returns the previous setting.
+#[pyfunction]
+pub(crate) fn set_assume_valid(assume_valid: bool) -> bool {
+    ASSUME_VALID.with(|cell| cell.replace(assume_valid))
+}
+
 #[inline]
-fn pyarray_to_h3array<T>(obj: &Bound<PyAny>) -> PyResult<T>
+fn pyarray_to_h3array<IX>(obj: &Bound<PyAny>) -> PyResult<H3Array<IX>>
 where
-    T: TryFrom<UInt64Array>,
-    <T as TryFrom<UInt64Array>>::Error: IntoPyErr,
+    IX: H3IndexArrayValue,
+    H3Array<IX>: TryFrom<UInt64Array>,
+    <H3Array<IX> as TryFrom<UInt64Array>>::Error: IntoPyErr,
 {
-    T::try_from(pyarray_to_uint64array(obj)?).into_pyresult()
+    let array = pyarray_to_uint64array(obj)?;
+    if ASSUME_VALID.with(Cell::get) {
+        // zero values are always rejected as these can not be represented by the h3o types at all.
+        if array.iter().flatten().any(|h3index| h3index == 0) {
+            return Err(PyValueError::new_err("0 is not a valid H3 index"));
+        }
+        // SAFETY: skipping the validation has been explicitly requested by the user.
+        Ok(unsafe { H3Array::new_unchecked(array) })
+    } else {
+        H3Array::try_from(array).into_pyresult()
+    }
 }
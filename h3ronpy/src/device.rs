@@ -0,0 +1,233 @@
+use std::ffi::c_void;
+
+use arrow::ffi::FFI_ArrowArray;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyTuple};
+#[cfg(feature = "cuda")]
+use pyo3_arrow::error::PyArrowResult;
+
+/// `ARROW_DEVICE_CPU` of the arrow C device data interface
+pub(crate) const ARROW_DEVICE_CPU: i32 = 1;
+
+/// `ARROW_DEVICE_CUDA` of the arrow C device data interface
+#[cfg(feature = "cuda")]
+pub(crate) const ARROW_DEVICE_CUDA: i32 = 2;
+
+/// `struct ArrowDeviceArray` of the arrow C device data interface.
+///
+/// See <https://arrow.apache.org/docs/format/CDeviceDataInterface.html>
+#[repr(C)]
+#[allow(dead_code)]
+struct ArrowDeviceArray {
+    array: FFI_ArrowArray,
+    device_id: i64,
+    device_type: i32,
+    sync_event: *mut c_void,
+    reserved: [i64; 3],
+}
+
+/// Returns the device type and the device id of an object implementing the
+/// `__arrow_c_device_array__` protocol.
+///
+/// Objects only implementing `__arrow_c_array__` are reported as residing on the CPU with
+/// device id -1.
+#[pyfunction]
+#[pyo3(signature = (obj,))]
+pub(crate) fn array_device(obj: &Bound<PyAny>) -> PyResult<(i32, i64)> {
+    if !obj.hasattr("__arrow_c_device_array__")? {
+        return Ok((ARROW_DEVICE_CPU, -1));
+    }
+
+    let (_, array_capsule) = device_array_capsules(obj)?;
+
+    // only the device description gets read here. Releasing the array is left to the
+    // destructor of the capsule.
+    let device_array = unsafe { &*(array_capsule.pointer() as *const ArrowDeviceArray) };
+    Ok((device_array.device_type, device_array.device_id))
+}
+
+/// The schema and device array capsules exported by `__arrow_c_device_array__`.
+fn device_array_capsules<'py>(
+    obj: &Bound<'py, PyAny>,
+) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+    let capsules = obj.call_method0("__arrow_c_device_array__")?;
+    let capsules = capsules.downcast::<PyTuple>()?;
+    Ok((
+        downcast_capsule(capsules.get_item(0)?, "arrow_schema")?,
+        downcast_capsule(capsules.get_item(1)?, "arrow_device_array")?,
+    ))
+}
+
+fn downcast_capsule<'py>(
+    capsule: Bound<'py, PyAny>,
+    expected_name: &str,
+) -> PyResult<Bound<'py, PyCapsule>> {
+    let capsule = capsule.downcast_into::<PyCapsule>()?;
+    match capsule.name()? {
+        Some(name) if name.to_bytes() == expected_name.as_bytes() => Ok(capsule),
+        _ => Err(PyValueError::new_err(format!(
+            "Expected a PyCapsule named {expected_name}"
+        ))),
+    }
+}
+
+/// Copies an array of a fixed-width primitive type residing on a CUDA device to host memory.
+///
+/// The copy waits for the synchronization event of the array, if there is one.
+#[cfg(feature = "cuda")]
+#[pyfunction]
+#[pyo3(signature = (obj,))]
+pub(crate) fn cuda_array_to_host(py: Python, obj: &Bound<PyAny>) -> PyArrowResult<PyObject> {
+    use arrow::array::{make_array, ArrayData};
+    use arrow::datatypes::DataType;
+    use arrow::ffi::FFI_ArrowSchema;
+    use pyo3_arrow::PyArray;
+
+    let (schema_capsule, array_capsule) = device_array_capsules(obj)?;
+
+    // ownership of both structs stays with the capsules, they are only read here.
+    let schema = unsafe { &*(schema_capsule.pointer() as *const FFI_ArrowSchema) };
+    let device_array = unsafe { &*(array_capsule.pointer() as *const ArrowDeviceArray) };
+
+    if device_array.device_type != ARROW_DEVICE_CUDA {
+        return Err(PyValueError::new_err(format!(
+            "Expected an array residing on a CUDA device, found device type {}",
+            device_array.device_type
+        ))
+        .into());
+    }
+    let data_type = DataType::try_from(schema)?;
+    let Some(width) = data_type.primitive_width() else {
+        return Err(PyValueError::new_err(format!(
+            "Copying arrays of type {data_type} from CUDA devices is not supported"
+        ))
+        .into());
+    };
+
+    let array = &device_array.array;
+    let (len, offset) = (array.len(), array.offset());
+    let context = cuda::PrimaryContext::retain(device_array.device_id)?;
+    context.synchronize(device_array.sync_event)?;
+    let values = context.copy_to_host(array.buffer(1), (offset + len) * width)?;
+    let validity = if array.null_count() != 0 && !array.buffer(0).is_null() {
+        Some(context.copy_to_host(array.buffer(0), (offset + len).div_ceil(8))?)
+    } else {
+        None
+    };
+    drop(context);
+
+    let data = ArrayData::builder(data_type)
+        .len(len)
+        .offset(offset)
+        .add_buffer(values)
+        .null_bit_buffer(validity)
+        .build()?;
+    Ok(PyArray::from_array_ref(make_array(data)).to_arro3(py)?)
+}
+
+/// Minimal bindings of the CUDA driver API to copy device memory to the host.
+#[cfg(feature = "cuda")]
+mod cuda {
+    use std::ffi::c_void;
+
+    use arrow::buffer::{Buffer, MutableBuffer};
+    use pyo3::exceptions::PyRuntimeError;
+    use pyo3::PyResult;
+
+    type CUresult = i32;
+    type CUdevice = i32;
+    type CUcontext = *mut c_void;
+    type CUevent = *mut c_void;
+
+    const CUDA_SUCCESS: CUresult = 0;
+
+    #[link(name = "cuda")]
+    extern "C" {
+        fn cuInit(flags: u32) -> CUresult;
+        fn cuDeviceGet(device: *mut CUdevice, ordinal: i32) -> CUresult;
+        fn cuDevicePrimaryCtxRetain(ctx: *mut CUcontext, device: CUdevice) -> CUresult;
+        #[link_name = "cuDevicePrimaryCtxRelease_v2"]
+        fn cuDevicePrimaryCtxRelease(device: CUdevice) -> CUresult;
+        #[link_name = "cuCtxPushCurrent_v2"]
+        fn cuCtxPushCurrent(ctx: CUcontext) -> CUresult;
+        #[link_name = "cuCtxPopCurrent_v2"]
+        fn cuCtxPopCurrent(ctx: *mut CUcontext) -> CUresult;
+        fn cuEventSynchronize(event: CUevent) -> CUresult;
+        #[link_name = "cuMemcpyDtoH_v2"]
+        fn cuMemcpyDtoH(dst: *mut c_void, src: u64, bytes: usize) -> CUresult;
+    }
+
+    fn check(result: CUresult, operation: &str) -> PyResult<()> {
+        if result == CUDA_SUCCESS {
+            Ok(())
+        } else {
+            Err(PyRuntimeError::new_err(format!(
+                "{operation} failed with CUDA error {result}"
+            )))
+        }
+    }
+
+    /// The primary context of a device, being the current context for the lifetime of this struct.
+    pub(super) struct PrimaryContext {
+        device: CUdevice,
+    }
+
+    impl PrimaryContext {
+        pub(super) fn retain(device_id: i64) -> PyResult<Self> {
+            let ordinal = i32::try_from(device_id)
+                .map_err(|_| PyRuntimeError::new_err(format!("invalid CUDA device {device_id}")))?;
+            let mut device: CUdevice = 0;
+            let mut ctx: CUcontext = std::ptr::null_mut();
+            unsafe {
+                check(cuInit(0), "cuInit")?;
+                check(cuDeviceGet(&mut device, ordinal), "cuDeviceGet")?;
+                check(
+                    cuDevicePrimaryCtxRetain(&mut ctx, device),
+                    "cuDevicePrimaryCtxRetain",
+                )?;
+                if let Err(e) = check(cuCtxPushCurrent(ctx), "cuCtxPushCurrent") {
+                    cuDevicePrimaryCtxRelease(device);
+                    return Err(e);
+                }
+            }
+            Ok(Self { device })
+        }
+
+        /// Wait for the `sync_event` of an `ArrowDeviceArray` - a pointer to a `CUevent`.
+        pub(super) fn synchronize(&self, sync_event: *mut c_void) -> PyResult<()> {
+            if sync_event.is_null() {
+                return Ok(());
+            }
+            unsafe {
+                check(
+                    cuEventSynchronize(*(sync_event as *const CUevent)),
+                    "cuEventSynchronize",
+                )
+            }
+        }
+
+        pub(super) fn copy_to_host(&self, src: *const u8, bytes: usize) -> PyResult<Buffer> {
+            let mut buffer = MutableBuffer::from_len_zeroed(bytes);
+            if bytes > 0 {
+                unsafe {
+                    check(
+                        cuMemcpyDtoH(buffer.as_mut_ptr() as *mut c_void, src as u64, bytes),
+                        "cuMemcpyDtoH",
+                    )?;
+                }
+            }
+            Ok(buffer.into())
+        }
+    }
+
+    impl Drop for PrimaryContext {
+        fn drop(&mut self) {
+            let mut ctx: CUcontext = std::ptr::null_mut();
+            unsafe {
+                cuCtxPopCurrent(&mut ctx);
+                cuDevicePrimaryCtxRelease(self.device);
+            }
+        }
+    }
+}
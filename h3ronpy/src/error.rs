@@ -1,7 +1,6 @@
 use h3arrow::error::Error as A3Error;
 use pyo3::exceptions::{PyIOError, PyRuntimeError, PyValueError};
 use pyo3::{PyErr, PyResult};
-use rasterh3::Error;
 
 pub trait IntoPyResult<T> {
     fn into_pyresult(self) -> PyResult<T>;
@@ -36,6 +35,15 @@ impl IntoPyErr for A3Error {
             | A3Error::NonParsableDirectedEdgeIndex
             | A3Error::NonParsableVertexIndex
             | A3Error::LengthMismatch
+            | A3Error::MixedResolutions
+            | A3Error::NotCanonical
+            | A3Error::InvalidCost(_)
+            | A3Error::InvalidTolerance(_)
+            | A3Error::InvalidBandwidth(_)
+            | A3Error::InvalidRegionCount(..)
+            | A3Error::InvalidDistance(_)
+            | A3Error::PayloadOverflow(..)
+            | A3Error::ResolutionTooFine(..)
             | A3Error::InvalidWKB => PyValueError::new_err(self.to_string()),
             A3Error::IO(e) => e.into_pyerr(),
         }
@@ -69,8 +77,10 @@ impl_h3o_value_err!(
     h3arrow::export::h3o::error::LocalIjError,
 );
 
+#[cfg(feature = "raster")]
 impl IntoPyErr for rasterh3::Error {
     fn into_pyerr(self) -> PyErr {
+        use rasterh3::Error;
         match self {
             Error::TransformNotInvertible | Error::EmptyArray => {
                 PyValueError::new_err(self.to_string())
@@ -89,6 +99,16 @@ impl IntoPyErr for std::io::Error {
     }
 }
 
+#[cfg(feature = "io")]
+impl IntoPyErr for parquet::errors::ParquetError {
+    fn into_pyerr(self) -> PyErr {
+        match self {
+            parquet::errors::ParquetError::External(e) => PyIOError::new_err(e.to_string()),
+            e => PyValueError::new_err(e.to_string()),
+        }
+    }
+}
+
 impl<T, E> IntoPyResult<T> for Result<T, E>
 where
     E: IntoPyErr,
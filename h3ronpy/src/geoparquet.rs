@@ -0,0 +1,267 @@
+//! Aggregation of GeoParquet point datasets to cells while reading them.
+//!
+//! Only the geometry column - and the value column when given - are read. When a bounding box is
+//! given, row groups are skipped using the statistics of the bbox covering column defined by
+//! GeoParquet 1.1, when the file has one.
+
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, Float64Array, RecordBatch, UInt64Array};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Float64Type, Schema};
+use geo::Point;
+use h3arrow::array::wkb::wkb_to_2d;
+use h3arrow::export::geozero::wkb::Wkb;
+use h3arrow::export::geozero::ToGeo;
+use h3arrow::export::h3o::{CellIndex, LatLng, Resolution};
+use hashbrown::HashMap;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ProjectionMask;
+use parquet::file::metadata::RowGroupMetaData;
+use parquet::file::statistics::Statistics;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::PyRecordBatch;
+use serde_json::Value;
+
+use crate::error::IntoPyResult;
+use crate::DEFAULT_CELL_COLUMN_NAME;
+
+/// Count the points of a GeoParquet file per cell - optionally summing up a value column.
+///
+/// Returns a record batch with the columns `cell`, `count` and - when `value_column` is given -
+/// `value`. The coordinates are expected to be WGS84 longitudes and latitudes.
+#[pyfunction]
+#[pyo3(signature = (path, resolution, value_column = None, bbox = None, geometry_column = None, batch_size = 65536))]
+pub(crate) fn heatmap_from_geoparquet(
+    py: Python<'_>,
+    path: PathBuf,
+    resolution: u8,
+    value_column: Option<String>,
+    bbox: Option<(f64, f64, f64, f64)>,
+    geometry_column: Option<String>,
+    batch_size: usize,
+) -> PyArrowResult<PyObject> {
+    let resolution = Resolution::try_from(resolution).into_pyresult()?;
+    if batch_size == 0 {
+        return Err(PyValueError::new_err("batch_size must be larger than 0").into());
+    }
+
+    let (cells, counts, sums) = py.allow_threads(|| -> PyResult<_> {
+        let file = File::open(&path).into_pyresult()?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file).into_pyresult()?;
+        let geo_metadata = builder
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .and_then(|kvs| kvs.iter().find(|kv| kv.key == "geo"))
+            .and_then(|kv| kv.value.as_deref())
+            .map(serde_json::from_str::<Value>)
+            .transpose()
+            .map_err(|e| PyValueError::new_err(format!("invalid GeoParquet metadata: {}", e)))?
+            .ok_or_else(|| PyValueError::new_err("not a GeoParquet file - no geo metadata"))?;
+
+        let geometry_column = match geometry_column {
+            Some(geometry_column) => geometry_column,
+            None => geo_metadata["primary_column"]
+                .as_str()
+                .ok_or_else(|| PyValueError::new_err("GeoParquet metadata lacks primary_column"))?
+                .to_string(),
+        };
+
+        let schema = builder.schema().clone();
+        let mut roots = vec![schema.index_of(&geometry_column).into_pyresult()?];
+        if let Some(value_column) = &value_column {
+            roots.push(schema.index_of(value_column).into_pyresult()?);
+        }
+        let projection = ProjectionMask::roots(builder.parquet_schema(), roots);
+
+        let row_groups = match bbox {
+            Some(bbox) => {
+                let covering =
+                    &geo_metadata["columns"][geometry_column.as_str()]["covering"]["bbox"];
+                builder
+                    .metadata()
+                    .row_groups()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, row_group)| row_group_intersects(row_group, covering, bbox))
+                    .map(|(i, _)| i)
+                    .collect()
+            }
+            None => (0..builder.metadata().num_row_groups()).collect(),
+        };
+
+        let reader = builder
+            .with_projection(projection)
+            .with_row_groups(row_groups)
+            .with_batch_size(batch_size)
+            .build()
+            .into_pyresult()?;
+
+        let mut groups: HashMap<CellIndex, usize> = HashMap::new();
+        let mut cells = vec![];
+        let mut counts = vec![];
+        let mut sums = vec![];
+        for batch in reader {
+            let batch = batch.into_pyresult()?;
+            let points = points(batch.column_by_name(&geometry_column).ok_or_else(|| {
+                PyValueError::new_err(format!("column {} not found", geometry_column))
+            })?)?;
+            let values = match &value_column {
+                Some(value_column) => Some(
+                    cast(
+                        batch.column_by_name(value_column).ok_or_else(|| {
+                            PyValueError::new_err(format!("column {} not found", value_column))
+                        })?,
+                        &DataType::Float64,
+                    )
+                    .into_pyresult()?,
+                ),
+                None => None,
+            };
+            let values = values
+                .as_ref()
+                .map(|values| values.as_primitive::<Float64Type>());
+
+            for (i, point) in points.into_iter().enumerate() {
+                let Some(point) = point else {
+                    continue;
+                };
+                if bbox.is_some_and(|bbox| !bbox_contains(bbox, &point)) {
+                    continue;
+                }
+                let cell = LatLng::new(point.y(), point.x())
+                    .into_pyresult()?
+                    .to_cell(resolution);
+                let group = *groups.entry(cell).or_insert_with(|| {
+                    cells.push(u64::from(cell));
+                    counts.push(0u64);
+                    sums.push(0.0f64);
+                    cells.len() - 1
+                });
+                counts[group] += 1;
+                if let Some(values) = values {
+                    if values.is_valid(i) {
+                        sums[group] += values.value(i);
+                    }
+                }
+            }
+        }
+        Ok((cells, counts, sums))
+    })?;
+
+    let mut fields = vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, false),
+        Field::new("count", DataType::UInt64, false),
+    ];
+    let mut columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from(cells)),
+        Arc::new(UInt64Array::from(counts)),
+    ];
+    if value_column.is_some() {
+        fields.push(Field::new("value", DataType::Float64, false));
+        columns.push(Arc::new(Float64Array::from(sums)));
+    }
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+/// Extract the points from a column of WKB geometries or of native GeoArrow points.
+fn points(column: &ArrayRef) -> PyResult<Vec<Option<Point>>> {
+    match column.data_type() {
+        DataType::Binary | DataType::LargeBinary => {
+            let column = cast(column, &DataType::LargeBinary).into_pyresult()?;
+            column
+                .as_binary::<i64>()
+                .iter()
+                .map(|wkb| wkb.map(wkb_to_point).transpose())
+                .collect()
+        }
+        DataType::Struct(_) => {
+            let column = column.as_struct();
+            let (Some(x), Some(y)) = (column.column_by_name("x"), column.column_by_name("y"))
+            else {
+                return Err(PyValueError::new_err(
+                    "point struct column without x and y fields",
+                ));
+            };
+            let x = cast(x, &DataType::Float64).into_pyresult()?;
+            let y = cast(y, &DataType::Float64).into_pyresult()?;
+            Ok(x.as_primitive::<Float64Type>()
+                .iter()
+                .zip(y.as_primitive::<Float64Type>().iter())
+                .enumerate()
+                .map(|(i, coords)| match coords {
+                    (Some(x), Some(y)) if column.is_valid(i) => Some(Point::new(x, y)),
+                    _ => None,
+                })
+                .collect())
+        }
+        _ => Err(PyValueError::new_err(
+            "unsupported geometry encoding - expected WKB or GeoArrow points",
+        )),
+    }
+}
+
+fn wkb_to_point(wkb: &[u8]) -> PyResult<Point> {
+    let wkb2d = wkb_to_2d(wkb).into_pyresult()?;
+    match Wkb(wkb2d.wkb).to_geo() {
+        Ok(geo::Geometry::Point(point)) => Ok(point),
+        Ok(_) => Err(PyValueError::new_err("geometry is not a point")),
+        Err(e) => Err(PyValueError::new_err(e.to_string())),
+    }
+}
+
+/// Check the statistics of the bbox covering columns of the row group. Row groups without
+/// statistics are always read.
+fn row_group_intersects(
+    row_group: &RowGroupMetaData,
+    covering: &Value,
+    bbox: (f64, f64, f64, f64),
+) -> bool {
+    let (minx, miny, maxx, maxy) = bbox;
+    let stat = |name: &str, max: bool| -> Option<f64> {
+        let path: Vec<&str> = covering[name]
+            .as_array()?
+            .iter()
+            .map(Value::as_str)
+            .collect::<Option<_>>()?;
+        let column = row_group
+            .columns()
+            .iter()
+            .find(|column| column.column_path().parts() == path.as_slice())?;
+        match column.statistics()? {
+            Statistics::Double(stats) if max => stats.max_opt().copied(),
+            Statistics::Double(stats) => stats.min_opt().copied(),
+            Statistics::Float(stats) if max => stats.max_opt().map(|v| *v as f64),
+            Statistics::Float(stats) => stats.min_opt().map(|v| *v as f64),
+            _ => None,
+        }
+    };
+    let (xmin, xmax) = (stat("xmin", false), stat("xmax", true));
+    let disjoint_x =
+        |minx: f64, maxx: f64| xmin.is_some_and(|v| v > maxx) || xmax.is_some_and(|v| v < minx);
+    let disjoint = if minx > maxx {
+        // bbox crossing the antimeridian
+        disjoint_x(minx, 180.0) && disjoint_x(-180.0, maxx)
+    } else {
+        disjoint_x(minx, maxx)
+    } || stat("ymin", false).is_some_and(|v| v > maxy)
+        || stat("ymax", true).is_some_and(|v| v < miny);
+    !disjoint
+}
+
+/// Bounding boxes with `minx` larger than `maxx` cross the antimeridian.
+fn bbox_contains(bbox: (f64, f64, f64, f64), point: &Point) -> bool {
+    let (minx, miny, maxx, maxy) = bbox;
+    let contains_x = if minx > maxx {
+        point.x() >= minx || point.x() <= maxx
+    } else {
+        point.x() >= minx && point.x() <= maxx
+    };
+    contains_x && point.y() >= miny && point.y() <= maxy
+}
@@ -0,0 +1,149 @@
+//! Reading GeoTIFFs without GDAL using the pure-rust `tiff` crate.
+//!
+//! Supports striped and tiled files, the compressions supported by the `tiff` crate - deflate,
+//! LZW, PackBits - and chunky multiband data. This covers the common case of cloud-optimized
+//! GeoTIFFs, other files still need to be read using rasterio/GDAL.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use ndarray::Array2;
+use numpy::PyArray2;
+use pyo3::exceptions::{PyIOError, PyValueError};
+use pyo3::prelude::*;
+use tiff::decoder::{Decoder, DecodingResult};
+use tiff::tags::Tag;
+
+use crate::transform::Transform;
+
+const GT_RASTER_TYPE_GEO_KEY: u16 = 1025;
+const RASTER_PIXEL_IS_POINT: u16 = 2;
+
+/// Read a band of a GeoTIFF.
+///
+/// Returns a tuple of the 2D array of the band - with the axis order `yx` -, the transform and
+/// the nodata value if the file defines one.
+#[pyfunction]
+#[pyo3(signature = (path, band = 1))]
+pub(crate) fn read_geotiff(
+    py: Python,
+    path: PathBuf,
+    band: usize,
+) -> PyResult<(PyObject, Transform, Option<f64>)> {
+    let (image, width, height, transform, nodata) =
+        py.allow_threads(|| read_image(path)).map_err(|e| match e {
+            tiff::TiffError::IoError(e) => PyIOError::new_err(e.to_string()),
+            e => PyValueError::new_err(format!("unable to read GeoTIFF: {}", e)),
+        })?;
+
+    let n_pixels = width * height;
+    macro_rules! band_to_numpy {
+        ($values:expr) => {{
+            let n_bands = $values.len() / n_pixels;
+            if band == 0 || band > n_bands {
+                return Err(PyValueError::new_err(format!(
+                    "band {} does not exist, the file has {} bands",
+                    band, n_bands
+                )));
+            }
+            let values: Vec<_> = $values
+                .into_iter()
+                .skip(band - 1)
+                .step_by(n_bands)
+                .collect();
+            let array = Array2::from_shape_vec((height, width), values)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            PyArray2::from_owned_array_bound(py, array).into_py(py)
+        }};
+    }
+
+    let array = match image {
+        DecodingResult::U8(values) => band_to_numpy!(values),
+        DecodingResult::U16(values) => band_to_numpy!(values),
+        DecodingResult::U32(values) => band_to_numpy!(values),
+        DecodingResult::U64(values) => band_to_numpy!(values),
+        DecodingResult::I8(values) => band_to_numpy!(values),
+        DecodingResult::I16(values) => band_to_numpy!(values),
+        DecodingResult::I32(values) => band_to_numpy!(values),
+        DecodingResult::I64(values) => band_to_numpy!(values),
+        DecodingResult::F32(values) => band_to_numpy!(values),
+        DecodingResult::F64(values) => band_to_numpy!(values),
+    };
+    Ok((array, Transform::from_gdal(transform), nodata))
+}
+
+#[allow(clippy::type_complexity)]
+fn read_image(
+    path: PathBuf,
+) -> Result<(DecodingResult, usize, usize, [f64; 6], Option<f64>), tiff::TiffError> {
+    let mut decoder = Decoder::new(BufReader::new(File::open(path)?))?;
+    let (width, height) = decoder.dimensions()?;
+    let transform = geotransform(&mut decoder)?;
+    let nodata = decoder
+        .find_tag(Tag::GdalNodata)?
+        .map(|value| value.into_string())
+        .transpose()?
+        .and_then(|value| value.trim_matches(char::from(0)).trim().parse::<f64>().ok());
+    let image = decoder.read_image()?;
+    Ok((image, width as usize, height as usize, transform, nodata))
+}
+
+/// Build the GDAL-style geotransform from the GeoTIFF tags.
+fn geotransform(decoder: &mut Decoder<BufReader<File>>) -> Result<[f64; 6], tiff::TiffError> {
+    let mut transform = if let Some(matrix) = decoder.find_tag(Tag::ModelTransformationTag)? {
+        let m = matrix.into_f64_vec()?;
+        if m.len() < 8 {
+            return Err(invalid_tag("ModelTransformation"));
+        }
+        [m[3], m[0], m[1], m[7], m[4], m[5]]
+    } else {
+        let scale = decoder
+            .find_tag(Tag::ModelPixelScaleTag)?
+            .ok_or_else(|| invalid_tag("ModelPixelScale"))?
+            .into_f64_vec()?;
+        let tiepoint = decoder
+            .find_tag(Tag::ModelTiepointTag)?
+            .ok_or_else(|| invalid_tag("ModelTiepoint"))?
+            .into_f64_vec()?;
+        if scale.len() < 2 || tiepoint.len() < 6 {
+            return Err(invalid_tag("ModelPixelScale/ModelTiepoint"));
+        }
+        [
+            tiepoint[3] - tiepoint[0] * scale[0],
+            scale[0],
+            0.0,
+            tiepoint[4] + tiepoint[1] * scale[1],
+            0.0,
+            -scale[1],
+        ]
+    };
+
+    // GDAL transforms always refer to the corner of the pixels
+    if raster_type(decoder)? == Some(RASTER_PIXEL_IS_POINT) {
+        transform[0] -= 0.5 * (transform[1] + transform[2]);
+        transform[3] -= 0.5 * (transform[4] + transform[5]);
+    }
+    Ok(transform)
+}
+
+fn raster_type(decoder: &mut Decoder<BufReader<File>>) -> Result<Option<u16>, tiff::TiffError> {
+    let Some(directory) = decoder.find_tag(Tag::GeoKeyDirectoryTag)? else {
+        return Ok(None);
+    };
+    let directory = directory.into_u16_vec()?;
+    // a header of 4 values is followed by entries of key id, location, count and value
+    Ok(directory
+        .get(4..)
+        .unwrap_or_default()
+        .chunks_exact(4)
+        .find(|entry| entry[0] == GT_RASTER_TYPE_GEO_KEY && entry[1] == 0)
+        .map(|entry| entry[3]))
+}
+
+fn invalid_tag(name: &str) -> tiff::TiffError {
+    tiff::TiffError::FormatError(tiff::TiffFormatError::Format(format!(
+        "missing or invalid {} tag - the file is not a GeoTIFF",
+        name
+    )))
+}
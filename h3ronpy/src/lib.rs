@@ -11,15 +11,23 @@
 use pyo3::{prelude::*, wrap_pyfunction, Python};
 
 use crate::op::init_op_submodule;
-use crate::raster::init_raster_submodule;
 use crate::vector::{init_vector_submodule, PyContainmentMode};
 
 mod array;
 mod arrow_interop;
+mod device;
 mod error;
+#[cfg(feature = "io")]
+mod geoparquet;
+#[cfg(feature = "pure-rust")]
+mod geotiff;
 mod op;
+#[cfg(feature = "raster")]
 mod raster;
+#[cfg(feature = "proj")]
+mod reproject;
 mod resolution;
+#[cfg(feature = "raster")]
 mod transform;
 mod vector;
 
@@ -48,10 +56,18 @@ fn h3ronpyrs(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyContainmentMode>()?;
     m.add_function(wrap_pyfunction!(version, m)?)?;
     m.add_function(wrap_pyfunction!(is_release_build, m)?)?;
+    m.add_function(wrap_pyfunction!(device::array_device, m)?)?;
+    #[cfg(feature = "cuda")]
+    m.add_function(wrap_pyfunction!(device::cuda_array_to_host, m)?)?;
+    m.add_function(wrap_pyfunction!(arrow_interop::set_assume_valid, m)?)?;
 
-    let raster_submod = PyModule::new_bound(py, "raster")?;
-    init_raster_submodule(&raster_submod)?;
-    m.add_submodule(&raster_submod)?;
+    // the raster submodule is optional, the python side checks for its presence
+    #[cfg(feature = "raster")]
+    {
+        let raster_submod = PyModule::new_bound(py, "raster")?;
+        raster::init_raster_submodule(&raster_submod)?;
+        m.add_submodule(&raster_submod)?;
+    }
 
     let op_submod = PyModule::new_bound(py, "op")?;
     init_op_submodule(&op_submod)?;
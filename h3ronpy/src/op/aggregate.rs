@@ -0,0 +1,398 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, Float32Array, Float64Array, RecordBatch, UInt64Array,
+};
+use arrow::compute::take;
+use arrow::datatypes::{DataType, Field, Float32Type, Float64Type, Schema};
+use arrow::row::{RowConverter, SortField};
+use h3arrow::export::h3o::Resolution;
+use hashbrown::HashMap;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::{PyArrowError, PyArrowResult};
+use pyo3_arrow::{PyArray, PyRecordBatch};
+
+use crate::array::PyCellArray;
+use crate::error::IntoPyResult;
+use crate::DEFAULT_CELL_COLUMN_NAME;
+
+#[derive(Clone, Copy)]
+enum Aggregation {
+    Count,
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+impl Aggregation {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::Sum => "sum",
+            Self::Mean => "mean",
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+}
+
+impl FromStr for Aggregation {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "count" => Ok(Self::Count),
+            "sum" => Ok(Self::Sum),
+            "mean" => Ok(Self::Mean),
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            _ => Err(PyValueError::new_err(format!("unknown aggregation: {}", s))),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Accumulator {
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl Accumulator {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn get(&self, aggregation: Aggregation) -> Option<f64> {
+        match aggregation {
+            Aggregation::Count => Some(self.count as f64),
+            _ if self.count == 0 => None,
+            Aggregation::Sum => Some(self.sum),
+            Aggregation::Mean => Some(self.sum / self.count as f64),
+            Aggregation::Min => Some(self.min),
+            Aggregation::Max => Some(self.max),
+        }
+    }
+}
+
+/// Value column of `aggregate_by`. Float32 columns are kept as they are to produce Float32
+/// aggregates, the accumulation itself always happens in f64.
+enum FloatValues {
+    Float32(Float32Array),
+    Float64(Float64Array),
+}
+
+impl FloatValues {
+    fn try_from_array(array: ArrayRef) -> PyResult<Self> {
+        match array.data_type() {
+            DataType::Float32 => Ok(Self::Float32(array.as_primitive::<Float32Type>().clone())),
+            DataType::Float64 => Ok(Self::Float64(array.as_primitive::<Float64Type>().clone())),
+            _ => Err(PyValueError::new_err(
+                "values must be of type float32 or float64",
+            )),
+        }
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Self::Float32(_) => DataType::Float32,
+            Self::Float64(_) => DataType::Float64,
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Float32(array) => array.len(),
+            Self::Float64(array) => array.len(),
+        }
+    }
+
+    fn get(&self, i: usize) -> Option<f64> {
+        match self {
+            Self::Float32(array) => array.is_valid(i).then(|| array.value(i) as f64),
+            Self::Float64(array) => array.is_valid(i).then(|| array.value(i)),
+        }
+    }
+
+    /// Build an array of the same type from the aggregated values.
+    fn to_array(&self, aggregated: impl Iterator<Item = Option<f64>>) -> ArrayRef {
+        match self {
+            Self::Float32(_) => Arc::new(
+                aggregated
+                    .map(|v| v.map(|v| v as f32))
+                    .collect::<Float32Array>(),
+            ),
+            Self::Float64(_) => Arc::new(aggregated.collect::<Float64Array>()),
+        }
+    }
+}
+
+/// Group the rows by the parent cells of `cellarray` at `cell_resolution` and the values of the
+/// `keys` arrays, and aggregate the `values` arrays per group - all in a single pass.
+///
+/// `aggs` is a list of (value index, aggregation) tuples. The output has the columns `cell`,
+/// `key_0 .. key_n`, `count` (the number of rows of the group) and one column per aggregation
+/// named `value_<index>_<aggregation>`, typed like the value array - float32 or float64. Rows
+/// with a null cell are skipped, null values are not included in the aggregations. The groups
+/// are ordered by their first occurrence.
+#[pyfunction]
+#[pyo3(signature = (cellarray, keys, values, aggs, cell_resolution))]
+pub(crate) fn aggregate_by(
+    py: Python<'_>,
+    cellarray: PyCellArray,
+    keys: Vec<PyArray>,
+    values: Vec<PyArray>,
+    aggs: Vec<(usize, String)>,
+    cell_resolution: u8,
+) -> PyArrowResult<PyObject> {
+    let cell_resolution = Resolution::try_from(cell_resolution).into_pyresult()?;
+    let cells = cellarray.into_inner();
+    let keys: Vec<ArrayRef> = keys.into_iter().map(|key| key.into_inner().0).collect();
+    let values: Vec<FloatValues> = values
+        .into_iter()
+        .map(|value| FloatValues::try_from_array(value.into_inner().0))
+        .collect::<PyResult<_>>()?;
+    if keys
+        .iter()
+        .map(|key| key.len())
+        .chain(values.iter().map(|value| value.len()))
+        .any(|len| len != cells.len())
+    {
+        return Err(PyValueError::new_err("all arrays must be of the same length").into());
+    }
+    let aggs = aggs
+        .into_iter()
+        .map(|(value_index, aggregation)| {
+            if value_index >= values.len() {
+                return Err(PyValueError::new_err("value index out of bounds"));
+            }
+            Ok((value_index, Aggregation::from_str(&aggregation)?))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let (parents, first_rows, counts, accumulators) = py.allow_threads(|| {
+        let mut parents = Vec::with_capacity(cells.len());
+        for cell in cells.iter() {
+            parents.push(match cell {
+                Some(cell) => Some(cell.parent(cell_resolution).ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "cell {} is of a coarser resolution than {}",
+                        cell, cell_resolution
+                    ))
+                })?),
+                None => None,
+            });
+        }
+        let parents: ArrayRef = Arc::new(UInt64Array::from_iter(
+            parents.into_iter().map(|parent| parent.map(u64::from)),
+        ));
+
+        let mut columns = vec![parents.clone()];
+        columns.extend(keys.iter().cloned());
+        let converter = RowConverter::new(
+            columns
+                .iter()
+                .map(|column| SortField::new(column.data_type().clone()))
+                .collect(),
+        )?;
+        let rows = converter.convert_columns(&columns)?;
+
+        let mut groups = HashMap::new();
+        let mut first_rows: Vec<u64> = vec![];
+        let mut counts: Vec<u64> = vec![];
+        let mut accumulators: Vec<Accumulator> = vec![];
+        for i in (0..cells.len()).filter(|i| columns[0].is_valid(*i)) {
+            let group = *groups.entry(rows.row(i)).or_insert_with(|| {
+                first_rows.push(i as u64);
+                counts.push(0);
+                accumulators.extend(std::iter::repeat(Accumulator::default()).take(values.len()));
+                counts.len() - 1
+            });
+            counts[group] += 1;
+            for (value_index, value) in values.iter().enumerate() {
+                if let Some(value) = value.get(i) {
+                    accumulators[group * values.len() + value_index].push(value);
+                }
+            }
+        }
+        Ok::<_, PyArrowError>((parents, UInt64Array::from(first_rows), counts, accumulators))
+    })?;
+
+    let mut fields = vec![Field::new(
+        DEFAULT_CELL_COLUMN_NAME,
+        DataType::UInt64,
+        false,
+    )];
+    let mut columns: Vec<ArrayRef> = vec![take(parents.as_ref(), &first_rows, None)?];
+    for (key_index, key) in keys.iter().enumerate() {
+        fields.push(Field::new(
+            format!("key_{}", key_index),
+            key.data_type().clone(),
+            true,
+        ));
+        columns.push(take(key.as_ref(), &first_rows, None)?);
+    }
+    fields.push(Field::new("count", DataType::UInt64, false));
+    columns.push(Arc::new(UInt64Array::from(counts)));
+    for (value_index, aggregation) in aggs {
+        fields.push(Field::new(
+            format!("value_{}_{}", value_index, aggregation.name()),
+            values[value_index].data_type(),
+            true,
+        ));
+        columns.push(
+            values[value_index].to_array(
+                accumulators
+                    .iter()
+                    .skip(value_index)
+                    .step_by(values.len())
+                    .map(|accumulator| accumulator.get(aggregation)),
+            ),
+        );
+    }
+
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+/// Merge per-cell values of a new batch into an existing state, decaying all values
+/// exponentially to the time `now`.
+///
+/// The times are given as numbers in the unit of `half_life`. Each value is weighted by
+/// `0.5 ^ ((now - time) / half_life)` and the weighted values are summed up per cell. Cells with
+/// all their decayed values below `prune_below` are dropped. The output has the columns `cell`
+/// and `value_0 .. value_n`. The cells of the state come first in their order, followed by the
+/// cells only found in the new batch. Rows with a null cell are skipped, null values count as zero.
+#[pyfunction]
+#[pyo3(signature = (current_cells, current_times, current_values, incoming_cells, incoming_times, incoming_values, half_life, now, prune_below = None))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decay_merge(
+    py: Python<'_>,
+    current_cells: PyCellArray,
+    current_times: PyArray,
+    current_values: Vec<PyArray>,
+    incoming_cells: PyCellArray,
+    incoming_times: PyArray,
+    incoming_values: Vec<PyArray>,
+    half_life: f64,
+    now: f64,
+    prune_below: Option<f64>,
+) -> PyArrowResult<PyObject> {
+    if !(half_life.is_finite() && half_life > 0.0) {
+        return Err(PyValueError::new_err("half_life must be a positive number").into());
+    }
+    if current_values.len() != incoming_values.len() {
+        return Err(PyValueError::new_err(
+            "the state and the new batch must have the same number of values",
+        )
+        .into());
+    }
+    let parts = [
+        (
+            current_cells.into_inner(),
+            float64_array(current_times)?,
+            float64_arrays(current_values)?,
+        ),
+        (
+            incoming_cells.into_inner(),
+            float64_array(incoming_times)?,
+            float64_arrays(incoming_values)?,
+        ),
+    ];
+    for (cells, times, values) in parts.iter() {
+        if times.len() != cells.len() || values.iter().any(|value| value.len() != cells.len()) {
+            return Err(PyValueError::new_err("all arrays must be of the same length").into());
+        }
+    }
+    let n_values = parts[0].2.len();
+
+    let (cells, sums) = py.allow_threads(|| {
+        let mut groups = HashMap::new();
+        let mut cells: Vec<u64> = vec![];
+        let mut sums: Vec<f64> = vec![];
+        for (part_cells, times, values) in parts.iter() {
+            for (i, cell) in part_cells.iter().enumerate() {
+                let Some(cell) = cell else {
+                    continue;
+                };
+                if times.is_null(i) {
+                    return Err(PyValueError::new_err("times must not be null"));
+                }
+                let weight = 0.5f64.powf((now - times.value(i)) / half_life);
+                let group = *groups.entry(cell).or_insert_with(|| {
+                    cells.push(u64::from(cell));
+                    sums.extend(std::iter::repeat(0.0).take(n_values));
+                    cells.len() - 1
+                });
+                for (value_index, value) in values.iter().enumerate() {
+                    if value.is_valid(i) {
+                        sums[group * n_values + value_index] += value.value(i) * weight;
+                    }
+                }
+            }
+        }
+        Ok((cells, sums))
+    })?;
+
+    let keep: Vec<usize> = (0..cells.len())
+        .filter(|group| match prune_below {
+            Some(prune_below) => sums[group * n_values..(group + 1) * n_values]
+                .iter()
+                .any(|sum| *sum >= prune_below),
+            None => true,
+        })
+        .collect();
+
+    let mut fields = vec![Field::new(
+        DEFAULT_CELL_COLUMN_NAME,
+        DataType::UInt64,
+        false,
+    )];
+    let mut columns: Vec<ArrayRef> = vec![Arc::new(UInt64Array::from_iter_values(
+        keep.iter().map(|group| cells[*group]),
+    ))];
+    for value_index in 0..n_values {
+        fields.push(Field::new(
+            format!("value_{}", value_index),
+            DataType::Float64,
+            false,
+        ));
+        columns.push(Arc::new(Float64Array::from_iter_values(
+            keep.iter()
+                .map(|group| sums[group * n_values + value_index]),
+        )));
+    }
+
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+fn float64_array(array: PyArray) -> PyResult<Float64Array> {
+    let array = array.into_inner().0;
+    if array.data_type() != &DataType::Float64 {
+        return Err(PyValueError::new_err("values must be of type float64"));
+    }
+    Ok(array.as_primitive::<Float64Type>().clone())
+}
+
+fn float64_arrays(arrays: Vec<PyArray>) -> PyResult<Vec<Float64Array>> {
+    arrays.into_iter().map(float64_array).collect()
+}
@@ -0,0 +1,71 @@
+use arrow::array::{Array, ArrayRef, UInt32Array};
+use arrow::compute::{cast, concat, take};
+use numpy::PyArray1;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::{PyArray, PyDataType};
+
+use crate::array::PyCellArray;
+
+/// Call `func` with numpy arrays of up to `batch_size` cells and assemble the arrays it returns
+/// into a single array of `output_type`.
+///
+/// Null cells are not passed to `func` and are null in the output.
+#[pyfunction]
+#[pyo3(signature = (cellarray, func, output_type, batch_size = 65536))]
+pub(crate) fn apply_per_cell(
+    py: Python,
+    cellarray: PyCellArray,
+    func: &Bound<PyAny>,
+    output_type: PyDataType,
+    batch_size: usize,
+) -> PyArrowResult<PyObject> {
+    if batch_size == 0 {
+        return Err(PyValueError::new_err("batch_size must be larger than 0").into());
+    }
+    let output_type = output_type.into_inner();
+    let cells = cellarray.into_inner();
+    let cells = cells.primitive_array();
+
+    let mut outputs: Vec<ArrayRef> = Vec::with_capacity(cells.len().div_ceil(batch_size));
+    let mut offset = 0;
+    while offset < cells.len() {
+        let batch = cells.slice(offset, batch_size.min(cells.len() - offset));
+        offset += batch.len();
+
+        let valid_cells: Vec<u64> = batch.iter().flatten().collect();
+        let n_valid_cells = valid_cells.len();
+        let result = func.call1((PyArray1::from_vec_bound(py, valid_cells),))?;
+        let (result, _) = result.extract::<PyArray>()?.into_inner();
+        if result.len() != n_valid_cells {
+            return Err(PyValueError::new_err(format!(
+                "func returned {} values for {} cells",
+                result.len(),
+                n_valid_cells
+            ))
+            .into());
+        }
+        let result = cast(&result, &output_type)?;
+
+        // position of the returned value for each cell of the batch, null for null cells.
+        let mut position = 0u32;
+        let indices: UInt32Array = batch
+            .iter()
+            .map(|cell| {
+                cell.map(|_| {
+                    position += 1;
+                    position - 1
+                })
+            })
+            .collect();
+        outputs.push(take(&result, &indices, None)?);
+    }
+
+    let output = if outputs.is_empty() {
+        arrow::array::new_empty_array(&output_type)
+    } else {
+        concat(&outputs.iter().map(|a| a.as_ref()).collect::<Vec<_>>())?
+    };
+    Ok(PyArray::from_array_ref(output).to_arro3(py)?)
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use arrow::array::{Float64Array, UInt32Array};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::PyArray;
+
+use crate::arrow_interop::pyarray_to_native;
+
+type Rgba = (u8, u8, u8, u8);
+
+const VIRIDIS: &[u32] = &[
+    0x440154, 0x472d7b, 0x3b528b, 0x2c728e, 0x21918c, 0x28ae80, 0x5ec962, 0xaddc30, 0xfde725,
+];
+const MAGMA: &[u32] = &[
+    0x000004, 0x1c1044, 0x4f127b, 0x812581, 0xb5367a, 0xe55064, 0xfb8761, 0xfec287, 0xfcfdbf,
+];
+const GRAY: &[u32] = &[0x000000, 0xffffff];
+
+fn named_colormap(name: &str) -> PyResult<Vec<Rgba>> {
+    let stops = match name.to_lowercase().as_str() {
+        "viridis" => VIRIDIS,
+        "magma" => MAGMA,
+        "gray" | "grey" => GRAY,
+        _ => return Err(PyValueError::new_err(format!("unknown colormap {name}"))),
+    };
+    Ok(stops
+        .iter()
+        .map(|rgb| ((rgb >> 16) as u8, (rgb >> 8) as u8, *rgb as u8, 255))
+        .collect())
+}
+
+/// Pack the color into an u32 whose little-endian bytes are R, G, B and A.
+#[inline]
+fn pack(color: Rgba) -> u32 {
+    u32::from_le_bytes([color.0, color.1, color.2, color.3])
+}
+
+fn interpolate(colors: &[Rgba], position: f64) -> Rgba {
+    if colors.len() == 1 {
+        return colors[0];
+    }
+    let scaled = position.clamp(0.0, 1.0) * (colors.len() - 1) as f64;
+    let lower = (scaled.floor() as usize).min(colors.len() - 2);
+    let fraction = scaled - lower as f64;
+    let (a, b) = (colors[lower], colors[lower + 1]);
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction).round() as u8;
+    (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2), mix(a.3, b.3))
+}
+
+/// Map the values to colors of the colormap `cmap` - either the name of a built-in colormap
+/// or a list of (r, g, b, a) tuples - and return them packed into u32 values.
+///
+/// Null and NaN values are null in the output.
+#[pyfunction]
+#[pyo3(signature = (valuearray, cmap, vmin = None, vmax = None))]
+pub(crate) fn colorize(
+    py: Python,
+    valuearray: &Bound<PyAny>,
+    cmap: &Bound<PyAny>,
+    vmin: Option<f64>,
+    vmax: Option<f64>,
+) -> PyResult<PyObject> {
+    let values = pyarray_to_native::<Float64Array>(valuearray)?;
+    let colors = if let Ok(name) = cmap.extract::<String>() {
+        named_colormap(&name)?
+    } else {
+        cmap.extract::<Vec<Rgba>>()?
+    };
+    if colors.is_empty() {
+        return Err(PyValueError::new_err(
+            "colormap must contain at least one color",
+        ));
+    }
+
+    let colorized: UInt32Array = py.allow_threads(|| {
+        let finite = || values.iter().flatten().filter(|v| v.is_finite());
+        let vmin = vmin.unwrap_or_else(|| finite().fold(f64::INFINITY, f64::min));
+        let vmax = vmax.unwrap_or_else(|| finite().fold(f64::NEG_INFINITY, f64::max));
+        let range = vmax - vmin;
+
+        values
+            .iter()
+            .map(|value| {
+                let value = value.filter(|v| !v.is_nan())?;
+                let position = if range > 0.0 {
+                    (value - vmin) / range
+                } else {
+                    0.0
+                };
+                Some(pack(interpolate(&colors, position)))
+            })
+            .collect()
+    });
+    PyArray::from_array_ref(Arc::new(colorized)).to_arro3(py)
+}
@@ -1,6 +1,12 @@
-use h3arrow::algorithm::CompactOp;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Float64Array, RecordBatch, UInt64Array, UInt8Array};
+use arrow::datatypes::{DataType, Field, Schema};
+use h3arrow::algorithm::{CompactOp, DifferenceOp, StreamingCompactor};
 use h3arrow::export::h3o::Resolution;
 use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::PyRecordBatch;
 
 use crate::array::PyCellArray;
 use crate::arrow_interop::*;
@@ -39,3 +45,147 @@ pub(crate) fn uncompact(
     let out = py.allow_threads(|| cellarray.uncompact(target_resolution));
     h3array_to_pyarray(out, py)
 }
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, exclude))]
+pub(crate) fn cells_without(
+    py: Python<'_>,
+    cellarray: PyCellArray,
+    exclude: PyCellArray,
+) -> PyResult<PyObject> {
+    let cellarray = cellarray.into_inner();
+    let exclude = exclude.into_inner();
+    let out = py.allow_threads(|| cellarray.without(&exclude));
+    h3array_to_pyarray(out, py)
+}
+
+/// Report the number of cells and their area per resolution, together with the number of cells
+/// remaining at each resolution after compacting the cells.
+///
+/// The last row has a null resolution and contains the totals: the area of the compacted - and
+/// thereby deduplicated - cells and the ratio of the number of compacted cells to the number of
+/// input cells.
+#[pyfunction]
+#[pyo3(signature = (cellarray,))]
+pub(crate) fn coverage_report(py: Python<'_>, cellarray: PyCellArray) -> PyArrowResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let (n_cells, area_km2, n_cells_compacted, compacted_area_km2) = py
+        .allow_threads(|| {
+            let mut n_cells = [0u64; 16];
+            let mut area_km2 = [0f64; 16];
+            for cell in cellindexarray.iter().flatten() {
+                n_cells[usize::from(cell.resolution())] += 1;
+                area_km2[usize::from(cell.resolution())] += cell.area_km2();
+            }
+
+            let compacted = cellindexarray.compact_mixed_resolutions()?;
+            let mut n_cells_compacted = [0u64; 16];
+            let mut compacted_area_km2 = 0.0;
+            for cell in compacted.iter().flatten() {
+                n_cells_compacted[usize::from(cell.resolution())] += 1;
+                compacted_area_km2 += cell.area_km2();
+            }
+            Ok::<_, h3arrow::error::Error>((
+                n_cells,
+                area_km2,
+                n_cells_compacted,
+                compacted_area_km2,
+            ))
+        })
+        .into_pyresult()?;
+
+    let resolutions: Vec<usize> = (0..16)
+        .filter(|r| n_cells[*r] > 0 || n_cells_compacted[*r] > 0)
+        .collect();
+    let total_n_cells: u64 = n_cells.iter().sum();
+    let total_n_cells_compacted: u64 = n_cells_compacted.iter().sum();
+
+    let resolution_column: UInt8Array = resolutions
+        .iter()
+        .map(|r| Some(*r as u8))
+        .chain(std::iter::once(None))
+        .collect();
+    let n_cells_column: UInt64Array = resolutions
+        .iter()
+        .map(|r| n_cells[*r])
+        .chain(std::iter::once(total_n_cells))
+        .map(Some)
+        .collect();
+    let area_column: Float64Array = resolutions
+        .iter()
+        .map(|r| area_km2[*r])
+        .chain(std::iter::once(compacted_area_km2))
+        .map(Some)
+        .collect();
+    let n_cells_compacted_column: UInt64Array = resolutions
+        .iter()
+        .map(|r| n_cells_compacted[*r])
+        .chain(std::iter::once(total_n_cells_compacted))
+        .map(Some)
+        .collect();
+    let compaction_ratio_column: Float64Array = resolutions
+        .iter()
+        .map(|_| None)
+        .chain(std::iter::once((total_n_cells > 0).then(|| {
+            total_n_cells_compacted as f64 / total_n_cells as f64
+        })))
+        .collect();
+
+    let schema = Schema::new(vec![
+        Field::new("resolution", DataType::UInt8, true),
+        Field::new("n_cells", DataType::UInt64, false),
+        Field::new("area_km2", DataType::Float64, false),
+        Field::new("n_cells_compacted", DataType::UInt64, false),
+        Field::new("compaction_ratio", DataType::Float64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(resolution_column),
+        Arc::new(n_cells_column),
+        Arc::new(area_column),
+        Arc::new(n_cells_compacted_column),
+        Arc::new(compaction_ratio_column),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+/// Compaction of a stream of cells which does not need to fit into memory at once. Branches
+/// exceeding the `max_cells` limit are spilled to files in `spill_directory`.
+#[pyclass(name = "StreamingCompactor")]
+pub(crate) struct PyStreamingCompactor {
+    inner: StreamingCompactor,
+}
+
+#[pymethods]
+impl PyStreamingCompactor {
+    #[new]
+    fn new(max_cells: usize, spill_directory: std::path::PathBuf) -> Self {
+        Self {
+            inner: StreamingCompactor::new(max_cells, spill_directory),
+        }
+    }
+
+    /// Add cells. Returns the base cells of the branches which have been finalized by them.
+    fn push(&mut self, py: Python<'_>, cellarray: PyCellArray) -> PyResult<PyObject> {
+        let cellarray = cellarray.into_inner();
+        let finalized = py
+            .allow_threads(|| self.inner.push(&cellarray))
+            .into_pyresult()?;
+        h3array_to_pyarray(finalized, py)
+    }
+
+    /// The compacted cells of the next remaining branch, or `None` when all have been emitted.
+    fn next_branch(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        match py
+            .allow_threads(|| self.inner.next_branch())
+            .into_pyresult()?
+        {
+            Some(cells) => Ok(Some(h3array_to_pyarray(cells, py)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, BooleanArray, Float64Array};
+use h3arrow::algorithm::coverage::{self, Coverage};
+use h3arrow::export::h3o::{LatLng, Resolution};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3_arrow::PyArray;
+
+use crate::array::PyCellArray;
+use crate::arrow_interop::{h3array_to_pyarray, pyarray_to_native};
+use crate::error::IntoPyResult;
+
+/// Test which points are covered by the cells of `coverage`.
+///
+/// The points are converted to cells of `resolution` - defaulting to the finest resolution of
+/// the coverage - and looked up together with their ancestors.
+#[pyfunction]
+#[pyo3(signature = (latarray, lngarray, coverage, resolution = None, radians = false))]
+pub(crate) fn points_in_coverage(
+    py: Python<'_>,
+    latarray: &Bound<PyAny>,
+    lngarray: &Bound<PyAny>,
+    coverage: PyCellArray,
+    resolution: Option<u8>,
+    radians: bool,
+) -> PyResult<PyObject> {
+    let latarray: Float64Array = pyarray_to_native(latarray)?;
+    let lngarray: Float64Array = pyarray_to_native(lngarray)?;
+    if lngarray.len() != latarray.len() {
+        return Err(PyValueError::new_err(
+            "latarray and lngarray must be of the same length",
+        ));
+    }
+    let resolution = resolution
+        .map(Resolution::try_from)
+        .transpose()
+        .into_pyresult()?;
+    let coverage = coverage.into_inner();
+
+    let out = py.allow_threads(|| {
+        let coverage = Coverage::new(&coverage);
+        // an empty coverage contains nothing, the resolution does not matter
+        let Some(resolution) = resolution.or(coverage.max_resolution()) else {
+            return Ok(latarray
+                .iter()
+                .zip(lngarray.iter())
+                .map(|(lat, lng)| lat.and(lng).map(|_| false))
+                .collect::<BooleanArray>());
+        };
+
+        latarray
+            .iter()
+            .zip(lngarray.iter())
+            .map(|(lat, lng)| {
+                if let (Some(lat), Some(lng)) = (lat, lng) {
+                    if radians {
+                        LatLng::from_radians(lat, lng).into_pyresult()
+                    } else {
+                        LatLng::new(lat, lng).into_pyresult()
+                    }
+                    .map(|ll| Some(coverage.contains_latlng(ll, resolution)))
+                } else {
+                    Ok(None)
+                }
+            })
+            .collect::<PyResult<BooleanArray>>()
+    })?;
+    PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
+}
+
+/// Compare the areas covered by two coverages of possibly mixed resolutions.
+#[pyfunction]
+#[pyo3(signature = (a, b))]
+pub(crate) fn coverage_similarity(
+    py: Python<'_>,
+    a: PyCellArray,
+    b: PyCellArray,
+) -> PyResult<PyObject> {
+    let a = a.into_inner();
+    let b = b.into_inner();
+    let similarity = py
+        .allow_threads(|| coverage::coverage_similarity(&a, &b))
+        .into_pyresult()?;
+
+    let out = PyDict::new_bound(py);
+    out.set_item("jaccard", similarity.jaccard)?;
+    out.set_item("intersection_area_km2", similarity.intersection_area_km2)?;
+    out.set_item("union_area_km2", similarity.union_area_km2)?;
+    out.set_item("area_a_km2", similarity.area_a_km2)?;
+    out.set_item("area_b_km2", similarity.area_b_km2)?;
+    out.set_item("n_cells_a", similarity.n_cells_a)?;
+    out.set_item("n_cells_b", similarity.n_cells_b)?;
+    out.set_item("n_cells_intersection", similarity.n_cells_intersection)?;
+    Ok(out.into())
+}
+
+/// Compact the cells and sort them in the canonical order expected by `cellset_contains`.
+#[pyfunction]
+#[pyo3(signature = (cellarray))]
+pub(crate) fn cellset_canonicalize(py: Python<'_>, cellarray: PyCellArray) -> PyResult<PyObject> {
+    let cellarray = cellarray.into_inner();
+    let canonical = py
+        .allow_threads(|| coverage::cellset_canonicalize(&cellarray))
+        .into_pyresult()?;
+    h3array_to_pyarray(canonical, py)
+}
+
+/// Test which cells of `query` are covered by the canonical `cellset` using a binary search.
+#[pyfunction]
+#[pyo3(signature = (cellset, query))]
+pub(crate) fn cellset_contains(
+    py: Python<'_>,
+    cellset: PyCellArray,
+    query: PyCellArray,
+) -> PyResult<PyObject> {
+    let cellset = cellset.into_inner();
+    let query = query.into_inner();
+    let out = py
+        .allow_threads(|| coverage::cellset_contains(&cellset, &query))
+        .into_pyresult()?;
+    PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
+}
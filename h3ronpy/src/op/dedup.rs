@@ -0,0 +1,75 @@
+use std::hash::Hash;
+use std::sync::Arc;
+
+use arrow::array::{Array, AsArray, UInt64Array};
+use arrow::datatypes::{DataType, UInt64Type};
+use arrow::row::{RowConverter, SortField};
+use hashbrown::HashSet;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::PyArray;
+
+/// Find the indexes of the rows to keep when removing rows with duplicate keys.
+///
+/// All key arrays must be of the same length. Null values are considered equal to each other. The
+/// returned indexes are in ascending order, so taking them preserves the order of the rows. Single
+/// `UInt64` keys - cells - are hashed directly, all other keys are converted to the arrow row
+/// format first.
+#[pyfunction]
+#[pyo3(signature = (keys, keep_last = false))]
+pub(crate) fn unique_row_indices(
+    py: Python,
+    keys: Vec<PyArray>,
+    keep_last: bool,
+) -> PyArrowResult<PyObject> {
+    let keys: Vec<_> = keys.into_iter().map(|key| key.into_inner().0).collect();
+    let Some(len) = keys.first().map(|key| key.len()) else {
+        return Err(PyValueError::new_err("at least one key is required").into());
+    };
+    if keys.iter().any(|key| key.len() != len) {
+        return Err(PyValueError::new_err("all keys must be of the same length").into());
+    }
+
+    let indexes = py.allow_threads(|| {
+        if let [key] = keys.as_slice() {
+            if key.data_type() == &DataType::UInt64 {
+                let values = key.as_primitive::<UInt64Type>();
+                return Ok(unique_indexes(len, keep_last, |i| {
+                    values.is_valid(i).then(|| values.value(i))
+                }));
+            }
+        }
+
+        let converter = RowConverter::new(
+            keys.iter()
+                .map(|key| SortField::new(key.data_type().clone()))
+                .collect(),
+        )?;
+        let rows = converter.convert_columns(&keys)?;
+        Ok::<_, arrow::error::ArrowError>(unique_indexes(len, keep_last, |i| rows.row(i)))
+    })?;
+
+    Ok(PyArray::from_array_ref(Arc::new(indexes)).to_arro3(py)?)
+}
+
+fn unique_indexes<K, F>(len: usize, keep_last: bool, key_at: F) -> UInt64Array
+where
+    K: Hash + Eq,
+    F: Fn(usize) -> K,
+{
+    let mut seen = HashSet::with_capacity(len);
+    let mut indexes: Vec<u64> = Vec::with_capacity(len);
+    let mut visit = |i: usize| {
+        if seen.insert(key_at(i)) {
+            indexes.push(i as u64);
+        }
+    };
+    if keep_last {
+        (0..len).rev().for_each(&mut visit);
+        indexes.reverse();
+    } else {
+        (0..len).for_each(&mut visit);
+    }
+    UInt64Array::from(indexes)
+}
@@ -0,0 +1,13 @@
+use pyo3::prelude::*;
+
+use crate::array::PyDirectedEdgeArray;
+use crate::arrow_interop::*;
+
+#[pyfunction]
+pub(crate) fn directededges_to_undirected_key(
+    py: Python,
+    directededgearray: PyDirectedEdgeArray,
+) -> PyResult<PyObject> {
+    let keys = py.allow_threads(|| directededgearray.as_ref().undirected_key());
+    h3array_to_pyarray(keys, py)
+}
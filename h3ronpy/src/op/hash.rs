@@ -0,0 +1,61 @@
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{UInt32Type, UInt64Type};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::PyArray;
+use twox_hash::{xxh3, XxHash32, XxHash64};
+
+use crate::array::PyCellArray;
+
+/// Hash the cells using a stable, non-cryptographic hash function - for bucketing and sharding.
+///
+/// The hashed input are the 8 bytes of the cell index in little-endian byte order, so the hashes can
+/// be reproduced by other implementations of the algorithms.
+#[pyfunction]
+#[pyo3(signature = (cellarray, algorithm = "xxhash64", seed = 0))]
+pub(crate) fn cells_hash(
+    py: Python,
+    cellarray: PyCellArray,
+    algorithm: &str,
+    seed: u64,
+) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let h3indexes = cellindexarray.primitive_array();
+
+    let hashes: ArrayRef = match algorithm.to_lowercase().as_str() {
+        "xxhash64" => Arc::new(py.allow_threads(|| {
+            h3indexes.unary::<_, UInt64Type>(|h3index| {
+                let mut hasher = XxHash64::with_seed(seed);
+                hasher.write(&h3index.to_le_bytes());
+                hasher.finish()
+            })
+        })),
+        "xxhash32" => {
+            let seed = u32::try_from(seed).map_err(|_| {
+                PyValueError::new_err("xxhash32 requires a seed fitting into 32 bits")
+            })?;
+            Arc::new(py.allow_threads(|| {
+                h3indexes.unary::<_, UInt32Type>(|h3index| {
+                    let mut hasher = XxHash32::with_seed(seed);
+                    hasher.write(&h3index.to_le_bytes());
+                    hasher.finish() as u32
+                })
+            }))
+        }
+        "xxh3" => Arc::new(py.allow_threads(|| {
+            h3indexes.unary::<_, UInt64Type>(|h3index| {
+                xxh3::hash64_with_seed(&h3index.to_le_bytes(), seed)
+            })
+        })),
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "unsupported hash algorithm: {}",
+                algorithm
+            )))
+        }
+    };
+    PyArray::from_array_ref(hashes).to_arro3(py)
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use arrow::array::{Int64Array, UInt64Array};
+use h3arrow::array::{CellIndexArray, FromIteratorWithValidity};
+use pyo3::prelude::*;
+use pyo3_arrow::PyArray;
+
+use crate::array::PyCellArray;
+use crate::arrow_interop::*;
+use crate::error::IntoPyResult;
+
+/// Reinterpret the bits of the cells as signed 64bit integers - the representation used by Spark
+/// and some databases.
+#[pyfunction]
+pub(crate) fn cells_to_int64(py: Python, cellarray: PyCellArray) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let int64array: Int64Array = cellindexarray
+        .primitive_array()
+        .unary(|h3index| h3index as i64);
+    PyArray::from_array_ref(Arc::new(int64array)).to_arro3(py)
+}
+
+/// Reinterpret the bits of signed 64bit integers as cells.
+#[pyfunction]
+#[pyo3(signature = (array, set_failing_to_invalid = false))]
+pub(crate) fn int64_to_cells(
+    py: Python,
+    array: &Bound<PyAny>,
+    set_failing_to_invalid: bool,
+) -> PyResult<PyObject> {
+    let int64array = pyarray_to_native::<Int64Array>(array)?;
+    let cells = py.allow_threads(|| {
+        let u64array: UInt64Array = int64array.unary(|value| value as u64);
+        if set_failing_to_invalid {
+            Ok(CellIndexArray::from_iter_with_validity(u64array.iter()))
+        } else {
+            CellIndexArray::try_from(u64array).into_pyresult()
+        }
+    })?;
+    h3array_to_pyarray(cells, py)
+}
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
 use crate::array::PyCellArray;
+use arrow::array::{Array, ArrayRef, RecordBatch};
+use arrow::datatypes::{DataType, Field, Schema};
 use pyo3::prelude::*;
-use pyo3_arrow::PyArray;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::{PyArray, PyRecordBatch};
 
 #[pyfunction]
 #[pyo3(signature = (cellarray))]
@@ -24,3 +27,26 @@ pub(crate) fn cells_area_rads2(py: Python, cellarray: PyCellArray) -> PyResult<P
     let out = cellarray.as_ref().area_rads2();
     PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
 }
+
+#[pyfunction]
+#[pyo3(signature = (cellarray))]
+pub(crate) fn cells_faces(py: Python, cellarray: PyCellArray) -> PyResult<PyObject> {
+    let out = cellarray.as_ref().icosahedron_faces();
+    PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray))]
+pub(crate) fn cells_vertex_angles(py: Python, cellarray: PyCellArray) -> PyArrowResult<PyObject> {
+    let cells = cellarray.as_ref();
+    let azimuths = cells.vertex_azimuths();
+    let is_pentagon = cells.is_pentagon();
+
+    let schema = Schema::new(vec![
+        Field::new("azimuths", azimuths.data_type().clone(), true),
+        Field::new("is_pentagon", DataType::Boolean, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![Arc::new(azimuths), Arc::new(is_pentagon)];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
@@ -0,0 +1,126 @@
+use std::hash::Hash;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, RecordBatch, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, UInt64Type};
+use arrow::row::{RowConverter, SortField};
+use hashbrown::{HashMap, HashSet};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::{PyArray, PyRecordBatch};
+
+use crate::error::IntoPyResult;
+
+/// Join the rows of two tables on their keys.
+///
+/// The keys of each table must be unique. Null values are considered equal to each other. Returns
+/// a record batch with the columns `a` and `b` holding the indexes of the joined rows in both
+/// tables - null for the table lacking the key. All rows of `a` come first in their order,
+/// followed by the rows only found in `b`. Single `UInt64` keys - cells - are hashed directly, all
+/// other keys are converted to the arrow row format first.
+#[pyfunction]
+pub(crate) fn merge_row_indices(
+    py: Python,
+    a_keys: Vec<PyArray>,
+    b_keys: Vec<PyArray>,
+) -> PyArrowResult<PyObject> {
+    let a_keys: Vec<_> = a_keys.into_iter().map(|key| key.into_inner().0).collect();
+    let b_keys: Vec<_> = b_keys.into_iter().map(|key| key.into_inner().0).collect();
+    let (Some(a_len), Some(b_len)) = (
+        a_keys.first().map(|key| key.len()),
+        b_keys.first().map(|key| key.len()),
+    ) else {
+        return Err(PyValueError::new_err("at least one key is required").into());
+    };
+    if a_keys.len() != b_keys.len() {
+        return Err(PyValueError::new_err("both tables must have the same number of keys").into());
+    }
+    if a_keys.iter().any(|key| key.len() != a_len) || b_keys.iter().any(|key| key.len() != b_len) {
+        return Err(PyValueError::new_err("all keys of a table must be of the same length").into());
+    }
+    if a_keys
+        .iter()
+        .zip(b_keys.iter())
+        .any(|(a, b)| a.data_type() != b.data_type())
+    {
+        return Err(
+            PyValueError::new_err("the keys of both tables must be of the same types").into(),
+        );
+    }
+
+    let (a_indexes, b_indexes) = py.allow_threads(|| {
+        if let ([a], [b]) = (a_keys.as_slice(), b_keys.as_slice()) {
+            if a.data_type() == &DataType::UInt64 {
+                let a = a.as_primitive::<UInt64Type>();
+                let b = b.as_primitive::<UInt64Type>();
+                return join_indexes(
+                    a_len,
+                    b_len,
+                    |i| a.is_valid(i).then(|| a.value(i)),
+                    |i| b.is_valid(i).then(|| b.value(i)),
+                );
+            }
+        }
+
+        let converter = RowConverter::new(
+            a_keys
+                .iter()
+                .map(|key| SortField::new(key.data_type().clone()))
+                .collect(),
+        )
+        .into_pyresult()?;
+        let a_rows = converter.convert_columns(&a_keys).into_pyresult()?;
+        let b_rows = converter.convert_columns(&b_keys).into_pyresult()?;
+        join_indexes(a_len, b_len, |i| a_rows.row(i), |i| b_rows.row(i))
+    })?;
+
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::UInt64, true),
+        Field::new("b", DataType::UInt64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![Arc::new(a_indexes), Arc::new(b_indexes)];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+fn join_indexes<K, FA, FB>(
+    a_len: usize,
+    b_len: usize,
+    a_key_at: FA,
+    b_key_at: FB,
+) -> PyResult<(UInt64Array, UInt64Array)>
+where
+    K: Hash + Eq,
+    FA: Fn(usize) -> K,
+    FB: Fn(usize) -> K,
+{
+    let mut b_lookup = HashMap::with_capacity(b_len);
+    for i in 0..b_len {
+        if b_lookup.insert(b_key_at(i), i).is_some() {
+            return Err(PyValueError::new_err("the keys of table b are not unique"));
+        }
+    }
+
+    let mut b_joined = vec![false; b_len];
+    let mut a_seen = HashSet::with_capacity(a_len);
+    let mut a_indexes = Vec::with_capacity(a_len + b_len);
+    let mut b_indexes = Vec::with_capacity(a_len + b_len);
+    for i in 0..a_len {
+        let key = a_key_at(i);
+        let b_index = b_lookup.get(&key).copied();
+        if !a_seen.insert(key) {
+            return Err(PyValueError::new_err("the keys of table a are not unique"));
+        }
+        if let Some(b_index) = b_index {
+            b_joined[b_index] = true;
+        }
+        a_indexes.push(Some(i as u64));
+        b_indexes.push(b_index.map(|b_index| b_index as u64));
+    }
+    for (i, _) in b_joined.iter().enumerate().filter(|(_, joined)| !**joined) {
+        a_indexes.push(None);
+        b_indexes.push(Some(i as u64));
+    }
+    Ok((a_indexes.into(), b_indexes.into()))
+}
@@ -1,9 +1,20 @@
 use pyo3::prelude::*;
 
+mod aggregate;
+mod apply;
+mod color;
 mod compact;
+mod coverage;
+mod dedup;
+mod edge;
+mod hash;
+mod int64;
 mod localij;
 mod measure;
+mod merge;
+mod ndjson;
 mod neighbor;
+mod payload;
 mod resolution;
 mod string;
 mod valid;
@@ -12,27 +23,82 @@ pub fn init_op_submodule(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(resolution::change_resolution, m)?)?;
     m.add_function(wrap_pyfunction!(resolution::change_resolution_list, m)?)?;
     m.add_function(wrap_pyfunction!(resolution::change_resolution_paired, m)?)?;
+    m.add_function(wrap_pyfunction!(resolution::cells_to_center_child, m)?)?;
+    m.add_function(wrap_pyfunction!(resolution::cells_to_children_size, m)?)?;
+    m.add_function(wrap_pyfunction!(resolution::dasymetric_resample, m)?)?;
     m.add_function(wrap_pyfunction!(resolution::cells_resolution, m)?)?;
+    m.add_function(wrap_pyfunction!(resolution::filter_by_resolution, m)?)?;
+    m.add_function(wrap_pyfunction!(resolution::split_by_resolution, m)?)?;
     m.add_function(wrap_pyfunction!(neighbor::grid_disk, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::grid_disk_union, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::grid_path_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::grid_distance, m)?)?;
     m.add_function(wrap_pyfunction!(neighbor::grid_disk_distances, m)?)?;
     m.add_function(wrap_pyfunction!(neighbor::grid_ring_distances, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::grid_disk_sector, m)?)?;
     m.add_function(wrap_pyfunction!(neighbor::grid_disk_aggregate_k, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::grid_voronoi, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::grid_distance_transform, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::kde, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::spatial_outliers, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::region_adjacency, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::regionalize, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::cells_skeleton, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::cells_expand_contract, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::coverage_boundary_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::simplify_cell_path, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::catchments, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::viewshed, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::ring_window, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::ring_pivot, m)?)?;
     m.add_function(wrap_pyfunction!(string::cells_parse, m)?)?;
     m.add_function(wrap_pyfunction!(string::vertexes_parse, m)?)?;
     m.add_function(wrap_pyfunction!(string::directededges_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(string::cells_parse_with_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(string::vertexes_parse_with_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        string::directededges_parse_with_errors,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(string::cells_to_string, m)?)?;
     m.add_function(wrap_pyfunction!(string::vertexes_to_string, m)?)?;
     m.add_function(wrap_pyfunction!(string::directededges_to_string, m)?)?;
+    m.add_function(wrap_pyfunction!(edge::directededges_to_undirected_key, m)?)?;
     m.add_function(wrap_pyfunction!(compact::compact, m)?)?;
+    m.add_function(wrap_pyfunction!(compact::coverage_report, m)?)?;
     m.add_function(wrap_pyfunction!(compact::uncompact, m)?)?;
+    m.add_function(wrap_pyfunction!(compact::cells_without, m)?)?;
+    m.add_class::<compact::PyStreamingCompactor>()?;
+    m.add_function(wrap_pyfunction!(coverage::points_in_coverage, m)?)?;
+    m.add_function(wrap_pyfunction!(coverage::coverage_similarity, m)?)?;
+    m.add_function(wrap_pyfunction!(coverage::cellset_canonicalize, m)?)?;
+    m.add_function(wrap_pyfunction!(coverage::cellset_contains, m)?)?;
     m.add_function(wrap_pyfunction!(valid::cells_valid, m)?)?;
     m.add_function(wrap_pyfunction!(valid::vertexes_valid, m)?)?;
     m.add_function(wrap_pyfunction!(valid::directededges_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(valid::cells_valid_with_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(valid::vertexes_valid_with_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(valid::directededges_valid_with_errors, m)?)?;
     m.add_function(wrap_pyfunction!(measure::cells_area_m2, m)?)?;
     m.add_function(wrap_pyfunction!(measure::cells_area_km2, m)?)?;
     m.add_function(wrap_pyfunction!(measure::cells_area_rads2, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_faces, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_vertex_angles, m)?)?;
     m.add_function(wrap_pyfunction!(localij::cells_to_localij, m)?)?;
     m.add_function(wrap_pyfunction!(localij::localij_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(payload::cells_encode_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(payload::cells_decode_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(color::colorize, m)?)?;
+    m.add_function(wrap_pyfunction!(int64::cells_to_int64, m)?)?;
+    m.add_function(wrap_pyfunction!(int64::int64_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(hash::cells_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(apply::apply_per_cell, m)?)?;
+    m.add_function(wrap_pyfunction!(dedup::unique_row_indices, m)?)?;
+    m.add_function(wrap_pyfunction!(merge::merge_row_indices, m)?)?;
+    m.add_function(wrap_pyfunction!(aggregate::aggregate_by, m)?)?;
+    m.add_function(wrap_pyfunction!(aggregate::decay_merge, m)?)?;
+    m.add_function(wrap_pyfunction!(ndjson::write_ndjson, m)?)?;
+    m.add_function(wrap_pyfunction!(ndjson::write_elasticsearch_bulk, m)?)?;
 
     Ok(())
 }
@@ -0,0 +1,193 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, LargeStringArray, RecordBatch, RecordBatchReader};
+use arrow::datatypes::{DataType, Field, Schema, UInt64Type};
+use arrow::json::LineDelimitedWriter;
+#[cfg(feature = "io")]
+use flate2::{write::GzEncoder, Compression};
+use h3arrow::algorithm::ToGenericStringArray;
+use h3arrow::array::CellIndexArray;
+use h3arrow::export::h3o::CellIndex;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::PyRecordBatchReader;
+
+use crate::error::IntoPyResult;
+
+/// Write the batches of a stream as newline-delimited JSON with the cells converted to their
+/// hexadecimal string representation. All other columns are written as they are.
+#[pyfunction]
+#[pyo3(signature = (stream, path, cell_column = "cell", gzip = false))]
+pub(crate) fn write_ndjson(
+    py: Python,
+    stream: PyRecordBatchReader,
+    path: PathBuf,
+    cell_column: &str,
+    gzip: bool,
+) -> PyArrowResult<()> {
+    let reader = stream.into_reader()?;
+    py.allow_threads(|| {
+        write_output(path, gzip, |writer| {
+            let mut json_writer = LineDelimitedWriter::new(writer);
+            for batch in reader {
+                let (batch, _cells) = cells_to_hex(batch?, cell_column)?;
+                json_writer.write(&batch)?;
+            }
+            json_writer.finish()?;
+            Ok(())
+        })
+    })
+}
+
+/// Write the batches of a stream in the format of the bulk API of Elasticsearch and OpenSearch.
+///
+/// Each row becomes an `index` action with the hexadecimal cell as `_id`, followed by the document
+/// holding the attributes, the hexadecimal cell and the hexagon of the cell as `geo_shape`
+/// polygon. Rows without a cell are skipped.
+#[pyfunction]
+#[pyo3(signature = (stream, path, index = None, cell_column = "cell", geometry_field = "geometry", gzip = false))]
+pub(crate) fn write_elasticsearch_bulk(
+    py: Python,
+    stream: PyRecordBatchReader,
+    path: PathBuf,
+    index: Option<String>,
+    cell_column: &str,
+    geometry_field: &str,
+    gzip: bool,
+) -> PyArrowResult<()> {
+    for name in index.iter().map(String::as_str).chain([geometry_field]) {
+        if name.is_empty()
+            || name
+                .chars()
+                .any(|c| c == '"' || c == '\\' || c.is_control())
+        {
+            return Err(PyValueError::new_err(format!("unsupported name: {:?}", name)).into());
+        }
+    }
+    let action_prefix = match index {
+        Some(index) => format!(r#"{{"index":{{"_index":"{}","_id":""#, index),
+        None => r#"{"index":{"_id":""#.to_string(),
+    };
+
+    let reader = stream.into_reader()?;
+    py.allow_threads(|| {
+        write_output(path, gzip, |writer| {
+            for batch in reader {
+                let (batch, cells) = cells_to_hex(batch?, cell_column)?;
+
+                let mut json_writer = LineDelimitedWriter::new(Vec::new());
+                json_writer.write(&batch)?;
+                json_writer.finish()?;
+                let documents = json_writer.into_inner();
+
+                // JSON strings never contain raw newlines, so splitting yields one document per row.
+                for (document, cell) in documents.split(|b| *b == b'\n').zip(cells.iter()) {
+                    if let Some(cell) = cell {
+                        write_bulk_entry(writer, &action_prefix, geometry_field, cell, document)
+                            .map_err(PyErr::from)?;
+                    }
+                }
+            }
+            Ok(())
+        })
+    })
+}
+
+fn write_bulk_entry(
+    writer: &mut dyn Write,
+    action_prefix: &str,
+    geometry_field: &str,
+    cell: CellIndex,
+    document: &[u8],
+) -> std::io::Result<()> {
+    writeln!(writer, "{}{}\"}}}}", action_prefix, cell)?;
+
+    // splice the geometry into the object written by arrow
+    let document = document.strip_suffix(b"}").unwrap_or(document);
+    writer.write_all(document)?;
+    if document.len() > 1 {
+        writer.write_all(b",")?;
+    }
+    write!(
+        writer,
+        r#""{}":{{"type":"Polygon","coordinates":[["#,
+        geometry_field
+    )?;
+    let boundary = cell.boundary();
+    for (i, ll) in boundary.iter().chain(boundary.iter().take(1)).enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        write!(writer, "[{},{}]", ll.lng(), ll.lat())?;
+    }
+    writer.write_all(b"]]}}\n")
+}
+
+/// Create the file at `path` - optionally gzip compressed - and pass it to `write`.
+///
+/// Gzip compression requires the `io` feature.
+fn write_output<F>(path: PathBuf, gzip: bool, write: F) -> PyArrowResult<()>
+where
+    F: FnOnce(&mut dyn Write) -> PyArrowResult<()>,
+{
+    #[cfg(not(feature = "io"))]
+    if gzip {
+        return Err(PyValueError::new_err(
+            "gzip compression is not supported as h3ronpy was built without the io feature",
+        )
+        .into());
+    }
+
+    let mut file = BufWriter::new(File::create(path).map_err(PyErr::from)?);
+    #[cfg(feature = "io")]
+    if gzip {
+        let mut encoder = GzEncoder::new(&mut file, Compression::default());
+        write(&mut encoder)?;
+        encoder.finish().map_err(PyErr::from)?;
+        file.flush().map_err(PyErr::from)?;
+        return Ok(());
+    }
+    write(&mut file)?;
+    file.flush().map_err(PyErr::from)?;
+    Ok(())
+}
+
+/// Replace the cells of the `cell_column` by their hexadecimal string representation.
+///
+/// Returns the converted batch together with the cells.
+fn cells_to_hex(
+    batch: RecordBatch,
+    cell_column: &str,
+) -> PyArrowResult<(RecordBatch, CellIndexArray)> {
+    let schema = batch.schema();
+    let (index, field) = schema
+        .column_with_name(cell_column)
+        .ok_or_else(|| PyValueError::new_err(format!("column {} not found", cell_column)))?;
+    if field.data_type() != &DataType::UInt64 {
+        return Err(PyValueError::new_err(format!(
+            "expected column {} to be of type uint64, found {}",
+            cell_column,
+            field.data_type()
+        ))
+        .into());
+    }
+
+    let cells = CellIndexArray::try_from(batch.column(index).as_primitive::<UInt64Type>().clone())
+        .into_pyresult()?;
+    let hex: LargeStringArray = cells.to_genericstringarray().into_pyresult()?;
+
+    let mut fields: Vec<_> = schema.fields().iter().cloned().collect();
+    fields[index] = Arc::new(Field::new(
+        cell_column,
+        DataType::LargeUtf8,
+        field.is_nullable(),
+    ));
+    let mut columns: Vec<ArrayRef> = batch.columns().to_vec();
+    columns[index] = Arc::new(hex);
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok((batch, cells))
+}
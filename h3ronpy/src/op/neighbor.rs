@@ -1,8 +1,28 @@
 use arrow::array::{
-    Array, ArrayRef, GenericListArray, LargeListArray, PrimitiveArray, RecordBatch, UInt32Array,
+    Array, ArrayRef, Float64Array, GenericListArray, LargeListArray, PrimitiveArray, RecordBatch,
+    UInt32Array,
+};
+use arrow::datatypes::{DataType, Field, Schema};
+use h3arrow::algorithm::adjacency::region_adjacency as h3arrow_region_adjacency;
+use h3arrow::algorithm::catchment::catchments as h3arrow_catchments;
+use h3arrow::algorithm::distance_transform::grid_distance_transform as h3arrow_grid_distance_transform;
+use h3arrow::algorithm::kde::{kde as h3arrow_kde, Kernel};
+use h3arrow::algorithm::morphology::cells_boundary as h3arrow_cells_boundary;
+use h3arrow::algorithm::morphology::cells_expand_contract as h3arrow_cells_expand_contract;
+use h3arrow::algorithm::outliers::{spatial_outliers as h3arrow_spatial_outliers, OutlierMethod};
+use h3arrow::algorithm::path::simplify_cell_path as h3arrow_simplify_cell_path;
+use h3arrow::algorithm::regionalize::regionalize as h3arrow_regionalize;
+use h3arrow::algorithm::skeleton::cells_skeleton as h3arrow_cells_skeleton;
+use h3arrow::algorithm::viewshed::viewshed as h3arrow_viewshed;
+use h3arrow::algorithm::voronoi::grid_voronoi as h3arrow_grid_voronoi;
+use h3arrow::algorithm::window::{
+    ring_cumulative, ring_pivot as h3arrow_ring_pivot, ring_rank, CumulativeAggregation,
+    RingAggregation,
 };
-use arrow::datatypes::{Field, Schema};
 use h3arrow::algorithm::{GridDiskDistances, GridOp, KAggregationMethod};
+use h3arrow::array::from_geo::{geometry_to_cells, ToCellsOptions};
+use h3arrow::array::CellIndexArray;
+use h3arrow::export::h3o::geom::ContainmentMode;
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::{PyObject, PyResult};
 use pyo3_arrow::error::PyArrowResult;
@@ -34,6 +54,43 @@ pub(crate) fn grid_disk(
     }
 }
 
+#[pyfunction]
+#[pyo3(signature = (origins, destinations))]
+pub(crate) fn grid_path_cells(
+    py: Python,
+    origins: PyCellArray,
+    destinations: PyCellArray,
+) -> PyResult<PyObject> {
+    let origins = origins.into_inner();
+    let destinations = destinations.into_inner();
+    let paths = py
+        .allow_threads(|| origins.grid_path_cells::<i64>(&destinations))
+        .into_pyresult()?;
+    PyArray::from_array_ref(Arc::new(LargeListArray::from(paths))).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray_a, cellarray_b))]
+pub(crate) fn grid_distance(
+    py: Python,
+    cellarray_a: PyCellArray,
+    cellarray_b: PyCellArray,
+) -> PyResult<PyObject> {
+    let cellarray_a = cellarray_a.into_inner();
+    let cellarray_b = cellarray_b.into_inner();
+    let distances = py
+        .allow_threads(|| cellarray_a.grid_distance(&cellarray_b))
+        .into_pyresult()?;
+    PyArray::from_array_ref(Arc::new(distances)).to_arro3(py)
+}
+
+#[pyfunction]
+pub(crate) fn grid_disk_union(py: Python, cellarray: PyCellArray, k: u32) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let cells = py.allow_threads(|| cellindexarray.grid_disk_union(k).into_pyresult())?;
+    h3array_to_pyarray(cells, py)
+}
+
 #[pyfunction]
 #[pyo3(signature = (cellarray, k, flatten = false))]
 pub(crate) fn grid_disk_distances(
@@ -70,6 +127,24 @@ pub(crate) fn grid_ring_distances(
     return_griddiskdistances_table(py, griddiskdistances, flatten)
 }
 
+#[pyfunction]
+#[pyo3(signature = (cellarray, k, azimuth_min, azimuth_max, flatten = false))]
+pub(crate) fn grid_disk_sector(
+    py: Python,
+    cellarray: PyCellArray,
+    k: u32,
+    azimuth_min: f64,
+    azimuth_max: f64,
+    flatten: bool,
+) -> PyArrowResult<PyObject> {
+    let griddiskdistances = cellarray
+        .into_inner()
+        .grid_disk_sector_distances(k, azimuth_min, azimuth_max)
+        .into_pyresult()?;
+
+    return_griddiskdistances_table(py, griddiskdistances, flatten)
+}
+
 fn return_griddiskdistances_table(
     py: Python,
     griddiskdistances: GridDiskDistances<i64>,
@@ -150,3 +225,377 @@ pub(crate) fn grid_disk_aggregate_k(
     let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
     Ok(PyRecordBatch::new(batch).to_arro3(py)?)
 }
+
+#[pyfunction]
+#[pyo3(signature = (seeds, extent))]
+pub(crate) fn grid_voronoi(
+    py: Python,
+    seeds: PyCellArray,
+    extent: &Bound<PyAny>,
+) -> PyArrowResult<PyObject> {
+    let seeds = seeds.into_inner();
+    let extent = if let Ok((minx, miny, maxx, maxy)) = extent.extract::<(f64, f64, f64, f64)>() {
+        match seeds.iter().flatten().next() {
+            Some(seed) => {
+                let rect = geo_types::Rect::new((minx, miny), (maxx, maxy));
+                let options = ToCellsOptions::new(seed.resolution())
+                    .containment_mode(ContainmentMode::Covers);
+                CellIndexArray::from(geometry_to_cells(&rect.into(), &options).into_pyresult()?)
+            }
+            // without seeds no cell is assigned, the resolution of the extent does not matter
+            None => CellIndexArray::new_null(0),
+        }
+    } else {
+        pyarray_to_cellindexarray(extent)?
+    };
+
+    let voronoi = py.allow_threads(|| h3arrow_grid_voronoi(&seeds, &extent).into_pyresult())?;
+
+    let schema = Schema::new(vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("seed", DataType::UInt64, true),
+        Field::new("k", DataType::UInt32, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(voronoi.cells.primitive_array().clone()),
+        Arc::new(voronoi.seeds.primitive_array().clone()),
+        Arc::new(voronoi.distances),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+#[pyfunction]
+pub(crate) fn grid_distance_transform(
+    py: Python,
+    extent: PyCellArray,
+    features: PyCellArray,
+    max_k: u32,
+) -> PyResult<PyObject> {
+    let extent = extent.into_inner();
+    let features = features.into_inner();
+    let distances = py.allow_threads(|| {
+        h3arrow_grid_distance_transform(&extent, &features, max_k).into_pyresult()
+    })?;
+    PyArray::from_array_ref(Arc::new(distances)).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, countarray, bandwidth_m, kernel = "gaussian"))]
+pub(crate) fn kde(
+    py: Python,
+    cellarray: PyCellArray,
+    countarray: &Bound<PyAny>,
+    bandwidth_m: f64,
+    kernel: &str,
+) -> PyArrowResult<PyObject> {
+    let kernel = match kernel.to_lowercase().as_str() {
+        "gaussian" => Kernel::Gaussian,
+        "epanechnikov" => Kernel::Epanechnikov,
+        _ => return Err(PyValueError::new_err("unknown kernel").into()),
+    };
+    let cellindexarray = cellarray.into_inner();
+    let counts = pyarray_to_native::<Float64Array>(countarray)?;
+
+    let density = py.allow_threads(|| {
+        h3arrow_kde(&cellindexarray, &counts, bandwidth_m, kernel).into_pyresult()
+    })?;
+
+    let schema = Schema::new(vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, false),
+        Field::new("density", DataType::Float64, false),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(density.cells.primitive_array().clone()),
+        Arc::new(density.densities),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, valuearray, k, method, threshold))]
+pub(crate) fn spatial_outliers(
+    py: Python,
+    cellarray: PyCellArray,
+    valuearray: &Bound<PyAny>,
+    k: u32,
+    method: &str,
+    threshold: f64,
+) -> PyArrowResult<PyObject> {
+    let method = match method.to_lowercase().as_str() {
+        "zscore" => OutlierMethod::ZScore,
+        "iqr" => OutlierMethod::Iqr,
+        _ => return Err(PyValueError::new_err("unknown outlier method").into()),
+    };
+    let cellindexarray = cellarray.into_inner();
+    let values = pyarray_to_native::<Float64Array>(valuearray)?;
+
+    let outliers = py.allow_threads(|| {
+        h3arrow_spatial_outliers(&cellindexarray, &values, k, method, threshold).into_pyresult()
+    })?;
+
+    let schema = Schema::new(vec![
+        Field::new("score", DataType::Float64, true),
+        Field::new("outlier", DataType::Boolean, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![Arc::new(outliers.scores), Arc::new(outliers.flags)];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+#[pyfunction]
+pub(crate) fn region_adjacency(
+    py: Python,
+    cellarray: PyCellArray,
+    labelarray: &Bound<PyAny>,
+) -> PyArrowResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let labels = pyarray_to_native::<UInt32Array>(labelarray)?;
+
+    let adjacency =
+        py.allow_threads(|| h3arrow_region_adjacency(&cellindexarray, &labels).into_pyresult())?;
+
+    let schema = Schema::new(vec![
+        Field::new("label_a", DataType::UInt32, false),
+        Field::new("label_b", DataType::UInt32, false),
+        Field::new("count", DataType::UInt64, false),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(adjacency.labels_a),
+        Arc::new(adjacency.labels_b),
+        Arc::new(adjacency.counts),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+#[pyfunction]
+pub(crate) fn regionalize(
+    py: Python,
+    cellarray: PyCellArray,
+    weightarray: &Bound<PyAny>,
+    n_regions: usize,
+) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let weights = pyarray_to_native::<Float64Array>(weightarray)?;
+    let regions = py.allow_threads(|| {
+        h3arrow_regionalize(&cellindexarray, &weights, n_regions).into_pyresult()
+    })?;
+    PyArray::from_array_ref(Arc::new(regions)).to_arro3(py)
+}
+
+#[pyfunction]
+pub(crate) fn cells_skeleton(py: Python, cellarray: PyCellArray) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let skeleton = py.allow_threads(|| h3arrow_cells_skeleton(&cellindexarray).into_pyresult())?;
+    h3array_to_pyarray(skeleton, py)
+}
+
+#[pyfunction]
+pub(crate) fn cells_expand_contract(
+    py: Python,
+    cellarray: PyCellArray,
+    grow_k: u32,
+    shrink_k: u32,
+) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let cells = py.allow_threads(|| {
+        h3arrow_cells_expand_contract(&cellindexarray, grow_k, shrink_k).into_pyresult()
+    })?;
+    h3array_to_pyarray(cells, py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, outside = false))]
+pub(crate) fn coverage_boundary_cells(
+    py: Python,
+    cellarray: PyCellArray,
+    outside: bool,
+) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let cells =
+        py.allow_threads(|| h3arrow_cells_boundary(&cellindexarray, outside).into_pyresult())?;
+    h3array_to_pyarray(cells, py)
+}
+
+#[pyfunction]
+pub(crate) fn simplify_cell_path(
+    py: Python,
+    patharray: PyCellArray,
+    tolerance_cells: f64,
+) -> PyResult<PyObject> {
+    let cellindexarray = patharray.into_inner();
+    let cells = py.allow_threads(|| {
+        h3arrow_simplify_cell_path(&cellindexarray, tolerance_cells).into_pyresult()
+    })?;
+    h3array_to_pyarray(cells, py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, costarray, facilities, max_cost = None))]
+pub(crate) fn catchments(
+    py: Python,
+    cellarray: PyCellArray,
+    costarray: &Bound<PyAny>,
+    facilities: PyCellArray,
+    max_cost: Option<f64>,
+) -> PyArrowResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let costs = pyarray_to_native::<Float64Array>(costarray)?;
+    let facilities = facilities.into_inner();
+
+    let catchments = py.allow_threads(|| {
+        h3arrow_catchments(&cellindexarray, &costs, &facilities, max_cost).into_pyresult()
+    })?;
+
+    let schema = Schema::new(vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("facility", DataType::UInt64, true),
+        Field::new("cost", DataType::Float64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(catchments.cells.primitive_array().clone()),
+        Arc::new(catchments.facilities.primitive_array().clone()),
+        Arc::new(catchments.costs),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, elevationarray, observers, max_k, observer_height = 0.0))]
+pub(crate) fn viewshed(
+    py: Python,
+    cellarray: PyCellArray,
+    elevationarray: &Bound<PyAny>,
+    observers: PyCellArray,
+    max_k: u32,
+    observer_height: f64,
+) -> PyArrowResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let elevations = pyarray_to_native::<Float64Array>(elevationarray)?;
+    let observers = observers.into_inner();
+
+    let viewshed = py.allow_threads(|| {
+        h3arrow_viewshed(
+            &cellindexarray,
+            &elevations,
+            &observers,
+            max_k,
+            observer_height,
+        )
+        .into_pyresult()
+    })?;
+
+    let schema = Schema::new(vec![
+        Field::new("observer", DataType::UInt64, true),
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("k", DataType::UInt32, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(viewshed.observers.primitive_array().clone()),
+        Arc::new(viewshed.cells.primitive_array().clone()),
+        Arc::new(viewshed.distances),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+enum RingWindowFunction {
+    Cumulative(CumulativeAggregation),
+    Rank,
+}
+
+impl FromStr for RingWindowFunction {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cumsum" => Ok(Self::Cumulative(CumulativeAggregation::Sum)),
+            "cummean" => Ok(Self::Cumulative(CumulativeAggregation::Mean)),
+            "cummin" => Ok(Self::Cumulative(CumulativeAggregation::Min)),
+            "cummax" => Ok(Self::Cumulative(CumulativeAggregation::Max)),
+            "rank" => Ok(Self::Rank),
+            _ => Err(PyValueError::new_err("unknown ring window function")),
+        }
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (originarray, karray, valuearray, function))]
+pub(crate) fn ring_window(
+    py: Python,
+    originarray: PyCellArray,
+    karray: &Bound<PyAny>,
+    valuearray: &Bound<PyAny>,
+    function: &str,
+) -> PyResult<PyObject> {
+    let function = RingWindowFunction::from_str(function)?;
+    let origins = originarray.into_inner();
+    let ks = pyarray_to_native::<UInt32Array>(karray)?;
+    let values = pyarray_to_native::<Float64Array>(valuearray)?;
+
+    let out: ArrayRef = py.allow_threads(|| match function {
+        RingWindowFunction::Cumulative(aggregation) => {
+            ring_cumulative(&origins, &ks, &values, aggregation)
+                .map(|a| Arc::new(a) as ArrayRef)
+                .into_pyresult()
+        }
+        RingWindowFunction::Rank => ring_rank(&origins, &ks, &values)
+            .map(|a| Arc::new(a) as ArrayRef)
+            .into_pyresult(),
+    })?;
+    PyArray::from_array_ref(out).to_arro3(py)
+}
+
+struct RingAggregationWrapper(RingAggregation);
+
+impl FromStr for RingAggregationWrapper {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sum" => Ok(Self(RingAggregation::Sum)),
+            "mean" => Ok(Self(RingAggregation::Mean)),
+            "min" => Ok(Self(RingAggregation::Min)),
+            "max" => Ok(Self(RingAggregation::Max)),
+            "count" => Ok(Self(RingAggregation::Count)),
+            _ => Err(PyValueError::new_err("unknown ring aggregation")),
+        }
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (originarray, karray, valuearray, aggregation, origin_column, prefix))]
+pub(crate) fn ring_pivot(
+    py: Python,
+    originarray: PyCellArray,
+    karray: &Bound<PyAny>,
+    valuearray: &Bound<PyAny>,
+    aggregation: &str,
+    origin_column: &str,
+    prefix: &str,
+) -> PyArrowResult<PyObject> {
+    let aggregation = RingAggregationWrapper::from_str(aggregation)?;
+    let origins = originarray.into_inner();
+    let ks = pyarray_to_native::<UInt32Array>(karray)?;
+    let values = pyarray_to_native::<Float64Array>(valuearray)?;
+
+    let pivot = py
+        .allow_threads(|| h3arrow_ring_pivot(&origins, &ks, &values, aggregation.0))
+        .into_pyresult()?;
+
+    let mut fields = vec![Field::new(origin_column, DataType::UInt64, true)];
+    let mut columns: Vec<ArrayRef> = vec![Arc::new(pivot.origins.primitive_array().clone())];
+    for (k, ring) in pivot.rings {
+        fields.push(Field::new(
+            format!("{}{}", prefix, k),
+            DataType::Float64,
+            true,
+        ));
+        columns.push(Arc::new(ring));
+    }
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, RecordBatch, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema};
+use h3arrow::algorithm::payload::PayloadCellArrays;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::{PyArray, PyRecordBatch};
+
+use crate::array::PyCellArray;
+use crate::arrow_interop::pyarray_to_native;
+use crate::error::IntoPyResult;
+use crate::DEFAULT_CELL_COLUMN_NAME;
+
+#[pyfunction]
+pub(crate) fn cells_encode_payload(
+    py: Python,
+    cellarray: PyCellArray,
+    payloads: &Bound<PyAny>,
+) -> PyResult<PyObject> {
+    let payloads = pyarray_to_native::<UInt64Array>(payloads)?;
+    let cellindexarray = cellarray.into_inner();
+
+    let encoded = py.allow_threads(|| {
+        PayloadCellArrays::try_new(cellindexarray, payloads)
+            .and_then(|arrays| arrays.encode())
+            .into_pyresult()
+    })?;
+
+    PyArray::from_array_ref(Arc::new(encoded)).to_arro3(py)
+}
+
+#[pyfunction]
+pub(crate) fn cells_decode_payload(py: Python, encoded: &Bound<PyAny>) -> PyArrowResult<PyObject> {
+    let encoded = pyarray_to_native::<UInt64Array>(encoded)?;
+
+    let decoded = py.allow_threads(|| PayloadCellArrays::decode(&encoded).into_pyresult())?;
+
+    let schema = Schema::new(vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("payload", DataType::UInt64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(decoded.cells.primitive_array().clone()),
+        Arc::new(decoded.payloads),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
@@ -1,10 +1,12 @@
 use std::sync::Arc;
 
-use arrow::array::{Array, ArrayRef, LargeListArray, RecordBatch};
-use arrow::datatypes::{Field, Schema};
+use arrow::array::{Array, ArrayRef, Float64Array, LargeListArray, RecordBatch};
+use arrow::datatypes::{DataType, Field, Schema};
+use h3arrow::algorithm::dasymetric::dasymetric_resample as h3arrow_dasymetric_resample;
 use h3arrow::algorithm::ChangeResolutionOp;
 use h3arrow::export::h3o::Resolution;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use pyo3_arrow::error::PyArrowResult;
 use pyo3_arrow::{PyArray, PyRecordBatch};
 
@@ -30,6 +32,32 @@ pub(crate) fn change_resolution(
     h3array_to_pyarray(out, py)
 }
 
+#[pyfunction]
+pub(crate) fn cells_to_center_child(
+    py: Python<'_>,
+    cellarray: PyCellArray,
+    h3_resolution: u8,
+) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let h3_resolution = Resolution::try_from(h3_resolution).into_pyresult()?;
+    let out = py.allow_threads(|| cellindexarray.center_child(h3_resolution));
+
+    h3array_to_pyarray(out, py)
+}
+
+#[pyfunction]
+pub(crate) fn cells_to_children_size(
+    py: Python<'_>,
+    cellarray: PyCellArray,
+    h3_resolution: u8,
+) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let h3_resolution = Resolution::try_from(h3_resolution).into_pyresult()?;
+    let out = py.allow_threads(|| cellindexarray.children_count(h3_resolution));
+
+    PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
+}
+
 #[pyfunction]
 pub(crate) fn change_resolution_list(
     py: Python,
@@ -80,8 +108,75 @@ pub(crate) fn change_resolution_paired(
     Ok(PyRecordBatch::new(batch).to_arro3(py)?)
 }
 
+#[pyfunction]
+pub(crate) fn dasymetric_resample(
+    py: Python,
+    cellarray: PyCellArray,
+    valuearray: &Bound<PyAny>,
+    weightcellarray: PyCellArray,
+    weightarray: &Bound<PyAny>,
+    target_resolution: u8,
+) -> PyArrowResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let values = pyarray_to_native::<Float64Array>(valuearray)?;
+    let weightcellindexarray = weightcellarray.into_inner();
+    let weights = pyarray_to_native::<Float64Array>(weightarray)?;
+    let target_resolution = Resolution::try_from(target_resolution).into_pyresult()?;
+
+    let resampled = py.allow_threads(|| {
+        h3arrow_dasymetric_resample(
+            &cellindexarray,
+            &values,
+            &weightcellindexarray,
+            &weights,
+            target_resolution,
+        )
+        .into_pyresult()
+    })?;
+
+    let schema = Schema::new(vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("value", DataType::Float64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(resampled.cells.primitive_array().clone()),
+        Arc::new(resampled.values),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
 #[pyfunction]
 pub(crate) fn cells_resolution(py: Python, cellarray: PyCellArray) -> PyResult<PyObject> {
     let resarray = cellarray.as_ref().resolution();
     PyArray::from_array_ref(Arc::new(resarray.into_inner())).to_arro3(py)
 }
+
+#[pyfunction]
+pub(crate) fn filter_by_resolution(
+    py: Python,
+    cellarray: PyCellArray,
+    resolutions: Vec<u8>,
+) -> PyResult<PyObject> {
+    let resolutions = resolutions
+        .into_iter()
+        .map(Resolution::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .into_pyresult()?;
+    let cellindexarray = cellarray.into_inner();
+    let out = py.allow_threads(|| cellindexarray.filter_by_resolution(&resolutions));
+
+    h3array_to_pyarray(out, py)
+}
+
+#[pyfunction]
+pub(crate) fn split_by_resolution(py: Python, cellarray: PyCellArray) -> PyResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let split = py.allow_threads(|| cellindexarray.split_by_resolution());
+
+    let dict = PyDict::new_bound(py);
+    for (resolution, cells) in split {
+        dict.set_item(u8::from(resolution), h3array_to_pyarray(cells, py)?)?;
+    }
+    Ok(dict.into_py(py))
+}
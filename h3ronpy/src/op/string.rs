@@ -1,10 +1,18 @@
 use std::sync::Arc;
 
-use arrow::array::{Array, LargeStringArray, StringArray};
-use h3arrow::algorithm::{ParseGenericStringArray, ToGenericStringArray};
-use h3arrow::array::{CellIndexArray, DirectedEdgeIndexArray, VertexIndexArray};
+use arrow::array::{Array, GenericStringArray, LargeStringArray, OffsetSizeTrait, StringArray};
+use h3arrow::algorithm::{
+    parse_cell, parse_directededge, parse_vertex, HexFormat, ParseGenericStringArray,
+    ToGenericStringArray,
+};
+use h3arrow::array::{
+    CellIndexArray, DirectedEdgeIndexArray, H3Array, H3IndexArrayValue, VertexIndexArray,
+};
+use h3arrow::error::Error as A3Error;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use pyo3_arrow::error::PyArrowResult;
 use pyo3_arrow::PyArray;
 
 use crate::array::PyCellArray;
@@ -86,31 +94,123 @@ pub(crate) fn directededges_parse(
     h3array_to_pyarray(edges, py)
 }
 
+/// Parse all values, setting the failing ones to null and collecting the positions and reasons of
+/// the failures.
+fn parse_collecting_errors<O, IX, F>(
+    stringarray: &GenericStringArray<O>,
+    parse: F,
+) -> (H3Array<IX>, Vec<u64>, Vec<String>)
+where
+    O: OffsetSizeTrait,
+    IX: H3IndexArrayValue,
+    F: Fn(&str) -> Result<IX, A3Error>,
+{
+    let mut rows = vec![];
+    let mut reasons = vec![];
+    let parsed: Vec<_> = stringarray
+        .iter()
+        .enumerate()
+        .map(|(row, value)| match parse(value?) {
+            Ok(h3index) => Some(h3index),
+            Err(e) => {
+                rows.push(row as u64);
+                reasons.push(e.to_string());
+                None
+            }
+        })
+        .collect();
+    (parsed.into(), rows, reasons)
+}
+
+macro_rules! impl_parse_with_errors {
+    ($name:ident, $parse:expr, $what:literal) => {
+        /// Returns a tuple of the parsed array - with the failing values set to null - and a
+        /// table of the failures.
+        #[pyfunction]
+        pub(crate) fn $name(py: Python<'_>, stringarray: PyArray) -> PyArrowResult<PyObject> {
+            let (boxed_array, _field) = stringarray.into_inner();
+            let (parsed, rows, reasons) = py.allow_threads(|| {
+                if let Some(stringarray) = boxed_array.as_any().downcast_ref::<StringArray>() {
+                    Ok(parse_collecting_errors(stringarray, $parse))
+                } else if let Some(stringarray) =
+                    boxed_array.as_any().downcast_ref::<LargeStringArray>()
+                {
+                    Ok(parse_collecting_errors(stringarray, $parse))
+                } else {
+                    Err(PyValueError::new_err(concat!(
+                        "unsupported array type to parse ",
+                        $what,
+                        " from"
+                    )))
+                }
+            })?;
+
+            let errors = errors_to_pyrecordbatch(py, boxed_array.as_ref(), rows, reasons)?;
+            Ok(PyTuple::new_bound(py, [h3array_to_pyarray(parsed, py)?, errors]).into_py(py))
+        }
+    };
+}
+
+impl_parse_with_errors!(cells_parse_with_errors, parse_cell, "cells");
+impl_parse_with_errors!(vertexes_parse_with_errors, parse_vertex, "vertexes");
+impl_parse_with_errors!(
+    directededges_parse_with_errors,
+    parse_directededge,
+    "directededges"
+);
+
 #[pyfunction]
-#[pyo3(signature = (cellarray))]
-pub(crate) fn cells_to_string(py: Python, cellarray: PyCellArray) -> PyResult<PyObject> {
-    let stringarray: LargeStringArray =
-        cellarray.as_ref().to_genericstringarray().into_pyresult()?;
+#[pyo3(signature = (cellarray, uppercase = false, short = false, zero_pad = false))]
+pub(crate) fn cells_to_string(
+    py: Python,
+    cellarray: PyCellArray,
+    uppercase: bool,
+    short: bool,
+    zero_pad: bool,
+) -> PyResult<PyObject> {
+    let format = hex_format(uppercase, short, zero_pad);
+    let stringarray: LargeStringArray = cellarray
+        .as_ref()
+        .to_genericstringarray_with_format(&format)
+        .into_pyresult()?;
     PyArray::from_array_ref(Arc::new(stringarray)).to_arro3(py)
 }
 
 #[pyfunction]
-#[pyo3(signature = (vertexarray))]
-pub(crate) fn vertexes_to_string(py: Python, vertexarray: &Bound<PyAny>) -> PyResult<PyObject> {
+#[pyo3(signature = (vertexarray, uppercase = false, short = false, zero_pad = false))]
+pub(crate) fn vertexes_to_string(
+    py: Python,
+    vertexarray: &Bound<PyAny>,
+    uppercase: bool,
+    short: bool,
+    zero_pad: bool,
+) -> PyResult<PyObject> {
+    let format = hex_format(uppercase, short, zero_pad);
     let stringarray: LargeStringArray = pyarray_to_vertexindexarray(vertexarray)?
-        .to_genericstringarray()
+        .to_genericstringarray_with_format(&format)
         .into_pyresult()?;
     PyArray::from_array_ref(Arc::new(stringarray)).to_arro3(py)
 }
 
 #[pyfunction]
-#[pyo3(signature = (directededgearray))]
+#[pyo3(signature = (directededgearray, uppercase = false, short = false, zero_pad = false))]
 pub(crate) fn directededges_to_string(
     py: Python,
     directededgearray: &Bound<PyAny>,
+    uppercase: bool,
+    short: bool,
+    zero_pad: bool,
 ) -> PyResult<PyObject> {
+    let format = hex_format(uppercase, short, zero_pad);
     let stringarray: LargeStringArray = pyarray_to_directededgeindexarray(directededgearray)?
-        .to_genericstringarray()
+        .to_genericstringarray_with_format(&format)
         .into_pyresult()?;
     PyArray::from_array_ref(Arc::new(stringarray)).to_arro3(py)
 }
+
+fn hex_format(uppercase: bool, short: bool, zero_pad: bool) -> HexFormat {
+    HexFormat::default()
+        .uppercase(uppercase)
+        .short(short)
+        .zero_pad(zero_pad)
+}
@@ -1,3 +1,4 @@
+use std::fmt::Display;
 use std::sync::Arc;
 
 use arrow::array::{Array, BooleanArray};
@@ -6,6 +7,8 @@ use h3arrow::array::{FromIteratorWithValidity, H3Array, H3IndexArrayValue};
 use h3arrow::h3o;
 use h3o::{CellIndex, DirectedEdgeIndex, VertexIndex};
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use pyo3_arrow::error::PyArrowResult;
 use pyo3_arrow::PyArray;
 
 use crate::arrow_interop::*;
@@ -30,6 +33,34 @@ where
     }
 }
 
+fn h3index_valid_with_errors<IX>(py: Python, arr: &Bound<PyAny>) -> PyArrowResult<PyObject>
+where
+    IX: H3IndexArrayValue + Send,
+    <IX as TryFrom<u64>>::Error: Display,
+{
+    let u64array = pyarray_to_uint64array(arr)?;
+    let (validated, rows, reasons) = py.allow_threads(|| {
+        let mut rows = vec![];
+        let mut reasons = vec![];
+        let validated: Vec<Option<IX>> = u64array
+            .iter()
+            .enumerate()
+            .map(|(row, value)| match IX::try_from(value?) {
+                Ok(h3index) => Some(h3index),
+                Err(e) => {
+                    rows.push(row as u64);
+                    reasons.push(e.to_string());
+                    None
+                }
+            })
+            .collect();
+        (H3Array::from(validated), rows, reasons)
+    });
+
+    let errors = errors_to_pyrecordbatch(py, &u64array, rows, reasons)?;
+    Ok(PyTuple::new_bound(py, [h3array_to_pyarray(validated, py)?, errors]).into_py(py))
+}
+
 macro_rules! impl_h3index_valid {
     ($name:ident, $arr_type:ty) => {
         #[pyfunction]
@@ -44,6 +75,21 @@ macro_rules! impl_h3index_valid {
     };
 }
 
+macro_rules! impl_h3index_valid_with_errors {
+    ($name:ident, $arr_type:ty) => {
+        /// Returns a tuple of the validated array - with the invalid values set to null - and a
+        /// table of the invalid values.
+        #[pyfunction]
+        pub(crate) fn $name(py: Python, array: &Bound<PyAny>) -> PyArrowResult<PyObject> {
+            h3index_valid_with_errors::<$arr_type>(py, array)
+        }
+    };
+}
+
 impl_h3index_valid!(cells_valid, CellIndex);
 impl_h3index_valid!(vertexes_valid, VertexIndex);
 impl_h3index_valid!(directededges_valid, DirectedEdgeIndex);
+
+impl_h3index_valid_with_errors!(cells_valid_with_errors, CellIndex);
+impl_h3index_valid_with_errors!(vertexes_valid_with_errors, VertexIndex);
+impl_h3index_valid_with_errors!(directededges_valid_with_errors, DirectedEdgeIndex);
@@ -1,26 +1,33 @@
 use geo_types::Point;
-use pyo3_arrow::PyArray;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::{PyRecordBatch, PySchema};
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use std::iter::repeat;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow::array::{
-    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, UInt16Array,
-    UInt32Array, UInt64Array, UInt8Array,
+    ArrayRef, AsArray, DictionaryArray, Float32Array, Float64Array, Int16Array, Int32Array,
+    Int64Array, Int8Array, RecordBatch, StringArray, UInt16Array, UInt32Array, UInt64Array,
+    UInt8Array,
 };
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Int32Type, Int64Type, Schema};
 use geo::{AffineOps, AffineTransform};
 use h3arrow::array::CellIndexArray;
-use h3arrow::export::h3o::{CellIndex, Resolution};
-use ndarray::ArrayView2;
-use numpy::PyReadonlyArray2;
+use h3arrow::export::h3o::{CellIndex, LatLng, Resolution};
+use ndarray::{s, Array2, ArrayView2, ArrayViewMut2};
+use numpy::{PyArray2, PyReadonlyArray2};
 use ordered_float::OrderedFloat;
 use pyo3::exceptions::PyValueError;
 use pyo3::{prelude::*, wrap_pyfunction};
 
-use crate::arrow_interop::h3array_to_pyarray;
+use crate::array::PyCellArray;
+use crate::arrow_interop::pyarray_to_native;
 use crate::error::IntoPyResult;
 use crate::transform::Transform;
+use crate::DEFAULT_CELL_COLUMN_NAME;
 
 pub struct AxisOrder {
     pub inner: rasterh3::AxisOrder,
@@ -106,6 +113,157 @@ pub fn nearest_h3_resolution(
         .map(Into::into)
 }
 
+/// Cells and values resulting from the conversion of a raster.
+///
+/// Exports to other representations are only performed when requested.
+#[pyclass(name = "ConvertedRaster")]
+pub struct PyConvertedRaster {
+    values: ArrayRef,
+    cells: CellIndexArray,
+    coverage_mask: Option<Array2<u8>>,
+    /// metadata of the `value` field, for example the class names and colors of a classified raster
+    value_metadata: HashMap<String, String>,
+}
+
+/// Metadata of a [`PyConvertedRaster`] describing the cells and values without containing them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvertedRasterMetadata {
+    pub n_cells: usize,
+
+    /// The distinct H3 resolutions of the cells in ascending order
+    pub resolutions: Vec<u8>,
+
+    /// Serialized using the notation of the `Display` implementation of `DataType`.
+    #[cfg_attr(feature = "serde", serde(with = "data_type_string"))]
+    pub value_type: DataType,
+
+    pub value_metadata: BTreeMap<String, String>,
+}
+
+#[cfg(feature = "serde")]
+mod data_type_string {
+    use std::str::FromStr;
+
+    use arrow::datatypes::DataType;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        data_type: &DataType,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(data_type)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DataType, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        DataType::from_str(&s).map_err(D::Error::custom)
+    }
+}
+
+impl PyConvertedRaster {
+    pub fn metadata(&self) -> ConvertedRasterMetadata {
+        ConvertedRasterMetadata {
+            n_cells: self.cells.len(),
+            resolutions: self.resolutions(),
+            value_type: self.values.data_type().clone(),
+            value_metadata: self
+                .value_metadata
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        }
+    }
+
+    fn value_field(&self) -> Field {
+        Field::new("value", self.values.data_type().clone(), true)
+            .with_metadata(self.value_metadata.clone())
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::new(vec![
+            self.value_field(),
+            Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        ])
+    }
+}
+
+#[pymethods]
+impl PyConvertedRaster {
+    fn __len__(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cells
+    #[getter]
+    fn n_cells(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The distinct H3 resolutions of the cells in ascending order
+    #[getter]
+    fn resolutions(&self) -> Vec<u8> {
+        let mut found = [false; 16];
+        for cell in self.cells.iter().flatten() {
+            found[usize::from(cell.resolution())] = true;
+        }
+        found
+            .iter()
+            .enumerate()
+            .filter_map(|(r, found)| found.then_some(r as u8))
+            .collect()
+    }
+
+    /// The schema of the attributes stored for each cell
+    #[getter]
+    fn attribute_schema(&self, py: Python) -> PyResult<PyObject> {
+        let schema = Schema::new(vec![self.value_field()]);
+        PySchema::new(Arc::new(schema)).to_arro3(py)
+    }
+
+    /// Mask of the shape of the input raster with 1 for the pixels which contributed to any cell
+    /// and 0 for all others. Only available when requested during the conversion.
+    #[getter]
+    fn coverage_mask<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyArray2<u8>>> {
+        self.coverage_mask
+            .as_ref()
+            .map(|mask| PyArray2::from_array_bound(py, mask))
+    }
+
+    /// Materialize as a record batch with the columns `value` and `cell`
+    fn to_arrow(&self, py: Python) -> PyArrowResult<PyObject> {
+        let columns: Vec<ArrayRef> = vec![
+            self.values.clone(),
+            Arc::new(self.cells.primitive_array().clone()),
+        ];
+        let batch = RecordBatch::try_new(Arc::new(self.schema()), columns)?;
+        Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+    }
+
+    /// Materialize as record batches of up to `batch_size` rows. The batches are slices of the
+    /// converted data and do not copy it.
+    fn to_batches(&self, py: Python, batch_size: usize) -> PyArrowResult<Vec<PyObject>> {
+        if batch_size == 0 {
+            return Err(PyValueError::new_err("batch_size must be larger than 0").into());
+        }
+        let schema = Arc::new(self.schema());
+        let cells = self.cells.primitive_array();
+        (0..self.cells.len())
+            .step_by(batch_size)
+            .map(|offset| {
+                let length = batch_size.min(self.cells.len() - offset);
+                let columns: Vec<ArrayRef> = vec![
+                    self.values.slice(offset, length),
+                    Arc::new(cells.slice(offset, length)),
+                ];
+                let batch = RecordBatch::try_new(schema.clone(), columns)?;
+                Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+            })
+            .collect()
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn raster_to_h3<'a, T>(
     arr: &'a ArrayView2<'a, T>,
@@ -139,10 +297,76 @@ where
     Ok((values, cells))
 }
 
+/// Replace the numeric class codes of a categorical raster by a dictionary array of their labels.
+///
+/// The dictionary is ordered by the codes. Codes without a label are an error.
+fn categorize(values: ArrayRef, categories: &HashMap<i64, String>) -> PyResult<ArrayRef> {
+    let mut codes: Vec<_> = categories.keys().copied().collect();
+    codes.sort_unstable();
+    let key_of: HashMap<i64, i32> = codes
+        .iter()
+        .enumerate()
+        .map(|(key, code)| (*code, key as i32))
+        .collect();
+    let labels = StringArray::from_iter_values(codes.iter().map(|code| &categories[code]));
+
+    let values = cast(&values, &DataType::Int64).into_pyresult()?;
+    let keys = values
+        .as_primitive::<Int64Type>()
+        .iter()
+        .map(|code| {
+            code.map(|code| {
+                key_of.get(&code).copied().ok_or_else(|| {
+                    PyValueError::new_err(format!("no category label for the value {}", code))
+                })
+            })
+            .transpose()
+        })
+        .collect::<PyResult<Int32Array>>()?;
+    let dictionary =
+        DictionaryArray::<Int32Type>::try_new(keys, Arc::new(labels)).into_pyresult()?;
+    Ok(Arc::new(dictionary))
+}
+
+/// Build a mask of the pixels whose values were assigned to cells - the pixels containing the
+/// centroids of the cells at `h3_resolution`.
+fn build_coverage_mask(
+    cells: &[CellIndex],
+    shape: (usize, usize),
+    transform: &Transform,
+    h3_resolution: u8,
+    axis_order_str: &str,
+) -> PyResult<Array2<u8>> {
+    let axis_order = AxisOrder::from_str(axis_order_str)?;
+    let h3_resolution = Resolution::try_from(h3_resolution).into_pyresult()?;
+    let inverse = transform
+        .inner
+        .inverse()
+        .ok_or_else(|| PyValueError::new_err("transform is not invertible"))?;
+
+    let mut mask = Array2::zeros(shape);
+    for cell in cells.iter().flat_map(|cell| cell.children(h3_resolution)) {
+        let pixel = Point::from(geo::Coord::from(LatLng::from(cell))).affine_transform(&inverse);
+        if pixel.x() < 0.0 || pixel.y() < 0.0 {
+            continue;
+        }
+        let (x, y) = (pixel.x() as usize, pixel.y() as usize);
+        let index = match axis_order.inner {
+            rasterh3::AxisOrder::XY => (x, y),
+            rasterh3::AxisOrder::YX => (y, x),
+        };
+        if let Some(value) = mask.get_mut(index) {
+            *value = 1;
+        }
+    }
+    Ok(mask)
+}
+
 macro_rules! make_raster_to_h3_variant {
     ($name:ident, $dtype:ty, $array_dtype:ty) => {
         #[pyfunction]
-        #[pyo3(signature = (np_array, transform, h3_resolution, axis_order_str, compact, nodata_value=None))]
+        #[pyo3(signature = (np_array, transform, h3_resolution, axis_order_str, compact, nodata_value=None, coverage_mask=false, categories=None, value_metadata=None))]
+        #[allow(clippy::too_many_arguments)]
         fn $name(
             py: Python,
             np_array: PyReadonlyArray2<$dtype>,
@@ -151,7 +375,10 @@ macro_rules! make_raster_to_h3_variant {
             axis_order_str: &str,
             compact: bool,
             nodata_value: Option<$dtype>,
-        ) -> PyResult<(PyObject, PyObject)> {
+            coverage_mask: bool,
+            categories: Option<HashMap<i64, String>>,
+            value_metadata: Option<HashMap<String, String>>,
+        ) -> PyResult<PyConvertedRaster> {
             let arr = np_array.as_array();
             let (values, cells) = py.allow_threads(|| raster_to_h3(
                 &arr,
@@ -162,10 +389,25 @@ macro_rules! make_raster_to_h3_variant {
                 compact,
             ).map(|(values, cells)| (<$array_dtype>::from(values), cells)))?;
 
-            let values = PyArray::from_array_ref(Arc::new(values)).to_arro3(py)?;
-            let cells = h3array_to_pyarray(CellIndexArray::from(cells), py)?;
+            let coverage_mask = if coverage_mask {
+                Some(py.allow_threads(|| {
+                    build_coverage_mask(&cells, arr.dim(), transform, h3_resolution, axis_order_str)
+                })?)
+            } else {
+                None
+            };
 
-            Ok((values, cells))
+            let mut values: ArrayRef = Arc::new(values);
+            if let Some(categories) = categories {
+                values = categorize(values, &categories)?;
+            }
+
+            Ok(PyConvertedRaster {
+                values,
+                cells: CellIndexArray::from(cells),
+                coverage_mask,
+                value_metadata: value_metadata.unwrap_or_default(),
+            })
         }
     };
 }
@@ -173,7 +415,8 @@ macro_rules! make_raster_to_h3_variant {
 macro_rules! make_raster_to_h3_float_variant {
     ($name:ident, $dtype:ty, $array_dtype:ty) => {
         #[pyfunction]
-        #[pyo3(signature = (np_array, transform, h3_resolution, axis_order_str, compact, nodata_value=None))]
+        #[pyo3(signature = (np_array, transform, h3_resolution, axis_order_str, compact, nodata_value=None, coverage_mask=false, categories=None, value_metadata=None))]
+        #[allow(clippy::too_many_arguments)]
         fn $name(
             py: Python,
             np_array: PyReadonlyArray2<$dtype>,
@@ -182,7 +425,10 @@ macro_rules! make_raster_to_h3_float_variant {
             axis_order_str: &str,
             compact: bool,
             nodata_value: Option<$dtype>,
-        ) -> PyResult<(PyObject, PyObject)> {
+            coverage_mask: bool,
+            categories: Option<HashMap<i64, String>>,
+            value_metadata: Option<HashMap<String, String>>,
+        ) -> PyResult<PyConvertedRaster> {
             let arr = np_array.as_array();
             // create a copy with the values wrapped in ordered floats to
             // support the internal hashing
@@ -198,10 +444,25 @@ macro_rules! make_raster_to_h3_float_variant {
                 <$array_dtype>::from(values.into_iter().map(|v| v.into_inner()).collect::<Vec<$dtype>>()),
                 cells)))?;
 
-            let values = PyArray::from_array_ref(Arc::new(values)).to_arro3(py)?;
-            let cells = h3array_to_pyarray(CellIndexArray::from(cells), py)?;
+            let coverage_mask = if coverage_mask {
+                Some(py.allow_threads(|| {
+                    build_coverage_mask(&cells, arr.dim(), transform, h3_resolution, axis_order_str)
+                })?)
+            } else {
+                None
+            };
+
+            let mut values: ArrayRef = Arc::new(values);
+            if let Some(categories) = categories {
+                values = categorize(values, &categories)?;
+            }
 
-            Ok((values, cells))
+            Ok(PyConvertedRaster {
+                values,
+                cells: CellIndexArray::from(cells),
+                coverage_mask,
+                value_metadata: value_metadata.unwrap_or_default(),
+            })
         }
     };
 }
@@ -218,8 +479,271 @@ make_raster_to_h3_variant!(raster_to_h3_i64, i64, Int64Array);
 make_raster_to_h3_float_variant!(raster_to_h3_f32, f32, Float32Array);
 make_raster_to_h3_float_variant!(raster_to_h3_f64, f64, Float64Array);
 
+/// Paint the hexagons of the cells into an image covering `bbox` (minx, miny, maxx, maxy).
+///
+/// The first row of the image is the northernmost one. Pixels are painted when their center is
+/// located within a cell, pixels not covered by any cell are NaN. Overlapping cells are painted
+/// in the order of the input.
+#[pyfunction]
+#[pyo3(signature = (cellarray, valuearray, bbox, width, height))]
+fn cells_to_image<'py>(
+    py: Python<'py>,
+    cellarray: PyCellArray,
+    valuearray: &Bound<'py, PyAny>,
+    bbox: (f64, f64, f64, f64),
+    width: usize,
+    height: usize,
+) -> PyResult<Bound<'py, PyArray2<f64>>> {
+    let cells = cellarray.into_inner();
+    let values = pyarray_to_native::<Float64Array>(valuearray)?;
+    if cells.len() != values.len() {
+        return Err(PyValueError::new_err(
+            "cell array and value array must have the same length",
+        ));
+    }
+    let (minx, miny, maxx, maxy) = bbox;
+    if minx >= maxx || miny >= maxy || width == 0 || height == 0 {
+        return Err(PyValueError::new_err("empty bbox or image dimensions"));
+    }
+
+    let image = py.allow_threads(|| {
+        let mut image = Array2::from_elem((height, width), f64::NAN);
+        let pixel_width = (maxx - minx) / width as f64;
+        let pixel_height = (maxy - miny) / height as f64;
+
+        for (cell, value) in cells.iter().zip(values.iter()) {
+            let (Some(cell), Some(value)) = (cell, value) else {
+                continue;
+            };
+            let boundary = cell.boundary();
+            let crosses_antimeridian = boundary
+                .iter()
+                .zip(boundary.iter().skip(1))
+                .any(|(a, b)| (a.lng() - b.lng()).abs() > 180.0);
+
+            // vertices in pixel coordinates
+            let vertices: Vec<(f64, f64)> = boundary
+                .iter()
+                .map(|ll| {
+                    let lng = if crosses_antimeridian && ll.lng() < 0.0 {
+                        ll.lng() + 360.0
+                    } else {
+                        ll.lng()
+                    };
+                    ((lng - minx) / pixel_width, (maxy - ll.lat()) / pixel_height)
+                })
+                .collect();
+            scanline_fill(&mut image, &vertices, value);
+        }
+        image
+    });
+    Ok(PyArray2::from_owned_array_bound(py, image))
+}
+
+enum Normalization {
+    PercentileClip { lower: f64, upper: f64 },
+    Log,
+    Standardize,
+}
+
+/// Pixel values `normalize_band` operates on. The statistics are always calculated in f64, the
+/// output keeps the type of the input.
+trait BandValue: Copy + PartialEq + Into<f64> + Send + Sync {
+    fn from_f64(value: f64) -> Self;
+}
+
+impl BandValue for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl BandValue for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+fn parse_normalization(
+    method: &str,
+    percentiles: (f64, f64),
+    tile_size: Option<usize>,
+) -> PyResult<Normalization> {
+    let normalization = match method {
+        "percentile_clip" | "percentile-clip" => {
+            let (lower, upper) = percentiles;
+            if !(0.0..=100.0).contains(&lower) || !(0.0..=100.0).contains(&upper) || lower >= upper
+            {
+                return Err(PyValueError::new_err(
+                    "percentiles must be ascending and within 0..100",
+                ));
+            }
+            Normalization::PercentileClip { lower, upper }
+        }
+        "log" => Normalization::Log,
+        "standardize" => Normalization::Standardize,
+        _ => return Err(PyValueError::new_err("unknown normalization method")),
+    };
+    if tile_size == Some(0) {
+        return Err(PyValueError::new_err("tile_size must be larger than 0"));
+    }
+    Ok(normalization)
+}
+
+fn normalize<T: BandValue>(
+    arr: ArrayView2<T>,
+    normalization: &Normalization,
+    nodata_value: Option<T>,
+    tile_size: Option<usize>,
+) -> Array2<T> {
+    let nan = T::from_f64(f64::NAN);
+    let mut normalized = arr.mapv(|v| if Some(v) == nodata_value { nan } else { v });
+    let (height, width) = normalized.dim();
+    let tile_size = tile_size.unwrap_or(height.max(width)).max(1);
+    for row in (0..height).step_by(tile_size) {
+        for col in (0..width).step_by(tile_size) {
+            let mut tile = normalized.slice_mut(s![
+                row..(row + tile_size).min(height),
+                col..(col + tile_size).min(width)
+            ]);
+            normalize_tile(&mut tile, normalization);
+        }
+    }
+    normalized
+}
+
+macro_rules! make_normalize_band_variant {
+    ($name:ident, $dtype:ty) => {
+        /// Normalize the values of a band before converting it, for example for imagery whose
+        /// raw digital numbers are not comparable between scenes.
+        ///
+        /// Methods:
+        ///
+        /// * `percentile_clip`: Clip the values to the `percentiles` and scale them to 0..1.
+        /// * `log`: Natural logarithm of `1 + value`. Negative values become NaN.
+        /// * `standardize`: Subtract the mean and divide by the standard deviation.
+        ///
+        /// The statistics are calculated over the whole band, or per tile of `tile_size` x
+        /// `tile_size` pixels when given. NaNs and `nodata_value` are excluded and become NaN in
+        /// the output.
+        #[pyfunction]
+        #[pyo3(signature = (np_array, method, nodata_value = None, percentiles = (2.0, 98.0), tile_size = None))]
+        fn $name<'py>(
+            py: Python<'py>,
+            np_array: PyReadonlyArray2<'py, $dtype>,
+            method: &str,
+            nodata_value: Option<$dtype>,
+            percentiles: (f64, f64),
+            tile_size: Option<usize>,
+        ) -> PyResult<Bound<'py, PyArray2<$dtype>>> {
+            let normalization = parse_normalization(method, percentiles, tile_size)?;
+            let arr = np_array.as_array();
+            let normalized =
+                py.allow_threads(|| normalize(arr, &normalization, nodata_value, tile_size));
+            Ok(PyArray2::from_owned_array_bound(py, normalized))
+        }
+    };
+}
+
+make_normalize_band_variant!(normalize_band_f32, f32);
+make_normalize_band_variant!(normalize_band_f64, f64);
+
+fn normalize_tile<T: BandValue>(tile: &mut ArrayViewMut2<T>, normalization: &Normalization) {
+    match normalization {
+        Normalization::Log => tile.mapv_inplace(|v| {
+            let v: f64 = v.into();
+            T::from_f64(if v >= 0.0 { v.ln_1p() } else { f64::NAN })
+        }),
+        Normalization::PercentileClip { lower, upper } => {
+            let mut values: Vec<f64> = tile
+                .iter()
+                .map(|v| (*v).into())
+                .filter(|v: &f64| !v.is_nan())
+                .collect();
+            if values.is_empty() {
+                return;
+            }
+            values.sort_unstable_by(f64::total_cmp);
+            let lower = percentile(&values, *lower);
+            let upper = percentile(&values, *upper);
+            let range = upper - lower;
+            tile.mapv_inplace(|v| {
+                let v: f64 = v.into();
+                T::from_f64(if v.is_nan() {
+                    v
+                } else if range > 0.0 {
+                    (v.clamp(lower, upper) - lower) / range
+                } else {
+                    0.0
+                })
+            });
+        }
+        Normalization::Standardize => {
+            let values = || {
+                tile.iter()
+                    .map(|v| (*v).into())
+                    .filter(|v: &f64| !v.is_nan())
+            };
+            let (count, sum) = values().fold((0usize, 0.0), |(count, sum), v| (count + 1, sum + v));
+            if count == 0 {
+                return;
+            }
+            let mean = sum / count as f64;
+            let variance = values().map(|v| (v - mean).powi(2)).sum::<f64>() / count as f64;
+            let std = variance.sqrt();
+            tile.mapv_inplace(|v| {
+                T::from_f64(match Into::<f64>::into(v) {
+                    v if v.is_nan() => v,
+                    v if std > 0.0 => (v - mean) / std,
+                    _ => 0.0,
+                })
+            });
+        }
+    }
+}
+
+/// Percentile of sorted values using linear interpolation between the closest ranks.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Fill the polygon given by its `vertices` in pixel coordinates using the even-odd rule.
+fn scanline_fill(image: &mut Array2<f64>, vertices: &[(f64, f64)], value: f64) {
+    let (height, width) = image.dim();
+    let (min_y, max_y) = vertices.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(min_y, max_y), (_, y)| (min_y.min(*y), max_y.max(*y)),
+    );
+    let row_start = (min_y - 0.5).ceil().max(0.0) as usize;
+    let row_end = ((max_y - 0.5).floor() + 1.0).clamp(0.0, height as f64) as usize;
+
+    let mut crossings = Vec::with_capacity(vertices.len());
+    for row in row_start..row_end {
+        let y = row as f64 + 0.5;
+        crossings.clear();
+        for (i, (x0, y0)) in vertices.iter().enumerate() {
+            let (x1, y1) = vertices[(i + 1) % vertices.len()];
+            if (*y0 <= y) != (y1 <= y) {
+                crossings.push(x0 + (y - y0) / (y1 - y0) * (x1 - x0));
+            }
+        }
+        crossings.sort_unstable_by(f64::total_cmp);
+
+        for span in crossings.chunks_exact(2) {
+            let col_start = (span[0] - 0.5).ceil().max(0.0) as usize;
+            let col_end = ((span[1] - 0.5).floor() + 1.0).clamp(0.0, width as f64) as usize;
+            for col in col_start..col_end {
+                image[(row, col)] = value;
+            }
+        }
+    }
+}
+
 pub fn init_raster_submodule(m: &Bound<PyModule>) -> PyResult<()> {
     m.add("Transform", m.py().get_type_bound::<Transform>())?;
+    m.add_class::<PyConvertedRaster>()?;
 
     m.add_function(wrap_pyfunction!(nearest_h3_resolution, m)?)?;
     m.add_function(wrap_pyfunction!(raster_to_h3_u8, m)?)?;
@@ -232,6 +756,42 @@ pub fn init_raster_submodule(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(raster_to_h3_i64, m)?)?;
     m.add_function(wrap_pyfunction!(raster_to_h3_f32, m)?)?;
     m.add_function(wrap_pyfunction!(raster_to_h3_f64, m)?)?;
+    m.add_function(wrap_pyfunction!(cells_to_image, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_band_f32, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_band_f64, m)?)?;
+
+    #[cfg(feature = "pure-rust")]
+    m.add_function(wrap_pyfunction!(crate::geotiff::read_geotiff, m)?)?;
 
     Ok(())
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow::array::{Array, UInt8Array};
+    use h3arrow::array::CellIndexArray;
+    use h3arrow::export::h3o::{LatLng, Resolution};
+
+    use super::{ConvertedRasterMetadata, PyConvertedRaster};
+
+    #[test]
+    fn metadata_roundtrip() {
+        let cell = LatLng::new(45.5, 10.2).unwrap().to_cell(Resolution::Seven);
+        let converted = PyConvertedRaster {
+            values: Arc::new(UInt8Array::from(vec![1u8, 2])),
+            cells: CellIndexArray::from(vec![cell, cell.parent(Resolution::Five).unwrap()]),
+            coverage_mask: None,
+            value_metadata: HashMap::from([("class".to_string(), "forest".to_string())]),
+        };
+        let metadata = converted.metadata();
+        assert_eq!(metadata.n_cells, converted.values.len());
+        assert_eq!(metadata.resolutions, vec![5, 7]);
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        let deserialized: ConvertedRasterMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, metadata);
+    }
+}
@@ -0,0 +1,59 @@
+//! Reprojection of WKB geometries from WGS84 to other coordinate reference systems using `proj`.
+//!
+//! Only available with the `proj` feature, which links against the PROJ library.
+
+use arrow::array::{AsArray, GenericBinaryArray, OffsetSizeTrait};
+use arrow::datatypes::DataType;
+use h3arrow::export::geoarrow::array::{WKBArray, WKBBuilder, WKBCapacity};
+use h3arrow::export::geoarrow::ArrayBase;
+use h3arrow::export::geozero::wkb::Wkb;
+use h3arrow::export::geozero::ToGeo;
+use proj::{Proj, Transform};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::PyArray;
+
+fn reproject_binaryarray<O: OffsetSizeTrait>(
+    array: &GenericBinaryArray<O>,
+    proj: &Proj,
+) -> PyResult<WKBArray<i64>> {
+    let geoms = array
+        .iter()
+        .map(|wkb| {
+            wkb.map(|wkb| {
+                let mut geom = Wkb(wkb.to_vec())
+                    .to_geo()
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                geom.transform(proj)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                Ok(geom)
+            })
+            .transpose()
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut builder = WKBBuilder::with_capacity(WKBCapacity::from_geometries(
+        geoms.iter().map(|v| v.as_ref()),
+    ));
+    builder.extend_from_iter(geoms.iter().map(|v| v.as_ref()));
+    Ok(builder.finish())
+}
+
+/// Reproject WKB geometries with WGS84 coordinates in degrees to the CRS `output_epsg`.
+#[pyfunction]
+pub(crate) fn wkb_reproject(py: Python, array: PyArray, output_epsg: u32) -> PyResult<PyObject> {
+    let out = py.allow_threads(|| {
+        let proj = Proj::new_known_crs("EPSG:4326", &format!("EPSG:{}", output_epsg), None)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        match array.field().data_type() {
+            DataType::Binary => reproject_binaryarray(array.array().as_binary::<i32>(), &proj),
+            DataType::LargeBinary => reproject_binaryarray(array.array().as_binary::<i64>(), &proj),
+            _ => Err(PyValueError::new_err(
+                "unsupported array type for WKB input",
+            )),
+        }
+    })?;
+
+    let field = out.extension_field();
+    PyArray::new(out.into_array_ref(), field).to_arro3(py)
+}
@@ -37,6 +37,18 @@ impl Transform {
         }
     }
 
+    /// the six coefficients in the order used by rasterio
+    pub fn to_rasterio(&self) -> [f64; 6] {
+        [
+            self.inner.a(),
+            self.inner.b(),
+            self.inner.xoff(),
+            self.inner.d(),
+            self.inner.e(),
+            self.inner.yoff(),
+        ]
+    }
+
     fn __richcmp__(&self, other: Transform, op: CompareOp) -> PyResult<bool> {
         match op {
             CompareOp::Eq => Ok(self.inner == other.inner),
@@ -1,32 +1,65 @@
+use std::fs::File;
+use std::io::{Seek, SeekFrom};
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow::array::{
-    ArrayRef, AsArray, Float64Array, GenericBinaryArray, GenericListArray, OffsetSizeTrait,
-    RecordBatch, UInt8Array,
+    Array, ArrayRef, AsArray, Float64Array, GenericBinaryArray, GenericListArray, LargeListArray,
+    OffsetSizeTrait, RecordBatch, StructArray, UInt64Array, UInt8Array,
+};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::{cast, concat_batches, take, take_record_batch};
+use arrow::csv::reader::Format;
+use arrow::csv::ReaderBuilder;
+use arrow::datatypes::{DataType, Field, FieldRef, Float64Type, Schema};
+use geo::{
+    BooleanOps, BoundingRect, HasDimensions, InteriorPoint, LineString, MultiPolygon, Polygon,
+    ToRadians,
+};
+use h3arrow::algorithm::bounding_rect::{
+    cell_geo_bounds, cells_in_geo_bounds, cells_intersecting_geo_bounds, coverage_geo_bounds,
+    GeoBounds,
+};
+use h3arrow::algorithm::footprint::{
+    geodesic_rect_to_cells as h3arrow_geodesic_rect_to_cells,
+    sector_to_cells as h3arrow_sector_to_cells,
+};
+#[cfg(feature = "lut")]
+use h3arrow::algorithm::latlng_lut::LatLngLut;
+use h3arrow::algorithm::overlap::{
+    cell_intersection_area, group_rows_by_cell, select_rows_by_highest_score,
 };
-use arrow::buffer::NullBuffer;
-use arrow::datatypes::{DataType, Field, Schema};
-use geo::{BoundingRect, HasDimensions, LineString, ToRadians};
 use h3arrow::algorithm::ToCoordinatesOp;
-use h3arrow::array::from_geo::{ToCellIndexArray, ToCellListArray, ToCellsOptions};
+use h3arrow::array::from_geo::{
+    geometry_to_cells as h3arrow_geometry_to_cells, ToCellIndexArray, ToCellListArray,
+    ToCellsOptions,
+};
 use h3arrow::array::to_geoarrow::{ToWKBLineStrings, ToWKBPoints, ToWKBPolygons};
-use h3arrow::array::{CellIndexArray, ResolutionArray};
+use h3arrow::array::wkb::{binaryarray_to_2d, wkb_is_2d, wkb_to_2d};
+use h3arrow::array::{CellIndexArray, H3ListArrayBuilder, ResolutionArray};
 use h3arrow::export::geoarrow::array::{WKBArray, WKBBuilder, WKBCapacity};
+use h3arrow::export::geoarrow::trait_::ArrayAccessor;
 use h3arrow::export::geoarrow::ArrayBase;
+use h3arrow::export::geozero::wkb::Wkb;
+use h3arrow::export::geozero::ToGeo;
 use h3arrow::export::h3o::geom::ContainmentMode;
 use h3arrow::export::h3o::Resolution;
 use h3arrow::h3o::geom::dissolve;
-use h3arrow::h3o::LatLng;
+use h3arrow::h3o::{CellIndex, LatLng};
 use itertools::multizip;
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyIOError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyTuple;
 use pyo3_arrow::error::PyArrowResult;
-use pyo3_arrow::{PyArray, PyRecordBatch};
+use pyo3_arrow::{PyArray, PyRecordBatch, PyTable};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::array::{PyCellArray, PyDirectedEdgeArray, PyVertexArray};
 use crate::arrow_interop::*;
 use crate::error::IntoPyResult;
+use crate::DEFAULT_CELL_COLUMN_NAME;
 
 /// Containment mode used to decide if a cell is contained in a polygon or not.
 ///
@@ -98,6 +131,97 @@ pub(crate) fn cells_bounds(py: Python<'_>, cellarray: PyCellArray) -> PyResult<O
     }
 }
 
+#[pyfunction]
+#[pyo3(signature = (cellarray,))]
+pub(crate) fn coverage_bounds(
+    py: Python<'_>,
+    cellarray: PyCellArray,
+) -> PyResult<Option<PyObject>> {
+    Ok(py
+        .allow_threads(|| coverage_geo_bounds(cellarray.as_ref()))
+        .map(|bounds| {
+            PyTuple::new_bound(py, [bounds.west, bounds.south, bounds.east, bounds.north])
+                .to_object(py)
+        }))
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, bbox, intersecting = false))]
+pub(crate) fn filter_cells_by_bbox(
+    py: Python<'_>,
+    cellarray: PyCellArray,
+    bbox: (f64, f64, f64, f64),
+    intersecting: bool,
+) -> PyResult<PyObject> {
+    let (west, south, east, north) = bbox;
+    if south > north {
+        return Err(PyValueError::new_err(
+            "miny of the bounding box must not be larger than maxy",
+        ));
+    }
+    let bounds = GeoBounds {
+        west,
+        south,
+        east,
+        north,
+    };
+    let mask = py.allow_threads(|| cells_in_geo_bounds(cellarray.as_ref(), bounds, intersecting));
+    PyArray::from_array_ref(Arc::new(mask)).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (resolution, bbox = None))]
+pub(crate) fn partition_cells(
+    py: Python,
+    resolution: u8,
+    bbox: Option<(f64, f64, f64, f64)>,
+) -> PyArrowResult<PyObject> {
+    let resolution = Resolution::try_from(resolution).into_pyresult()?;
+    let (west, south, east, north) = bbox.unwrap_or((-180.0, -90.0, 180.0, 90.0));
+    if south > north {
+        return Err(
+            PyValueError::new_err("miny of the bounding box must not be larger than maxy").into(),
+        );
+    }
+    let bounds = GeoBounds {
+        west,
+        south,
+        east,
+        north,
+    };
+
+    let (cells, cell_bounds) = py.allow_threads(|| {
+        let cells = cells_intersecting_geo_bounds(bounds, resolution);
+        let cell_bounds: Vec<_> = cells.iter().flatten().map(cell_geo_bounds).collect();
+        (cells, cell_bounds)
+    });
+
+    let schema = Schema::new(vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, false),
+        Field::new("minx", DataType::Float64, false),
+        Field::new("miny", DataType::Float64, false),
+        Field::new("maxx", DataType::Float64, false),
+        Field::new("maxy", DataType::Float64, false),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(cells.primitive_array().clone()),
+        Arc::new(Float64Array::from_iter_values(
+            cell_bounds.iter().map(|b| b.west),
+        )),
+        Arc::new(Float64Array::from_iter_values(
+            cell_bounds.iter().map(|b| b.south),
+        )),
+        Arc::new(Float64Array::from_iter_values(
+            cell_bounds.iter().map(|b| b.east),
+        )),
+        Arc::new(Float64Array::from_iter_values(
+            cell_bounds.iter().map(|b| b.north),
+        )),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
 #[pyfunction]
 #[pyo3(signature = (cellarray,))]
 pub(crate) fn cells_bounds_arrays(py: Python, cellarray: PyCellArray) -> PyArrowResult<PyObject> {
@@ -117,13 +241,12 @@ pub(crate) fn cells_bounds_arrays(py: Python, cellarray: PyCellArray) -> PyArrow
         validity_vec.iter_mut(),
     )) {
         if let Some(cell) = cell {
-            if let Some(rect) = LineString::from(cell.boundary()).bounding_rect() {
-                *validity = true;
-                *minx = rect.min().x;
-                *miny = rect.min().y;
-                *maxx = rect.max().x;
-                *maxy = rect.max().y;
-            };
+            let bounds = cell_geo_bounds(cell);
+            *validity = true;
+            *minx = bounds.west;
+            *miny = bounds.south;
+            *maxx = bounds.east;
+            *maxy = bounds.north;
         }
     }
 
@@ -145,12 +268,19 @@ pub(crate) fn cells_bounds_arrays(py: Python, cellarray: PyCellArray) -> PyArrow
     Ok(PyRecordBatch::new(batch).to_arro3(py)?)
 }
 
+/// Convert cells to the coordinates of their centroids.
+///
+/// Setting `precision` rounds the coordinates to the given number of decimals, `float32` returns
+/// the coordinates as Float32 instead of Float64 - both reduce the size of the output where the
+/// full precision is not needed.
 #[pyfunction]
-#[pyo3(signature = (cellarray, radians = false))]
+#[pyo3(signature = (cellarray, radians = false, precision = None, float32 = false))]
 pub(crate) fn cells_to_coordinates(
     py: Python,
     cellarray: PyCellArray,
     radians: bool,
+    precision: Option<u8>,
+    float32: bool,
 ) -> PyArrowResult<PyObject> {
     let coordinate_arrays = if radians {
         cellarray.as_ref().to_coordinates_radians()
@@ -159,56 +289,138 @@ pub(crate) fn cells_to_coordinates(
     }
     .into_pyresult()?;
 
+    let (mut lat, mut lng) = (coordinate_arrays.lat, coordinate_arrays.lng);
+    if let Some(precision) = precision {
+        let factor = 10f64.powi(precision as i32);
+        let round = |v: f64| (v * factor).round() / factor;
+        lat = lat.unary(round);
+        lng = lng.unary(round);
+    }
+    let (data_type, lat, lng): (_, ArrayRef, ArrayRef) = if float32 {
+        (
+            DataType::Float32,
+            cast(&lat, &DataType::Float32)?,
+            cast(&lng, &DataType::Float32)?,
+        )
+    } else {
+        (DataType::Float64, Arc::new(lat), Arc::new(lng))
+    };
+
     let schema = Schema::new(vec![
-        Field::new("lat", DataType::Float64, true),
-        Field::new("lng", DataType::Float64, true),
+        Field::new("lat", data_type.clone(), true),
+        Field::new("lng", data_type, true),
     ]);
-    let columns: Vec<ArrayRef> = vec![
-        Arc::new(coordinate_arrays.lat),
-        Arc::new(coordinate_arrays.lng),
-    ];
-    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    let batch = RecordBatch::try_new(Arc::new(schema), vec![lat, lng])?;
     Ok(PyRecordBatch::new(batch).to_arro3(py)?)
 }
 
+/// Read a CSV file and derive the cell of each row from its coordinate columns while reading.
+///
+/// The schema is inferred from the first rows of the file. Rows with null coordinates get a null cell.
 #[pyfunction]
-#[pyo3(signature = (latarray, lngarray, resolution, radians = false))]
+#[pyo3(signature = (path, lat_column, lng_column, resolution, columns = None, delimiter = ',', has_header = true, batch_size = 65536))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn read_csv_to_cells(
+    py: Python<'_>,
+    path: PathBuf,
+    lat_column: &str,
+    lng_column: &str,
+    resolution: u8,
+    columns: Option<Vec<String>>,
+    delimiter: char,
+    has_header: bool,
+    batch_size: usize,
+) -> PyArrowResult<PyObject> {
+    let resolution = Resolution::try_from(resolution).into_pyresult()?;
+    let delimiter = u8::try_from(delimiter)
+        .map_err(|_| PyValueError::new_err("delimiter must be a single byte character"))?;
+
+    let (batches, schema) = py.allow_threads(|| -> PyArrowResult<_> {
+        let mut file = File::open(&path).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let format = Format::default()
+            .with_header(has_header)
+            .with_delimiter(delimiter);
+        let (schema, _) = format.infer_schema(&mut file, Some(1000))?;
+        file.seek(SeekFrom::Start(0))
+            .map_err(|e| PyIOError::new_err(e.to_string()))?;
+
+        let lat_index = schema.index_of(lat_column)?;
+        let lng_index = schema.index_of(lng_column)?;
+        let projection = match &columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.index_of(name))
+                .collect::<Result<Vec<_>, _>>()?,
+            None => (0..schema.fields().len()).collect(),
+        };
+
+        let mut fields: Vec<FieldRef> = projection
+            .iter()
+            .map(|i| Arc::new(schema.field(*i).clone()))
+            .collect();
+        fields.push(Arc::new(Field::new(
+            DEFAULT_CELL_COLUMN_NAME,
+            DataType::UInt64,
+            true,
+        )));
+        let out_schema = Arc::new(Schema::new(fields));
+
+        let reader = ReaderBuilder::new(Arc::new(schema))
+            .with_format(format)
+            .with_batch_size(batch_size)
+            .build(file)?;
+
+        let mut batches = vec![];
+        for batch in reader {
+            let batch = batch?;
+            let lats = cast(batch.column(lat_index), &DataType::Float64)?;
+            let lngs = cast(batch.column(lng_index), &DataType::Float64)?;
+            let cells = lats
+                .as_primitive::<Float64Type>()
+                .iter()
+                .zip(lngs.as_primitive::<Float64Type>().iter())
+                .map(|(lat, lng)| match (lat, lng) {
+                    (Some(lat), Some(lng)) => LatLng::new(lat, lng)
+                        .into_pyresult()
+                        .map(|ll| Some(ll.to_cell(resolution))),
+                    _ => Ok(None),
+                })
+                .collect::<PyResult<CellIndexArray>>()?;
+
+            let mut out_columns: Vec<ArrayRef> = projection
+                .iter()
+                .map(|i| batch.column(*i).clone())
+                .collect();
+            out_columns.push(Arc::new(cells.primitive_array().clone()));
+            batches.push(RecordBatch::try_new(out_schema.clone(), out_columns)?);
+        }
+        Ok((batches, out_schema))
+    })?;
+
+    Ok(PyTable::try_new(batches, schema)?.to_arro3(py)?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (latarray, lngarray, resolution, radians = false, strict = false, errors_table = false))]
 pub(crate) fn coordinates_to_cells(
     py: Python<'_>,
     latarray: &Bound<PyAny>,
     lngarray: &Bound<PyAny>,
     resolution: &Bound<PyAny>,
     radians: bool,
-) -> PyResult<PyObject> {
+    strict: bool,
+    errors_table: bool,
+) -> PyArrowResult<PyObject> {
     let latarray: Float64Array = pyarray_to_native(latarray)?;
     let lngarray: Float64Array = pyarray_to_native(lngarray)?;
     if lngarray.len() != latarray.len() {
-        return Err(PyValueError::new_err(
-            "latarray and lngarray must be of the same length",
-        ));
+        return Err(
+            PyValueError::new_err("latarray and lngarray must be of the same length").into(),
+        );
     }
 
-    let cells = if let Ok(resolution) = resolution.extract::<u8>() {
-        let resolution = Resolution::try_from(resolution).into_pyresult()?;
-
-        py.allow_threads(|| {
-            latarray
-                .iter()
-                .zip(lngarray.iter())
-                .map(|(lat, lng)| {
-                    if let (Some(lat), Some(lng)) = (lat, lng) {
-                        if radians {
-                            LatLng::from_radians(lat, lng).into_pyresult()
-                        } else {
-                            LatLng::new(lat, lng).into_pyresult()
-                        }
-                        .map(|ll| Some(ll.to_cell(resolution)))
-                    } else {
-                        Ok(None)
-                    }
-                })
-                .collect::<PyResult<CellIndexArray>>()
-        })?
+    let mut resolutions = if let Ok(resolution) = resolution.extract::<u8>() {
+        Resolutions::single(Resolution::try_from(resolution).into_pyresult()?)
     } else {
         let resarray = ResolutionArray::try_from(pyarray_to_native::<UInt8Array>(resolution)?)
             .into_pyresult()?;
@@ -216,28 +428,230 @@ pub(crate) fn coordinates_to_cells(
         if resarray.len() != latarray.len() {
             return Err(PyValueError::new_err(
                 "resarray must be of the same length as the coordinate arrays",
-            ));
+            )
+            .into());
         }
+        Resolutions::PerRow(resarray.iter().collect())
+    };
+    let validate = strict || errors_table;
 
-        py.allow_threads(|| {
-            multizip((latarray.iter(), lngarray.iter(), resarray.iter()))
-                .map(|(lat, lng, res)| {
-                    if let (Some(lat), Some(lng), Some(res)) = (lat, lng, res) {
-                        if radians {
-                            LatLng::from_radians(lat, lng).into_pyresult()
-                        } else {
-                            LatLng::new(lat, lng).into_pyresult()
-                        }
-                        .map(|ll| Some(ll.to_cell(res)))
-                    } else {
-                        Ok(None)
-                    }
-                })
-                .collect::<PyResult<CellIndexArray>>()
-        })?
+    let mut rows = vec![];
+    let mut reasons = vec![];
+    let cells = py.allow_threads(|| {
+        let mut cells = Vec::with_capacity(latarray.len());
+        for (row, (lat, lng)) in latarray.iter().zip(lngarray.iter()).enumerate() {
+            let (Some(lat), Some(lng), Some(res)) = (lat, lng, resolutions.get(row)) else {
+                cells.push(None);
+                continue;
+            };
+            if !validate {
+                let ll = if radians {
+                    LatLng::from_radians(lat, lng).into_pyresult()?
+                } else {
+                    LatLng::new(lat, lng).into_pyresult()?
+                };
+                cells.push(Some(resolutions.to_cell(ll, res)));
+                continue;
+            }
+            match check_coordinate(lat, lng, radians) {
+                Ok(ll) => cells.push(Some(resolutions.to_cell(ll, res))),
+                Err(reason) if strict => {
+                    return Err(PyValueError::new_err(format!(
+                        "invalid coordinate ({}, {}) at row {}: {}",
+                        lat, lng, row, reason
+                    )));
+                }
+                Err(reason) => {
+                    rows.push(row as u64);
+                    reasons.push(reason.to_string());
+                    cells.push(None);
+                }
+            }
+        }
+        Ok(CellIndexArray::from(cells))
+    })?;
+
+    let cells = h3array_to_pyarray(cells, py)?;
+    if !errors_table {
+        return Ok(cells);
+    }
+    let coordinates = StructArray::from(vec![
+        (
+            Arc::new(Field::new("lat", DataType::Float64, true)),
+            Arc::new(latarray) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("lng", DataType::Float64, true)),
+            Arc::new(lngarray) as ArrayRef,
+        ),
+    ]);
+    let errors = errors_to_pyrecordbatch(py, &coordinates, rows, reasons)?;
+    Ok(PyTuple::new_bound(py, [cells, errors]).into_py(py))
+}
+
+enum Resolutions {
+    #[cfg(not(feature = "lut"))]
+    Single(Resolution),
+    /// A single resolution, memoizing the cells of densely located coordinates.
+    #[cfg(feature = "lut")]
+    Single(LatLngLut),
+    PerRow(Vec<Option<Resolution>>),
+}
+
+impl Resolutions {
+    fn single(resolution: Resolution) -> Self {
+        #[cfg(feature = "lut")]
+        let resolution = LatLngLut::new(resolution);
+        Self::Single(resolution)
+    }
+
+    fn get(&self, row: usize) -> Option<Resolution> {
+        match self {
+            #[cfg(not(feature = "lut"))]
+            Self::Single(resolution) => Some(*resolution),
+            #[cfg(feature = "lut")]
+            Self::Single(lut) => Some(lut.resolution()),
+            Self::PerRow(resolutions) => resolutions[row],
+        }
+    }
+
+    fn to_cell(&mut self, latlng: LatLng, resolution: Resolution) -> CellIndex {
+        #[cfg(feature = "lut")]
+        if let Self::Single(lut) = self {
+            return lut.to_cell(latlng);
+        }
+        latlng.to_cell(resolution)
+    }
+}
+
+/// Check for coordinates which can not be converted or are most likely bogus although they can be
+/// converted to cells - like coordinates outside of the valid ranges, which would be wrapped around,
+/// or "null island" at (0, 0), a common placeholder for missing coordinates.
+fn check_coordinate(lat: f64, lng: f64, radians: bool) -> Result<LatLng, &'static str> {
+    if !lat.is_finite() || !lng.is_finite() {
+        return Err("coordinate is not finite");
+    }
+    let (lat_deg, lng_deg) = if radians {
+        (lat.to_degrees(), lng.to_degrees())
+    } else {
+        (lat, lng)
     };
+    if !(-90.0..=90.0).contains(&lat_deg) {
+        return Err("latitude out of range");
+    }
+    if !(-180.0..=180.0).contains(&lng_deg) {
+        return Err("longitude out of range");
+    }
+    if lat == 0.0 && lng == 0.0 {
+        return Err("null island (0, 0)");
+    }
+    if radians {
+        LatLng::from_radians(lat, lng)
+    } else {
+        LatLng::new(lat, lng)
+    }
+    .map_err(|_| "invalid coordinate")
+}
 
-    h3array_to_pyarray(cells, py)
+#[inline]
+fn normalize_lng(lng: f64) -> f64 {
+    (lng + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Draw a point uniformly distributed on the sphere within the given cell.
+///
+/// Points are sampled within a padded lat/lng bounding box of the cell - uniform with respect
+/// to the area on the sphere - and rejected until one falls into the cell.
+fn random_point_in_cell<R: Rng>(cell: CellIndex, rng: &mut R) -> LatLng {
+    let resolution = cell.resolution();
+    let center = LatLng::from(cell);
+
+    let (mut min_lat, mut max_lat) = (f64::MAX, f64::MIN);
+    let (mut min_dlng, mut max_dlng) = (f64::MAX, f64::MIN);
+    for vertex in cell.boundary().iter() {
+        min_lat = min_lat.min(vertex.lat());
+        max_lat = max_lat.max(vertex.lat());
+        let dlng = normalize_lng(vertex.lng() - center.lng());
+        min_dlng = min_dlng.min(dlng);
+        max_dlng = max_dlng.max(dlng);
+    }
+
+    // the edges of the cells are great-circle arcs which may bulge out of the bounding box
+    // of the vertices.
+    let lat_padding = (max_lat - min_lat) * 0.25;
+    let lng_padding = (max_dlng - min_dlng) * 0.25;
+    min_lat = (min_lat - lat_padding).max(-90.0);
+    max_lat = (max_lat + lat_padding).min(90.0);
+    min_dlng = (min_dlng - lng_padding).max(-180.0);
+    max_dlng = (max_dlng + lng_padding).min(180.0);
+
+    // cells containing a pole span all longitudes
+    for pole_lat in [90.0, -90.0] {
+        let pole = LatLng::new(pole_lat, 0.0).expect("valid pole coordinate");
+        if pole.to_cell(resolution) == cell {
+            min_lat = min_lat.min(pole_lat);
+            max_lat = max_lat.max(pole_lat);
+            min_dlng = -180.0;
+            max_dlng = 180.0;
+        }
+    }
+
+    let min_z = min_lat.to_radians().sin();
+    let max_z = max_lat.to_radians().sin();
+    loop {
+        let lat = rng.gen_range(min_z..=max_z).asin().to_degrees();
+        let lng = normalize_lng(center.lng() + rng.gen_range(min_dlng..=max_dlng));
+        if let Ok(latlng) = LatLng::new(lat, lng) {
+            if latlng.to_cell(resolution) == cell {
+                return latlng;
+            }
+        }
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, n_per_cell, seed = None))]
+pub(crate) fn random_points_in_cells(
+    py: Python,
+    cellarray: PyCellArray,
+    n_per_cell: usize,
+    seed: Option<u64>,
+) -> PyArrowResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+
+    let (cells, lat, lng) = py.allow_threads(|| {
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let capacity = cellindexarray.len() * n_per_cell;
+        let mut cells = Vec::with_capacity(capacity);
+        let mut lat = Vec::with_capacity(capacity);
+        let mut lng = Vec::with_capacity(capacity);
+        for cell in cellindexarray.iter().flatten() {
+            for _ in 0..n_per_cell {
+                let latlng = random_point_in_cell(cell, &mut rng);
+                cells.push(cell);
+                lat.push(latlng.lat());
+                lng.push(latlng.lng());
+            }
+        }
+        (CellIndexArray::from(cells), lat, lng)
+    });
+
+    let schema = Schema::new(vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("lat", DataType::Float64, true),
+        Field::new("lng", DataType::Float64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(cells.primitive_array().clone()),
+        Arc::new(Float64Array::from(lat)),
+        Arc::new(Float64Array::from(lng)),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
 }
 
 #[pyfunction]
@@ -341,16 +755,23 @@ fn get_to_cells_options(
     resolution: u8,
     containment_mode: Option<PyContainmentMode>,
     compact: bool,
+    simplify_tolerance_m: Option<f64>,
 ) -> PyResult<ToCellsOptions> {
+    if simplify_tolerance_m.is_some_and(|tolerance| tolerance.is_nan() || tolerance < 0.0) {
+        return Err(PyValueError::new_err(
+            "simplify_tolerance_m must be a non-negative number",
+        ));
+    }
     Ok(
         ToCellsOptions::new(Resolution::try_from(resolution).into_pyresult()?)
             .containment_mode(containment_mode.unwrap_or_default().containment_mode())
-            .compact(compact),
+            .compact(compact)
+            .simplify_tolerance_m(simplify_tolerance_m),
     )
 }
 
 #[pyfunction]
-#[pyo3(signature = (array, resolution, containment_mode = None, compact = false, flatten = false))]
+#[pyo3(signature = (array, resolution, containment_mode = None, compact = false, flatten = false, simplify_tolerance_m = None))]
 pub(crate) fn wkb_to_cells(
     py: Python,
     array: PyArray,
@@ -358,8 +779,10 @@ pub(crate) fn wkb_to_cells(
     containment_mode: Option<PyContainmentMode>,
     compact: bool,
     flatten: bool,
+    simplify_tolerance_m: Option<f64>,
 ) -> PyResult<PyObject> {
-    let options = get_to_cells_options(resolution, containment_mode, compact)?;
+    let options =
+        get_to_cells_options(resolution, containment_mode, compact, simplify_tolerance_m)?;
 
     match array.field().data_type() {
         DataType::Binary => generic_wkb_to_cells(
@@ -380,13 +803,25 @@ pub(crate) fn wkb_to_cells(
     }
 }
 
+/// Drop Z and M coordinates - and embedded SRIDs - from the geometries when any of them has
+/// these. Arrays of plain two-dimensional WKB are returned unchanged.
+fn wkb_force_2d<O: OffsetSizeTrait>(
+    binarray: GenericBinaryArray<O>,
+) -> PyResult<GenericBinaryArray<O>> {
+    if binarray.iter().flatten().all(wkb_is_2d) {
+        Ok(binarray)
+    } else {
+        Ok(binaryarray_to_2d(&binarray).into_pyresult()?.0)
+    }
+}
+
 fn generic_wkb_to_cells<O: OffsetSizeTrait>(
     py: Python,
     binarray: GenericBinaryArray<O>,
     flatten: bool,
     options: &ToCellsOptions,
 ) -> PyResult<PyObject> {
-    let wkbarray = WKBArray::new(binarray, Default::default());
+    let wkbarray = WKBArray::new(wkb_force_2d(binarray)?, Default::default());
 
     if flatten {
         let cells = py
@@ -403,19 +838,470 @@ fn generic_wkb_to_cells<O: OffsetSizeTrait>(
     }
 }
 
+/// Returns a tuple of the cells - with null values for the failing geometries - and a table
+/// of the failures.
+///
+/// With `repair` set, the conversion of failing polygonal geometries is retried after repairing
+/// them. Successfully repaired geometries are reported in the table as well, with a reason
+/// prefixed by `repaired: `.
+#[pyfunction]
+#[pyo3(signature = (array, resolution, containment_mode = None, compact = false, flatten = false, simplify_tolerance_m = None, repair = false))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn wkb_to_cells_with_errors(
+    py: Python,
+    array: PyArray,
+    resolution: u8,
+    containment_mode: Option<PyContainmentMode>,
+    compact: bool,
+    flatten: bool,
+    simplify_tolerance_m: Option<f64>,
+    repair: bool,
+) -> PyArrowResult<PyObject> {
+    let options =
+        get_to_cells_options(resolution, containment_mode, compact, simplify_tolerance_m)?;
+
+    let (cell_vecs, rows, reasons) = match array.field().data_type() {
+        DataType::Binary => py.allow_threads(|| {
+            generic_wkb_to_cells_collecting_errors(
+                array.array().as_binary::<i32>(),
+                &options,
+                repair,
+            )
+        }),
+        DataType::LargeBinary => py.allow_threads(|| {
+            generic_wkb_to_cells_collecting_errors(
+                array.array().as_binary::<i64>(),
+                &options,
+                repair,
+            )
+        }),
+        _ => {
+            return Err(PyValueError::new_err("unsupported array type for WKB input").into());
+        }
+    };
+
+    let cells = if flatten {
+        let cells: CellIndexArray = cell_vecs.into_iter().flatten().flatten().collect();
+        h3array_to_pyarray(cells, py)?
+    } else {
+        let capacity = cell_vecs.iter().flatten().map(Vec::len).sum();
+        let mut builder = H3ListArrayBuilder::<CellIndex>::with_capacity(cell_vecs.len(), capacity);
+        for cells in cell_vecs {
+            let is_valid = cells.is_some();
+            builder.values().append_many(cells.unwrap_or_default());
+            builder.append(is_valid);
+        }
+        let listarray: LargeListArray = builder.finish().into_pyresult()?.into();
+        PyArray::from_array_ref(Arc::new(listarray)).to_arro3(py)?
+    };
+
+    let errors = errors_to_pyrecordbatch(py, array.array().as_ref(), rows, reasons)?;
+    Ok(PyTuple::new_bound(py, [cells, errors]).into_py(py))
+}
+
+/// Convert each geometry on its own, setting the failing ones to null and collecting the
+/// positions and reasons of the failures.
+#[allow(clippy::type_complexity)]
+fn generic_wkb_to_cells_collecting_errors<O: OffsetSizeTrait>(
+    binarray: &GenericBinaryArray<O>,
+    options: &ToCellsOptions,
+    repair: bool,
+) -> (Vec<Option<Vec<CellIndex>>>, Vec<u64>, Vec<String>) {
+    let mut rows = vec![];
+    let mut reasons = vec![];
+    let mut cell_vecs = Vec::with_capacity(binarray.len());
+    for (row, wkb) in binarray.iter().enumerate() {
+        let Some(wkb) = wkb else {
+            cell_vecs.push(None);
+            continue;
+        };
+        let wkb = if wkb_is_2d(wkb) {
+            Ok(wkb.to_vec())
+        } else {
+            wkb_to_2d(wkb).map(|wkb2d| wkb2d.wkb)
+        };
+        let converted = wkb
+            .map_err(|e| e.to_string())
+            .and_then(|wkb| Wkb(wkb).to_geo().map_err(|e| e.to_string()))
+            .and_then(|geom| match h3arrow_geometry_to_cells(&geom, options) {
+                Ok(cells) => Ok(cells),
+                Err(e) if repair => {
+                    let cells = repair_geometry(&geom)
+                        .and_then(|repaired| h3arrow_geometry_to_cells(&repaired, options).ok())
+                        .ok_or_else(|| e.to_string())?;
+                    rows.push(row as u64);
+                    reasons.push(format!("repaired: {}", e));
+                    Ok(cells)
+                }
+                Err(e) => Err(e.to_string()),
+            });
+        match converted {
+            Ok(cells) => cell_vecs.push(Some(cells)),
+            Err(reason) => {
+                rows.push(row as u64);
+                reasons.push(reason);
+                cell_vecs.push(None);
+            }
+        }
+    }
+    (cell_vecs, rows, reasons)
+}
+
+/// Repair polygonal geometries - comparable to `buffer(0)` - by resolving self-intersections and
+/// dropping degenerate rings using a union with an empty geometry. Returns `None` for all other
+/// geometry types.
+fn repair_geometry(geom: &geo::Geometry) -> Option<geo::Geometry> {
+    let multipolygon = match geom {
+        geo::Geometry::Polygon(polygon) => MultiPolygon::new(vec![polygon.clone()]),
+        geo::Geometry::MultiPolygon(multipolygon) => multipolygon.clone(),
+        _ => return None,
+    };
+    let repaired = multipolygon.union(&MultiPolygon::<f64>::new(vec![]));
+    (!repaired.0.is_empty()).then_some(geo::Geometry::MultiPolygon(repaired))
+}
+
+/// The mean of the Z coordinates of each geometry. Null for null geometries and geometries
+/// without Z coordinates.
+#[pyfunction]
+#[pyo3(signature = (array,))]
+pub(crate) fn wkb_mean_z(py: Python, array: PyArray) -> PyResult<PyObject> {
+    let mean_z = match array.field().data_type() {
+        DataType::Binary => {
+            py.allow_threads(|| binaryarray_to_2d(array.array().as_binary::<i32>()))
+        }
+        DataType::LargeBinary => {
+            py.allow_threads(|| binaryarray_to_2d(array.array().as_binary::<i64>()))
+        }
+        _ => {
+            return Err(PyValueError::new_err(
+                "unsupported array type for WKB input",
+            ))
+        }
+    }
+    .into_pyresult()?
+    .1;
+    PyArray::from_array_ref(Arc::new(mean_z)).to_arro3(py)
+}
+
+/// Strategy to handle cells claimed by multiple geometries.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum OverlapStrategy {
+    /// Keep a row for each geometry claiming the cell.
+    KeepAll,
+    /// Assign the cell to the geometry with the largest intersection with the cell.
+    LargestIntersection,
+    /// Assign the cell to the geometry with the highest priority.
+    Priority,
+    /// Keep a single row per cell with the ids of all geometries claiming the cell.
+    Merge,
+}
+
+impl FromStr for OverlapStrategy {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "keep_all" => Ok(Self::KeepAll),
+            "largest_intersection" => Ok(Self::LargestIntersection),
+            "priority" => Ok(Self::Priority),
+            "merge" => Ok(Self::Merge),
+            _ => Err(PyValueError::new_err("unknown overlap strategy")),
+        }
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, id_array, resolution, containment_mode = None, compact = false, overlap = "keep_all", priority_array = None, attributes = None, simplify_tolerance_m = None))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn geometries_to_cells_with_ids(
+    py: Python,
+    array: PyArray,
+    id_array: PyArray,
+    resolution: u8,
+    containment_mode: Option<PyContainmentMode>,
+    compact: bool,
+    overlap: &str,
+    priority_array: Option<PyArray>,
+    attributes: Option<PyTable>,
+    simplify_tolerance_m: Option<f64>,
+) -> PyArrowResult<PyObject> {
+    let options =
+        get_to_cells_options(resolution, containment_mode, compact, simplify_tolerance_m)?;
+    let overlap = OverlapStrategy::from_str(overlap)?;
+    if array.array().len() != id_array.array().len() {
+        return Err(
+            PyValueError::new_err("geometry array and id array must have the same length").into(),
+        );
+    }
+    let attributes = match attributes {
+        Some(_) if overlap == OverlapStrategy::Merge => {
+            return Err(PyValueError::new_err(
+                "passing through attributes is not supported with the merge overlap strategy",
+            )
+            .into());
+        }
+        Some(attributes) => Some(attribute_batch(
+            attributes,
+            array.array().len(),
+            &["id", DEFAULT_CELL_COLUMN_NAME],
+        )?),
+        None => None,
+    };
+    if compact && overlap != OverlapStrategy::KeepAll {
+        return Err(PyValueError::new_err(
+            "compacting is only supported with the keep_all overlap strategy",
+        )
+        .into());
+    }
+    let priorities = match (overlap, priority_array) {
+        (OverlapStrategy::Priority, Some(priority_array)) => {
+            if priority_array.array().len() != array.array().len() {
+                return Err(PyValueError::new_err(
+                    "geometry array and priority array must have the same length",
+                )
+                .into());
+            }
+            Some(
+                cast(priority_array.array().as_ref(), &DataType::Float64)?
+                    .as_primitive::<Float64Type>()
+                    .clone(),
+            )
+        }
+        (OverlapStrategy::Priority, None) => {
+            return Err(PyValueError::new_err(
+                "the priority overlap strategy requires a priority array",
+            )
+            .into());
+        }
+        _ => None,
+    };
+
+    let (mut geometry_indices, mut cells) = wkb_to_cells_with_indices(
+        py,
+        &array,
+        &options,
+        overlap == OverlapStrategy::LargestIntersection,
+    )?;
+
+    if let Some(priorities) = priorities {
+        let scores = geometry_indices
+            .iter()
+            .map(|geometry_index| {
+                let geometry_index = *geometry_index as usize;
+                if priorities.is_valid(geometry_index) {
+                    priorities.value(geometry_index)
+                } else {
+                    f64::NEG_INFINITY
+                }
+            })
+            .collect::<Vec<_>>();
+        let rows = select_rows_by_highest_score(&cells, &scores);
+        geometry_indices = rows.iter().map(|row| geometry_indices[*row]).collect();
+        cells = rows.iter().map(|row| cells[*row]).collect();
+    }
+
+    let ids = if overlap == OverlapStrategy::Merge {
+        let groups = group_rows_by_cell(&cells);
+        let mut offsets = Vec::with_capacity(groups.len() + 1);
+        offsets.push(0i64);
+        let mut take_indices = Vec::with_capacity(geometry_indices.len());
+        cells = Vec::with_capacity(groups.len());
+        for (cell, rows) in groups {
+            cells.push(cell);
+            take_indices.extend(rows.iter().map(|row| geometry_indices[*row]));
+            offsets.push(take_indices.len() as i64);
+        }
+        let values = take(
+            id_array.array().as_ref(),
+            &UInt64Array::from(take_indices),
+            None,
+        )?;
+        Arc::new(LargeListArray::try_new(
+            Arc::new(Field::new("item", values.data_type().clone(), true)),
+            OffsetBuffer::new(offsets.into()),
+            values,
+            None,
+        )?) as ArrayRef
+    } else {
+        take(
+            id_array.array().as_ref(),
+            &UInt64Array::from(geometry_indices.clone()),
+            None,
+        )?
+    };
+    let cells = CellIndexArray::from(cells);
+
+    let mut fields: Vec<FieldRef> = vec![Arc::new(Field::new("id", ids.data_type().clone(), true))];
+    let mut columns: Vec<ArrayRef> = vec![ids];
+    if let Some(attributes) = attributes {
+        let attributes = take_record_batch(&attributes, &UInt64Array::from(geometry_indices))?;
+        fields.extend(attributes.schema().fields().iter().cloned());
+        columns.extend(attributes.columns().iter().cloned());
+    }
+    fields.push(Arc::new(Field::new(
+        DEFAULT_CELL_COLUMN_NAME,
+        DataType::UInt64,
+        true,
+    )));
+    columns.push(Arc::new(cells.primitive_array().clone()));
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+/// Convert the WKB geometries to cells and replicate the row of `attributes` belonging to the geometry
+/// for each of its cells.
+///
+/// The attribute columns are replicated using the arrow `take` kernel, so columns of all types - including
+/// decimals, timestamps, durations and nested lists - are passed through.
+#[pyfunction]
+#[pyo3(signature = (array, attributes, resolution, containment_mode = None, compact = false, simplify_tolerance_m = None))]
+pub(crate) fn geometries_to_cells_with_attributes(
+    py: Python,
+    array: PyArray,
+    attributes: PyTable,
+    resolution: u8,
+    containment_mode: Option<PyContainmentMode>,
+    compact: bool,
+    simplify_tolerance_m: Option<f64>,
+) -> PyArrowResult<PyObject> {
+    let options =
+        get_to_cells_options(resolution, containment_mode, compact, simplify_tolerance_m)?;
+    let attributes = attribute_batch(attributes, array.array().len(), &[DEFAULT_CELL_COLUMN_NAME])?;
+    let schema = attributes.schema();
+
+    let (geometry_indices, cells) = wkb_to_cells_with_indices(py, &array, &options, false)?;
+    let attributes = take_record_batch(&attributes, &UInt64Array::from(geometry_indices))?;
+    let cells = CellIndexArray::from(cells);
+
+    let mut fields: Vec<FieldRef> = schema.fields().iter().cloned().collect();
+    fields.push(Arc::new(Field::new(
+        DEFAULT_CELL_COLUMN_NAME,
+        DataType::UInt64,
+        true,
+    )));
+    let mut columns = attributes.columns().to_vec();
+    columns.push(Arc::new(cells.primitive_array().clone()));
+
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        columns,
+    )?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+/// Concatenate the attribute table into a single batch, validating that it has a row for each of the
+/// `num_geometries` geometries and does not contain any of the `reserved_columns`.
+fn attribute_batch(
+    attributes: PyTable,
+    num_geometries: usize,
+    reserved_columns: &[&str],
+) -> PyArrowResult<RecordBatch> {
+    let (batches, schema) = attributes.into_inner();
+    let attributes = concat_batches(&schema, &batches)?;
+    if num_geometries != attributes.num_rows() {
+        return Err(PyValueError::new_err(
+            "geometry array and attribute table must have the same length",
+        )
+        .into());
+    }
+    if let Some(name) = reserved_columns
+        .iter()
+        .find(|name| schema.column_with_name(name).is_some())
+    {
+        return Err(PyValueError::new_err(format!(
+            "attribute table must not contain a column named {name}"
+        ))
+        .into());
+    }
+    Ok(attributes)
+}
+
+fn wkb_to_cells_with_indices(
+    py: Python,
+    array: &PyArray,
+    options: &ToCellsOptions,
+    largest_intersection: bool,
+) -> PyResult<(Vec<u64>, Vec<CellIndex>)> {
+    match array.field().data_type() {
+        DataType::Binary => generic_wkb_to_cells_with_indices(
+            py,
+            array.array().as_binary::<i32>().clone(),
+            options,
+            largest_intersection,
+        ),
+        DataType::LargeBinary => generic_wkb_to_cells_with_indices(
+            py,
+            array.array().as_binary::<i64>().clone(),
+            options,
+            largest_intersection,
+        ),
+        _ => Err(PyValueError::new_err(
+            "unsupported array type for WKB input",
+        )),
+    }
+}
+
+/// Convert the geometries to cells and return the cells together with the position of the
+/// geometry they have been generated from.
+///
+/// With `largest_intersection` each cell is only returned once - for the geometry having the
+/// largest intersection with the cell.
+fn generic_wkb_to_cells_with_indices<O: OffsetSizeTrait>(
+    py: Python,
+    binarray: GenericBinaryArray<O>,
+    options: &ToCellsOptions,
+    largest_intersection: bool,
+) -> PyResult<(Vec<u64>, Vec<CellIndex>)> {
+    let wkbarray = WKBArray::new(wkb_force_2d(binarray)?, Default::default());
+
+    py.allow_threads(|| {
+        let listarray = wkbarray.to_celllistarray(options).into_pyresult()?;
+
+        let mut geometry_indices = Vec::new();
+        let mut cells = Vec::new();
+        for (pos, cellarray) in listarray.iter_arrays().enumerate() {
+            if let Some(cellarray) = cellarray {
+                for cell in cellarray.into_pyresult()?.iter().flatten() {
+                    geometry_indices.push(pos as u64);
+                    cells.push(cell);
+                }
+            }
+        }
+
+        if largest_intersection {
+            let scores = geometry_indices
+                .iter()
+                .zip(cells.iter())
+                .map(|(geometry_index, cell)| {
+                    wkbarray
+                        .get_as_geo(*geometry_index as usize)
+                        .map(|geometry| cell_intersection_area(*cell, &geometry))
+                        .unwrap_or(0.0)
+                })
+                .collect::<Vec<_>>();
+            let rows = select_rows_by_highest_score(&cells, &scores);
+            geometry_indices = rows.iter().map(|row| geometry_indices[*row]).collect();
+            cells = rows.iter().map(|row| cells[*row]).collect();
+        }
+        Ok((geometry_indices, cells))
+    })
+}
+
 #[pyfunction]
-#[pyo3(signature = (obj, resolution, containment_mode = None, compact = false))]
+#[pyo3(signature = (obj, resolution, containment_mode = None, compact = false, simplify_tolerance_m = None))]
 pub(crate) fn geometry_to_cells(
     py: Python<'_>,
     obj: py_geo_interface::Geometry,
     resolution: u8,
     containment_mode: Option<PyContainmentMode>,
     compact: bool,
+    simplify_tolerance_m: Option<f64>,
 ) -> PyResult<PyObject> {
     if obj.0.is_empty() {
         return h3array_to_pyarray(CellIndexArray::new_null(0), py);
     }
-    let options = get_to_cells_options(resolution, containment_mode, compact)?;
+    let options =
+        get_to_cells_options(resolution, containment_mode, compact, simplify_tolerance_m)?;
     let cellindexarray = py.allow_threads(|| {
         Ok::<_, PyErr>(CellIndexArray::from(
             h3arrow::array::from_geo::geometry_to_cells(&obj.0, &options).into_pyresult()?,
@@ -424,16 +1310,118 @@ pub(crate) fn geometry_to_cells(
     h3array_to_pyarray(cellindexarray, py)
 }
 
+#[pyfunction]
+pub(crate) fn sector_to_cells(
+    py: Python<'_>,
+    lat: f64,
+    lng: f64,
+    radius_m: f64,
+    azimuth_min: f64,
+    azimuth_max: f64,
+    resolution: u8,
+) -> PyResult<PyObject> {
+    let center = LatLng::new(lat, lng).into_pyresult()?;
+    let resolution = Resolution::try_from(resolution).into_pyresult()?;
+    let cellindexarray = py.allow_threads(|| {
+        h3arrow_sector_to_cells(center, radius_m, azimuth_min, azimuth_max, resolution)
+            .into_pyresult()
+    })?;
+    h3array_to_pyarray(cellindexarray, py)
+}
+
+#[pyfunction]
+pub(crate) fn geodesic_rect_to_cells(
+    py: Python<'_>,
+    lat: f64,
+    lng: f64,
+    width_m: f64,
+    height_m: f64,
+    azimuth: f64,
+    resolution: u8,
+) -> PyResult<PyObject> {
+    let center = LatLng::new(lat, lng).into_pyresult()?;
+    let resolution = Resolution::try_from(resolution).into_pyresult()?;
+    let cellindexarray = py.allow_threads(|| {
+        h3arrow_geodesic_rect_to_cells(center, width_m, height_m, azimuth, resolution)
+            .into_pyresult()
+    })?;
+    h3array_to_pyarray(cellindexarray, py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, obj, radians = false))]
+pub(crate) fn representative_point_in_intersection(
+    py: Python<'_>,
+    cellarray: PyCellArray,
+    obj: py_geo_interface::Geometry,
+    radians: bool,
+) -> PyResult<PyObject> {
+    let multipolygon = match obj.0 {
+        geo_types::Geometry::Polygon(polygon) => MultiPolygon::new(vec![polygon]),
+        geo_types::Geometry::MultiPolygon(multipolygon) => multipolygon,
+        _ => {
+            return Err(PyValueError::new_err(
+                "Expected a Polygon or MultiPolygon geometry",
+            ))
+        }
+    };
+    let cellindexarray = cellarray.into_inner();
+
+    let out: WKBArray<i64> = py.allow_threads(|| {
+        let points = cellindexarray
+            .iter()
+            .map(|cell| {
+                let cell = cell?;
+                let cell_polygon = Polygon::new(LineString::from(cell.boundary()), vec![]);
+                let mut point = cell_polygon.intersection(&multipolygon).interior_point()?;
+                if radians {
+                    point.to_radians_in_place();
+                }
+                Some(geo_types::Geometry::from(point))
+            })
+            .collect::<Vec<_>>();
+
+        let mut builder = WKBBuilder::with_capacity(WKBCapacity::from_geometries(
+            points.iter().map(|v| v.as_ref()),
+        ));
+        builder.extend_from_iter(points.iter().map(|v| v.as_ref()));
+        builder.finish()
+    });
+
+    let field = out.extension_field();
+    PyArray::new(out.into_array_ref(), field).to_arro3(py)
+}
+
 pub fn init_vector_submodule(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(cells_to_coordinates, m)?)?;
     m.add_function(wrap_pyfunction!(cells_bounds, m)?)?;
     m.add_function(wrap_pyfunction!(cells_bounds_arrays, m)?)?;
+    m.add_function(wrap_pyfunction!(partition_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(coverage_bounds, m)?)?;
+    m.add_function(wrap_pyfunction!(filter_cells_by_bbox, m)?)?;
     m.add_function(wrap_pyfunction!(cells_to_wkb_polygons, m)?)?;
     m.add_function(wrap_pyfunction!(cells_to_wkb_points, m)?)?;
     m.add_function(wrap_pyfunction!(vertexes_to_wkb_points, m)?)?;
     m.add_function(wrap_pyfunction!(directededges_to_wkb_linestrings, m)?)?;
+    // reprojection is optional, the python side checks for its presence
+    #[cfg(feature = "proj")]
+    m.add_function(wrap_pyfunction!(crate::reproject::wkb_reproject, m)?)?;
     m.add_function(wrap_pyfunction!(wkb_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(wkb_to_cells_with_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(wkb_mean_z, m)?)?;
     m.add_function(wrap_pyfunction!(geometry_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(sector_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(geodesic_rect_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(geometries_to_cells_with_ids, m)?)?;
+    m.add_function(wrap_pyfunction!(geometries_to_cells_with_attributes, m)?)?;
     m.add_function(wrap_pyfunction!(coordinates_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(read_csv_to_cells, m)?)?;
+    #[cfg(feature = "io")]
+    m.add_function(wrap_pyfunction!(
+        crate::geoparquet::heatmap_from_geoparquet,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(random_points_in_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(representative_point_in_intersection, m)?)?;
     Ok(())
 }
@@ -0,0 +1,94 @@
+import h3.api.numpy_int as h3
+import pyarrow as pa
+import pytest
+from h3ronpy import aggregate_by, decay_merge
+
+
+def _table():
+    a = h3.geo_to_h3(10.0, 45.0, 8)
+    b = h3.h3_to_center_child(h3.h3_to_parent(a, 6), 8)
+    c = h3.geo_to_h3(20.0, 45.0, 8)
+    return pa.table(
+        {
+            "cell": pa.array([a, b, a, c, None], type=pa.uint64()),
+            "category": ["x", "x", "y", "x", "x"],
+            "value": [1.0, 2.0, 3.0, None, 5.0],
+        }
+    )
+
+
+def test_aggregate_by():
+    aggregated = aggregate_by(_table(), 6, keys=["category"], aggs={"value": ["sum", "mean", "count"]})
+    assert aggregated.column_names == ["cell", "category", "count", "value_sum", "value_mean", "value_count"]
+    assert aggregated.num_rows == 3
+    assert aggregated["category"].to_pylist() == ["x", "y", "x"]
+    assert aggregated["count"].to_pylist() == [2, 1, 1]
+    assert aggregated["value_sum"].to_pylist() == [3.0, 3.0, None]
+    assert aggregated["value_mean"].to_pylist() == [1.5, 3.0, None]
+    assert aggregated["value_count"].to_pylist() == [2.0, 1.0, 0.0]
+
+    parents = aggregated["cell"].to_pylist()
+    assert parents[0] == parents[1]
+    assert all(h3.h3_get_resolution(parent) == 6 for parent in parents)
+
+
+def test_aggregate_by_cell_only():
+    aggregated = aggregate_by(_table(), 6, aggs={"value": "max"})
+    assert aggregated.column_names == ["cell", "count", "value_max"]
+    assert aggregated["value_max"].to_pylist() == [3.0, None]
+
+
+def test_aggregate_by_float32():
+    table = _table()
+    table = table.set_column(2, "value", table["value"].cast(pa.float32()))
+    aggregated = aggregate_by(table, 6, aggs={"value": ["sum", "mean"]})
+    assert aggregated.schema.field("value_sum").type == pa.float32()
+    assert aggregated.schema.field("value_mean").type == pa.float32()
+    assert aggregated["value_mean"].to_pylist() == [2.0, None]
+
+
+def test_aggregate_by_coarser_cells():
+    with pytest.raises(ValueError):
+        aggregate_by(_table(), 9)
+
+
+def test_decay_merge():
+    a = h3.geo_to_h3(10.0, 45.0, 8)
+    b = h3.geo_to_h3(20.0, 45.0, 8)
+
+    incoming = pa.table({"cell": pa.array([a, a], type=pa.uint64()), "t": [0, 0], "n": [1, 3]})
+    state = decay_merge(None, incoming, 10, "t")
+    assert state.column_names == ["cell", "n", "t"]
+    assert state["n"].to_pylist() == [4.0]
+
+    incoming = pa.table({"cell": pa.array([b], type=pa.uint64()), "t": [10], "n": [1]})
+    state = decay_merge(state, incoming, 10, "t")
+    assert state["cell"].to_pylist() == [a, b]
+    assert state["n"].to_pylist() == [2.0, 1.0]
+    assert state["t"].to_pylist() == [10, 10]
+
+    incoming = pa.table({"cell": pa.array([b], type=pa.uint64()), "t": [30], "n": [0]})
+    state = decay_merge(state, incoming, 10, "t", prune_below=0.3)
+    assert state["cell"].to_pylist() == [a]
+    assert state["n"].to_pylist() == [0.5]
+
+
+def test_decay_merge_timestamps():
+    import datetime
+
+    a = h3.geo_to_h3(10.0, 45.0, 8)
+    t0 = datetime.datetime(2024, 1, 1, 12, 0)
+    state = pa.table({"cell": pa.array([a], type=pa.uint64()), "timestamp": pa.array([t0]), "count": [8]})
+    incoming = pa.table(
+        {
+            "cell": pa.array([a], type=pa.uint64()),
+            "timestamp": pa.array([t0 + datetime.timedelta(hours=2)]),
+            "count": [1],
+        }
+    )
+    merged = decay_merge(state, incoming, datetime.timedelta(hours=1))
+    assert merged["count"].to_pylist() == [3.0]
+    assert merged["timestamp"].to_pylist() == [t0 + datetime.timedelta(hours=2)]
+
+    with pytest.raises(ValueError):
+        decay_merge(state, incoming, 3600)
@@ -0,0 +1,35 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pytest
+from arro3.core import DataType
+from h3ronpy import apply_per_cell
+
+
+def test_apply_per_cell_batches():
+    cells = np.array([h3.geo_to_h3(10.0 + i, 45.0, 5) for i in range(10)], dtype=np.uint64)
+    batch_lengths = []
+
+    def resolution_times_ten(batch):
+        assert isinstance(batch, np.ndarray)
+        batch_lengths.append(len(batch))
+        return np.array([h3.h3_get_resolution(cell) * 10 for cell in batch], dtype=np.int64)
+
+    result = pa.array(apply_per_cell(cells, resolution_times_ten, DataType.int32(), batch_size=4))
+    assert result.type == pa.int32()
+    assert result.to_pylist() == [50] * 10
+    assert batch_lengths == [4, 4, 2]
+
+
+def test_apply_per_cell_nulls():
+    cell = int(h3.geo_to_h3(10.0, 45.0, 5))
+    cells = pa.array([cell, None, cell], type=pa.uint64())
+
+    result = pa.array(apply_per_cell(cells, lambda batch: batch == cell, DataType.bool()))
+    assert result.to_pylist() == [True, None, True]
+
+
+def test_apply_per_cell_length_mismatch():
+    cells = np.array([h3.geo_to_h3(10.0, 45.0, 5)], dtype=np.uint64)
+    with pytest.raises(ValueError):
+        apply_per_cell(cells, lambda batch: [1.0, 2.0], DataType.float64())
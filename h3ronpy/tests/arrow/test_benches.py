@@ -1,7 +1,18 @@
 import h3.api.numpy_int as h3
 import numpy as np
 import polars as pl
-from h3ronpy import cells_to_string
+import pytest
+import shapely
+from h3ronpy import cells_to_string, compact, grid_disk, uncompact
+from h3ronpy.raster import raster_to_dataframe
+from h3ronpy.vector import coordinates_to_cells, geometry_to_cells
+
+# Fixtures of the benchmarks below are generated deterministically, so results of different revisions can be
+# compared using the pytest-benchmark storage:
+#
+#   pytest tests/arrow/test_benches.py --benchmark-only --benchmark-autosave
+#   # ... apply changes ...
+#   pytest tests/arrow/test_benches.py --benchmark-only --benchmark-compare --benchmark-compare-fail=mean:10%
 
 
 def some_cells() -> np.ndarray:
@@ -30,3 +41,44 @@ h3_to_string_numpy_vectorized = np.vectorize(
 
 def test_h3_to_string_numpy_vectorized(benchmark):
     benchmark(h3_to_string_numpy_vectorized, some_cells())
+
+
+def some_coordinates(n: int = 100_000):
+    rng = np.random.default_rng(seed=42)
+    return rng.uniform(44.0, 46.0, n), rng.uniform(9.0, 11.0, n)
+
+
+def some_polygon():
+    return shapely.Point(10.0, 45.0).buffer(0.5, quad_segs=16)
+
+
+def test_coordinates_to_cells(benchmark):
+    lat, lng = some_coordinates()
+    benchmark(coordinates_to_cells, lat, lng, 9)
+
+
+@pytest.mark.parametrize("resolution", [6, 8])
+def test_polyfill(benchmark, resolution):
+    benchmark(geometry_to_cells, some_polygon(), resolution)
+
+
+@pytest.mark.parametrize("k", [1, 3])
+def test_grid_disk(benchmark, k):
+    lat, lng = some_coordinates(10_000)
+    benchmark(grid_disk, coordinates_to_cells(lat, lng, 9), k, flatten=True)
+
+
+def test_compact(benchmark):
+    benchmark(compact, geometry_to_cells(some_polygon(), 8))
+
+
+def test_uncompact(benchmark):
+    benchmark(uncompact, geometry_to_cells(some_polygon(), 8, compact=True), 8)
+
+
+def test_raster_to_dataframe(benchmark):
+    rng = np.random.default_rng(seed=42)
+    raster = rng.integers(1, 10, size=(1000, 1000), dtype=np.uint8)
+    # gdal geotransform of a 1x1 degree raster
+    transform = (10.0, 0.001, 0.0, 46.0, 0.0, -0.001)
+    benchmark(raster_to_dataframe, raster, transform, 8, nodata_value=0, compact=False)
@@ -0,0 +1,44 @@
+import os
+
+import pyarrow as pa
+import shapely
+from h3ronpy.cache import PolyfillCache
+from h3ronpy.vector import ContainmentMode, wkb_to_cells
+
+
+def _wkbs():
+    polygon = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)))
+    return pa.array([shapely.to_wkb(polygon)], type=pa.binary())
+
+
+def test_cache_roundtrip(tmp_path):
+    cache = PolyfillCache(tmp_path)
+    wkbs = _wkbs()
+
+    cells = wkb_to_cells(wkbs, 6, flatten=True, cache=cache)
+    assert len(os.listdir(tmp_path)) == 1
+
+    cached = wkb_to_cells(wkbs, 6, flatten=True, cache=cache)
+    assert len(os.listdir(tmp_path)) == 1
+    assert pa.array(cached).to_pylist() == pa.array(cells).to_pylist()
+
+    # different parameters result in separate entries
+    wkb_to_cells(wkbs, 6, flatten=True, containment_mode=ContainmentMode.Covers, cache=str(tmp_path))
+    wkb_to_cells(wkbs, 6, flatten=False, cache=cache)
+    assert len(os.listdir(tmp_path)) == 3
+
+
+def test_cache_is_used(tmp_path):
+    cache = PolyfillCache(tmp_path)
+    key = cache.key(
+        _wkbs(),
+        resolution=6,
+        containment_mode=repr(ContainmentMode.ContainsCentroid),
+        compact=False,
+        flatten=True,
+        simplify_tolerance_m=None,
+    )
+    cache.put(key, pa.array([1, 2, 3], type=pa.uint64()))
+
+    cells = wkb_to_cells(_wkbs(), 6, flatten=True, cache=cache)
+    assert pa.array(cells).to_pylist() == [1, 2, 3]
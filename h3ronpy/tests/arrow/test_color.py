@@ -0,0 +1,34 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+from arro3.core import DataType
+from h3ronpy.color import cells_to_lonboard_layer, colorize, rgba_to_numpy
+
+
+def test_colorize():
+    colors = colorize(np.array([0.0, 0.5, 1.0, np.nan]), cmap="gray")
+    assert colors.type == DataType.uint32()
+
+    rgba = rgba_to_numpy(colors)
+    assert rgba.shape == (4, 4)
+    assert rgba[0].tolist() == [0, 0, 0, 255]
+    assert rgba[1].tolist() == [128, 128, 128, 255]
+    assert rgba[2].tolist() == [255, 255, 255, 255]
+    assert rgba[3].tolist() == [0, 0, 0, 0]
+
+
+def test_colorize_custom_colormap():
+    rgba = rgba_to_numpy(colorize(np.array([1.0, 2.0, 3.0]), cmap=[(1.0, 0.0, 0.0), (0.0, 0.0, 1.0)], vmin=1, vmax=2))
+    assert rgba[0].tolist() == [255, 0, 0, 255]
+    assert rgba[1].tolist() == [0, 0, 255, 255]
+    assert rgba[2].tolist() == [0, 0, 255, 255]
+
+
+def test_cells_to_lonboard_layer():
+    cells = np.array([h3.geo_to_h3(10.0, 10.0, 5), h3.geo_to_h3(20.0, 10.0, 5)], dtype=np.uint64)
+    table = pa.table({"cell": cells, "value": [1.0, 2.0]})
+
+    layer = cells_to_lonboard_layer(table, "value")
+    assert len(layer["hexagons"]) == 2
+    assert layer["fill_colors"].shape == (2, 4)
+    assert layer["fill_colors"].dtype == np.uint8
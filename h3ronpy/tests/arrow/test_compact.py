@@ -1,7 +1,16 @@
 import h3.api.numpy_int as h3
 import numpy as np
 import pytest
-from h3ronpy import change_resolution, compact, uncompact
+import pyarrow as pa
+from h3ronpy import (
+    cells_area_km2,
+    cells_without,
+    change_resolution,
+    compact,
+    compact_stream,
+    coverage_report,
+    uncompact,
+)
 
 
 def compact_to_one(expected_cell, input_cells, **kw):
@@ -35,6 +44,26 @@ def test_compact_mixed():
     compact_to_one(cell, [cell, h3.geo_to_h3(10.3, 45.1, 9)], mixed_resolutions=True)
 
 
+def test_compact_stream(tmp_path):
+    cells = np.concatenate(
+        [
+            np.asarray(change_resolution(np.array([h3.geo_to_h3(lat, 45.1, 5)], dtype=np.uint64), 8))
+            for lat in (10.3, 20.3, -30.3)
+        ]
+    )
+    np.random.default_rng(1).shuffle(cells)
+    expected = sorted(np.asarray(compact(cells)).tolist())
+
+    batches = [pa.record_batch({"cell": pa.array(chunk)}) for chunk in np.array_split(cells, 10)]
+    for max_cells in (1_000_000, 100):
+        compacted = list(compact_stream(pa.RecordBatchReader.from_batches(batches[0].schema, batches), max_cells))
+        assert sorted(np.concatenate([np.asarray(arr) for arr in compacted]).tolist()) == expected
+
+    compacted = list(compact_stream(np.array_split(cells, 10), 100, spill_directory=str(tmp_path)))
+    assert sorted(np.concatenate([np.asarray(arr) for arr in compacted]).tolist()) == expected
+    assert list(tmp_path.iterdir()) == []
+
+
 def test_uncompact():
     cells = uncompact(
         [
@@ -43,3 +72,32 @@ def test_uncompact():
         9,
     )
     assert len(cells) == 7
+
+
+def test_cells_without():
+    cell = h3.geo_to_h3(10.3, 45.1, 5)
+    other = h3.geo_to_h3(30.3, 45.1, 5)
+    grandchild = h3.h3_to_center_child(cell, 7)
+    exclude = np.array([h3.h3_to_parent(other, 3), grandchild], dtype=np.uint64)
+
+    remainder = cells_without(np.array([cell, other], dtype=np.uint64), exclude).to_numpy()
+    assert len(remainder) == 12
+    assert grandchild not in remainder
+    assert uncompact(remainder, 7).to_numpy().shape[0] == 48
+
+
+def test_coverage_report():
+    cell = h3.geo_to_h3(10.3, 45.1, 6)
+    children = change_resolution(np.array([cell], dtype=np.uint64), 7).to_numpy()
+    other = h3.geo_to_h3(30.3, 45.1, 7)
+    cells = np.concatenate([children, np.array([other, other], dtype=np.uint64)])
+
+    report = coverage_report(cells)
+    assert report["resolution"].to_pylist() == [6, 7, None]
+    assert report["n_cells"].to_pylist() == [0, 9, 9]
+    assert report["n_cells_compacted"].to_pylist() == [1, 1, 2]
+    assert report["compaction_ratio"].to_pylist()[:2] == [None, None]
+    assert report["compaction_ratio"][2].as_py() == pytest.approx(2 / 9)
+
+    expected_area = sum(cells_area_km2(np.array([cell, other], dtype=np.uint64)).to_pylist())
+    assert report["area_km2"][2].as_py() == pytest.approx(expected_area)
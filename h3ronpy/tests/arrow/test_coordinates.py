@@ -1,11 +1,15 @@
 import h3.api.numpy_int as h3
 import numpy as np
+import pyarrow as pa
+import pytest
 from arro3.core import RecordBatch
 from h3ronpy.vector import (
     cells_bounds,
     cells_bounds_arrays,
     cells_to_coordinates,
     coordinates_to_cells,
+    coverage_bounds,
+    filter_cells_by_bbox,
 )
 
 
@@ -22,6 +26,14 @@ def test_cells_to_coordinates():
     assert 45.0 < coords["lng"][0].as_py() < 46.0
 
 
+def test_cells_to_coordinates_precision():
+    h3indexes = np.array([h3.geo_to_h3(10.3, 45.1, 8)], dtype=np.uint64)
+    coords = pa.record_batch(cells_to_coordinates(h3indexes, precision=2, float32=True))
+    assert coords.schema.field("lat").type == pa.float32()
+    lat = coords["lat"][0].as_py()
+    assert abs(lat - round(lat, 2)) < 1e-5
+
+
 def test_coordinates_to_cells():
     lat = np.array([10.3, 23.1], dtype=np.float64)
     lng = np.array([45.1, 2.3], dtype=np.float64)
@@ -42,6 +54,30 @@ def test_coordinates_to_cells_resolution_array():
     assert cells[1] == h3.geo_to_h3(lat[1], lng[1], r[1])
 
 
+def test_coordinates_to_cells_strict():
+    lat = np.array([10.3, 0.0], dtype=np.float64)
+    lng = np.array([45.1, 0.0], dtype=np.float64)
+    with pytest.raises(ValueError, match="null island"):
+        coordinates_to_cells(lat, lng, 7, strict=True)
+
+
+def test_coordinates_to_cells_errors_table():
+    lat = np.array([10.3, 0.0, 95.0, np.nan], dtype=np.float64)
+    lng = np.array([45.1, 0.0, 2.3, 2.3], dtype=np.float64)
+    cells, errors = coordinates_to_cells(lat, lng, 7, errors="table")
+    assert len(cells) == 4
+    assert cells[0] == h3.geo_to_h3(lat[0], lng[0], 7)
+    assert pa.array(cells).is_valid().to_pylist() == [True, False, False, False]
+
+    errors = pa.table(errors)
+    assert errors["row"].to_pylist() == [1, 2, 3]
+    assert errors["reason"].to_pylist() == [
+        "null island (0, 0)",
+        "latitude out of range",
+        "coordinate is not finite",
+    ]
+
+
 def test_cells_bounds():
     h3indexes = np.array(
         [
@@ -77,3 +113,37 @@ def test_cells_bounds_arrays():
     assert bounds_df["maxx"][0].as_py() > 45.1
     assert bounds_df["miny"][0].as_py() < 10.3
     assert bounds_df["maxy"][0].as_py() > 10.3
+
+
+def test_coverage_bounds_antimeridian():
+    h3indexes = np.array(
+        [
+            h3.geo_to_h3(10.0, 179.0, 5),
+            h3.geo_to_h3(12.0, -179.0, 5),
+        ],
+        dtype=np.uint64,
+    )
+    minx, miny, maxx, maxy = coverage_bounds(h3indexes)
+    assert 178.0 < minx < 179.0
+    assert -179.0 < maxx < -178.0
+    assert miny < 10.0
+    assert maxy > 12.0
+
+    assert coverage_bounds(np.array([], dtype=np.uint64)) is None
+
+
+def test_filter_cells_by_bbox():
+    h3indexes = np.array(
+        [
+            h3.geo_to_h3(10.0, 10.0, 5),
+            h3.geo_to_h3(20.0, 10.0, 5),
+            h3.geo_to_h3(0.0, 179.5, 5),
+        ],
+        dtype=np.uint64,
+    )
+    mask = filter_cells_by_bbox(h3indexes, (9.0, 9.0, 11.0, 11.0))
+    assert [v.as_py() for v in mask] == [True, False, False]
+
+    # crossing the antimeridian
+    mask = filter_cells_by_bbox(h3indexes, (179.0, -1.0, -179.0, 1.0))
+    assert [v.as_py() for v in mask] == [False, False, True]
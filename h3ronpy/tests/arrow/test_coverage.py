@@ -0,0 +1,60 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pytest
+from h3ronpy import cells_area_km2, cellset_canonicalize, cellset_contains, coverage_similarity, points_in_coverage
+
+
+def test_points_in_coverage():
+    coarse = h3.geo_to_h3(10.3, 45.1, 5)
+    fine = h3.geo_to_h3(30.3, 45.1, 9)
+    coverage = np.array([coarse, fine], dtype=np.uint64)
+
+    lat = np.array([10.3, 30.3, -10.0, 10.3])
+    lng = np.array([45.1, 45.1, 45.1, 45.1])
+    contained = points_in_coverage(lat, lng, coverage)
+    assert contained.to_numpy().tolist() == [True, True, False, True]
+
+    # the point of the fine cell is not contained in the coarse cells
+    assert points_in_coverage(lat, lng, coverage, resolution=5).to_numpy().tolist() == [True, False, False, True]
+
+
+def test_points_in_empty_coverage():
+    contained = points_in_coverage(np.array([10.3]), np.array([45.1]), np.array([], dtype=np.uint64))
+    assert contained.to_numpy().tolist() == [False]
+
+
+def test_coverage_similarity():
+    cell = h3.geo_to_h3(10.3, 45.1, 5)
+    children = np.array(h3.h3_to_children(cell, 6), dtype=np.uint64)
+    other = h3.geo_to_h3(30.3, 45.1, 5)
+
+    similarity = coverage_similarity(np.array([cell], dtype=np.uint64), children)
+    assert similarity["jaccard"] == pytest.approx(1.0)
+    assert similarity["n_cells_b"] == 1
+
+    similarity = coverage_similarity(np.array([cell, other], dtype=np.uint64), children[:1])
+    assert similarity["n_cells_intersection"] == 1
+    assert similarity["intersection_area_km2"] == pytest.approx(cells_area_km2(children[:1])[0].as_py())
+    assert similarity["union_area_km2"] == pytest.approx(similarity["area_a_km2"])
+    assert 0.0 < similarity["jaccard"] < 0.1
+
+
+def test_coverage_similarity_empty():
+    empty = np.array([], dtype=np.uint64)
+    assert coverage_similarity(empty, empty)["jaccard"] is None
+
+
+def test_cellset_contains():
+    coarse = h3.geo_to_h3(10.3, 45.1, 5)
+    fine = h3.geo_to_h3(30.3, 45.1, 9)
+    cellset = cellset_canonicalize(np.array([fine, *h3.h3_to_children(coarse, 6)], dtype=np.uint64))
+    assert sorted(cellset.to_numpy().tolist()) == sorted([coarse, fine])
+
+    query = np.array(
+        [coarse, h3.geo_to_h3(10.3, 45.1, 12), fine, h3.h3_to_parent(fine, 5), h3.geo_to_h3(-10.0, 45.1, 9)],
+        dtype=np.uint64,
+    )
+    assert cellset_contains(cellset, query).to_numpy().tolist() == [True, True, True, False, False]
+
+    with pytest.raises(ValueError, match="canonical"):
+        cellset_contains(np.array([coarse, h3.geo_to_h3(10.3, 45.1, 7)], dtype=np.uint64), query)
@@ -0,0 +1,35 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pytest
+from h3ronpy import deduplicate_table
+
+
+def _table():
+    a = h3.geo_to_h3(10.0, 45.0, 7)
+    b = h3.geo_to_h3(11.0, 45.0, 7)
+    return pa.table(
+        {
+            "cell": np.array([a, b, a, b, a], dtype=np.uint64),
+            "run": ["x", "x", "y", "x", "y"],
+            "value": [1, 2, 3, 4, 5],
+        }
+    )
+
+
+def test_deduplicate_table_cell():
+    assert deduplicate_table(_table())["value"].to_pylist() == [1, 2]
+    assert deduplicate_table(_table(), keep="last")["value"].to_pylist() == [4, 5]
+
+
+def test_deduplicate_table_multiple_keys():
+    deduplicated = deduplicate_table(_table(), keys=["cell", "run"])
+    assert deduplicated["value"].to_pylist() == [1, 2, 3]
+
+    deduplicated = deduplicate_table(_table(), keys=["cell", "run"], keep="last")
+    assert deduplicated["value"].to_pylist() == [1, 4, 5]
+
+
+def test_deduplicate_table_invalid_keep():
+    with pytest.raises(ValueError, match="keep"):
+        deduplicate_table(_table(), keep="none")
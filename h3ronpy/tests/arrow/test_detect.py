@@ -0,0 +1,27 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+from h3ronpy import detect_h3_columns
+
+
+def test_detect_h3_columns():
+    cells = np.array([h3.geo_to_h3(10.0 + i * 0.1, 45.0, 7) for i in range(20)], dtype=np.uint64)
+    table = pa.table(
+        {
+            "id": np.arange(20, dtype=np.int64),
+            "h3_int": cells.view(np.int64),
+            "h3_hex": [h3.h3_to_string(int(c)) for c in cells],
+            "name": [f"row {i}" for i in range(20)],
+            "edge": np.array(
+                [h3.get_h3_unidirectional_edges_from_hexagon(int(c))[0] for c in cells], dtype=np.uint64
+            ),
+        }
+    )
+    candidates = detect_h3_columns(table, sample_size=10)
+    found = {(c["column"], c["kind"], c["encoding"]) for c in candidates}
+    assert found == {
+        ("h3_int", "cell", "uint64"),
+        ("h3_hex", "cell", "string"),
+        ("edge", "directededge", "uint64"),
+    }
+    assert all(c["confidence"] == 1.0 for c in candidates)
@@ -0,0 +1,51 @@
+import pyarrow as pa
+import pytest
+from h3ronpy import change_resolution
+from h3ronpy.device import (
+    DEVICE_CPU,
+    DEVICE_CUDA,
+    device_of,
+    has_cuda_support,
+    register_kernel,
+    to_host,
+    unregister_kernel,
+)
+
+
+def test_device_of_cpu_array():
+    device_type, _ = device_of(pa.array([1, 2, 3], type=pa.uint64()))
+    assert device_type == DEVICE_CPU
+
+
+def test_cpu_path_ignores_registered_kernels():
+    def kernel(*args, **kw):
+        raise AssertionError("must not be called for CPU arrays")
+
+    register_kernel("change_resolution", DEVICE_CUDA, kernel)
+    try:
+        cells = change_resolution(pa.array([0x8001FFFFFFFFFFF], type=pa.uint64()), 1)
+        assert len(cells) == 7
+    finally:
+        unregister_kernel("change_resolution", DEVICE_CUDA)
+
+
+def test_register_kernel_invalid():
+    with pytest.raises(ValueError):
+        register_kernel("grid_disk", DEVICE_CUDA, lambda *a: None)
+    with pytest.raises(ValueError):
+        register_kernel("change_resolution", DEVICE_CPU, lambda *a: None)
+
+
+def test_to_host_cpu_array():
+    arr = pa.array([1.0, 2.0])
+    assert to_host(arr) is arr
+
+
+def test_default_cuda_kernels():
+    from h3ronpy.device import _DEFAULT_KERNELS
+
+    if has_cuda_support():
+        assert ("coordinates_to_cells", DEVICE_CUDA) in _DEFAULT_KERNELS
+        assert ("change_resolution", DEVICE_CUDA) in _DEFAULT_KERNELS
+    else:
+        assert not _DEFAULT_KERNELS
@@ -0,0 +1,28 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+from h3ronpy import directededges_to_undirected_key
+
+
+def test_directededges_to_undirected_key():
+    cell = h3.geo_to_h3(45.5, 10.2, 8)
+    outgoing = h3.get_h3_unidirectional_edges_from_hexagon(cell)
+    incoming = np.array(
+        [
+            h3.get_h3_unidirectional_edge(h3.get_destination_h3_index_from_unidirectional_edge(edge), cell)
+            for edge in outgoing
+        ],
+        dtype=np.uint64,
+    )
+
+    keys_out = pa.array(directededges_to_undirected_key(outgoing))
+    keys_in = pa.array(directededges_to_undirected_key(incoming))
+    assert keys_out.type == pa.uint64()
+    assert keys_out.to_pylist() == keys_in.to_pylist()
+    assert len(set(keys_out.to_pylist())) == len(outgoing)
+    assert set(keys_out.to_pylist()) <= set(outgoing.tolist()) | set(incoming.tolist())
+
+
+def test_directededges_to_undirected_key_null():
+    keys = pa.array(directededges_to_undirected_key(pa.array([None], type=pa.uint64())))
+    assert keys.to_pylist() == [None]
@@ -0,0 +1,77 @@
+import numpy as np
+import pyarrow as pa
+import pytest
+from arro3.core import DataType
+from h3ronpy import (
+    cells_area_m2,
+    cells_hash,
+    cells_resolution,
+    cells_to_string,
+    change_resolution,
+    compact,
+    dry_run,
+    grid_disk,
+    grid_disk_distances,
+    grid_voronoi,
+    uncompact,
+)
+from h3ronpy.vector import cells_bounds_arrays, cells_to_coordinates, cells_to_wkb_polygons, coverage_bounds
+
+CELL = 0x8828308281FFFFF
+
+ELEMENTWISE_OPS = [
+    lambda arr: change_resolution(arr, 5),
+    cells_resolution,
+    cells_to_string,
+    cells_area_m2,
+    cells_hash,
+    cells_to_wkb_polygons,
+]
+
+OPS = ELEMENTWISE_OPS + [
+    compact,
+    lambda arr: uncompact(arr, 9),
+    lambda arr: grid_disk(arr, 1),
+    lambda arr: grid_disk_distances(arr, 1),
+    cells_to_coordinates,
+    cells_bounds_arrays,
+]
+
+
+def output_type(result):
+    return result.schema if hasattr(result, "schema") else result.type
+
+
+@pytest.mark.parametrize("op", OPS)
+def test_empty_input(op):
+    # the type of the output does not depend on the number of rows
+    empty = op(pa.array([], type=pa.uint64()))
+    assert len(empty) == 0
+    assert output_type(empty) == output_type(op(pa.array([CELL], type=pa.uint64())))
+
+
+@pytest.mark.parametrize("op", OPS)
+def test_stream_without_chunks(op):
+    assert len(op(pa.chunked_array([], type=pa.uint64()))) == 0
+
+
+@pytest.mark.parametrize("op", ELEMENTWISE_OPS)
+def test_all_null_input(op):
+    result = pa.array(op(pa.nulls(3, type=pa.uint64())))
+    assert len(result) == 3
+    assert result.null_count == 3
+
+
+def test_empty_aggregates():
+    assert coverage_bounds(pa.array([], type=pa.uint64())) is None
+    assert coverage_bounds(pa.nulls(3, type=pa.uint64())) is None
+    assert pa.table(grid_voronoi(pa.array([], type=pa.uint64()), (0.0, 0.0, 1.0, 1.0))).num_rows == 0
+
+
+def test_dry_run():
+    cells = pa.array(np.full(1000, CELL, dtype=np.uint64))
+    assert dry_run(change_resolution, cells, 5) == DataType.uint64()
+    assert dry_run(cells_to_string, cells) == DataType.large_utf8()
+
+    schema = dry_run(grid_disk_distances, cells, 2)
+    assert pa.schema(schema) == pa.schema(grid_disk_distances(cells[:1], 2))
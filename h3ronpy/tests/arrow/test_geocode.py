@@ -0,0 +1,81 @@
+import hashlib
+import io
+import json
+
+import h3.api.numpy_int as h3
+import pytest
+from h3ronpy import geocode
+from h3ronpy.geocode import country_to_cells, load_countries
+
+
+@pytest.fixture
+def countries_path(tmp_path):
+    path = tmp_path / "countries.geojson"
+    polygon = [[[10.0, 50.0], [11.0, 50.0], [11.0, 51.0], [10.0, 51.0], [10.0, 50.0]]]
+    path.write_text(
+        json.dumps(
+            {
+                "type": "FeatureCollection",
+                "features": [
+                    {
+                        "type": "Feature",
+                        "properties": {"ISO_A2": "-99", "ISO_A2_EH": "XX", "ISO_A3": "XXX"},
+                        "geometry": {"type": "Polygon", "coordinates": polygon},
+                    }
+                ],
+            }
+        )
+    )
+    return str(path)
+
+
+def test_country_to_cells(countries_path):
+    cells = country_to_cells("xx", 5, path=countries_path).to_numpy()
+    assert len(cells) > 10
+    assert h3.geo_to_h3(50.5, 10.5, 5) in cells.tolist()
+
+    assert len(country_to_cells("XXX", 5, path=countries_path)) == len(cells)
+
+
+def test_country_to_cells_unknown(countries_path):
+    with pytest.raises(ValueError):
+        country_to_cells("-99", 5, path=countries_path)
+
+
+def _no_download(url):
+    raise AssertionError(f"unexpected download of {url}")
+
+
+def test_load_countries_bundled(tmp_path, monkeypatch):
+    monkeypatch.setenv("H3RONPY_CACHE_DIR", str(tmp_path / "cache"))
+    monkeypatch.setattr(geocode.urllib.request, "urlopen", _no_download)
+
+    assert len(load_countries()) == 177
+    cells = country_to_cells("DE", 3).to_numpy()
+    assert h3.geo_to_h3(51.0, 10.0, 3) in cells.tolist()
+    assert not (tmp_path / "cache").exists()
+
+
+def test_load_countries_download_requires_checksum(tmp_path, monkeypatch):
+    monkeypatch.setenv("H3RONPY_CACHE_DIR", str(tmp_path / "cache"))
+    monkeypatch.setattr(geocode.urllib.request, "urlopen", _no_download)
+
+    with pytest.raises(ValueError, match="sha256"):
+        load_countries(scale="50m")
+
+
+def test_load_countries_download_checksum(tmp_path, monkeypatch, countries_path):
+    content = open(countries_path, "rb").read()
+    monkeypatch.setenv("H3RONPY_CACHE_DIR", str(tmp_path / "cache"))
+    monkeypatch.setattr(geocode.urllib.request, "urlopen", lambda url: io.BytesIO(content))
+
+    with pytest.raises(IOError, match="checksum"):
+        load_countries(scale="50m", sha256="0" * 64)
+    # nothing is cached after a failed verification
+    assert not list((tmp_path / "cache").rglob("*.geojson"))
+
+    features = load_countries(scale="50m", sha256=hashlib.sha256(content).hexdigest())
+    assert len(features) == 1
+    # the cached file is used without a checksum and the parsed features are reused
+    monkeypatch.setattr(geocode.urllib.request, "urlopen", _no_download)
+    assert load_countries(scale="50m") is features
@@ -0,0 +1,45 @@
+import pyarrow as pa
+import pyarrow.compute as pc
+import shapely
+from h3ronpy.gtfs import gtfs_shapes_to_cells, gtfs_stops_to_cells
+from h3ronpy.vector import geometry_to_cells
+
+
+def write_feed(path):
+    (path / "stops.txt").write_text(
+        "stop_id,stop_name,stop_lat,stop_lon\n" "001,Central,52.52,13.405\n" "002,Harbour,52.51,13.39\n"
+    )
+    (path / "shapes.txt").write_text(
+        "shape_id,shape_pt_lat,shape_pt_lon,shape_pt_sequence\n"
+        "s1,52.52,13.405,1\n"
+        "s1,52.51,13.39,2\n"
+        "s2,52.50,13.40,1\n"
+        "s3,52.40,13.30,2\n"
+        "s3,52.41,13.20,3\n"
+        "s3,52.45,13.35,1\n"
+    )
+    (path / "trips.txt").write_text("route_id,service_id,trip_id,shape_id\n" "r1,daily,t1,s1\n" "r2,daily,t2,s1\n")
+
+
+def test_gtfs_stops_to_cells(tmp_path):
+    write_feed(tmp_path)
+    stops = pa.table(gtfs_stops_to_cells(tmp_path, 9))
+    assert stops.column_names == ["stop_id", "stop_name", "cell"]
+    assert stops["stop_id"].to_pylist() == ["001", "002"]
+    assert stops["cell"].null_count == 0
+
+
+def test_gtfs_shapes_to_cells(tmp_path):
+    write_feed(tmp_path)
+    shapes = pa.table(gtfs_shapes_to_cells(tmp_path, 9))
+    assert set(shapes.column_names) == {"shape_id", "route_id", "cell"}
+    assert shapes.num_rows > 1
+    # s2 only has a single point and is skipped
+    assert set(shapes["shape_id"].to_pylist()) == {"s1", "s3"}
+    # s3 is not used by any trip
+    assert set(zip(shapes["shape_id"].to_pylist(), shapes["route_id"].to_pylist())) == {("s1", "r1"), ("s3", None)}
+
+    # the points of the shapes are connected in the order of their sequence
+    s3 = shapely.LineString([(13.35, 52.45), (13.30, 52.40), (13.20, 52.41)])
+    s3_cells = shapes.filter(pc.equal(shapes["shape_id"], "s3"))["cell"].to_pylist()
+    assert set(s3_cells) == set(pa.array(geometry_to_cells(s3, 9)).to_pylist())
@@ -0,0 +1,48 @@
+import h3.api.numpy_int as h3
+import numpy as np
+from h3ronpy import h3compat
+
+
+def test_latlng_to_cell_roundtrip():
+    cells = h3compat.latlng_to_cell(np.array([10.0, 20.0]), np.array([45.0, 46.0]), 7)
+    assert cells.dtype == np.uint64
+    assert cells[0] == h3.geo_to_h3(10.0, 45.0, 7)
+
+    lats, lngs = h3compat.cell_to_latlng(cells)
+    assert np.allclose(lats, [10.0, 20.0], atol=0.01)
+    assert np.allclose(lngs, [45.0, 46.0], atol=0.01)
+
+
+def test_parents_and_children():
+    cells = np.array([h3.geo_to_h3(10.0, 45.0, 7), h3.geo_to_h3(10.0, 45.0, 5)], dtype=np.uint64)
+    parents = h3compat.cell_to_parent(cells)
+    assert h3compat.get_resolution(parents).tolist() == [6, 4]
+    assert parents[0] == h3.h3_to_parent(int(cells[0]), 6)
+
+    children = h3compat.cell_to_children(cells)
+    assert [len(c) for c in children] == [7, 7]
+    assert set(children[1].tolist()) == set(h3.h3_to_children(int(cells[1]), 6).tolist())
+
+
+def test_strings_and_disk():
+    cells = np.array([h3.geo_to_h3(10.0, 45.0, 7)], dtype=np.uint64)
+    strings = h3compat.int_to_str(cells)
+    assert strings[0] == h3.h3_to_string(int(cells[0]))
+    assert np.array_equal(h3compat.str_to_int(strings), cells)
+
+    disks = h3compat.grid_disk(cells, 1)
+    assert len(disks) == 1
+    assert len(disks[0]) == 7
+    assert h3compat.is_valid_cell(disks[0]).all()
+
+
+def test_center_child_and_children_size():
+    cells = np.array([h3.geo_to_h3(10.0, 45.0, 7), h3.geo_to_h3(10.0, 45.0, 5)], dtype=np.uint64)
+
+    center_children = h3compat.cell_to_center_child(cells)
+    assert center_children.tolist() == [
+        h3.h3_to_center_child(int(cell), h3.h3_get_resolution(cell) + 1) for cell in cells
+    ]
+
+    assert h3compat.cell_to_children_size(cells).tolist() == [7, 7]
+    assert h3compat.cell_to_children_size(cells, 8).tolist() == [7, 343]
@@ -0,0 +1,36 @@
+import numpy as np
+import pyarrow as pa
+import pytest
+from h3ronpy import cells_hash
+from h3ronpy.vector import coordinates_to_cells
+
+
+def test_cells_hash():
+    cells = coordinates_to_cells([10.2, 45.5, 50.1], [45.5, 10.2, 3.1], 8)
+    cells = pa.array(cells).to_pylist() + [None]
+    cells = pa.array(cells, type=pa.uint64())
+
+    hashes = pa.array(cells_hash(cells))
+    assert hashes.type == pa.uint64()
+    assert hashes.null_count == 1
+    # stable across calls and dependent on the seed
+    assert hashes.to_pylist() == pa.array(cells_hash(cells)).to_pylist()
+    assert hashes.to_pylist() != pa.array(cells_hash(cells, seed=42)).to_pylist()
+    assert len(set(hashes.to_pylist()[:3])) == 3
+
+    assert pa.array(cells_hash(cells, algorithm="xxhash32")).type == pa.uint32()
+    assert pa.array(cells_hash(cells, algorithm="xxh3")).type == pa.uint64()
+
+
+def test_cells_hash_reference_value():
+    # XXH64 of the little-endian bytes of the cell with seed 0
+    hashes = pa.array(cells_hash(np.array([0x8828308281FFFFF], dtype=np.uint64)))
+    assert hashes.to_pylist() == [16148738948737941305]
+
+
+def test_cells_hash_invalid():
+    cells = np.array([0x8828308281FFFFF], dtype=np.uint64)
+    with pytest.raises(ValueError):
+        cells_hash(cells, algorithm="md5")
+    with pytest.raises(ValueError):
+        cells_hash(cells, algorithm="xxhash32", seed=2**40)
@@ -0,0 +1,32 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pytest
+from h3ronpy import cells_to_int64, int64_to_cells
+
+
+def test_int64_roundtrip():
+    cells = np.array([h3.geo_to_h3(10.2, 45.5, 5), h3.geo_to_h3(10.3, 45.1, 14)], dtype=np.uint64)
+
+    int64s = pa.array(cells_to_int64(cells))
+    assert int64s.type == pa.int64()
+    assert int64s.to_numpy().tolist() == cells.view(np.int64).tolist()
+
+    back = pa.array(int64_to_cells(int64s))
+    assert back.type == pa.uint64()
+    assert back.to_numpy().tolist() == cells.tolist()
+
+
+def test_int64_preserves_nulls():
+    cell = int(h3.geo_to_h3(10.2, 45.5, 5))
+    int64s = pa.array(cells_to_int64(pa.array([cell, None], type=pa.uint64())))
+    assert int64s.to_pylist() == [cell, None]
+
+
+def test_int64_to_cells_invalid():
+    arr = pa.array([-1, 5], type=pa.int64())
+    with pytest.raises(ValueError):
+        int64_to_cells(arr)
+
+    cells = pa.array(int64_to_cells(arr, set_failing_to_invalid=True))
+    assert cells.null_count == 2
@@ -0,0 +1,41 @@
+import datetime
+
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pytest
+from h3ronpy.keplergl import to_keplergl
+
+
+def test_to_keplergl():
+    cells = np.array([h3.geo_to_h3(10.0, 10.0, 5), h3.geo_to_h3(20.0, 10.0, 5)], dtype=np.uint64)
+    table = pa.table(
+        {
+            "cell": cells,
+            "value": [1.5, None],
+            "count": pa.array([1, 2], type=pa.uint32()),
+            "ts": pa.array([datetime.datetime(2024, 1, 1), None], type=pa.timestamp("s")),
+            "tags": [["a"], []],
+        }
+    )
+
+    dataset, config = to_keplergl(table)
+    fields = {field["name"]: field["type"] for field in dataset["data"]["fields"]}
+    assert fields == {"cell": "string", "value": "real", "count": "integer", "ts": "timestamp", "tags": "string"}
+
+    rows = dataset["data"]["rows"]
+    assert len(rows) == 2
+    assert rows[0][0] == h3.h3_to_string(cells[0])
+    assert rows[0][3] == "2024-01-01T00:00:00"
+    assert rows[1][1] is None
+
+    layer = config["config"]["visState"]["layers"][0]
+    assert layer["type"] == "hexagonId"
+    assert layer["config"]["columns"]["hex_id"] == "cell"
+    assert layer["config"]["dataId"] == dataset["info"]["id"]
+
+
+def test_to_keplergl_max_rows():
+    table = pa.table({"cell": np.array([h3.geo_to_h3(10.0, 10.0, 5)] * 3, dtype=np.uint64)})
+    with pytest.raises(ValueError, match="exceeds"):
+        to_keplergl(table, max_rows=2)
@@ -1,5 +1,7 @@
+import numpy as np
 import polars as pl
-from h3ronpy import cells_parse, cells_to_localij, localij_to_cells
+import pyarrow as pa
+from h3ronpy import cells_parse, cells_to_localij, grid_disk, localij_to_cells
 from polars.testing import assert_series_equal
 
 anchors = cells_parse(
@@ -56,3 +58,17 @@ def test_localij_to_cells():
     left = pl.Series(cells)
     right = pl.Series(cells2)
     assert_series_equal(left, right, check_names=False)
+
+
+def test_localij_roundtrip_scalar_anchors():
+    anchor = anchors[0].as_py()
+    disk = grid_disk(pa.array([anchor], type=pa.uint64()), 2, flatten=True)
+
+    # single cells as python ints, numpy integers and arrow scalars are applied to all positions
+    for scalar_anchor in (anchor, np.uint64(anchor), anchors[0], pa.scalar(anchor, type=pa.uint64())):
+        df = cells_to_localij(disk, scalar_anchor)
+        assert df.num_rows == len(disk)
+        assert set(pl.Series(df["anchor"]).to_list()) == {anchor}
+
+        cells2 = localij_to_cells(scalar_anchor, df["i"], df["j"])
+        assert_series_equal(pl.Series(cells2), pl.Series(disk), check_names=False)
@@ -1,7 +1,8 @@
 import h3.api.numpy_int as h3
 import numpy as np
+import pyarrow as pa
 from arro3.core import Array
-from h3ronpy import cells_area_km2
+from h3ronpy import cells_area_km2, cells_faces, cells_vertex_angles
 
 
 def test_cells_area_km2():
@@ -19,3 +20,26 @@ def test_cells_area_km2():
     assert int(areas[0].as_py() * 100) == 62
     assert int(areas[1].as_py()) == 213
     assert int(areas[2].as_py()) == 10456
+
+
+def test_cells_faces():
+    cells = [h3.geo_to_h3(10.3, 45.1, 8), h3.geo_to_h3(10.3, 45.1, 0)]
+    faces = cells_faces(pa.array(cells + [None], type=pa.uint64()))
+    assert len(faces) == 3
+    for i, cell in enumerate(cells):
+        assert set(faces[i].as_py()) == set(h3.h3_get_faces(cell))
+    assert faces[2].as_py() is None
+
+
+def test_cells_vertex_angles():
+    hexagon = h3.geo_to_h3(10.3, 45.1, 5)
+    pentagon = [cell for cell in h3.get_res0_indexes() if h3.h3_is_pentagon(cell)][0]
+    angles = cells_vertex_angles(pa.array([hexagon, pentagon, None], type=pa.uint64()))
+    assert angles.num_rows == 3
+
+    azimuths = angles["azimuths"].to_pylist()
+    assert len(azimuths[0]) == 6
+    assert len(azimuths[1]) == 5
+    assert azimuths[2] is None
+    assert all(0.0 <= azimuth < 360.0 for azimuth in azimuths[0])
+    assert angles["is_pentagon"].to_pylist() == [False, True, None]
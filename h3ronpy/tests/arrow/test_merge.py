@@ -0,0 +1,51 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pytest
+from h3ronpy import merge_cell_tables
+
+
+def _tables():
+    a = h3.geo_to_h3(10.0, 45.0, 7)
+    b = h3.geo_to_h3(11.0, 45.0, 7)
+    c = h3.geo_to_h3(12.0, 45.0, 7)
+    existing = pa.table(
+        {
+            "cell": np.array([a, b], dtype=np.uint64),
+            "count": pa.array([1, 2], type=pa.int64()),
+            "name": ["a", "b"],
+        }
+    )
+    increment = pa.table(
+        {
+            "cell": np.array([c, b], dtype=np.uint64),
+            "count": pa.array([10, 20], type=pa.int32()),
+            "name": ["c", "b2"],
+        }
+    )
+    return (a, b, c), existing, increment
+
+
+def test_merge_cell_tables_sum():
+    (a, b, c), existing, increment = _tables()
+    merged = merge_cell_tables(existing, increment)
+    assert merged.column_names == ["cell", "count", "name"]
+    assert merged["cell"].to_pylist() == [a, b, c]
+    assert merged["count"].to_pylist() == [1, 22, 10]
+    assert merged["name"].to_pylist() == ["a", "b2", "c"]
+
+
+def test_merge_cell_tables_prefer_b():
+    (a, b, c), existing, increment = _tables()
+    merged = merge_cell_tables(existing, increment, conflict="prefer_b")
+    assert merged["cell"].to_pylist() == [a, b, c]
+    assert merged["count"].to_pylist() == [1, 20, 10]
+
+
+def test_merge_cell_tables_error():
+    _, existing, increment = _tables()
+    with pytest.raises(ValueError, match="1 keys"):
+        merge_cell_tables(existing, increment, conflict="error")
+
+    with pytest.raises(ValueError, match="not unique"):
+        merge_cell_tables(pa.concat_tables([existing, existing]), increment)
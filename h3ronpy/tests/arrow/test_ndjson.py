@@ -0,0 +1,54 @@
+import gzip
+import json
+
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pytest
+from h3ronpy.ndjson import write_elasticsearch_bulk, write_ndjson
+
+
+def _table():
+    cells = np.array([h3.geo_to_h3(45.5, 10.2, 5), h3.geo_to_h3(45.6, 11.2, 5)], dtype=np.uint64)
+    return pa.table({"cell": cells, "name": ["a", None], "value": [1.5, 2.0]})
+
+
+@pytest.mark.parametrize("use_gzip", [False, True])
+def test_write_ndjson(tmp_path, use_gzip):
+    table = _table()
+    path = tmp_path / "cells.ndjson"
+    write_ndjson(table, path, gzip=use_gzip)
+
+    opener = gzip.open if use_gzip else open
+    with opener(path, "rt") as f:
+        rows = [json.loads(line) for line in f]
+
+    assert rows == [
+        {"cell": h3.h3_to_string(int(table["cell"][0].as_py())), "name": "a", "value": 1.5},
+        {"cell": h3.h3_to_string(int(table["cell"][1].as_py())), "value": 2.0},
+    ]
+
+
+def test_write_ndjson_missing_column(tmp_path):
+    with pytest.raises(ValueError, match="not found"):
+        write_ndjson(_table(), tmp_path / "cells.ndjson", cell_column="h3index")
+
+
+def test_write_elasticsearch_bulk(tmp_path):
+    table = _table()
+    path = tmp_path / "bulk.ndjson"
+    write_elasticsearch_bulk(table, path, index="cells")
+
+    with open(path) as f:
+        lines = [json.loads(line) for line in f]
+    assert len(lines) == 4
+
+    cell = int(table["cell"][0].as_py())
+    assert lines[0] == {"index": {"_index": "cells", "_id": h3.h3_to_string(cell)}}
+    document = lines[1]
+    assert document["cell"] == h3.h3_to_string(cell)
+    assert document["name"] == "a"
+    assert document["geometry"]["type"] == "Polygon"
+    ring = document["geometry"]["coordinates"][0]
+    assert len(ring) == 7
+    assert ring[0] == ring[-1]
@@ -2,12 +2,31 @@ import h3.api.numpy_int as h3
 import numpy as np
 import polars as pl
 import pyarrow as pa
+import pytest
 from arro3.core import RecordBatch
 from h3ronpy import (
+    catchments,
+    cells_expand_contract,
+    cells_skeleton,
+    coverage_boundary_cells,
     grid_disk,
     grid_disk_aggregate_k,
     grid_disk_distances,
+    grid_disk_sector,
+    grid_disk_union,
+    grid_distance,
+    grid_distance_transform,
+    grid_path_cells,
     grid_ring_distances,
+    grid_voronoi,
+    kde,
+    region_adjacency,
+    regionalize,
+    ring_pivot,
+    ring_window,
+    simplify_cell_path,
+    spatial_outliers,
+    viewshed,
 )
 
 
@@ -29,6 +48,19 @@ def test_grid_disk():
     assert disks_flat.type == pa.uint64()
 
 
+def test_grid_disk_small_offset():
+    h3indexes = np.array([h3.geo_to_h3(10.3, 45.1, 8), h3.geo_to_h3(5.3, -5.1, 8)], dtype=np.uint64)
+    disks = pa.array(grid_disk(h3indexes, 2, offset="small"))
+    assert disks.type == pa.list_(pa.uint64())
+    assert disks.to_pylist() == pa.array(grid_disk(h3indexes, 2)).to_pylist()
+
+    distances = grid_disk_distances(h3indexes, 2, offset="small")
+    assert pa.field(distances["k"].type).type == pa.list_(pa.uint32())
+
+    with pytest.raises(ValueError, match="offset"):
+        grid_disk(h3indexes, 2, offset="medium")
+
+
 def test_grid_disk_distances():
     h3indexes = np.array(
         [
@@ -93,3 +125,281 @@ def test_grid_disk_aggregate_k():
     assert disks["k"].type == pa.uint32()
 
     # TODO: check values
+
+
+def test_grid_voronoi():
+    seed_a = h3.geo_to_h3(10.0, 10.0, 7)
+    seed_b = h3.geo_to_h3(10.1, 10.1, 7)
+    seeds = np.array([seed_a, seed_b], dtype=np.uint64)
+    extent = grid_disk(seeds, 10, flatten=True)
+
+    voronoi = grid_voronoi(seeds, extent)
+    cells = voronoi["cell"].to_numpy()
+    assigned_seeds = voronoi["seed"].to_numpy()
+    assert len(cells) == len(np.unique(extent.to_numpy()))
+    assert set(assigned_seeds.tolist()) == {seed_a, seed_b}
+
+    seed_of_cell = dict(zip(cells.tolist(), assigned_seeds.tolist()))
+    assert seed_of_cell[seed_a] == seed_a
+    assert seed_of_cell[seed_b] == seed_b
+
+
+def test_grid_voronoi_bbox():
+    seeds = np.array([h3.geo_to_h3(10.0, 10.0, 6), h3.geo_to_h3(10.5, 10.5, 6)], dtype=np.uint64)
+    voronoi = grid_voronoi(seeds, (9.8, 9.8, 10.7, 10.7))
+    assert voronoi.num_rows > 10
+
+
+def test_grid_distance_transform():
+    feature = h3.geo_to_h3(10.0, 10.0, 7)
+    extent = pa.array(grid_disk(np.array([feature], dtype=np.uint64), 5, flatten=True))
+
+    distances = pa.array(grid_distance_transform(extent, np.array([feature], dtype=np.uint64), 3))
+    assert len(distances) == len(extent)
+    for cell, distance in zip(extent.to_pylist(), distances.to_pylist()):
+        expected = h3.h3_distance(feature, cell)
+        assert distance == (expected if expected <= 3 else None)
+
+
+def test_kde():
+    a = h3.geo_to_h3(10.0, 10.0, 9)
+    b = h3.geo_to_h3(10.05, 10.0, 9)
+    counts = pa.table({"cell": np.array([a, b], dtype=np.uint64), "count": [10, 1]})
+
+    density = pa.table(kde(counts, 500.0, kernel="epanechnikov"))
+    assert density.column_names == ["cell", "density"]
+    densities = dict(zip(density["cell"].to_pylist(), density["density"].to_pylist()))
+    assert densities[a] > densities[b] > 0.0
+    assert max(densities.values()) == densities[a]
+
+    with pytest.raises(ValueError):
+        kde(counts, 500.0, kernel="box")
+
+
+def test_spatial_outliers():
+    center = h3.geo_to_h3(10.0, 10.0, 9)
+    cells = pa.array(grid_disk(np.array([center], dtype=np.uint64), 2, flatten=True))
+    values = [100.0 if cell == center else 1.0 for cell in cells.to_pylist()]
+
+    for method in ("zscore", "iqr"):
+        outliers = pa.table(spatial_outliers(cells, values, 1, method=method))
+        assert outliers.column_names == ["score", "outlier"]
+        assert outliers.num_rows == len(cells)
+        flagged = [cell for cell, flag in zip(cells.to_pylist(), outliers["outlier"].to_pylist()) if flag]
+        assert flagged == [center]
+
+
+def test_region_adjacency():
+    center = h3.geo_to_h3(10.0, 10.0, 9)
+    disk = pa.table(grid_disk_distances(np.array([center], dtype=np.uint64), 2, flatten=True))
+    table = pa.table({"cell": disk["cell"], "zone": [f"ring{k}" for k in disk["k"].to_pylist()]})
+
+    edges = region_adjacency(table, "zone")
+    assert edges.column_names == ["source", "target", "count"]
+    assert sorted(zip(edges["source"].to_pylist(), edges["target"].to_pylist(), edges["count"].to_pylist())) == [
+        ("ring0", "ring1", 6),
+        ("ring1", "ring2", 18),
+    ]
+
+
+def test_regionalize():
+    center = h3.geo_to_h3(10.0, 10.0, 9)
+    cells = pa.array(grid_disk(np.array([center], dtype=np.uint64), 5, flatten=True))
+    table = pa.table({"cell": cells, "population": np.ones(len(cells))})
+
+    regionalized = regionalize(table, "population", 3)
+    assert regionalized.column_names == ["cell", "population", "region"]
+    sizes = regionalized.group_by("region").aggregate([("population", "sum")])["population_sum"].to_pylist()
+    assert len(sizes) == 3
+    assert max(sizes) - min(sizes) < len(cells) * 0.2
+
+    # the regions border on each other
+    edges = region_adjacency(regionalized, "region")
+    assert edges.num_rows >= 2
+
+    with pytest.raises(ValueError):
+        regionalize(table, "population", 0)
+
+
+def test_cells_skeleton():
+    origin = h3.geo_to_h3(10.0, 10.0, 9)
+    destination = h3.geo_to_h3(10.0, 10.1, 9)
+    path = np.array(h3.h3_line(origin, destination), dtype=np.uint64)
+    corridor = np.unique(grid_disk(path, 1, flatten=True).to_numpy())
+
+    skeleton = cells_skeleton(corridor).to_numpy()
+    assert 0 < len(skeleton) < len(corridor) / 2
+    assert set(skeleton.tolist()).issubset(set(corridor.tolist()))
+
+
+def test_catchments():
+    facility_a = h3.geo_to_h3(10.0, 10.0, 7)
+    facility_b = h3.geo_to_h3(10.1, 10.1, 7)
+    facilities = np.array([facility_a, facility_b], dtype=np.uint64)
+    cells = np.unique(grid_disk(facilities, 8, flatten=True).to_numpy())
+    cost_table = pa.table({"cell": cells, "cost": np.ones(len(cells), dtype=np.float64)})
+
+    result = catchments(cost_table, facilities, max_cost=4.0)
+    assert 0 < result.num_rows < len(cells)
+    assert set(result["facility"].to_numpy().tolist()) == {facility_a, facility_b}
+    assert result["cost"].to_numpy().max() <= 4.0
+
+
+def test_ring_window():
+    origin_a = h3.geo_to_h3(10.0, 10.0, 7)
+    origin_b = h3.geo_to_h3(20.0, 10.0, 7)
+    table = pa.table(
+        {
+            "origin": np.array([origin_a, origin_b, origin_a, origin_a], dtype=np.uint64),
+            "k": np.array([2, 0, 0, 1], dtype=np.uint32),
+            "value": np.array([1.0, 5.0, 2.0, 3.0]),
+        }
+    )
+
+    assert ring_window(table, "cumsum").to_numpy().tolist() == [6.0, 5.0, 2.0, 5.0]
+    assert ring_window(table, "cummax").to_numpy().tolist() == [3.0, 5.0, 2.0, 3.0]
+    assert ring_window(table, "rank").to_numpy().tolist() == [3, 1, 2, 1]
+
+
+def test_ring_pivot():
+    origin_a = h3.geo_to_h3(10.0, 10.0, 7)
+    origin_b = h3.geo_to_h3(20.0, 10.0, 7)
+    table = pa.table(
+        {
+            "origin": np.array([origin_a, origin_b, origin_a, origin_a, origin_a], dtype=np.uint64),
+            "k": np.array([2, 0, 0, 1, 1], dtype=np.uint32),
+            "value": np.array([1.0, 5.0, 2.0, 3.0, 4.0]),
+        }
+    )
+
+    pivoted = ring_pivot(table)
+    assert pivoted.column_names == ["origin", "value_k0", "value_k1", "value_k2"]
+    assert pivoted["origin"].to_pylist() == [origin_a, origin_b]
+    assert pivoted["value_k0"].to_pylist() == [2.0, 5.0]
+    assert pivoted["value_k1"].to_pylist() == [7.0, None]
+    assert pivoted["value_k2"].to_pylist() == [1.0, None]
+
+    pivoted = ring_pivot(table, aggregation="mean", prefix="ring_")
+    assert pivoted["ring_1"].to_pylist() == [3.5, None]
+
+
+def test_ring_pivot_sparse_k():
+    origin = h3.geo_to_h3(10.0, 10.0, 7)
+    table = pa.table(
+        {
+            "origin": np.array([origin, origin], dtype=np.uint64),
+            "k": np.array([2**32 - 1, 3], dtype=np.uint32),
+            "value": np.array([1.0, 2.0]),
+        }
+    )
+
+    pivoted = ring_pivot(table)
+    assert pivoted.column_names == ["origin", "value_k3", f"value_k{2**32 - 1}"]
+
+
+def test_grid_disk_sector():
+    origin = h3.geo_to_h3(10.0, 10.0, 7)
+    cells = np.array([origin], dtype=np.uint64)
+    n_disk = len(grid_disk(cells, 4, flatten=True))
+
+    east = grid_disk_sector(cells, 4, 45.0, 135.0, flatten=True)
+    assert east.num_rows < n_disk / 2
+    for cell in east["cell"].to_numpy():
+        if cell != origin:
+            lat, lng = h3.h3_to_geo(cell)
+            assert lng > 10.0
+
+    north = grid_disk_sector(cells, 4, 315.0, 45.0, flatten=True)
+    assert north.num_rows < n_disk / 2
+    for cell in north["cell"].to_numpy():
+        if cell != origin:
+            lat, lng = h3.h3_to_geo(cell)
+            assert lat > 10.0
+
+
+def test_viewshed():
+    observer = h3.geo_to_h3(10.0, 10.0, 9)
+    surface = grid_disk_distances(np.array([observer], dtype=np.uint64), 6, flatten=True)
+    ks = surface["k"].to_numpy()
+
+    # a ring shaped wall at k = 3 blocks the view
+    elevation_table = pa.table({"cell": surface["cell"].to_numpy(), "elevation": np.where(ks == 3, 100.0, 0.0)})
+
+    visible = viewshed(elevation_table, np.array([observer], dtype=np.uint64), 6, observer_height=2.0)
+    assert set(visible["observer"].to_numpy().tolist()) == {observer}
+    assert visible["k"].to_numpy().max() == 3
+    assert visible.num_rows == len(grid_disk(np.array([observer], dtype=np.uint64), 3, flatten=True))
+
+
+def test_cells_expand_contract():
+    center = h3.geo_to_h3(10.0, 10.0, 9)
+    ring = np.array(list(h3.hex_ring(center, 1)), dtype=np.uint64)
+
+    closed = cells_expand_contract(ring, 1, 1).to_numpy()
+    assert center in closed.tolist()
+    assert set(ring.tolist()).issubset(set(closed.tolist()))
+    assert len(closed) == 7
+
+
+def test_coverage_boundary_cells():
+    center = h3.geo_to_h3(10.0, 10.0, 9)
+    cells = np.array(list(h3.k_ring(center, 2)), dtype=np.uint64)
+
+    inside = coverage_boundary_cells(cells).to_numpy()
+    assert set(inside.tolist()) == {int(c) for c in h3.hex_ring(center, 2)}
+
+    outside = coverage_boundary_cells(cells, outside=True).to_numpy()
+    assert set(outside.tolist()) == {int(c) for c in h3.hex_ring(center, 3)}
+
+
+def test_simplify_cell_path():
+    start = h3.geo_to_h3(10.0, 10.0, 9)
+    end = h3.geo_to_h3(10.1, 10.1, 9)
+    path = np.array(h3.h3_line(start, end), dtype=np.uint64)
+    path = np.repeat(path, 2)  # consecutive duplicates
+
+    simplified = simplify_cell_path(path, 1.0).to_numpy()
+    assert simplified.tolist() == [start, end]
+
+
+def test_simplify_cell_path_invalid_tolerance():
+    with pytest.raises(ValueError, match="tolerance"):
+        simplify_cell_path(np.array([h3.geo_to_h3(10.0, 10.0, 9)], dtype=np.uint64), -1.0)
+
+
+def test_grid_disk_union():
+    a = h3.geo_to_h3(10.3, 45.1, 8)
+    b = h3.k_ring(a, 1)[1]
+    cells = np.array([a, b, a], dtype=np.uint64)
+
+    union = grid_disk_union(cells, 2).to_numpy()
+    expected = np.unique(grid_disk(cells, 2, flatten=True).to_numpy())
+    assert union.tolist() == expected.tolist()
+
+
+def test_grid_path_cells():
+    origin = h3.geo_to_h3(10.3, 45.1, 8)
+    destination = h3.geo_to_h3(10.4, 45.3, 8)
+    origins = pa.array([origin, origin, None], type=pa.uint64())
+    destinations = pa.array([destination, h3.h3_to_parent(destination, 7), destination], type=pa.uint64())
+
+    paths = pa.array(grid_path_cells(origins, destinations))
+    assert pa.types.is_large_list(paths.type)
+    assert paths[0].as_py() == list(h3.h3_line(origin, destination))
+    # different resolutions and null cells
+    assert paths[1].as_py() is None
+    assert paths[2].as_py() is None
+
+
+def test_grid_distance():
+    origin = h3.geo_to_h3(10.3, 45.1, 8)
+    destination = h3.geo_to_h3(10.4, 45.3, 8)
+    a = pa.array([origin, origin, None, origin], type=pa.uint64())
+    b = pa.array([destination, origin, destination, h3.h3_to_parent(destination, 7)], type=pa.uint64())
+
+    distances = pa.array(grid_distance(a, b))
+    assert distances.type == pa.uint32()
+    assert distances.to_pylist() == [h3.h3_distance(origin, destination), 0, None, None]
+
+    with pytest.raises(ValueError):
+        grid_distance(a, b[:2])
@@ -0,0 +1,128 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pytest
+from h3ronpy import DEFAULT_CELL_COLUMN_NAME
+from h3ronpy.ogr import ogr_layer_to_cells, write_to_ogr_dataset
+
+ogr = pytest.importorskip("osgeo.ogr")
+osr = pytest.importorskip("osgeo.osr")
+
+
+def write_gpkg(path):
+    srs = osr.SpatialReference()
+    srs.ImportFromEPSG(4326)
+    dataset = ogr.GetDriverByName("GPKG").CreateDataSource(str(path))
+    layer = dataset.CreateLayer("areas", srs, ogr.wkbPolygon)
+    layer.CreateField(ogr.FieldDefn("name", ogr.OFTString))
+    layer.CreateField(ogr.FieldDefn("population", ogr.OFTInteger))
+    for name, population, wkt in (
+        ("a", 10, "POLYGON ((10 50, 10.1 50, 10.1 50.1, 10 50.1, 10 50))"),
+        ("b", 20, "POLYGON ((11 50, 11.1 50, 11.1 50.1, 11 50.1, 11 50))"),
+    ):
+        feature = ogr.Feature(layer.GetLayerDefn())
+        feature.SetField("name", name)
+        feature.SetField("population", population)
+        feature.SetGeometry(ogr.CreateGeometryFromWkt(wkt))
+        layer.CreateFeature(feature)
+    dataset = None
+
+
+def test_ogr_layer_to_cells(tmp_path):
+    path = tmp_path / "areas.gpkg"
+    write_gpkg(path)
+
+    batches = list(ogr_layer_to_cells(path, 7, layer="areas", fields=["name"], batch_size=1))
+    assert len(batches) == 2
+    table = pa.Table.from_batches([pa.record_batch(batch) for batch in batches])
+    assert table.column_names == ["name", DEFAULT_CELL_COLUMN_NAME]
+    assert set(table["name"].to_pylist()) == {"a", "b"}
+    assert table.num_rows > 2
+
+
+def test_ogr_layer_to_cells_missing_field(tmp_path):
+    path = tmp_path / "areas.gpkg"
+    write_gpkg(path)
+    with pytest.raises(ValueError):
+        list(ogr_layer_to_cells(path, 7, fields=["missing"]))
+
+
+def cell_table():
+    cells = [h3.geo_to_h3(50.0, 10.0, 7), h3.geo_to_h3(50.0, 11.0, 7), h3.geo_to_h3(50.0, 12.0, 8)]
+    return pa.table(
+        {
+            DEFAULT_CELL_COLUMN_NAME: pa.array(np.array(cells, dtype=np.uint64)),
+            "landuse": ["forest", "water", "forest"],
+            "value": [1.5, 2.5, 3.5],
+        }
+    )
+
+
+def layer_feature_counts(path):
+    dataset = ogr.Open(str(path))
+    return {
+        dataset.GetLayer(i).GetName(): dataset.GetLayer(i).GetFeatureCount() for i in range(dataset.GetLayerCount())
+    }
+
+
+def test_write_to_ogr_dataset(tmp_path):
+    path = tmp_path / "cells.gpkg"
+    assert write_to_ogr_dataset(cell_table(), path) == ["cells"]
+    assert layer_feature_counts(path) == {"cells": 3}
+
+    dataset = ogr.Open(str(path))
+    feature = dataset.GetLayer("cells").GetNextFeature()
+    assert feature.GetField(DEFAULT_CELL_COLUMN_NAME) == h3.h3_to_string(h3.geo_to_h3(50.0, 10.0, 7))
+    assert feature.GetField("value") == 1.5
+    assert feature.GetGeometryRef().GetGeometryName() == "POLYGON"
+
+    # layers are not overwritten
+    with pytest.raises(ValueError):
+        write_to_ogr_dataset(cell_table(), path)
+
+
+def test_write_to_ogr_dataset_split(tmp_path):
+    path = tmp_path / "cells.gpkg"
+    names = write_to_ogr_dataset(cell_table(), path, layer="landuse", split_by="landuse")
+    assert sorted(names) == ["landuse_forest", "landuse_water"]
+
+    names = write_to_ogr_dataset(cell_table(), path, split_by_resolution=True, layer_name_template="{layer}_r{value}")
+    assert sorted(names) == ["cells_r7", "cells_r8"]
+    assert layer_feature_counts(path) == {"landuse_forest": 2, "landuse_water": 1, "cells_r7": 2, "cells_r8": 1}
+
+    with pytest.raises(ValueError):
+        write_to_ogr_dataset(cell_table(), path, split_by="landuse", split_by_resolution=True)
+
+
+def test_write_to_ogr_dataset_append(tmp_path):
+    path = tmp_path / "cells.gpkg"
+    write_to_ogr_dataset(cell_table(), path, batch_size=2)
+    write_to_ogr_dataset(cell_table(), path, if_exists="append", batch_size=2)
+    assert layer_feature_counts(path) == {"cells": 6}
+
+    write_to_ogr_dataset(cell_table(), path, if_exists="replace")
+    assert layer_feature_counts(path) == {"cells": 3}
+
+    # the schema of the existing layer does not match
+    mismatching = cell_table().set_column(2, "value", pa.array(["x", "y", "z"]))
+    with pytest.raises(ValueError, match="value"):
+        write_to_ogr_dataset(mismatching, path, if_exists="append")
+    with pytest.raises(ValueError, match="extra"):
+        write_to_ogr_dataset(cell_table().append_column("extra", pa.array([1, 2, 3])), path, if_exists="append")
+    assert layer_feature_counts(path) == {"cells": 3}
+
+
+def test_write_to_ogr_dataset_output_epsg(tmp_path):
+    path = tmp_path / "cells.gpkg"
+    write_to_ogr_dataset(cell_table(), path, output_epsg=3857)
+
+    ogr_layer = ogr.Open(str(path)).GetLayer("cells")
+    assert ogr_layer.GetSpatialRef().GetAuthorityCode(None) == "3857"
+    min_x, max_x, min_y, max_y = ogr_layer.GetExtent()
+    # web mercator coordinates in meters
+    assert 1_000_000 < min_x < max_x < 1_400_000
+    assert 6_000_000 < min_y < max_y < 7_000_000
+
+    # appending in a different CRS is refused
+    with pytest.raises(ValueError, match="spatial reference"):
+        write_to_ogr_dataset(cell_table(), path, if_exists="append")
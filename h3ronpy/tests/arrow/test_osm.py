@@ -0,0 +1,63 @@
+import pyarrow as pa
+import pyarrow.compute as pc
+import pytest
+from h3ronpy import DEFAULT_CELL_COLUMN_NAME
+from h3ronpy.osm import osm_highways_to_cells
+
+ogr = pytest.importorskip("osgeo.ogr")
+
+
+def write_osm(path):
+    path.write_text(
+        """<?xml version="1.0" encoding="UTF-8"?>
+<osm version="0.6" generator="h3ronpy">
+  <node id="1" version="1" lat="52.50" lon="13.30"/>
+  <node id="2" version="1" lat="52.50" lon="13.40"/>
+  <node id="3" version="1" lat="52.55" lon="13.40"/>
+  <way id="10" version="1">
+    <nd ref="1"/>
+    <nd ref="2"/>
+    <tag k="highway" v="primary"/>
+    <tag k="name" v="Main Street"/>
+  </way>
+  <way id="11" version="1">
+    <nd ref="2"/>
+    <nd ref="3"/>
+    <tag k="highway" v="residential"/>
+  </way>
+  <way id="12" version="1">
+    <nd ref="1"/>
+    <nd ref="3"/>
+    <tag k="waterway" v="canal"/>
+  </way>
+</osm>
+"""
+    )
+
+
+@pytest.fixture
+def osm_path(tmp_path):
+    if ogr.GetDriverByName("OSM") is None:
+        pytest.skip("GDAL is built without the OSM driver")
+    path = tmp_path / "extract.osm"
+    write_osm(path)
+    return path
+
+
+def test_osm_highways_to_cells(osm_path):
+    table = pa.Table.from_batches([pa.record_batch(batch) for batch in osm_highways_to_cells(osm_path, 9)])
+    assert table.column_names == ["osm_id", "highway", "name", DEFAULT_CELL_COLUMN_NAME]
+    # the canal is not a highway
+    assert set(table["osm_id"].to_pylist()) == {"10", "11"}
+    assert set(table.filter(pc.equal(table["osm_id"], "10"))["name"].to_pylist()) == {"Main Street"}
+    assert table[DEFAULT_CELL_COLUMN_NAME].null_count == 0
+
+
+def test_osm_highways_to_cells_highway_types(osm_path):
+    table = pa.Table.from_batches(
+        [pa.record_batch(batch) for batch in osm_highways_to_cells(osm_path, 9, highway_types=["primary"])]
+    )
+    assert set(table["highway"].to_pylist()) == {"primary"}
+
+    with pytest.raises(ValueError):
+        osm_highways_to_cells(osm_path, 9, highway_types=[])
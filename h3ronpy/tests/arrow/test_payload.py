@@ -0,0 +1,23 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pytest
+from h3ronpy import cells_decode_payload, cells_encode_payload
+
+
+def test_payload_roundtrip():
+    cells = np.array([h3.geo_to_h3(10.2, 45.5, 5), h3.geo_to_h3(10.3, 45.1, 14)], dtype=np.uint64)
+    payloads = np.array([1234, 7], dtype=np.uint64)
+
+    encoded = cells_encode_payload(cells, payloads)
+    assert len(encoded) == 2
+    assert encoded[0].as_py() != cells[0]
+
+    decoded = cells_decode_payload(encoded)
+    assert decoded["cell"].to_numpy().tolist() == cells.tolist()
+    assert decoded["payload"].to_numpy().tolist() == payloads.tolist()
+
+
+def test_payload_overflow():
+    cells = np.array([h3.geo_to_h3(10.3, 45.1, 14)], dtype=np.uint64)
+    with pytest.raises(ValueError):
+        cells_encode_payload(cells, np.array([8], dtype=np.uint64))
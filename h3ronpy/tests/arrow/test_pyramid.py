@@ -0,0 +1,62 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+from h3ronpy import DEFAULT_CELL_COLUMN_NAME
+from h3ronpy.pyramid import overview_resolutions_for, write_pyramid
+
+
+def cell_table():
+    cells = h3.k_ring(h3.geo_to_h3(50.0, 10.0, 8), 10)
+    return pa.table(
+        {
+            DEFAULT_CELL_COLUMN_NAME: pa.array(np.array(cells, dtype=np.uint64)),
+            "value": np.arange(len(cells), dtype=np.float64),
+        }
+    )
+
+
+def test_overview_resolutions_for():
+    assert overview_resolutions_for(8) == [6, 4, 2]
+    assert overview_resolutions_for(3) == [1]
+    assert overview_resolutions_for(9, levels=2, step=1) == [8, 7]
+
+
+def test_write_pyramid_parquet(tmp_path):
+    table = cell_table()
+    names = write_pyramid(table, tmp_path / "pyramid")
+    assert names == ["cells", "cells_r6", "cells_r4", "cells_r2"]
+
+    assert pq.read_table(tmp_path / "pyramid" / "cells.parquet").num_rows == table.num_rows
+    for name, resolution in (("cells_r6", 6), ("cells_r4", 4), ("cells_r2", 2)):
+        overview = pq.read_table(tmp_path / "pyramid" / f"{name}.parquet")
+        assert overview.column_names == [DEFAULT_CELL_COLUMN_NAME, "count", "value_mean"]
+        assert {h3.h3_get_resolution(cell) for cell in overview[DEFAULT_CELL_COLUMN_NAME].to_pylist()} == {resolution}
+        assert sum(overview["count"].to_pylist()) == table.num_rows
+
+    # the means are aggregated from the cells of the full resolution
+    overview = pq.read_table(tmp_path / "pyramid" / "cells_r2.parquet")
+    counts = overview["count"].to_numpy()
+    means = overview["value_mean"].to_numpy()
+    assert np.sum(counts * means) / np.sum(counts) == pytest.approx(np.mean(table["value"].to_numpy()))
+
+    with pytest.raises(ValueError):
+        write_pyramid(table, tmp_path / "pyramid")
+    write_pyramid(table, tmp_path / "pyramid", if_exists="replace")
+
+
+def test_write_pyramid_invalid_resolutions(tmp_path):
+    with pytest.raises(ValueError, match="not coarser"):
+        write_pyramid(cell_table(), tmp_path / "pyramid", overview_resolutions=[8])
+
+
+def test_write_pyramid_gpkg(tmp_path):
+    ogr = pytest.importorskip("osgeo.ogr")
+    path = tmp_path / "pyramid.gpkg"
+    names = write_pyramid(cell_table(), path, driver="GPKG", overview_resolutions=[7, 5])
+    assert names == ["cells", "cells_r7", "cells_r5"]
+
+    dataset = ogr.Open(str(path))
+    assert sorted(dataset.GetLayer(i).GetName() for i in range(dataset.GetLayerCount())) == sorted(names)
+    assert dataset.GetLayer("cells_r7").GetLayerDefn().GetFieldIndex("value_mean") >= 0
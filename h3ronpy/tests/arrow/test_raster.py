@@ -6,16 +6,35 @@ except ImportError:
     # rasterio is an optional dependency
     HAS_RASTERIO = False
 
+import json
+
 import numpy as np
 import polars as pl
 import pyarrow as pa
 import pytest
-from h3ronpy import DEFAULT_CELL_COLUMN_NAME, H3_CRS
-from h3ronpy.raster import raster_to_dataframe, rasterize_cells
+from h3ronpy import DEFAULT_CELL_COLUMN_NAME, H3_CRS, change_resolution, has_raster_support
+from h3ronpy.raster import (
+    CATEGORIES_METADATA_KEY,
+    COLORS_METADATA_KEY,
+    Transform,
+    bin_points_weighted_by_raster,
+    cells_to_image,
+    convert_partition,
+    convert_raster,
+    normalize_band,
+    raster_to_dataframe,
+    rasterize_cells,
+    read_geotiff,
+)
 
 from tests import TESTDATA_PATH
 
 
+def test_has_raster_support():
+    # the raster module could be imported
+    assert has_raster_support()
+
+
 @pytest.mark.skipif(not HAS_RASTERIO, reason="requires rasterio")
 def test_r_tiff():
     dataset = rasterio.open(TESTDATA_PATH / "r.tiff")
@@ -36,6 +55,195 @@ def test_r_tiff_float32():
     assert df["value"].type == pa.float32()
 
 
+@pytest.mark.skipif(not HAS_RASTERIO, reason="requires rasterio")
+def test_r_tiff_cast_to_float32():
+    dataset = rasterio.open(TESTDATA_PATH / "r.tiff")
+    band = dataset.read(1).astype(np.float64)
+    band[0, 0] = 0.1  # not representable as float32
+    df = raster_to_dataframe(band, dataset.transform, 8, nodata_value=0.1, compact=True, value_dtype=np.float32)
+    assert len(df) > 100
+    assert df["value"].type == pa.float32()
+
+    rasterized, _ = rasterize_cells(df[DEFAULT_CELL_COLUMN_NAME], df["value"], 100)
+    assert rasterized.dtype == np.float32
+
+
+@pytest.mark.skipif(not HAS_RASTERIO, reason="requires rasterio")
+def test_convert_raster():
+    dataset = rasterio.open(TESTDATA_PATH / "r.tiff")
+    band = dataset.read(1)
+    converted = convert_raster(band, dataset.transform, 8, nodata_value=0, compact=True)
+    assert converted.n_cells > 100
+    assert len(converted) == converted.n_cells
+    assert converted.resolutions[-1] == 8
+    assert converted.resolutions == sorted(converted.resolutions)
+    assert pa.schema(converted.attribute_schema).field("value").type == pa.uint8()
+
+    table = pa.table(converted.to_arrow())
+    assert table.num_rows == converted.n_cells
+    assert table.column_names == ["value", DEFAULT_CELL_COLUMN_NAME]
+
+
+def test_convert_partition():
+    band, transform, _ = read_geotiff(TESTDATA_PATH / "r.tiff")
+    full = pa.table(convert_raster(band, transform, 8, nodata_value=0, compact=False).to_arrow())
+    parents = np.unique(change_resolution(full[DEFAULT_CELL_COLUMN_NAME], 4).to_numpy())
+    assert len(parents) > 1
+
+    partitions = [convert_partition(TESTDATA_PATH / "r.tiff", parent, 8, nodata_value=0) for parent in parents]
+    cells = np.concatenate([p[DEFAULT_CELL_COLUMN_NAME].to_numpy() for p in partitions])
+    # every cell is returned by exactly one partition
+    assert len(cells) == len(np.unique(cells))
+    assert set(cells.tolist()) == set(full[DEFAULT_CELL_COLUMN_NAME].to_numpy().tolist())
+
+    with pytest.raises(ValueError):
+        convert_partition(TESTDATA_PATH / "r.tiff", parents[0], 3)
+
+
+def test_bin_points_weighted_by_raster():
+    band, transform, _ = read_geotiff(TESTDATA_PATH / "r.tiff")
+    a, _, c, _, e, f = transform.to_rasterio()
+    rows, cols = np.nonzero(band)
+    rows, cols = rows[:100], cols[:100]
+    lat = f + (rows + 0.5) * e
+    lng = c + (cols + 0.5) * a
+    # a point outside of the raster is skipped
+    lat = np.append(lat, f - band.shape[0] * e + 1.0)
+    lng = np.append(lng, c)
+
+    binned = pa.record_batch(bin_points_weighted_by_raster(lat, lng, 7, TESTDATA_PATH / "r.tiff", nodata_value=0))
+    assert binned.column("count").to_numpy().sum() == len(rows)
+    assert binned.column("weight").to_numpy().sum() == pytest.approx(band[rows, cols].astype(np.float64).sum())
+    assert len(np.unique(binned.column(DEFAULT_CELL_COLUMN_NAME).to_numpy())) == binned.num_rows
+
+
+@pytest.mark.skipif(not HAS_RASTERIO, reason="requires rasterio")
+def test_bin_points_weighted_by_raster_float32(tmp_path):
+    from rasterio.transform import from_origin
+
+    band = np.full((10, 10), 2.5, dtype=np.float32)
+    band[:, 5:] = -9999.0
+    path = tmp_path / "weights.tiff"
+    with rasterio.open(
+        path,
+        "w",
+        driver="GTiff",
+        width=10,
+        height=10,
+        count=1,
+        dtype="float32",
+        crs="EPSG:4326",
+        transform=from_origin(10.0, 50.0, 0.01, 0.01),
+        nodata=-9999.0,
+    ) as dataset:
+        dataset.write(band, 1)
+
+    # one point per pixel, the right half of the pixels is nodata
+    rows, cols = np.indices(band.shape)
+    lat = 50.0 - (rows.ravel() + 0.5) * 0.01
+    lng = 10.0 + (cols.ravel() + 0.5) * 0.01
+
+    binned = pa.record_batch(bin_points_weighted_by_raster(lat, lng, 5, path))
+    assert binned.column("weight").type == pa.float32()
+    assert binned.column("count").to_numpy().sum() == 50
+    assert binned.column("weight").to_numpy().sum() == pytest.approx(50 * 2.5)
+
+
+def test_convert_raster_coverage_mask():
+    band = np.ones((100, 100), dtype=np.uint8)
+    band[:, :50] = 0
+    transform = Transform.from_gdal([10.0, 0.001, 0.0, 50.0, 0.0, -0.001])
+
+    converted = convert_raster(band, transform, 9, nodata_value=0, compact=True, coverage_mask=True)
+    mask = converted.coverage_mask
+    assert mask.shape == band.shape
+    assert mask.dtype == np.uint8
+    assert mask[:, :50].sum() == 0
+    assert mask[:, 50:].sum() > 0
+
+    assert convert_raster(band, transform, 9, nodata_value=0).coverage_mask is None
+
+
+def test_convert_raster_categories():
+    band = np.full((100, 100), 1, dtype=np.uint8)
+    band[:, 50:] = 2
+    transform = Transform.from_gdal([10.0, 0.001, 0.0, 50.0, 0.0, -0.001])
+
+    converted = convert_raster(band, transform, 9, compact=False, categories={1: "forest", 2: "water"})
+    table = pa.table(converted.to_arrow())
+    assert pa.types.is_dictionary(table["value"].type)
+    assert set(table["value"].to_pylist()) == {"forest", "water"}
+
+    with pytest.raises(ValueError):
+        convert_raster(band, transform, 9, categories={1: "forest"})
+
+
+def test_convert_raster_category_metadata():
+    band = np.full((100, 100), 1, dtype=np.uint8)
+    transform = Transform.from_gdal([10.0, 0.001, 0.0, 50.0, 0.0, -0.001])
+
+    converted = convert_raster(band, transform, 9, category_metadata={1: "forest"}, colors={1: (0, 128, 0, 255)})
+    field = pa.schema(converted.attribute_schema).field("value")
+    assert field.type == pa.uint8()
+    assert json.loads(field.metadata[CATEGORIES_METADATA_KEY.encode()]) == {"1": "forest"}
+    assert json.loads(field.metadata[COLORS_METADATA_KEY.encode()]) == {"1": "#008000ff"}
+
+
+def test_normalize_band():
+    band = np.arange(100, dtype=np.uint16).reshape((10, 10))
+    band[0, 0] = 9999
+
+    clipped = normalize_band(band, "percentile_clip", nodata_value=9999, percentiles=(0.0, 50.0))
+    assert clipped.dtype == np.float64
+    assert np.isnan(clipped[0, 0])
+    assert clipped[0, 1] == 0.0
+    assert clipped[9, 9] == 1.0
+
+    standardized = normalize_band(band, "standardize", nodata_value=9999, tile_size=5)
+    assert abs(np.nanmean(standardized[:5, :5])) < 1e-9
+    assert abs(np.nanmean(standardized[5:, 5:])) < 1e-9
+
+    assert np.isclose(normalize_band(band, "log")[0, 1], np.log(2.0))
+    with pytest.raises(ValueError):
+        normalize_band(band, "unknown")
+
+
+def test_normalize_band_float32():
+    band = np.arange(100, dtype=np.float32).reshape(10, 10)
+    for method in ("percentile_clip", "log", "standardize"):
+        normalized = normalize_band(band, method)
+        assert normalized.dtype == np.float32
+        np.testing.assert_allclose(normalized, normalize_band(band.astype(np.float64), method), rtol=1e-5, atol=1e-6)
+
+
+@pytest.mark.skipif(not HAS_RASTERIO, reason="requires rasterio")
+def test_converted_raster_write_to_sql(tmp_path):
+    import sqlite3
+
+    from h3ronpy.sql import sqlite_uri
+
+    dataset = rasterio.open(TESTDATA_PATH / "r.tiff")
+    band = dataset.read(1)
+    converted = convert_raster(band, dataset.transform, 8, nodata_value=0, compact=True)
+
+    path = tmp_path / "cells.db"
+    converted.write_to_sql(sqlite_uri(path), "cells", batch_size=1000)
+    with pytest.raises(ValueError):
+        converted.write_to_sql(sqlite_uri(path), "cells")
+    converted.write_to_sql(sqlite_uri(path), "cells", if_exists="append")
+
+    table = pa.table(converted.to_arrow())
+    with sqlite3.connect(path) as connection:
+        (count, distinct_cells) = connection.execute(
+            f"SELECT count(*), count(DISTINCT {DEFAULT_CELL_COLUMN_NAME}) FROM cells"
+        ).fetchone()
+        assert count == 2 * converted.n_cells
+        assert distinct_cells == converted.n_cells
+        (cell, value) = connection.execute(f"SELECT {DEFAULT_CELL_COLUMN_NAME}, value FROM cells LIMIT 1").fetchone()
+        assert cell == table[DEFAULT_CELL_COLUMN_NAME][0].as_py()
+        assert value == table["value"][0].as_py()
+
+
 def write_gtiff(filename, array, transform, nodata_value):
     with rasterio.open(
         filename,
@@ -84,3 +292,38 @@ def test_rasterize_cells_auto_aspect():
     # for inspection during debugging
     if False:
         write_gtiff("/tmp/rasterized_auto_aspect.tif", array, transform, nodata_value)
+
+
+def test_cells_to_image():
+    df = pl.read_parquet(TESTDATA_PATH / "population-841fa8bffffffff.parquet")
+    image, transform = cells_to_image(df["h3index"], df["pop_general"], None, 200, 150)
+
+    assert image.shape == (150, 200)
+    assert image.dtype == np.float64
+    assert np.any(np.isnan(image))
+    assert np.nanmax(image) <= df["pop_general"].max()
+    assert np.count_nonzero(~np.isnan(image)) > 0.5 * image.size
+
+
+def test_read_geotiff():
+    band, transform, nodata = read_geotiff(TESTDATA_PATH / "r.tiff")
+    assert band.ndim == 2
+    assert band.dtype == np.uint8
+
+    df = raster_to_dataframe(band, transform, 8, nodata_value=0, compact=True)
+    assert len(df) > 100
+
+
+@pytest.mark.skipif(not HAS_RASTERIO, reason="requires rasterio")
+def test_read_geotiff_matches_rasterio():
+    band, transform, nodata = read_geotiff(TESTDATA_PATH / "r.tiff")
+    dataset = rasterio.open(TESTDATA_PATH / "r.tiff")
+    np.testing.assert_array_equal(band, dataset.read(1))
+    assert nodata == dataset.nodata
+    t = dataset.transform
+    assert transform == Transform.from_rasterio([t.a, t.b, t.c, t.d, t.e, t.f])
+
+
+def test_read_geotiff_invalid_band():
+    with pytest.raises(ValueError):
+        read_geotiff(TESTDATA_PATH / "r.tiff", band=5)
@@ -2,7 +2,19 @@ import math
 
 import h3.api.numpy_int as h3
 import numpy as np
-from h3ronpy import cells_resolution, change_resolution, change_resolution_paired
+import pyarrow as pa
+import pytest
+from h3ronpy import (
+    assume_valid,
+    cells_resolution,
+    cells_to_center_child,
+    cells_to_children_size,
+    change_resolution,
+    change_resolution_paired,
+    dasymetric_resample,
+    filter_by_resolution,
+    split_by_resolution,
+)
 
 
 def test_change_resolution_up():
@@ -44,3 +56,63 @@ def test_cells_resolution():
     assert len(res) == 2
     assert res[0] == 5
     assert res[1] == 8
+
+
+def test_filter_and_split_by_resolution():
+    h3indexes = np.array(
+        [
+            h3.geo_to_h3(10.2, 45.5, 5),
+            h3.geo_to_h3(10.3, 45.1, 8),
+            h3.geo_to_h3(10.1, 45.2, 5),
+        ],
+        dtype=np.uint64,
+    )
+    filtered = filter_by_resolution(h3indexes, [5, 6])
+    assert len(filtered) == 2
+    assert cells_resolution(filtered).to_numpy().tolist() == [5, 5]
+
+    split = split_by_resolution(h3indexes)
+    assert sorted(split.keys()) == [5, 8]
+    assert split[5].to_numpy().tolist() == [h3indexes[0], h3indexes[2]]
+    assert split[8].to_numpy().tolist() == [h3indexes[1]]
+
+
+def test_assume_valid():
+    h3indexes = np.array([h3.geo_to_h3(10.2, 45.5, 5), h3.geo_to_h3(10.3, 45.1, 8)], dtype=np.uint64)
+    expected = change_resolution(h3indexes, 4).to_numpy()
+
+    np.testing.assert_array_equal(change_resolution(h3indexes, 4, assume_valid=True).to_numpy(), expected)
+    with assume_valid():
+        np.testing.assert_array_equal(change_resolution(h3indexes, 4).to_numpy(), expected)
+
+    # validation is active again after leaving the context
+    with pytest.raises(ValueError):
+        cells_resolution(np.array([1], dtype=np.uint64))
+
+    # zero values are rejected in any case
+    with pytest.raises(ValueError):
+        cells_resolution(np.array([0], dtype=np.uint64), assume_valid=True)
+
+
+def test_dasymetric_resample():
+    parent = h3.geo_to_h3(10.2, 45.5, 5)
+    children = np.array(sorted(h3.h3_to_children(parent, 6)), dtype=np.uint64)
+    values = pa.table({"cell": np.array([parent], dtype=np.uint64), "value": [100.0]})
+    weights = pa.table({"cell": children[:2], "weight": [3.0, 1.0]})
+
+    resampled = pa.table(dasymetric_resample(values, weights, 6))
+    assert resampled.num_rows == 7
+    by_cell = dict(zip(resampled["cell"].to_pylist(), resampled["value"].to_pylist()))
+    assert by_cell[int(children[0])] == pytest.approx(75.0)
+    assert by_cell[int(children[1])] == pytest.approx(25.0)
+    assert sum(by_cell.values()) == pytest.approx(100.0)
+
+
+def test_center_child_and_children_size():
+    cells = np.array([h3.geo_to_h3(10.3, 45.1, 5), h3.geo_to_h3(10.3, 45.1, 9)], dtype=np.uint64)
+
+    center_children = pa.array(cells_to_center_child(cells, 7))
+    assert center_children.to_pylist() == [h3.h3_to_center_child(int(cells[0]), 7), None]
+
+    sizes = pa.array(cells_to_children_size(cells, 7))
+    assert sizes.to_pylist() == [49, 0]
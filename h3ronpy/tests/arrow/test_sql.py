@@ -0,0 +1,66 @@
+import sqlite3
+
+import h3.api.numpy_int as h3
+import pyarrow as pa
+import pytest
+from h3ronpy import DEFAULT_CELL_COLUMN_NAME
+from h3ronpy.sql import read_sql, sqlite_uri, write_batches_to_sql
+
+
+def _cells():
+    return [int(h3.geo_to_h3(10.0 + i, 45.0, 7)) for i in range(5)]
+
+
+def test_sqlite_roundtrip(tmp_path):
+    table = pa.table(
+        {
+            "value": pa.array([1.0, 2.0, 3.0, 4.0, 5.0]),
+            DEFAULT_CELL_COLUMN_NAME: pa.array(_cells(), type=pa.uint64()),
+        }
+    )
+    uri = sqlite_uri(tmp_path / "cells.db")
+    write_batches_to_sql(table.to_batches(max_chunksize=2), table.schema, uri, "cells")
+
+    result = pa.table(read_sql("SELECT * FROM cells", uri, batch_size=2))
+    assert result[DEFAULT_CELL_COLUMN_NAME].type == pa.uint64()
+    assert result[DEFAULT_CELL_COLUMN_NAME].to_pylist() == _cells()
+    assert result["value"].to_pylist() == [1.0, 2.0, 3.0, 4.0, 5.0]
+
+    with pytest.raises(ValueError):
+        write_batches_to_sql(table.to_batches(), table.schema, uri, "cells")
+    write_batches_to_sql(table.to_batches(), table.schema, uri, "cells", if_exists="append")
+    assert pa.table(read_sql("SELECT * FROM cells", uri)).num_rows == 10
+    write_batches_to_sql(table.to_batches(), table.schema, uri, "cells", if_exists="replace")
+    assert pa.table(read_sql("SELECT * FROM cells", uri)).num_rows == 5
+
+
+def test_read_sql_streams_batches(tmp_path):
+    table = pa.table({DEFAULT_CELL_COLUMN_NAME: pa.array(_cells(), type=pa.uint64())})
+    uri = sqlite_uri(tmp_path / "cells.db")
+    write_batches_to_sql(table.to_batches(), table.schema, uri, "cells")
+
+    reader = pa.RecordBatchReader.from_stream(read_sql("SELECT * FROM cells", uri, batch_size=2))
+    assert [batch.num_rows for batch in reader] == [2, 2, 1]
+
+
+def test_read_sql_strings_and_invalid(tmp_path):
+    path = tmp_path / "cells.db"
+    with sqlite3.connect(path) as connection:
+        connection.execute("CREATE TABLE cells (h3 TEXT)")
+        connection.executemany("INSERT INTO cells VALUES (?)", [(h3.h3_to_string(c),) for c in _cells()] + [("x",)])
+
+    with pytest.raises(ValueError):
+        read_sql("SELECT h3 FROM cells", sqlite_uri(path), cell_column="h3")
+
+    result = pa.table(read_sql("SELECT h3 FROM cells", sqlite_uri(path), cell_column="h3", set_failing_to_invalid=True))
+    assert result["h3"].to_pylist() == _cells() + [None]
+
+
+def test_read_sql_empty(tmp_path):
+    path = tmp_path / "cells.db"
+    with sqlite3.connect(path) as connection:
+        connection.execute(f"CREATE TABLE cells ({DEFAULT_CELL_COLUMN_NAME} INTEGER)")
+
+    result = pa.table(read_sql("SELECT * FROM cells", sqlite_uri(path)))
+    assert result.num_rows == 0
+    assert result[DEFAULT_CELL_COLUMN_NAME].type == pa.uint64()
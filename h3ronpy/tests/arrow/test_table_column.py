@@ -0,0 +1,41 @@
+import pyarrow as pa
+import pytest
+from h3ronpy import cells_area_km2, cells_to_string, change_resolution
+
+CELLS = [0x8828308281FFFFF, 0x882830828DFFFFF, 0x8828308283FFFFF]
+
+
+def test_replace_column():
+    table = pa.Table.from_batches(
+        [
+            pa.record_batch({"cell": pa.array(CELLS[:2], type=pa.uint64()), "value": [1, 2]}),
+            pa.record_batch({"cell": pa.array(CELLS[2:], type=pa.uint64()), "value": [3]}),
+        ]
+    )
+    out = pa.table(cells_to_string(table, column="cell"))
+    assert out.column_names == ["cell", "value"]
+    assert out["cell"].to_pylist() == [f"{cell:x}" for cell in CELLS]
+    assert out["value"].to_pylist() == [1, 2, 3]
+    # the chunking is preserved
+    assert out["cell"].num_chunks == 2
+
+
+def test_append_column():
+    table = pa.table({"cell": pa.array(CELLS, type=pa.uint64())})
+    out = pa.table(change_resolution(table, 5, column="cell", output_column="parent"))
+    assert out.column_names == ["cell", "parent"]
+    assert out["parent"].to_pylist() == pa.array(change_resolution(table["cell"], 5)).to_pylist()
+
+    out = pa.table(cells_area_km2(out, column="cell", output_column="area_km2"))
+    assert out.column_names == ["cell", "parent", "area_km2"]
+
+
+def test_invalid_column():
+    table = pa.table({"cell": pa.array(CELLS, type=pa.uint64())})
+    with pytest.raises(ValueError):
+        cells_to_string(table, column="missing")
+    with pytest.raises(ValueError):
+        # increasing the resolution changes the number of rows
+        change_resolution(table, 10, column="cell")
+    with pytest.raises(ValueError):
+        cells_to_string(table["cell"], output_column="string")
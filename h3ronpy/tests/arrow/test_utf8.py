@@ -40,6 +40,18 @@ def test_parse_cell_set_invalid():
     assert not cells[0].is_valid
 
 
+def test_parse_cell_errors_table():
+    strings = pa.array(["invalid", "801ffffffffffff", None, "10.2, 45.5, 5"])
+    cells, errors = cells_parse(strings, errors="table")
+    assert len(cells) == 4
+    assert pa.array(cells).is_valid().to_pylist() == [False, True, False, True]
+
+    errors = pa.table(errors)
+    assert errors["row"].to_pylist() == [0]
+    assert errors["value"].to_pylist() == ["invalid"]
+    assert errors["reason"].to_pylist() == ["non-parsable CellIndex"]
+
+
 def test_cells_valid():
     input = np.array(
         [45, h3.geo_to_h3(45.5, 10.2, 5)],
@@ -60,6 +72,21 @@ def test_cells_valid():
     assert pa.array(cells).is_valid() == pa.array(bools)
 
 
+def test_cells_valid_errors_table():
+    input = np.array(
+        [45, h3.geo_to_h3(45.5, 10.2, 5)],
+        dtype=np.uint64,
+    )
+    cells, errors = cells_valid(input, errors="table")
+    assert not cells[0].is_valid
+    assert cells[1].is_valid
+
+    errors = pa.table(errors)
+    assert errors["row"].to_pylist() == [0]
+    assert errors["value"].to_pylist() == [45]
+    assert errors.num_rows == 1
+
+
 def test_cells_to_string():
     cells = np.array(
         [
@@ -71,3 +98,21 @@ def test_cells_to_string():
     assert isinstance(strings, Array)
     assert strings.type == pa.large_utf8()
     assert strings[0] == "851f9923fffffff"
+
+
+def test_cells_to_string_formats():
+    cell = h3.geo_to_h3(45.5, 10.2, 5)
+    cells = np.array([cell], dtype=np.uint64)
+    canonical = h3.h3_to_string(cell)
+
+    uppercase = cells_to_string(cells, uppercase=True)[0].as_py()
+    assert uppercase == canonical.upper()
+
+    short = cells_to_string(cells, short=True)[0].as_py()
+    assert short == canonical.rstrip("f")
+
+    padded = cells_to_string(cells, zero_pad=True)[0].as_py()
+    assert padded == "0" + canonical
+
+    parsed = cells_parse(np.array([uppercase, short, padded, "0x" + canonical]))
+    assert parsed.to_numpy().tolist() == [cell] * 4
@@ -1,7 +1,32 @@
+import datetime
+import decimal
+
 import h3.api.numpy_int as h3
+import numpy as np
+import pyarrow as pa
+import pytest
 import shapely
 from arro3.core import Array, DataType, Scalar
-from h3ronpy.vector import ContainmentMode, cells_to_wkb_points, geometry_to_cells
+from h3ronpy.vector import (
+    ContainmentMode,
+    cells_to_wkb_points,
+    cells_to_wkb_polygons,
+    geodesic_rect_to_cells,
+    geometries_to_cells_with_attributes,
+    geometries_to_cells_with_ids,
+    geometry_to_cells,
+    has_proj_support,
+    heatmap_from_geoparquet,
+    partition_bbox,
+    partition_globe,
+    random_points_in_cells,
+    read_csv_to_cells,
+    representative_point_in_intersection,
+    sector_to_cells,
+    tessellate_exclusive,
+    wkb_mean_z,
+    wkb_to_cells,
+)
 from shapely import wkb
 from shapely.geometry import Point
 
@@ -14,6 +39,49 @@ def test_geometry_to_cells():
     assert len(cells) > 10
 
 
+def test_geometry_to_cells_simplify_tolerance():
+    # a square with a noisy southern edge
+    noisy_edge = [(i / 100.0, 0.00001 if i % 2 == 0 else -0.00001) for i in range(1, 100)]
+    geom = shapely.Polygon([(0.0, 0.0)] + noisy_edge + [(1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)])
+
+    cells = set(geometry_to_cells(geom, 6).to_numpy().tolist())
+    simplified_cells = set(geometry_to_cells(geom, 6, simplify_tolerance_m=10.0).to_numpy().tolist())
+    assert len(cells.symmetric_difference(simplified_cells)) < 10
+
+    with pytest.raises(ValueError):
+        geometry_to_cells(geom, 6, simplify_tolerance_m=-1.0)
+
+
+def test_sector_and_geodesic_rect_to_cells():
+    circle = sector_to_cells((10.0, 45.0), 2000.0, 0.0, 360.0, 10)
+    assert circle.type == DataType.uint64()
+    quarter = sector_to_cells(Point(10.0, 45.0), 2000.0, 315.0, 45.0, 10)
+    assert len(circle) / 5 < len(quarter) < len(circle) / 3
+    assert set(quarter.to_numpy().tolist()) <= set(circle.to_numpy().tolist())
+
+    rect = geodesic_rect_to_cells((10.0, 45.0), 1000.0, 4000.0, 30.0, 10)
+    assert abs(len(rect) / len(circle) - 4.0 / (3.14159 * 4.0)) < 0.05
+
+    with pytest.raises(ValueError):
+        sector_to_cells((10.0, 45.0), -1.0, 0.0, 90.0, 10)
+
+
+def test_partition_globe_and_bbox():
+    globe = partition_globe(1)
+    assert globe.num_rows == 842
+    assert globe.schema.names == ["cell", "minx", "miny", "maxx", "maxy"]
+
+    # crossing the antimeridian
+    parts = partition_bbox((170.0, -20.0, -170.0, -10.0), 3)
+    assert parts.num_rows > 0
+    minx = parts.column("minx").to_numpy()
+    maxx = parts.column("maxx").to_numpy()
+    assert ((minx >= 160.0) | (maxx <= -160.0) | (minx > maxx)).all()
+
+    with pytest.raises(ValueError):
+        partition_bbox((0.0, 10.0, 1.0, 0.0), 3)
+
+
 def test_geometry_to_cells_central_park():
     # Manhattan Central Park
     point = Point(-73.9575, 40.7938)
@@ -43,3 +111,296 @@ def test_coordinate_values_are_not_equal_issue_58():
         shapely_point = wkb.loads(wkb_point.as_py())
         assert int(lat) == int(shapely_point.y)
         assert int(lon) == int(shapely_point.x)
+
+
+def test_random_points_in_cells():
+    cells = np.array([h3.geo_to_h3(10.2, 45.5, 5), h3.geo_to_h3(89.9, 0.0, 2)], dtype=np.uint64)
+    points = random_points_in_cells(cells, 20, seed=42)
+    assert points.num_rows == 40
+
+    lats = points["lat"].to_numpy()
+    lngs = points["lng"].to_numpy()
+    for i, cell in enumerate(points["cell"].to_numpy()):
+        assert h3.geo_to_h3(lats[i], lngs[i], h3.h3_get_resolution(cell)) == cell
+
+    # seeded generation is reproducible
+    again = random_points_in_cells(cells, 20, seed=42)
+    assert (again["lat"].to_numpy() == lats).all()
+
+
+def test_representative_point_in_intersection():
+    geom = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)))
+    cells = geometry_to_cells(geom, 6, containment_mode=ContainmentMode.IntersectsBoundary)
+    points = representative_point_in_intersection(cells, geom)
+    assert len(points) == len(cells)
+    n_valid = 0
+    for i in range(len(points)):
+        value = points[i].as_py()
+        if value is None:
+            continue
+        assert geom.covers(wkb.loads(value))
+        n_valid += 1
+    assert n_valid > 10
+
+
+def test_geometries_to_cells_with_ids():
+    square = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)))
+    multi = shapely.MultiPolygon(
+        [
+            shapely.Polygon(
+                ((10.0, 10.0), (10.0, 11.0), (11.0, 11.0), (11.0, 10.0), (10.0, 10.0)),
+                holes=[((10.4, 10.4), (10.4, 10.6), (10.6, 10.6), (10.6, 10.4), (10.4, 10.4))],
+            ),
+            shapely.Polygon(((20.0, 20.0), (20.0, 21.0), (21.0, 21.0), (21.0, 20.0), (20.0, 20.0))),
+        ]
+    )
+    wkbs = Array([shapely.to_wkb(square), shapely.to_wkb(multi)], type=DataType.binary())
+    ids = Array([7, 9], type=DataType.int32())
+
+    table = geometries_to_cells_with_ids(wkbs, ids, 6)
+    assert table["id"].type == DataType.int32()
+
+    table_ids = table["id"].to_numpy()
+    table_cells = table["cell"].to_numpy()
+    assert len(table_ids) == len(geometry_to_cells(square, 6)) + len(geometry_to_cells(multi, 6))
+    assert set(table_ids.tolist()) == {7, 9}
+
+    hole_cell = h3.geo_to_h3(10.5, 10.5, 6)
+    assert hole_cell not in set(table_cells[table_ids == 9].tolist())
+
+
+def test_geometries_to_cells_with_attributes():
+    square = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)))
+    wkbs = Array([shapely.to_wkb(square), None], type=DataType.binary())
+    attributes = pa.table(
+        {
+            "price": pa.array([decimal.Decimal("1.23"), decimal.Decimal("4.56")], type=pa.decimal128(5, 2)),
+            "ts": pa.array([datetime.datetime(2024, 1, 1), None], type=pa.timestamp("ms")),
+            "duration": pa.array([datetime.timedelta(seconds=3), None], type=pa.duration("s")),
+            "tags": pa.array([["a", "b"], []], type=pa.list_(pa.string())),
+        }
+    )
+
+    table = pa.table(geometries_to_cells_with_attributes(wkbs, attributes, 6))
+    assert table.schema.names == ["price", "ts", "duration", "tags", "cell"]
+    assert table.num_rows == len(geometry_to_cells(square, 6))
+    for name in ("price", "ts", "duration", "tags"):
+        assert table.schema.field(name).type == attributes.schema.field(name).type
+    assert set(table["price"].to_pylist()) == {decimal.Decimal("1.23")}
+    assert set(tuple(tags) for tags in table["tags"].to_pylist()) == {("a", "b")}
+
+
+def test_geometries_to_cells_with_ids_attributes():
+    square = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)))
+    wkbs = Array([shapely.to_wkb(square), None], type=DataType.binary())
+    attributes = pa.table(
+        {
+            "price": pa.array([decimal.Decimal("1.23"), decimal.Decimal("4.56")], type=pa.decimal128(5, 2)),
+            "ts": pa.array([datetime.datetime(2024, 1, 1), None], type=pa.timestamp("ms")),
+        }
+    )
+
+    table = pa.table(geometries_to_cells_with_ids(wkbs, pa.array([7, 9]), 6, attributes=attributes))
+    assert table.schema.names == ["id", "price", "ts", "cell"]
+    assert table.num_rows == len(geometry_to_cells(square, 6))
+    assert table.schema.field("price").type == pa.decimal128(5, 2)
+    assert set(table["id"].to_pylist()) == {7}
+    assert set(table["ts"].to_pylist()) == {datetime.datetime(2024, 1, 1)}
+
+    with pytest.raises(ValueError):
+        geometries_to_cells_with_ids(wkbs, pa.array([7, 9]), 6, overlap="merge", attributes=attributes)
+    with pytest.raises(ValueError):
+        geometries_to_cells_with_ids(wkbs, pa.array([7, 9]), 6, attributes=pa.table({"id": [1, 2]}))
+
+
+def _overlapping_squares():
+    a = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)))
+    b = shapely.Polygon(((0.5, 0.0), (0.5, 1.0), (1.5, 1.0), (1.5, 0.0), (0.5, 0.0)))
+    return Array([shapely.to_wkb(a), shapely.to_wkb(b)], type=DataType.binary()), Array([1, 2], type=DataType.int64())
+
+
+def test_geometries_to_cells_with_ids_overlap_strategies():
+    wkbs, ids = _overlapping_squares()
+
+    keep_all = geometries_to_cells_with_ids(wkbs, ids, 6, containment_mode=ContainmentMode.IntersectsBoundary)
+    n_distinct = len(set(keep_all["cell"].to_numpy().tolist()))
+    assert keep_all.num_rows > n_distinct
+
+    for strategy in ("largest_intersection", "merge"):
+        table = geometries_to_cells_with_ids(
+            wkbs, ids, 6, containment_mode=ContainmentMode.IntersectsBoundary, overlap=strategy
+        )
+        assert table.num_rows == n_distinct
+
+    priority = geometries_to_cells_with_ids(
+        wkbs,
+        ids,
+        6,
+        containment_mode=ContainmentMode.IntersectsBoundary,
+        overlap="priority",
+        priority_arr=Array([1.0, 5.0], type=DataType.float64()),
+    )
+    assert priority.num_rows == n_distinct
+    cell_in_overlap = h3.geo_to_h3(0.5, 0.75, 6)
+    cells = priority["cell"].to_numpy().tolist()
+    assert priority["id"].to_numpy()[cells.index(cell_in_overlap)] == 2
+
+    with pytest.raises(ValueError):
+        geometries_to_cells_with_ids(wkbs, ids, 6, overlap="priority")
+
+
+def test_tessellate_exclusive():
+    wkbs, ids = _overlapping_squares()
+    table = tessellate_exclusive(wkbs, ids, 6)
+    cells = table["cell"].to_numpy().tolist()
+    assert len(cells) == len(set(cells))
+
+    # no gaps: all cells having their centroid within any of the polygons are assigned
+    for i in range(len(wkbs)):
+        for cell in geometry_to_cells(shapely.from_wkb(wkbs[i].as_py()), 6).to_numpy().tolist():
+            assert cell in cells
+
+
+def test_read_csv_to_cells(tmp_path):
+    path = tmp_path / "points.tsv"
+    path.write_text("name\tlat\tlng\tvalue\na\t10.2\t45.5\t1\nb\t\t\t2\nc\t-12\t30\t3\n")
+
+    table = read_csv_to_cells(path, "lat", "lng", 7, columns=["name", "value"], delimiter="\t")
+    assert table.schema.names == ["name", "value", "cell"]
+    assert table.num_rows == 3
+
+    cells = pa.table(table)["cell"].to_pylist()
+    assert cells == [h3.geo_to_h3(10.2, 45.5, 7), None, h3.geo_to_h3(-12.0, 30.0, 7)]
+
+
+def test_heatmap_from_geoparquet(tmp_path):
+    import json
+
+    import pyarrow.parquet as pq
+
+    coords = [(45.5, 10.2), (45.5, 10.2), (30.0, -12.0), (-100.0, 40.0)]
+    points = [Point(x, y) for x, y in coords]
+    table = pa.table(
+        {
+            "geometry": pa.array([p.wkb for p in points], type=pa.binary()),
+            "bbox": pa.array(
+                [{"xmin": x, "ymin": y, "xmax": x, "ymax": y} for x, y in coords],
+            ),
+            "value": pa.array([1, 2, 3, 4], type=pa.int32()),
+        }
+    )
+    geo = {
+        "version": "1.1.0",
+        "primary_column": "geometry",
+        "columns": {
+            "geometry": {
+                "encoding": "WKB",
+                "geometry_types": ["Point"],
+                "covering": {
+                    "bbox": {
+                        "xmin": ["bbox", "xmin"],
+                        "ymin": ["bbox", "ymin"],
+                        "xmax": ["bbox", "xmax"],
+                        "ymax": ["bbox", "ymax"],
+                    }
+                },
+            }
+        },
+    }
+    path = tmp_path / "points.parquet"
+    pq.write_table(table.replace_schema_metadata({"geo": json.dumps(geo)}), path, row_group_size=2)
+
+    heatmap = pa.table(heatmap_from_geoparquet(path, 7, value="value"))
+    assert heatmap.schema.names == ["cell", "count", "value"]
+    result = {row["cell"]: (row["count"], row["value"]) for row in heatmap.to_pylist()}
+    assert result == {
+        h3.geo_to_h3(10.2, 45.5, 7): (2, 3.0),
+        h3.geo_to_h3(-12.0, 30.0, 7): (1, 3.0),
+        h3.geo_to_h3(40.0, -100.0, 7): (1, 4.0),
+    }
+
+    heatmap = pa.table(heatmap_from_geoparquet(path, 7, bbox=(0.0, -20.0, 40.0, 20.0)))
+    assert heatmap.schema.names == ["cell", "count"]
+    assert heatmap.to_pylist() == [{"cell": h3.geo_to_h3(-12.0, 30.0, 7), "count": 1}]
+
+
+def test_wkb_to_cells_errors_table():
+    wkbs = pa.array([Point(10.0, 45.0).wkb, b"not wkb", None], type=pa.binary())
+    cells, errors = wkb_to_cells(wkbs, 7, errors="table")
+    cells = pa.array(cells)
+    assert len(cells) == 3
+    assert cells.is_valid().to_pylist() == [True, False, False]
+    assert len(cells[0].as_py()) == 1
+
+    errors = pa.table(errors)
+    assert errors["row"].to_pylist() == [1]
+    assert errors["value"].to_pylist() == [b"not wkb"]
+    assert errors["reason"][0].as_py() != ""
+
+
+def _polygon_with_degenerate_hole_wkb() -> bytes:
+    import struct
+
+    def ring(coords):
+        return struct.pack("<I", len(coords)) + b"".join(struct.pack("<dd", *c) for c in coords)
+
+    exterior = [(10.0, 45.0), (10.2, 45.0), (10.2, 45.2), (10.0, 45.2), (10.0, 45.0)]
+    # a hole with too few coordinates
+    interior = [(10.1, 45.1), (10.15, 45.1), (10.1, 45.1)]
+    return struct.pack("<BII", 1, 3, 2) + ring(exterior) + ring(interior)
+
+
+def test_wkb_to_cells_on_invalid():
+    wkbs = pa.array([Point(10.0, 45.0).wkb, _polygon_with_degenerate_hole_wkb()], type=pa.binary())
+    with pytest.raises(ValueError):
+        wkb_to_cells(wkbs, 7)
+
+    cells, report = wkb_to_cells(wkbs, 7, on_invalid="skip")
+    assert pa.array(cells).is_valid().to_pylist() == [True, False]
+    assert pa.table(report)["row"].to_pylist() == [1]
+
+    cells, report = wkb_to_cells(wkbs, 7, on_invalid="repair")
+    cells = pa.array(cells)
+    assert cells.is_valid().to_pylist() == [True, True]
+    assert len(cells[1].as_py()) > 1
+    report = pa.table(report)
+    assert report["row"].to_pylist() == [1]
+    assert report["reason"][0].as_py().startswith("repaired:")
+
+
+def test_wkb_to_cells_z_coordinates():
+    from shapely.geometry import Polygon
+
+    polygon_z = Polygon([(10.0, 45.0, 5.0), (10.2, 45.0, 7.0), (10.2, 45.2, 5.0), (10.0, 45.2, 7.0)])
+    polygon_2d = Polygon([(10.0, 45.0), (10.2, 45.0), (10.2, 45.2), (10.0, 45.2)])
+    wkbs = pa.array([polygon_z.wkb, shapely.to_wkb(polygon_z, flavor="iso"), polygon_2d.wkb], type=pa.binary())
+
+    cells = pa.array(wkb_to_cells(wkbs, 7)).to_pylist()
+    assert len(cells[2]) > 0
+    assert cells[0] == cells[2]
+    assert cells[1] == cells[2]
+
+    mean_z = pa.array(wkb_mean_z(wkbs)).to_pylist()
+    assert mean_z[0] == pytest.approx(6.0)
+    assert mean_z[1] == pytest.approx(6.0)
+    assert mean_z[2] is None
+
+    table = pa.table(
+        geometries_to_cells_with_attributes(wkbs, pa.table({"id": [1, 2, 3]}), 7, mean_z_column="height")
+    )
+    assert table.column_names == ["id", "height", "cell"]
+
+
+@pytest.mark.skipif(not has_proj_support(), reason="built without the proj feature")
+def test_cells_to_wkb_output_epsg():
+    cells = np.array([h3.geo_to_h3(50.0, 10.0, 7), h3.geo_to_h3(-20.0, -60.0, 7)], dtype=np.uint64)
+    points = [wkb.loads(g.as_py()) for g in cells_to_wkb_points(cells, output_epsg=3857)]
+    # web mercator coordinates in meters
+    assert points[0].x == pytest.approx(1113194.9, abs=3000.0)
+    assert points[1].y < -2_000_000.0
+
+    polygons = [wkb.loads(g.as_py()) for g in cells_to_wkb_polygons(cells, output_epsg=3857)]
+    assert polygons[0].contains(points[0])
+
+    with pytest.raises(ValueError):
+        cells_to_wkb_points(cells, radians=True, output_epsg=3857)
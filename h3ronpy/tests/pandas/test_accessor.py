@@ -0,0 +1,39 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import pandas as pd
+import pytest
+
+import h3ronpy.pandas  # noqa: F401
+
+
+def cell_series() -> pd.Series:
+    cells = [h3.geo_to_h3(45.5, 10.2, 8), h3.geo_to_h3(45.1, 10.3, 9)]
+    return pd.Series(np.array(cells, dtype=np.uint64), index=["a", "b"], name="cell")
+
+
+def test_to_parent():
+    series = cell_series()
+    parents = series.h3.to_parent(5)
+    assert list(parents.index) == ["a", "b"]
+    assert parents.tolist() == [h3.h3_to_parent(int(cell), 5) for cell in series]
+
+    with pytest.raises(ValueError):
+        series.h3.to_parent(9)
+
+
+def test_latlng():
+    latlng = cell_series().h3.latlng()
+    assert list(latlng.columns) == ["lat", "lng"]
+    assert list(latlng.index) == ["a", "b"]
+    assert latlng["lat"].iloc[0] == pytest.approx(45.5, abs=0.01)
+
+
+def test_is_valid_and_resolution():
+    series = pd.Series(np.array([h3.geo_to_h3(45.5, 10.2, 8), 5], dtype=np.uint64))
+    assert series.h3.is_valid().tolist() == [True, False]
+    assert cell_series().h3.resolution().tolist() == [8, 9]
+
+
+def test_nullable_dtype():
+    series = pd.Series([h3.geo_to_h3(45.5, 10.2, 8), None], dtype="UInt64")
+    assert series.h3.to_string().iloc[0] == f"{h3.geo_to_h3(45.5, 10.2, 8):x}"
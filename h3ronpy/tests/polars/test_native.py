@@ -0,0 +1,40 @@
+import h3.api.numpy_int as h3
+import numpy as np
+import polars as pl
+from h3ronpy import cells_resolution, change_resolution, grid_disk_distances, split_by_resolution
+
+
+def cell_series() -> pl.Series:
+    return pl.Series(
+        "cell",
+        np.array([h3.geo_to_h3(10.3, 45.1, 8), h3.geo_to_h3(10.3, 45.1, 6)], dtype=np.uint64),
+    )
+
+
+def test_series_in_series_out():
+    resolutions = cells_resolution(cell_series())
+    assert isinstance(resolutions, pl.Series)
+    assert resolutions.to_list() == [8, 6]
+
+    parents = change_resolution(cell_series(), 5)
+    assert isinstance(parents, pl.Series)
+    assert parents.dtype == pl.UInt64
+
+
+def test_dataframe_out():
+    df = grid_disk_distances(cell_series(), 1, flatten=True)
+    assert isinstance(df, pl.DataFrame)
+    assert df.height == 14
+
+
+def test_dataframe_in_dataframe_out():
+    df = pl.DataFrame({"cell": cell_series(), "value": [1, 2]})
+    out = cells_resolution(df, column="cell", output_column="resolution")
+    assert isinstance(out, pl.DataFrame)
+    assert out.columns == ["cell", "value", "resolution"]
+
+
+def test_dict_of_series():
+    parts = split_by_resolution(cell_series())
+    assert set(parts.keys()) == {6, 8}
+    assert all(isinstance(part, pl.Series) for part in parts.values())